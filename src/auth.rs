@@ -0,0 +1,260 @@
+//! Authentication against ServiceDesk Plus.
+//!
+//! On-premises SDP authenticates with a technician API key sent in the
+//! `authtoken` header. SDP Cloud (OnDemand) uses Zoho OAuth instead: a
+//! long-lived refresh token is exchanged for short-lived access tokens,
+//! which are sent as `Authorization: Zoho-oauthtoken <token>`.
+
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::error::GlassError;
+
+/// Default Zoho accounts server (US data center).
+pub const DEFAULT_ZOHO_ACCOUNTS_URL: &str = "https://accounts.zoho.com";
+
+/// Renew access tokens this long before they expire (seconds).
+const TOKEN_REFRESH_MARGIN_SECS: u64 = 60;
+
+/// Lifetime assumed when Zoho omits `expires_in` (seconds).
+const DEFAULT_TOKEN_LIFETIME_SECS: u64 = 3600;
+
+/// How Glass authenticates to SDP.
+#[derive(Clone)]
+pub enum AuthMode {
+    /// On-premises technician API key.
+    ApiKey(String),
+
+    /// SDP Cloud with Zoho OAuth.
+    ZohoOAuth(ZohoOAuthConfig),
+}
+
+/// Zoho OAuth client credentials and refresh token for SDP Cloud.
+///
+/// The secret and refresh token must never be logged or included in
+/// error messages.
+#[derive(Clone)]
+pub struct ZohoOAuthConfig {
+    /// OAuth client ID from the Zoho API console.
+    pub client_id: String,
+
+    /// OAuth client secret.
+    client_secret: String,
+
+    /// Refresh token granted to the client.
+    refresh_token: String,
+
+    /// Zoho accounts server for the tenant's data center
+    /// (e.g., `https://accounts.zoho.eu`).
+    pub accounts_url: String,
+}
+
+impl ZohoOAuthConfig {
+    /// Creates the OAuth configuration.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+        accounts_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            refresh_token: refresh_token.into(),
+            accounts_url: accounts_url.into(),
+        }
+    }
+}
+
+/// An access token and when it must be renewed.
+struct AccessToken {
+    token: String,
+    refresh_at: Instant,
+}
+
+/// Zoho token endpoint response. Errors come back with status 200 and an
+/// `error` field.
+#[derive(Deserialize)]
+struct TokenResponse {
+    #[serde(default)]
+    access_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Produces the authentication header for SDP calls.
+///
+/// For OAuth, the access token is cached and renewed shortly before it
+/// expires; concurrent callers wait for a single renewal.
+pub(crate) struct Authenticator {
+    mode: AuthMode,
+    http: Client,
+    token: Mutex<Option<AccessToken>>,
+}
+
+impl Authenticator {
+    /// Creates an authenticator that renews tokens with the given HTTP client.
+    pub(crate) fn new(mode: AuthMode, http: Client) -> Self {
+        Self {
+            mode,
+            http,
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Returns whether this is SDP Cloud (OAuth) authentication.
+    pub(crate) fn is_cloud(&self) -> bool {
+        matches!(self.mode, AuthMode::ZohoOAuth(_))
+    }
+
+    /// Returns the long-lived secret to redact from error messages.
+    pub(crate) fn secret(&self) -> &str {
+        match &self.mode {
+            AuthMode::ApiKey(key) => key,
+            AuthMode::ZohoOAuth(oauth) => &oauth.refresh_token,
+        }
+    }
+
+    /// Returns the header name and value authenticating one SDP call.
+    pub(crate) async fn header(&self) -> Result<(&'static str, String), GlassError> {
+        match &self.mode {
+            AuthMode::ApiKey(key) => Ok(("authtoken", key.clone())),
+            AuthMode::ZohoOAuth(oauth) => {
+                let mut cached = self.token.lock().await;
+                if let Some(token) = cached.as_ref().filter(|t| Instant::now() < t.refresh_at) {
+                    return Ok(("Authorization", format!("Zoho-oauthtoken {}", token.token)));
+                }
+
+                let token = self.refresh(oauth).await?;
+                let value = format!("Zoho-oauthtoken {}", token.token);
+                *cached = Some(token);
+                Ok(("Authorization", value))
+            }
+        }
+    }
+
+    /// Drops the cached access token so the next call renews it.
+    ///
+    /// Used when SDP rejects a token before its expected expiry.
+    pub(crate) async fn invalidate(&self) {
+        if self.is_cloud() {
+            *self.token.lock().await = None;
+        }
+    }
+
+    /// Exchanges the refresh token for a new access token.
+    async fn refresh(&self, oauth: &ZohoOAuthConfig) -> Result<AccessToken, GlassError> {
+        tracing::debug!(accounts_url = %oauth.accounts_url, "Renewing Zoho OAuth access token");
+
+        let url = format!("{}/oauth/v2/token", oauth.accounts_url);
+        let response = self
+            .http
+            .post(&url)
+            .form(&[
+                ("grant_type", "refresh_token"),
+                ("client_id", oauth.client_id.as_str()),
+                ("client_secret", oauth.client_secret.as_str()),
+                ("refresh_token", oauth.refresh_token.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(GlassError::Http)?;
+
+        if !response.status().is_success() {
+            tracing::warn!(status = %response.status(), "Zoho token endpoint rejected the refresh");
+            return Err(GlassError::Authentication);
+        }
+
+        let body: TokenResponse = response.json().await.map_err(GlassError::Http)?;
+        match (body.access_token, body.error) {
+            (Some(token), None) => {
+                let lifetime = body
+                    .expires_in
+                    .unwrap_or(DEFAULT_TOKEN_LIFETIME_SECS)
+                    .saturating_sub(TOKEN_REFRESH_MARGIN_SECS);
+                Ok(AccessToken {
+                    token,
+                    refresh_at: Instant::now() + Duration::from_secs(lifetime),
+                })
+            }
+            (_, error) => {
+                tracing::warn!(
+                    error = error.as_deref().unwrap_or("no access token"),
+                    "Zoho OAuth token refresh failed"
+                );
+                Err(GlassError::Authentication)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_api_key_header() {
+        let auth = Authenticator::new(AuthMode::ApiKey("key123".to_string()), Client::new());
+        assert_eq!(
+            auth.header().await.unwrap(),
+            ("authtoken", "key123".to_string())
+        );
+        assert_eq!(auth.secret(), "key123");
+        assert!(!auth.is_cloud());
+    }
+
+    #[tokio::test]
+    async fn test_oauth_token_is_cached_until_invalidated() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "tok1",
+                "expires_in": 3600
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let oauth = ZohoOAuthConfig::new("id", "secret", "refresh", server.uri());
+        let auth = Authenticator::new(AuthMode::ZohoOAuth(oauth), Client::new());
+        let expected = ("Authorization", "Zoho-oauthtoken tok1".to_string());
+        assert_eq!(auth.header().await.unwrap(), expected);
+        assert_eq!(auth.header().await.unwrap(), expected);
+
+        auth.invalidate().await;
+        assert_eq!(auth.header().await.unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_oauth_refresh_error_is_authentication_failure() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/v2/token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"error": "invalid_code"})),
+            )
+            .mount(&server)
+            .await;
+
+        let oauth = ZohoOAuthConfig::new("id", "secret", "refresh", server.uri());
+        let auth = Authenticator::new(AuthMode::ZohoOAuth(oauth), Client::new());
+        assert!(matches!(
+            auth.header().await.unwrap_err(),
+            GlassError::Authentication
+        ));
+    }
+}
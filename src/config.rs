@@ -3,22 +3,132 @@
 //! This module handles loading configuration from environment variables,
 //! with validation to ensure all required values are present.
 
+use crate::auth::{AuthMode, ZohoOAuthConfig, DEFAULT_ZOHO_ACCOUNTS_URL};
 use crate::error::GlassError;
+use crate::i18n::Lang;
+use crate::models::PriorityMatrix;
 use std::env;
+use std::fs;
+use std::path::Path;
+
+/// OS keyring service the API key is stored under; the account is the
+/// SDP base URL, so keys for several instances can coexist.
+pub const KEYRING_SERVICE: &str = "glass";
+
+/// Smallest accepted `GLASS_MAX_DESCRIPTION_LENGTH`, so output stays readable.
+const MIN_DESCRIPTION_LENGTH: usize = 100;
+
+/// Loads dotenv files into the process environment.
+///
+/// `override_file` is loaded first, so its values win over those in
+/// `env_file`; neither replaces variables already set in the environment.
+/// Without `env_file`, a `.env` in the working directory is loaded if
+/// present. Named files must exist.
+///
+/// # Errors
+///
+/// Returns `GlassError::Config` if a named file cannot be read or parsed.
+pub fn load_env_files(
+    env_file: Option<&Path>,
+    override_file: Option<&Path>,
+) -> Result<(), GlassError> {
+    let load = |path: &Path| {
+        dotenvy::from_path(path).map_err(|e| {
+            GlassError::invalid_config(format!("cannot load env file {}: {}", path.display(), e))
+        })
+    };
+
+    if let Some(path) = override_file {
+        load(path)?;
+    }
+    match env_file {
+        Some(path) => load(path)?,
+        None => {
+            dotenvy::dotenv().ok();
+        }
+    }
+    Ok(())
+}
 
 /// Configuration for connecting to ServiceDesk Plus.
 ///
-/// All fields are required and loaded from environment variables.
-/// The API key is stored but never logged or exposed in error messages.
+/// Fields are loaded from environment variables; only the base URL and
+/// credentials (an API key, or Zoho OAuth for SDP Cloud) are required.
+/// Credentials are stored but never logged or exposed in error messages.
 #[derive(Clone)]
 pub struct Config {
     /// Base URL for the SDP instance (e.g., `https://servicedesk.example.com`).
     pub base_url: String,
 
-    /// Technician API key for authentication.
+    /// How to authenticate: an on-prem API key or SDP Cloud OAuth.
+    /// Contains secrets that must never be logged or included in error
+    /// messages. Accessed via the `auth()` getter for security.
+    auth: AuthMode,
+
+    /// SDP user ID of the technician that owns the API key.
+    /// Used by `pickup_request` to assign tickets to "me"; when unset,
+    /// SDP's own pickup action is used instead.
+    pub technician_id: Option<String>,
+
+    /// When true, all write operations are rejected before reaching SDP.
+    pub read_only: bool,
+
+    /// Explicit HTTP(S) proxy for reaching SDP. When unset, the standard
+    /// `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` variables are honored.
+    pub proxy_url: Option<String>,
+
+    /// Username for proxy basic authentication.
+    pub proxy_username: Option<String>,
+
+    /// Password for proxy basic authentication.
     /// This value must never be logged or included in error messages.
-    /// Accessed via the `api_key()` getter for security.
-    api_key: String,
+    proxy_password: Option<String>,
+
+    /// Path to an extra PEM root certificate to trust, for SDP servers
+    /// signed by a private CA.
+    pub ca_cert_path: Option<String>,
+
+    /// When true, TLS certificate validation is disabled entirely.
+    /// Only for testing against servers with broken certificates.
+    pub accept_invalid_certs: bool,
+
+    /// ServiceDesk Plus MSP portal (account) ID sent with every call,
+    /// unless a tool call names another portal.
+    pub portal_id: Option<String>,
+
+    /// How long read responses (request details, technicians, metadata)
+    /// are cached, in seconds. `None` disables the cache.
+    pub cache_ttl_secs: Option<u64>,
+
+    /// Endpoints to treat as missing on this SDP build (e.g., "projects").
+    /// When set, the startup capability probe is skipped.
+    pub unsupported_endpoints: Option<Vec<String>>,
+
+    /// Length in characters at which descriptions, resolutions, and article
+    /// content are truncated in tool output. `None` uses the server default.
+    pub max_description_length: Option<usize>,
+
+    /// Language of labels and placeholders in tool output.
+    pub language: Lang,
+
+    /// Page size of list and search tools when the caller sets none.
+    pub default_limit: Option<u32>,
+
+    /// Support group list and search tools filter by unless told otherwise.
+    pub default_group: Option<String>,
+
+    /// Site list and search tools filter by unless told otherwise.
+    pub default_site: Option<String>,
+
+    /// Signature template appended to replies and requester-visible notes.
+    pub reply_signature: Option<String>,
+
+    /// Directory that export tools save files to; saving is off when unset.
+    pub export_dir: Option<String>,
+
+    /// Impact and urgency to priority mapping for `suggest_priority`.
+    /// `None` uses the default ITIL matrix.
+    pub priority_matrix: Option<PriorityMatrix>,
 }
 
 impl Config {
@@ -27,7 +137,38 @@ impl Config {
     /// # Required Environment Variables
     ///
     /// - `SDP_BASE_URL`: The base URL of the ServiceDesk Plus instance
-    /// - `SDP_API_KEY`: The technician API key for authentication
+    /// - `SDP_API_KEY`: The technician API key for authentication, or for
+    ///   SDP Cloud `SDP_OAUTH_CLIENT_ID`, `SDP_OAUTH_CLIENT_SECRET`, and
+    ///   `SDP_OAUTH_REFRESH_TOKEN`. With the `keyring` feature, an unset
+    ///   `SDP_API_KEY` is read from the OS keyring instead
+    ///
+    /// `SDP_API_KEY_FILE` may name a file holding the API key instead of
+    /// `SDP_API_KEY`, e.g. a mounted Docker or Kubernetes secret.
+    ///
+    /// # Optional Environment Variables
+    ///
+    /// - `SDP_OAUTH_ACCOUNTS_URL`: Zoho accounts server for OAuth (default: `https://accounts.zoho.com`)
+    /// - `SDP_PORTAL_ID`: Default ServiceDesk Plus MSP portal (account) ID
+    /// - `GLASS_TECHNICIAN_ID`: The SDP user ID of the API key's technician
+    /// - `GLASS_READ_ONLY`: Set to `true` or `1` to disable write operations
+    /// - `SDP_PROXY_URL`: HTTP(S) proxy to route SDP traffic through
+    /// - `SDP_PROXY_USERNAME` / `SDP_PROXY_PASSWORD`: Proxy credentials
+    /// - `SDP_CA_CERT`: Path to an extra PEM root certificate to trust
+    /// - `SDP_ACCEPT_INVALID_CERTS`: Set to `true` to skip TLS certificate validation
+    /// - `GLASS_CACHE_TTL_SECS`: Cache read responses for this many seconds (off when unset or 0)
+    /// - `GLASS_UNSUPPORTED_ENDPOINTS`: Comma-separated endpoints this SDP build lacks, or `none`;
+    ///   replaces the startup capability probe
+    /// - `GLASS_MAX_DESCRIPTION_LENGTH`: Characters of a description or resolution to show
+    ///   before truncating (default: 2000)
+    /// - `GLASS_LANG`: Language of output labels, `en` or `da` (default: `en`)
+    /// - `GLASS_DEFAULT_LIMIT`: Page size of list and search tools, 1-100 (default: 20)
+    /// - `GLASS_DEFAULT_GROUP` / `GLASS_DEFAULT_SITE`: Group and site that ticket lists
+    ///   and searches are filtered to unless the caller picks another (or `any`)
+    /// - `GLASS_REPLY_SIGNATURE`: Signature appended to replies and requester-visible
+    ///   notes; `{technician}`, `{email}`, and `{job_title}` are filled in
+    /// - `GLASS_EXPORT_DIR`: Directory `export_requests_csv` may save CSV files to
+    /// - `GLASS_PRIORITY_MATRIX`: Priority matrix as `impact/urgency=priority` cells
+    ///   separated by `;`, mirroring the one configured in SDP
     ///
     /// # Errors
     ///
@@ -42,23 +183,138 @@ impl Config {
     /// ```
     pub fn from_env() -> Result<Self, GlassError> {
         let base_url = Self::get_required_env("SDP_BASE_URL")?;
-        let api_key = Self::get_required_env("SDP_API_KEY")?;
 
         // Validate base URL format
         let base_url = Self::validate_base_url(base_url)?;
 
-        // Validate API key is not empty or placeholder
-        Self::validate_api_key(&api_key)?;
+        let oauth = Self::oauth_from_parts(
+            Self::get_optional_env("SDP_OAUTH_CLIENT_ID"),
+            Self::get_optional_env("SDP_OAUTH_CLIENT_SECRET"),
+            Self::get_optional_env("SDP_OAUTH_REFRESH_TOKEN"),
+            Self::get_optional_env("SDP_OAUTH_ACCOUNTS_URL"),
+        )?;
+        let api_key = Self::api_key_from_env()?;
+        let auth = match oauth {
+            Some(oauth) => {
+                if api_key.is_some() {
+                    return Err(GlassError::invalid_config(
+                        "set either SDP_API_KEY or the SDP_OAUTH_* variables, not both",
+                    ));
+                }
+                AuthMode::ZohoOAuth(oauth)
+            }
+            None => {
+                let api_key = match api_key {
+                    Some(api_key) => api_key,
+                    None => Self::keyring_api_key(&base_url)?
+                        .ok_or_else(|| GlassError::missing_env("SDP_API_KEY"))?,
+                };
+                // Validate API key is not empty or placeholder
+                Self::validate_api_key(&api_key)?;
+                AuthMode::ApiKey(api_key)
+            }
+        };
+
+        let portal_id = Self::get_optional_env("SDP_PORTAL_ID");
+        if let Some(portal) = &portal_id {
+            Self::validate_portal_id(portal)?;
+        }
+
+        let technician_id = Self::get_optional_env("GLASS_TECHNICIAN_ID");
+        if let Some(id) = &technician_id {
+            Self::validate_technician_id(id)?;
+        }
+
+        let read_only = Self::get_optional_env("GLASS_READ_ONLY")
+            .map(|value| Self::parse_flag(&value))
+            .unwrap_or(false);
+
+        let proxy_url = Self::get_optional_env("SDP_PROXY_URL")
+            .map(Self::validate_proxy_url)
+            .transpose()?;
+        let proxy_username = Self::get_optional_env("SDP_PROXY_USERNAME");
+        let proxy_password = Self::get_optional_env("SDP_PROXY_PASSWORD");
+        Self::validate_proxy_credentials(
+            proxy_url.as_deref(),
+            proxy_username.as_deref(),
+            proxy_password.as_deref(),
+        )?;
+
+        let ca_cert_path = Self::get_optional_env("SDP_CA_CERT");
+        let accept_invalid_certs = Self::get_optional_env("SDP_ACCEPT_INVALID_CERTS")
+            .map(|value| Self::parse_flag(&value))
+            .unwrap_or(false);
+        if accept_invalid_certs {
+            tracing::warn!(
+                "SDP_ACCEPT_INVALID_CERTS is set - TLS certificates are not validated. Do not use in production."
+            );
+        }
+
+        let cache_ttl_secs = Self::get_optional_env("GLASS_CACHE_TTL_SECS")
+            .map(|value| Self::parse_cache_ttl(&value))
+            .transpose()?
+            .flatten();
+
+        let unsupported_endpoints = Self::get_optional_env("GLASS_UNSUPPORTED_ENDPOINTS")
+            .map(|value| Self::parse_endpoints(&value));
 
-        Ok(Config { base_url, api_key })
+        let max_description_length = Self::get_optional_env("GLASS_MAX_DESCRIPTION_LENGTH")
+            .map(|value| Self::parse_max_description_length(&value))
+            .transpose()?;
+
+        let language = Self::get_optional_env("GLASS_LANG")
+            .map(|value| Self::parse_language(&value))
+            .transpose()?
+            .unwrap_or_default();
+
+        let default_limit = Self::get_optional_env("GLASS_DEFAULT_LIMIT")
+            .map(|value| Self::parse_default_limit(&value))
+            .transpose()?;
+        let default_group = Self::get_optional_env("GLASS_DEFAULT_GROUP");
+        let default_site = Self::get_optional_env("GLASS_DEFAULT_SITE");
+        let reply_signature = Self::get_optional_env("GLASS_REPLY_SIGNATURE");
+        let export_dir = Self::get_optional_env("GLASS_EXPORT_DIR");
+        let priority_matrix = Self::get_optional_env("GLASS_PRIORITY_MATRIX")
+            .map(|value| Self::parse_priority_matrix(&value))
+            .transpose()?;
+
+        Ok(Config {
+            base_url,
+            auth,
+            technician_id,
+            read_only,
+            proxy_url,
+            proxy_username,
+            proxy_password,
+            ca_cert_path,
+            accept_invalid_certs,
+            portal_id,
+            cache_ttl_secs,
+            unsupported_endpoints,
+            max_description_length,
+            language,
+            default_limit,
+            default_group,
+            default_site,
+            reply_signature,
+            export_dir,
+            priority_matrix,
+        })
+    }
+
+    /// Returns the authentication mode and its credentials.
+    ///
+    /// The field is private to prevent accidental exposure.
+    /// Use this getter only to build the SDP client.
+    pub fn auth(&self) -> &AuthMode {
+        &self.auth
     }
 
-    /// Returns a reference to the API key.
+    /// Returns the proxy password, if configured.
     ///
-    /// The API key field is private to prevent accidental exposure.
-    /// Use this getter when the key is needed (e.g., for HTTP headers).
-    pub fn api_key(&self) -> &str {
-        &self.api_key
+    /// Kept private like the API key; use this getter only to build the proxy.
+    pub fn proxy_password(&self) -> Option<&str> {
+        self.proxy_password.as_deref()
     }
 
     /// Gets a required environment variable, returning an error if missing or empty.
@@ -74,6 +330,218 @@ impl Config {
             })
     }
 
+    /// Reads the API key from `SDP_API_KEY`, or from the file named by
+    /// `SDP_API_KEY_FILE`.
+    fn api_key_from_env() -> Result<Option<String>, GlassError> {
+        match (
+            Self::get_optional_env("SDP_API_KEY"),
+            Self::get_optional_env("SDP_API_KEY_FILE"),
+        ) {
+            (Some(_), Some(_)) => Err(GlassError::invalid_config(
+                "set either SDP_API_KEY or SDP_API_KEY_FILE, not both",
+            )),
+            (Some(api_key), None) => Ok(Some(api_key)),
+            (None, Some(path)) => Self::read_secret_file(&path).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Reads a secret from a file, trimming surrounding whitespace such as
+    /// the trailing newline most secret files end with.
+    fn read_secret_file(path: &str) -> Result<String, GlassError> {
+        let secret = fs::read_to_string(path).map_err(|e| {
+            GlassError::invalid_config(format!("cannot read SDP_API_KEY_FILE {}: {}", path, e))
+        })?;
+        let secret = secret.trim();
+        if secret.is_empty() {
+            return Err(GlassError::invalid_config(format!(
+                "SDP_API_KEY_FILE {} is empty",
+                path
+            )));
+        }
+        Ok(secret.to_string())
+    }
+
+    /// Reads the API key stored in the OS keyring for `base_url`, if any.
+    #[cfg(feature = "keyring")]
+    fn keyring_api_key(base_url: &str) -> Result<Option<String>, GlassError> {
+        match keyring::Entry::new(KEYRING_SERVICE, base_url).and_then(|e| e.get_password()) {
+            Ok(api_key) => Ok(Some(api_key)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(GlassError::invalid_config(format!(
+                "could not read the API key from the OS keyring: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Without the `keyring` feature there is no keyring to fall back to.
+    #[cfg(not(feature = "keyring"))]
+    fn keyring_api_key(_base_url: &str) -> Result<Option<String>, GlassError> {
+        Ok(None)
+    }
+
+    /// Stores an API key in the OS keyring for the given SDP base URL, so
+    /// `from_env` finds it when `SDP_API_KEY` is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GlassError::Config` if the URL or key is invalid, the
+    /// keyring cannot be written, or the build lacks the `keyring` feature.
+    pub fn store_api_key(base_url: &str, api_key: &str) -> Result<(), GlassError> {
+        let base_url = Self::validate_base_url(base_url.to_string())?;
+        let api_key = api_key.trim();
+        if api_key.is_empty() {
+            return Err(GlassError::invalid_config("API key is empty"));
+        }
+        Self::validate_api_key(api_key)?;
+        Self::write_keyring(&base_url, api_key)
+    }
+
+    #[cfg(feature = "keyring")]
+    fn write_keyring(base_url: &str, api_key: &str) -> Result<(), GlassError> {
+        keyring::Entry::new(KEYRING_SERVICE, base_url)
+            .and_then(|e| e.set_password(api_key))
+            .map_err(|e| {
+                GlassError::invalid_config(format!(
+                    "could not store the API key in the OS keyring: {}",
+                    e
+                ))
+            })
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    fn write_keyring(_base_url: &str, _api_key: &str) -> Result<(), GlassError> {
+        Err(GlassError::invalid_config(
+            "this build lacks the `keyring` feature; rebuild with --features keyring",
+        ))
+    }
+
+    /// Gets an optional environment variable, treating empty values as unset.
+    fn get_optional_env(name: &str) -> Option<String> {
+        env::var(name)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Parses a boolean flag value such as `true`, `1`, or `yes`.
+    fn parse_flag(value: &str) -> bool {
+        matches!(
+            value.to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    }
+
+    /// Parses a comma-separated endpoint list; `none` means an empty list.
+    fn parse_endpoints(value: &str) -> Vec<String> {
+        if value.eq_ignore_ascii_case("none") {
+            return Vec::new();
+        }
+        value
+            .split(',')
+            .map(|name| name.trim().trim_matches('/').to_ascii_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Parses the cache TTL in seconds; 0 disables the cache.
+    fn parse_cache_ttl(value: &str) -> Result<Option<u64>, GlassError> {
+        let secs = value.parse::<u64>().map_err(|_| {
+            GlassError::invalid_config("GLASS_CACHE_TTL_SECS must be a whole number of seconds")
+        })?;
+        Ok((secs > 0).then_some(secs))
+    }
+
+    /// Parses the description truncation length, which must be at least
+    /// `MIN_DESCRIPTION_LENGTH` characters.
+    fn parse_max_description_length(value: &str) -> Result<usize, GlassError> {
+        value
+            .parse::<usize>()
+            .ok()
+            .filter(|&length| length >= MIN_DESCRIPTION_LENGTH)
+            .ok_or_else(|| {
+                GlassError::invalid_config(format!(
+                    "GLASS_MAX_DESCRIPTION_LENGTH must be a whole number of at least {}",
+                    MIN_DESCRIPTION_LENGTH
+                ))
+            })
+    }
+
+    /// Parses the default page size, which must be between 1 and 100.
+    fn parse_default_limit(value: &str) -> Result<u32, GlassError> {
+        value
+            .parse::<u32>()
+            .ok()
+            .filter(|limit| (1..=100).contains(limit))
+            .ok_or_else(|| {
+                GlassError::invalid_config(
+                    "GLASS_DEFAULT_LIMIT must be a whole number from 1 to 100",
+                )
+            })
+    }
+
+    /// Parses the output language code.
+    fn parse_language(value: &str) -> Result<Lang, GlassError> {
+        Lang::parse(value)
+            .ok_or_else(|| GlassError::invalid_config("GLASS_LANG must be 'en' or 'da'"))
+    }
+
+    /// Parses a priority matrix of `impact/urgency=priority` cells separated by `;`.
+    fn parse_priority_matrix(value: &str) -> Result<PriorityMatrix, GlassError> {
+        let cells = value
+            .split(';')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .map(|cell| {
+                let parsed = cell.split_once('=').and_then(|(key, priority)| {
+                    let (impact, urgency) = key.split_once('/')?;
+                    let parts = [impact.trim(), urgency.trim(), priority.trim()];
+                    parts.iter().all(|p| !p.is_empty()).then_some(parts)
+                });
+                parsed.ok_or_else(|| {
+                    GlassError::invalid_config(format!(
+                        "GLASS_PRIORITY_MATRIX cell {:?} must look like impact/urgency=priority",
+                        cell
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if cells.is_empty() {
+            return Err(GlassError::invalid_config(
+                "GLASS_PRIORITY_MATRIX has no impact/urgency=priority cells",
+            ));
+        }
+        Ok(PriorityMatrix::new(cells.into_iter().map(
+            |[impact, urgency, priority]| (impact, urgency, priority),
+        )))
+    }
+
+    /// Validates an MSP portal ID; it is sent as a header, so only letters,
+    /// digits, `-` and `_` are allowed.
+    pub(crate) fn validate_portal_id(portal: &str) -> Result<(), GlassError> {
+        let valid = !portal.is_empty()
+            && portal
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(GlassError::invalid_config(
+                "portal ID may only contain letters, digits, '-' and '_'",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates the technician ID is numeric.
+    fn validate_technician_id(id: &str) -> Result<(), GlassError> {
+        if !id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(GlassError::invalid_config(
+                "GLASS_TECHNICIAN_ID must be a numeric SDP user ID",
+            ));
+        }
+        Ok(())
+    }
+
     /// Validates and normalizes the base URL.
     fn validate_base_url(url: String) -> Result<String, GlassError> {
         let url = url.trim().to_string();
@@ -98,6 +566,68 @@ impl Config {
         Ok(url)
     }
 
+    /// Validates and normalizes the proxy URL.
+    fn validate_proxy_url(url: String) -> Result<String, GlassError> {
+        let url = url.trim().trim_end_matches('/').to_string();
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(GlassError::invalid_config(
+                "SDP_PROXY_URL must start with http:// or https://",
+            ));
+        }
+        Ok(url)
+    }
+
+    /// Validates that proxy credentials come with a proxy URL and a username.
+    fn validate_proxy_credentials(
+        url: Option<&str>,
+        username: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<(), GlassError> {
+        if url.is_none() && (username.is_some() || password.is_some()) {
+            return Err(GlassError::invalid_config(
+                "SDP_PROXY_USERNAME and SDP_PROXY_PASSWORD require SDP_PROXY_URL",
+            ));
+        }
+        if username.is_none() && password.is_some() {
+            return Err(GlassError::invalid_config(
+                "SDP_PROXY_PASSWORD requires SDP_PROXY_USERNAME",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Builds the SDP Cloud OAuth configuration, if any OAuth variable is set.
+    ///
+    /// Client ID, secret, and refresh token must then all be present.
+    fn oauth_from_parts(
+        client_id: Option<String>,
+        client_secret: Option<String>,
+        refresh_token: Option<String>,
+        accounts_url: Option<String>,
+    ) -> Result<Option<ZohoOAuthConfig>, GlassError> {
+        let accounts_url = accounts_url
+            .map(|url| url.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| DEFAULT_ZOHO_ACCOUNTS_URL.to_string());
+        if !accounts_url.starts_with("https://") {
+            return Err(GlassError::invalid_config(
+                "SDP_OAUTH_ACCOUNTS_URL must start with https://",
+            ));
+        }
+
+        match (client_id, client_secret, refresh_token) {
+            (None, None, None) => Ok(None),
+            (Some(id), Some(secret), Some(refresh)) => Ok(Some(ZohoOAuthConfig::new(
+                id,
+                secret,
+                refresh,
+                accounts_url,
+            ))),
+            _ => Err(GlassError::invalid_config(
+                "SDP Cloud OAuth needs SDP_OAUTH_CLIENT_ID, SDP_OAUTH_CLIENT_SECRET, and SDP_OAUTH_REFRESH_TOKEN",
+            )),
+        }
+    }
+
     /// Validates the API key is not a placeholder value.
     fn validate_api_key(key: &str) -> Result<(), GlassError> {
         let key_lower = key.to_lowercase();
@@ -122,6 +652,57 @@ mod tests {
     // Note: Tests that modify environment variables should not run in parallel.
     // Use `cargo test -- --test-threads=1` for full integration tests.
 
+    #[test]
+    fn test_load_env_files_override_wins() {
+        let dir = std::env::temp_dir();
+        let base = dir.join("glass-test-base.env");
+        let extra = dir.join("glass-test-override.env");
+        std::fs::write(
+            &base,
+            "GLASS_TEST_ENV_SHARED=base\nGLASS_TEST_ENV_BASE_ONLY=base\n",
+        )
+        .unwrap();
+        std::fs::write(&extra, "GLASS_TEST_ENV_SHARED=override\n").unwrap();
+
+        let result = load_env_files(Some(&base), Some(&extra));
+        std::fs::remove_file(&base).ok();
+        std::fs::remove_file(&extra).ok();
+        result.unwrap();
+        assert_eq!(env::var("GLASS_TEST_ENV_SHARED").unwrap(), "override");
+        assert_eq!(env::var("GLASS_TEST_ENV_BASE_ONLY").unwrap(), "base");
+
+        let err = load_env_files(Some(Path::new("/nonexistent/glass.env")), None).unwrap_err();
+        assert!(err.to_string().contains("cannot load env file"));
+    }
+
+    #[test]
+    fn test_read_secret_file() {
+        let err = Config::read_secret_file("/nonexistent/glass-api-key").unwrap_err();
+        assert!(err.to_string().contains("cannot read SDP_API_KEY_FILE"));
+
+        let path = std::env::temp_dir().join("glass-test-api-key");
+        std::fs::write(&path, "abc123\n").unwrap();
+        let secret = Config::read_secret_file(path.to_str().unwrap());
+        std::fs::write(&path, " \n").unwrap();
+        let empty = Config::read_secret_file(path.to_str().unwrap());
+        std::fs::remove_file(&path).ok();
+        assert_eq!(secret.unwrap(), "abc123");
+        assert!(empty.unwrap_err().to_string().contains("is empty"));
+    }
+
+    #[test]
+    fn test_store_api_key_validates_input() {
+        assert!(Config::store_api_key("servicedesk.example.com", "abc123").is_err());
+        assert!(Config::store_api_key("https://servicedesk.example.com", "  ").is_err());
+    }
+
+    #[cfg(not(feature = "keyring"))]
+    #[test]
+    fn test_store_api_key_requires_feature() {
+        let err = Config::store_api_key("https://servicedesk.example.com", "abc123").unwrap_err();
+        assert!(err.to_string().contains("--features keyring"));
+    }
+
     #[test]
     fn test_validate_base_url_removes_trailing_slash() {
         let result = Config::validate_base_url("https://example.com/".to_string()).unwrap();
@@ -145,4 +726,122 @@ mod tests {
         let result = Config::validate_api_key("abc123def456");
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_oauth_from_parts() {
+        let some = |v: &str| Some(v.to_string());
+        assert!(Config::oauth_from_parts(None, None, None, None)
+            .unwrap()
+            .is_none());
+
+        let oauth = Config::oauth_from_parts(
+            some("1000.ABC"),
+            some("secret"),
+            some("1000.refresh"),
+            some("https://accounts.zoho.eu/"),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(oauth.client_id, "1000.ABC");
+        assert_eq!(oauth.accounts_url, "https://accounts.zoho.eu");
+
+        // Partial credentials are a mistake, not a fallback to the API key
+        assert!(Config::oauth_from_parts(some("1000.ABC"), None, None, None).is_err());
+        assert!(Config::oauth_from_parts(
+            some("1000.ABC"),
+            some("secret"),
+            some("1000.refresh"),
+            some("http://accounts.zoho.com"),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_technician_id() {
+        assert!(Config::validate_technician_id("12345").is_ok());
+        assert!(Config::validate_technician_id("12a45").is_err());
+    }
+
+    #[test]
+    fn test_parse_flag() {
+        assert!(Config::parse_flag("true"));
+        assert!(Config::parse_flag("1"));
+        assert!(Config::parse_flag("YES"));
+        assert!(!Config::parse_flag("false"));
+        assert!(!Config::parse_flag("0"));
+    }
+
+    #[test]
+    fn test_validate_portal_id() {
+        assert!(Config::validate_portal_id("10001").is_ok());
+        assert!(Config::validate_portal_id("acme_corp-2").is_ok());
+        assert!(Config::validate_portal_id("").is_err());
+        assert!(Config::validate_portal_id("1\r\nX-Evil: 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_endpoints() {
+        assert_eq!(
+            Config::parse_endpoints("Projects, /assets/ ,,worklogs"),
+            vec!["projects", "assets", "worklogs"]
+        );
+        assert!(Config::parse_endpoints("none").is_empty());
+    }
+
+    #[test]
+    fn test_parse_cache_ttl() {
+        assert_eq!(Config::parse_cache_ttl("60").unwrap(), Some(60));
+        assert_eq!(Config::parse_cache_ttl("0").unwrap(), None);
+        assert!(Config::parse_cache_ttl("1m").is_err());
+        assert!(Config::parse_cache_ttl("-5").is_err());
+    }
+
+    #[test]
+    fn test_parse_max_description_length() {
+        assert_eq!(Config::parse_max_description_length("8000").unwrap(), 8000);
+        assert!(Config::parse_max_description_length("50").is_err());
+        assert!(Config::parse_max_description_length("lots").is_err());
+    }
+
+    #[test]
+    fn test_parse_default_limit() {
+        assert_eq!(Config::parse_default_limit("50").unwrap(), 50);
+        assert!(Config::parse_default_limit("0").is_err());
+        assert!(Config::parse_default_limit("500").is_err());
+    }
+
+    #[test]
+    fn test_parse_language() {
+        assert_eq!(Config::parse_language("da").unwrap(), Lang::Da);
+        assert_eq!(Config::parse_language("English").unwrap(), Lang::En);
+        assert!(Config::parse_language("sv").is_err());
+    }
+
+    #[test]
+    fn test_parse_priority_matrix() {
+        let matrix = Config::parse_priority_matrix("High/Urgent = P1; High/Low=P3;").unwrap();
+        assert_eq!(matrix.priority("high", "urgent"), Some("P1"));
+        assert_eq!(matrix.priority("High", "Low"), Some("P3"));
+        assert!(Config::parse_priority_matrix("High=P1").is_err());
+        assert!(Config::parse_priority_matrix("High/Low=").is_err());
+        assert!(Config::parse_priority_matrix(" ; ").is_err());
+    }
+
+    #[test]
+    fn test_validate_proxy_url() {
+        assert_eq!(
+            Config::validate_proxy_url(" http://proxy.example.com:3128/ ".to_string()).unwrap(),
+            "http://proxy.example.com:3128"
+        );
+        assert!(Config::validate_proxy_url("proxy.example.com:3128".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_validate_proxy_credentials() {
+        let url = Some("http://proxy:3128");
+        assert!(Config::validate_proxy_credentials(url, Some("svc"), Some("pw")).is_ok());
+        assert!(Config::validate_proxy_credentials(url, None, None).is_ok());
+        assert!(Config::validate_proxy_credentials(url, None, Some("pw")).is_err());
+        assert!(Config::validate_proxy_credentials(None, Some("svc"), None).is_err());
+    }
 }
@@ -99,14 +99,38 @@ pub enum GlassError {
         id: String,
     },
 
-    /// Authentication failed - likely an invalid API key.
-    #[error("authentication failed - check SDP_API_KEY")]
+    /// Authentication failed - likely an invalid API key or OAuth token.
+    #[error("authentication failed - check SDP_API_KEY or the SDP_OAUTH_* credentials")]
     Authentication,
 
     /// Input validation failed.
     #[error("validation error: {0}")]
     Validation(String),
 
+    /// A write operation was attempted while read-only mode is enabled.
+    #[error("write operations are disabled (GLASS_READ_ONLY is set)")]
+    ReadOnly,
+
+    /// SDP failed to respond to several calls in a row, so calls fail fast
+    /// until a periodic probe gets through.
+    #[error("SDP unreachable since {since} - failing fast until it responds again")]
+    Unreachable {
+        /// Local time (HH:MM) of the first failure in the current outage.
+        since: String,
+    },
+
+    /// The endpoint does not exist on this SDP build, per the startup probe
+    /// or `GLASS_UNSUPPORTED_ENDPOINTS`.
+    #[error("{endpoint} is not supported by your SDP version")]
+    Unsupported {
+        /// The endpoint name (e.g., "projects").
+        endpoint: String,
+    },
+
+    /// The MCP client cancelled the tool call before the operation finished.
+    #[error("operation cancelled by the client")]
+    Cancelled,
+
     /// Connection test failed.
     #[error("connection test failed: {message}")]
     ConnectionTest {
@@ -187,6 +211,18 @@ impl GlassError {
         }
     }
 
+    /// Returns true if SDP could not be reached at all (connection failure or timeout).
+    ///
+    /// Unlike HTTP error statuses, these mean no response arrived.
+    #[must_use]
+    pub fn is_connection_failure(&self) -> bool {
+        match self {
+            GlassError::Timeout { .. } => true,
+            GlassError::Http(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+
     /// Returns true if this is a rate limit error, indicating we should back off.
     #[must_use]
     pub fn is_rate_limit(&self) -> bool {
@@ -253,6 +289,13 @@ mod tests {
         assert_eq!(err.to_string(), "validation error: subject is required");
     }
 
+    #[test]
+    fn test_read_only_error_is_not_retryable() {
+        let err = GlassError::ReadOnly;
+        assert!(err.to_string().contains("GLASS_READ_ONLY"));
+        assert!(!err.is_retryable());
+    }
+
     #[test]
     fn test_not_found_error() {
         let err = GlassError::not_found("12345");
@@ -349,4 +392,20 @@ mod tests {
         assert!(msg.contains("connection test failed"));
         assert!(msg.contains("Could not reach server"));
     }
+
+    #[test]
+    fn test_is_connection_failure() {
+        assert!(
+            GlassError::timeout(Duration::from_secs(30), "GET /requests").is_connection_failure()
+        );
+        assert!(!GlassError::Authentication.is_connection_failure());
+        let err = GlassError::Unreachable {
+            since: "14:05".to_string(),
+        };
+        assert!(!err.is_retryable());
+        assert_eq!(
+            err.to_string(),
+            "SDP unreachable since 14:05 - failing fast until it responds again"
+        );
+    }
 }
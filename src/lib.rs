@@ -19,9 +19,12 @@
 //!
 //! - [`config`] - Configuration loading from environment variables
 //! - [`error`] - Error types with security-conscious message sanitization
+//! - [`i18n`] - Output language for tool responses
 //! - [`sdp_client`] - HTTP client for the ServiceDesk Plus API
 //! - [`server`] - MCP server implementation with tool routing
 //! - [`models`] - Data models for SDP API requests and responses
+//! - [`prompts`] - MCP prompts for common workflows
+//! - [`rendering`] - Plain-text rendering of SDP HTML content
 //! - [`tools`] - Tool input parameter structs
 //!
 //! ## Usage
@@ -42,9 +45,16 @@
 //! Glass requires two environment variables:
 //!
 //! - `SDP_BASE_URL`: Base URL of your ServiceDesk Plus instance
-//! - `SDP_API_KEY`: Technician API key for authentication
+//! - `SDP_API_KEY`: Technician API key for authentication (for SDP Cloud,
+//!   `SDP_OAUTH_CLIENT_ID`, `SDP_OAUTH_CLIENT_SECRET`, and
+//!   `SDP_OAUTH_REFRESH_TOKEN` instead)
 //!
 //! Optional:
+//! - `GLASS_TECHNICIAN_ID`: SDP user ID of the API key's technician
+//! - `GLASS_READ_ONLY`: Set to `true` to disable write operations
+//! - `GLASS_CACHE_TTL_SECS`: Cache read responses for this many seconds
+//! - `GLASS_MAX_DESCRIPTION_LENGTH`: Characters of a description to show before truncating
+//! - `GLASS_LANG`: Language of output labels (`en` or `da`)
 //! - `RUST_LOG`: Log level (e.g., `glass=debug`)
 //!
 //! ## Security Considerations
@@ -84,9 +94,14 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+pub mod auth;
 pub mod config;
 pub mod error;
+pub mod i18n;
 pub mod models;
+pub mod prompts;
+pub mod rendering;
 pub mod sdp_client;
 pub mod server;
+pub mod telemetry;
 pub mod tools;
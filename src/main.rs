@@ -19,30 +19,61 @@
 //!
 //! # With environment variables
 //! SDP_BASE_URL=https://servicedesk.example.com SDP_API_KEY=xxx ./glass
+//!
+//! # Separate configurations side by side; the override file's values win
+//! ./glass --env-file ~/glass/prod.env --env-override ~/glass/local.env
+//!
+//! # Save the API key in the OS keyring (`keyring` feature), read from stdin
+//! SDP_BASE_URL=https://servicedesk.example.com ./glass --store-api-key
 //! ```
 
-use anyhow::{Context, Result};
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
 use rmcp::{transport::stdio, ServiceExt};
-use tracing_subscriber::{fmt, EnvFilter};
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
+use glass::telemetry::Telemetry;
 use glass::{config, sdp_client, server};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Load .env file if present (ignore errors if not found)
-    dotenvy::dotenv().ok();
+    let args = Args::parse(std::env::args().skip(1))?;
+
+    // Load the env files; without one, .env is used if present
+    let env_file = args
+        .env_file
+        .or_else(|| std::env::var_os("GLASS_ENV_FILE").map(PathBuf::from));
+    let env_override = args
+        .env_override
+        .or_else(|| std::env::var_os("GLASS_ENV_OVERRIDE_FILE").map(PathBuf::from));
+    config::load_env_files(env_file.as_deref(), env_override.as_deref())
+        .context("Failed to load environment files")?;
+
+    if args.store_api_key {
+        return store_api_key();
+    }
+
+    // Start OTLP export first so the subscriber can forward spans to it
+    let telemetry = Telemetry::init().context("Failed to initialize OpenTelemetry")?;
 
     // Initialize logging to stderr (critical for stdio transport!)
     // stdout is reserved for MCP JSON-RPC messages
-    fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("glass=info")),
-        )
-        .with_writer(std::io::stderr)
-        .with_ansi(false)
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("glass=info")))
+        .with(fmt::layer().with_writer(std::io::stderr).with_ansi(false))
+        .with(telemetry.as_ref().map(|t| t.layer()))
         .init();
 
     tracing::info!("Starting Glass MCP server v{}", env!("CARGO_PKG_VERSION"));
+    if telemetry.is_some() {
+        tracing::info!("Exporting traces and metrics over OTLP");
+    } else if Telemetry::endpoint_configured() {
+        tracing::warn!(
+            "OTEL_EXPORTER_OTLP_ENDPOINT is set but this build lacks the `otel` feature; not exporting"
+        );
+    }
 
     // Load configuration from environment
     let config = config::Config::from_env().context("Failed to load configuration")?;
@@ -64,10 +95,33 @@ async fn main() -> Result<()> {
             "Server will start but may not be able to reach ServiceDesk Plus. \
              Check configuration and network connectivity."
         );
+    } else {
+        // Detect endpoints missing on older SDP builds
+        sdp_client.probe_capabilities().await;
     }
 
+    // Output language for formatter labels
+    glass::i18n::set_language(config.language);
+
     // Create the MCP server
-    let server = server::GlassServer::new(sdp_client);
+    let mut server = server::GlassServer::new(sdp_client);
+    if let Some(length) = config.max_description_length {
+        server = server.with_max_description_length(length);
+    }
+    server = server.with_list_defaults(server::ListDefaults {
+        limit: config.default_limit.unwrap_or(server::DEFAULT_LIST_LIMIT),
+        group: config.default_group.clone(),
+        site: config.default_site.clone(),
+    });
+    if let Some(signature) = &config.reply_signature {
+        server = server.with_reply_signature(server::ReplySignature::new(signature));
+    }
+    if let Some(dir) = &config.export_dir {
+        server = server.with_export_dir(dir);
+    }
+    if let Some(matrix) = &config.priority_matrix {
+        server = server.with_priority_matrix(matrix.clone());
+    }
 
     tracing::info!("Server initialized, starting stdio transport");
 
@@ -90,5 +144,69 @@ async fn main() -> Result<()> {
 
     tracing::info!("Server shutting down");
 
+    if let Some(telemetry) = telemetry {
+        telemetry.shutdown();
+    }
+
+    Ok(())
+}
+
+/// Command-line options.
+#[derive(Debug, Default)]
+struct Args {
+    /// Dotenv file to load instead of `.env`.
+    env_file: Option<PathBuf>,
+    /// Dotenv file whose values win over `env_file`.
+    env_override: Option<PathBuf>,
+    /// Save an API key in the OS keyring and exit.
+    store_api_key: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut parsed = Args::default();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = || {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .map(PathBuf::from)
+                    .with_context(|| format!("{} needs a file path", flag))
+            };
+            match flag.as_str() {
+                "--env-file" => parsed.env_file = Some(value()?),
+                "--env-override" => parsed.env_override = Some(value()?),
+                "--store-api-key" => parsed.store_api_key = true,
+                _ => bail!(
+                    "unknown argument {}; expected --env-file, --env-override, or --store-api-key",
+                    flag
+                ),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// Reads an API key from stdin and saves it in the OS keyring for
+/// `SDP_BASE_URL`, so it no longer has to live in a `.env` file.
+fn store_api_key() -> Result<()> {
+    let base_url = std::env::var("SDP_BASE_URL").context("SDP_BASE_URL must be set")?;
+
+    eprint!("SDP API key for {}: ", base_url);
+    std::io::stderr().flush().ok();
+    let mut api_key = String::new();
+    std::io::stdin()
+        .lock()
+        .read_line(&mut api_key)
+        .context("Failed to read the API key from stdin")?;
+
+    config::Config::store_api_key(&base_url, &api_key).context("Failed to store the API key")?;
+    eprintln!(
+        "Stored. Remove SDP_API_KEY from your .env file; Glass reads the keyring when it is unset."
+    );
     Ok(())
 }
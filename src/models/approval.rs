@@ -0,0 +1,198 @@
+//! Approval models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP request approvals.
+//! Approvals are organized into levels; each level holds one or more
+//! approvals that must be acted on before the request can proceed.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// An approval level on a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalLevel {
+    /// Unique approval level ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Position of this level in the approval chain (1-based).
+    #[serde(default)]
+    pub level: Option<serde_json::Value>,
+
+    /// Overall status of this level (e.g., "Pending Approval", "Approved").
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+}
+
+impl ApprovalLevel {
+    /// Returns the level status name or "Unknown".
+    pub fn display_status(&self) -> &str {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the level number as text, falling back to the ID.
+    pub fn display_level(&self) -> String {
+        match &self.level {
+            Some(serde_json::Value::Number(n)) => n.to_string(),
+            Some(serde_json::Value::String(s)) => s.clone(),
+            _ => self.id.clone(),
+        }
+    }
+}
+
+/// The person asked to approve a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Approver {
+    /// Approver user ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Approver display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Approver email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_id: Option<String>,
+}
+
+/// A single approval within an approval level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Approval {
+    /// Unique approval ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Who is asked to approve.
+    #[serde(default)]
+    pub approver: Option<Approver>,
+
+    /// Approval status (e.g., "Pending Approval", "Approved", "Denied").
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+
+    /// Comments left by the approver.
+    #[serde(default)]
+    pub comments: Option<String>,
+
+    /// When the approval request was sent.
+    #[serde(default)]
+    pub sent_on: Option<SdpTimestamp>,
+
+    /// When the approver acted on it.
+    #[serde(default)]
+    pub action_taken_on: Option<SdpTimestamp>,
+}
+
+impl Approval {
+    /// Returns the approver's name, falling back to email.
+    pub fn display_approver(&self) -> &str {
+        self.approver
+            .as_ref()
+            .and_then(|a| a.name.as_deref().or(a.email_id.as_deref()))
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the approval status name or "Unknown".
+    pub fn display_status(&self) -> &str {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+}
+
+/// An action an approver can take on a pending approval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovalAction {
+    /// Approve the request.
+    Approve,
+    /// Reject (deny) the request.
+    Reject,
+}
+
+impl ApprovalAction {
+    /// Returns the SDP endpoint segment for this action.
+    pub fn path_segment(self) -> &'static str {
+        match self {
+            ApprovalAction::Approve => "approve",
+            ApprovalAction::Reject => "reject",
+        }
+    }
+
+    /// Returns the past-tense verb for user-facing messages.
+    pub fn past_tense(self) -> &'static str {
+        match self {
+            ApprovalAction::Approve => "approved",
+            ApprovalAction::Reject => "rejected",
+        }
+    }
+}
+
+/// Response wrapper for list approval levels operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListApprovalLevelsResponse {
+    /// List of approval levels.
+    #[serde(default)]
+    pub approval_levels: Vec<ApprovalLevel>,
+}
+
+/// Response wrapper for single approval level operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApprovalLevelResponse {
+    /// The approval level.
+    pub approval_level: ApprovalLevel,
+}
+
+/// Response wrapper for list approvals operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListApprovalsResponse {
+    /// List of approvals.
+    #[serde(default)]
+    pub approvals: Vec<Approval>,
+}
+
+/// Response wrapper for single approval operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApprovalResponse {
+    /// The approval.
+    pub approval: Approval,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_deserialize() {
+        let json = r#"{
+            "id": 55,
+            "approver": {"id": "9", "name": "Line Manager", "email_id": "lm@example.com"},
+            "status": {"id": "1", "name": "Pending Approval"},
+            "comments": null
+        }"#;
+        let approval: Approval = serde_json::from_str(json).unwrap();
+        assert_eq!(approval.id, "55");
+        assert_eq!(approval.display_approver(), "Line Manager");
+        assert_eq!(approval.display_status(), "Pending Approval");
+    }
+
+    #[test]
+    fn test_approval_level_display_level() {
+        let json = r#"{"id": "3001", "level": 2, "status": {"name": "Approved"}}"#;
+        let level: ApprovalLevel = serde_json::from_str(json).unwrap();
+        assert_eq!(level.display_level(), "2");
+        assert_eq!(level.display_status(), "Approved");
+    }
+
+    #[test]
+    fn test_approval_action_segments() {
+        assert_eq!(ApprovalAction::Approve.path_segment(), "approve");
+        assert_eq!(ApprovalAction::Reject.past_tense(), "rejected");
+    }
+}
@@ -0,0 +1,142 @@
+//! Asset models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP assets,
+//! the hardware and other configuration items tracked in the CMDB.
+
+use serde::{Deserialize, Serialize};
+
+use super::{deserialize_string_or_int, NamedEntity};
+
+/// An asset (hardware, software, or other configuration item).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Asset {
+    /// Unique asset ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Asset name (often the hostname, e.g. "DK-0421").
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Asset tag.
+    #[serde(default)]
+    pub asset_tag: Option<String>,
+
+    /// Manufacturer serial number.
+    #[serde(default)]
+    pub serial_number: Option<String>,
+
+    /// Barcode.
+    #[serde(default)]
+    pub barcode: Option<String>,
+
+    /// Product model (e.g. "Latitude 7440").
+    #[serde(default)]
+    pub product: Option<NamedEntity>,
+
+    /// Product type (e.g. "Laptop").
+    #[serde(default)]
+    pub product_type: Option<NamedEntity>,
+
+    /// Lifecycle state (e.g. "In Use", "In Store").
+    #[serde(default)]
+    pub state: Option<NamedEntity>,
+
+    /// User the asset is assigned to.
+    #[serde(default)]
+    pub user: Option<NamedEntity>,
+
+    /// Department the asset is assigned to.
+    #[serde(default)]
+    pub department: Option<NamedEntity>,
+
+    /// Site the asset is located at.
+    #[serde(default)]
+    pub site: Option<NamedEntity>,
+
+    /// Physical location.
+    #[serde(default)]
+    pub location: Option<String>,
+
+    /// IP address, if discovered.
+    #[serde(default)]
+    pub ip_address: Option<String>,
+}
+
+impl Asset {
+    /// Returns the name, falling back to the asset tag or ID.
+    pub fn display_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .or(self.asset_tag.as_deref())
+            .unwrap_or(&self.id)
+    }
+
+    /// Returns the product name, if any.
+    pub fn display_product(&self) -> Option<&str> {
+        self.product.as_ref().and_then(|p| p.name.as_deref())
+    }
+
+    /// Returns the product type name, if any.
+    pub fn display_product_type(&self) -> Option<&str> {
+        self.product_type.as_ref().and_then(|p| p.name.as_deref())
+    }
+
+    /// Returns the state name, if any.
+    pub fn display_state(&self) -> Option<&str> {
+        self.state.as_ref().and_then(|s| s.name.as_deref())
+    }
+
+    /// Returns the assigned user's name, if any.
+    pub fn display_user(&self) -> Option<&str> {
+        self.user.as_ref().and_then(|u| u.name.as_deref())
+    }
+}
+
+/// Response wrapper for single asset operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssetResponse {
+    /// The asset.
+    pub asset: Asset,
+}
+
+/// Response wrapper for list assets operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListAssetsResponse {
+    /// List of assets.
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_deserialize() {
+        let json = r#"{
+            "id": 3001,
+            "name": "DK-0421",
+            "serial_number": "5CG1234XYZ",
+            "product": {"id": "12", "name": "Latitude 7440"},
+            "product_type": {"id": "2", "name": "Laptop"},
+            "state": {"id": "1", "name": "In Use"},
+            "user": {"id": "901", "name": "Anna Jensen", "email_id": "anna@example.com"}
+        }"#;
+        let asset: Asset = serde_json::from_str(json).unwrap();
+        assert_eq!(asset.id, "3001");
+        assert_eq!(asset.display_name(), "DK-0421");
+        assert_eq!(asset.display_product(), Some("Latitude 7440"));
+        assert_eq!(asset.display_product_type(), Some("Laptop"));
+        assert_eq!(asset.display_state(), Some("In Use"));
+        assert_eq!(asset.display_user(), Some("Anna Jensen"));
+    }
+
+    #[test]
+    fn test_asset_display_name_falls_back_to_tag() {
+        let json = r#"{"id": "7", "asset_tag": "TAG-7"}"#;
+        let asset: Asset = serde_json::from_str(json).unwrap();
+        assert_eq!(asset.display_name(), "TAG-7");
+        assert!(asset.display_product().is_none());
+    }
+}
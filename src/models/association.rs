@@ -0,0 +1,136 @@
+//! Problem and change association models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for associating an incident
+//! request with the problem that explains it or the change that fixes it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity};
+
+/// The kind of record a request can be associated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssociationKind {
+    /// A problem record (the root cause behind incidents).
+    Problem,
+    /// A change record (the planned fix).
+    Change,
+}
+
+impl AssociationKind {
+    /// Returns the SDP field name, also used as the request sub-resource.
+    pub fn key(self) -> &'static str {
+        match self {
+            AssociationKind::Problem => "problem",
+            AssociationKind::Change => "change",
+        }
+    }
+
+    /// Returns the label shown in tool output.
+    pub fn label(self) -> &'static str {
+        match self {
+            AssociationKind::Problem => "Problem",
+            AssociationKind::Change => "Change",
+        }
+    }
+}
+
+/// Summary of a problem or change associated with a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssociatedRecord {
+    /// Unique problem or change ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Problem or change title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Current status.
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+}
+
+impl AssociatedRecord {
+    /// Returns a one-line summary of the associated record.
+    pub fn display_summary(&self) -> String {
+        let title = self.title.as_deref().unwrap_or(tr("(No title)"));
+        let status = self
+            .status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("Unknown"));
+        format!("#{}: {} [{}]", self.id, title, status)
+    }
+}
+
+/// The problem and change a request is associated with.
+///
+/// Also the response wrapper for the association endpoints, which return
+/// the record under its `problem` or `change` key.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RequestAssociations {
+    /// The associated problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub problem: Option<AssociatedRecord>,
+
+    /// The associated change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change: Option<AssociatedRecord>,
+}
+
+impl RequestAssociations {
+    /// Returns the record associated under `kind`, if any.
+    pub fn get(&self, kind: AssociationKind) -> Option<&AssociatedRecord> {
+        match kind {
+            AssociationKind::Problem => self.problem.as_ref(),
+            AssociationKind::Change => self.change.as_ref(),
+        }
+    }
+
+    /// Takes the record associated under `kind`, if any.
+    pub fn take(self, kind: AssociationKind) -> Option<AssociatedRecord> {
+        match kind {
+            AssociationKind::Problem => self.problem,
+            AssociationKind::Change => self.change,
+        }
+    }
+
+    /// Returns true if the request has no associations.
+    pub fn is_empty(&self) -> bool {
+        self.problem.is_none() && self.change.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_associations_deserialize() {
+        let json = r#"{
+            "problem": {"id": 31, "title": "VPN gateway drops sessions", "status": {"name": "Open"}},
+            "response_status": {"status_code": 2000, "status": "success"}
+        }"#;
+        let associations: RequestAssociations = serde_json::from_str(json).unwrap();
+        let problem = associations.get(AssociationKind::Problem).unwrap();
+        assert_eq!(problem.id, "31");
+        assert_eq!(
+            problem.display_summary(),
+            "#31: VPN gateway drops sessions [Open]"
+        );
+        assert!(associations.get(AssociationKind::Change).is_none());
+        assert!(!associations.is_empty());
+        assert!(RequestAssociations::default().is_empty());
+    }
+
+    #[test]
+    fn test_association_kind_keys() {
+        assert_eq!(AssociationKind::Problem.key(), "problem");
+        assert_eq!(AssociationKind::Change.key(), "change");
+        let kind: AssociationKind = serde_json::from_str(r#""change""#).unwrap();
+        assert_eq!(kind, AssociationKind::Change);
+    }
+}
@@ -0,0 +1,119 @@
+//! Attachment models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for files attached to requests,
+//! such as screenshots and log files.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// Size of an attachment as SDP reports it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentSize {
+    /// Formatted size (e.g., "18.09 KB").
+    #[serde(default)]
+    pub display_value: Option<String>,
+
+    /// Size in bytes; a number or a numeric string depending on the SDP build.
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+}
+
+/// A file attached to a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Unique attachment ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// File name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// File size.
+    #[serde(default)]
+    pub size: Option<AttachmentSize>,
+
+    /// MIME type, when SDP includes it.
+    #[serde(default)]
+    pub content_type: Option<String>,
+
+    /// Who attached the file.
+    #[serde(default)]
+    pub attached_by: Option<NamedEntity>,
+
+    /// When the file was attached.
+    #[serde(default)]
+    pub attached_on: Option<SdpTimestamp>,
+}
+
+impl Attachment {
+    /// Returns the file name or a placeholder.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(tr("(No name)"))
+    }
+
+    /// Returns the file size, preferring SDP's own formatting.
+    pub fn display_size(&self) -> Option<String> {
+        let size = self.size.as_ref()?;
+        if let Some(display) = size.display_value.as_deref().filter(|d| !d.is_empty()) {
+            return Some(display.to_string());
+        }
+        let bytes = match size.value.as_ref()? {
+            serde_json::Value::Number(n) => n.as_u64()?,
+            serde_json::Value::String(s) => s.parse().ok()?,
+            _ => return None,
+        };
+        Some(format_bytes(bytes))
+    }
+
+    /// Returns who attached the file, if known.
+    pub fn display_attached_by(&self) -> Option<&str> {
+        self.attached_by.as_ref().and_then(|a| a.name.as_deref())
+    }
+}
+
+/// Formats a byte count with a binary unit (e.g., "1.5 MB").
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_display_size() {
+        let attachment: Attachment = serde_json::from_str(
+            r#"{"id": 7, "name": "error.png", "size": {"display_value": "18.09 KB", "value": 18530}}"#,
+        )
+        .unwrap();
+        assert_eq!(attachment.id, "7");
+        assert_eq!(attachment.display_name(), "error.png");
+        assert_eq!(attachment.display_size().as_deref(), Some("18.09 KB"));
+
+        let raw: Attachment =
+            serde_json::from_str(r#"{"id": "8", "size": {"value": "1572864"}}"#).unwrap();
+        assert_eq!(raw.display_name(), "(No name)");
+        assert_eq!(raw.display_size().as_deref(), Some("1.5 MB"));
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.0 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+    }
+}
@@ -58,6 +58,32 @@ where
     deserializer.deserialize_any(StringOrIntVisitor)
 }
 
+/// Renders a loosely typed SDP value as display text.
+///
+/// Handles plain strings and numbers, named or timestamp objects (using
+/// `name`, `display_value`, or `value`), and lists of those. Returns `None`
+/// for null or empty values.
+pub fn display_json_value(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) if s.trim().is_empty() => None,
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Object(map) => ["name", "display_value", "value"]
+            .iter()
+            .find_map(|key| map.get(*key))
+            .and_then(display_json_value),
+        serde_json::Value::Array(items) => {
+            let parts: Vec<String> = items.iter().filter_map(display_json_value).collect();
+            if parts.is_empty() {
+                None
+            } else {
+                Some(parts.join(", "))
+            }
+        }
+        other => Some(other.to_string()),
+    }
+}
+
 /// Pagination and sorting parameters for list operations.
 ///
 /// Used in `input_data` to control the number of results returned
@@ -83,6 +109,10 @@ pub struct ListInfo {
     /// Whether to get only the row count without data.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub get_total_count: Option<bool>,
+
+    /// Predefined SDP list view to query (e.g., the trash).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_by: Option<super::NamedEntity>,
 }
 
 impl ListInfo {
@@ -108,6 +138,23 @@ impl ListInfo {
         self.get_total_count = Some(true);
         self
     }
+
+    /// Sorts results by a field in the given order ("asc" or "desc").
+    pub fn with_sort(mut self, field: impl Into<String>, order: impl Into<String>) -> Self {
+        self.sort_field = Some(field.into());
+        self.sort_order = Some(order.into());
+        self
+    }
+
+    /// Queries a predefined SDP list view by name.
+    pub fn with_filter_by(mut self, name: impl Into<String>) -> Self {
+        self.filter_by = Some(super::NamedEntity {
+            id: None,
+            name: Some(name.into()),
+            email_id: None,
+        });
+        self
+    }
 }
 
 /// A single search criterion for filtering list results.
@@ -125,6 +172,10 @@ pub struct SearchCriterion {
     /// Logical operator to combine with next criterion: "AND" or "OR".
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logical_operator: Option<String>,
+
+    /// Nested criteria evaluated as a group together with this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<SearchCriterion>,
 }
 
 impl SearchCriterion {
@@ -135,6 +186,18 @@ impl SearchCriterion {
             condition: "is".to_string(),
             value: serde_json::Value::String(value.into()),
             logical_operator: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates an "is" condition matching records where the field is empty.
+    pub fn is_null(field: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            condition: "is".to_string(),
+            value: serde_json::Value::Null,
+            logical_operator: None,
+            children: Vec::new(),
         }
     }
 
@@ -145,9 +208,46 @@ impl SearchCriterion {
             condition: "contains".to_string(),
             value: serde_json::Value::String(value.into()),
             logical_operator: None,
+            children: Vec::new(),
         }
     }
 
+    /// Groups criteria so that a record matching any of them matches.
+    ///
+    /// The first criterion becomes the group head and the rest are nested as
+    /// OR-ed children, so the group combines with sibling criteria as a unit.
+    /// Returns `None` if `criteria` is empty.
+    pub fn any_of(criteria: Vec<SearchCriterion>) -> Option<Self> {
+        let mut criteria = criteria.into_iter();
+        let mut head = criteria.next()?;
+        head.children = criteria
+            .map(|c| {
+                let mut c = c.or();
+                c.children.clear();
+                c
+            })
+            .collect();
+        Some(head)
+    }
+
+    /// Groups criteria so that only a record matching all of them matches.
+    ///
+    /// Works like [`SearchCriterion::any_of`] but AND-s the children, which
+    /// lets a group nest inside an OR-ed set of sibling criteria.
+    /// Returns `None` if `criteria` is empty.
+    pub fn all_of(criteria: Vec<SearchCriterion>) -> Option<Self> {
+        let mut criteria = criteria.into_iter();
+        let mut head = criteria.next()?;
+        head.children = criteria
+            .map(|c| {
+                let mut c = c.and();
+                c.children.clear();
+                c
+            })
+            .collect();
+        Some(head)
+    }
+
     /// Adds an AND operator to chain with the next criterion.
     pub fn and(mut self) -> Self {
         self.logical_operator = Some("AND".to_string());
@@ -355,12 +455,30 @@ pub struct GetRequestResponse {
     pub request: super::Request,
 }
 
+/// Response wrapper for resolution operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResolutionResponse {
+    /// The request's resolution.
+    pub resolution: super::Resolution,
+}
+
+/// Response wrapper for operations that return no data beyond the status.
+///
+/// Used for DELETE and action endpoints where SDP only returns
+/// `response_status`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EmptyResponse {}
+
 /// Response wrapper for list notes operations.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListNotesResponse {
     /// List of notes.
     #[serde(default)]
     pub notes: Vec<super::Note>,
+
+    /// Pagination info (if requested).
+    #[serde(default)]
+    pub list_info: Option<ListInfoResponse>,
 }
 
 #[cfg(test)]
@@ -406,4 +524,48 @@ mod tests {
         let err = status.into_error();
         assert!(matches!(err, GlassError::SdpApi { code: 4000, .. }));
     }
+
+    #[test]
+    fn test_search_criterion_any_of() {
+        let group = SearchCriterion::any_of(vec![
+            SearchCriterion::contains("subject", "vpn"),
+            SearchCriterion::contains("description", "vpn"),
+        ])
+        .unwrap();
+        let json = serde_json::to_value(&group).unwrap();
+        assert_eq!(json["field"], "subject");
+        assert_eq!(json["children"][0]["field"], "description");
+        assert_eq!(json["children"][0]["logical_operator"], "OR");
+        assert!(SearchCriterion::any_of(Vec::new()).is_none());
+
+        let group = SearchCriterion::all_of(vec![
+            SearchCriterion::is("priority.name", "High"),
+            SearchCriterion::is("status.name", "Open"),
+        ])
+        .unwrap();
+        let json = serde_json::to_value(&group).unwrap();
+        assert_eq!(json["children"][0]["field"], "status.name");
+        assert_eq!(json["children"][0]["logical_operator"], "AND");
+
+        let single = serde_json::to_value(SearchCriterion::is("status.name", "Open")).unwrap();
+        assert!(single.get("children").is_none());
+    }
+
+    #[test]
+    fn test_display_json_value() {
+        use serde_json::json;
+
+        assert_eq!(display_json_value(&json!("Oslo")).as_deref(), Some("Oslo"));
+        assert_eq!(display_json_value(&json!(42)).as_deref(), Some("42"));
+        assert_eq!(
+            display_json_value(&json!({"id": "3", "name": "Gold"})).as_deref(),
+            Some("Gold")
+        );
+        assert_eq!(
+            display_json_value(&json!([{"name": "A"}, "B", null])).as_deref(),
+            Some("A, B")
+        );
+        assert!(display_json_value(&json!(null)).is_none());
+        assert!(display_json_value(&json!("  ")).is_none());
+    }
 }
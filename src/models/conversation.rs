@@ -1,16 +1,21 @@
 //! Conversation models for ServiceDesk Plus API.
 //!
 //! This module defines the data structures for SDP request conversations,
-//! which are email replies and messages exchanged with requesters.
+//! which are email replies and messages exchanged with requesters, and
+//! groups them into email threads.
 
-use serde::Deserialize;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
 
 use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
 
 /// A conversation entry attached to a request/ticket.
 ///
 /// Conversations represent email exchanges between technicians and requesters.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Conversation {
     /// Unique conversation ID.
     #[serde(deserialize_with = "deserialize_string_or_int")]
@@ -70,13 +75,13 @@ impl Conversation {
         }
         // If content_url exists but we couldn't fetch, indicate that
         if self.content_url.is_some() {
-            return "(Content could not be fetched)".to_string();
+            return tr("(Content could not be fetched)").to_string();
         }
         // Try subject as fallback
         if let Some(subj) = &self.subject {
-            return format!("[Subject: {}]", subj);
+            return format!("[{}: {}]", tr("Subject"), subj);
         }
-        "(No content)".to_string()
+        tr("(No content)").to_string()
     }
 
     /// Returns the timestamp for display.
@@ -89,17 +94,138 @@ impl Conversation {
         self.from_user
             .as_ref()
             .and_then(|f| f.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
     }
 
     /// Returns the direction indicator.
     pub fn direction(&self) -> &str {
         match self.is_incoming {
-            Some(true) => "Incoming",
-            Some(false) => "Outgoing",
-            None => "Unknown",
+            Some(true) => tr("Incoming"),
+            Some(false) => tr("Outgoing"),
+            None => tr("Unknown"),
+        }
+    }
+
+    /// Returns the subject normalized for thread matching.
+    ///
+    /// See [`normalize_subject`].
+    pub fn thread_subject(&self) -> String {
+        normalize_subject(self.subject.as_deref().unwrap_or_default())
+    }
+}
+
+/// Reply and forward prefixes that mail clients put in front of a subject,
+/// including the Danish (SV/VS) and German (AW/WG) forms.
+const REPLY_PREFIXES: [&str; 7] = ["re", "fw", "fwd", "sv", "vs", "aw", "wg"];
+
+/// Normalizes an email subject so replies match the message they answer.
+///
+/// Removes SDP's request reference tag (e.g., "[Request ID :##123##]") and
+/// any number of reply/forward prefixes such as "RE:", "Fwd:", or "SV[2]:",
+/// then collapses whitespace and lowercases the rest.
+pub fn normalize_subject(subject: &str) -> String {
+    let mut text = subject.to_string();
+    while let Some(start) = text.find('[') {
+        match text[start..].find(']') {
+            Some(len) if text[start..start + len].contains("##") => {
+                text.replace_range(start..=start + len, " ");
+            }
+            _ => break,
+        }
+    }
+
+    let mut rest = text.trim();
+    while let Some((head, tail)) = rest.split_once(':') {
+        let head = head.trim().to_lowercase();
+        let head = match head.split_once('[') {
+            Some((prefix, count))
+                if count
+                    .trim_end_matches(']')
+                    .chars()
+                    .all(|c| c.is_ascii_digit()) =>
+            {
+                prefix.trim().to_string()
+            }
+            _ => head,
+        };
+        if !REPLY_PREFIXES.contains(&head.as_str()) {
+            break;
+        }
+        rest = tail.trim_start();
+    }
+
+    rest.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Conversations that belong to one email thread, oldest first.
+#[derive(Debug, Clone)]
+pub struct ConversationThread<'a> {
+    /// Messages in the thread, oldest first.
+    pub messages: Vec<&'a Conversation>,
+}
+
+impl ConversationThread<'_> {
+    /// Returns the subject of the first message that has one.
+    pub fn subject(&self) -> Option<&str> {
+        self.messages
+            .iter()
+            .find_map(|m| m.subject.as_deref().filter(|s| !s.trim().is_empty()))
+    }
+
+    /// Returns the distinct senders in the order they first wrote.
+    pub fn participants(&self) -> Vec<&str> {
+        let mut participants: Vec<&str> = Vec::new();
+        for message in &self.messages {
+            let from = message.display_from();
+            if !participants.contains(&from) {
+                participants.push(from);
+            }
+        }
+        participants
+    }
+}
+
+/// Groups conversations into email threads.
+///
+/// Messages join a thread when their normalized subjects match, so "RE:" and
+/// "FW:" replies land with the message they answer. A message without a
+/// subject joins the latest thread its sender already took part in, or
+/// starts its own. Threads are ordered by their first message.
+pub fn group_threads(conversations: &[Conversation]) -> Vec<ConversationThread<'_>> {
+    let mut ordered: Vec<&Conversation> = conversations.iter().collect();
+    // Stable sort keeps list order for messages with equal or missing times
+    ordered.sort_by_key(|c| {
+        let millis = c.sent_time.as_ref().and_then(|t| t.epoch_millis());
+        (millis.is_none(), millis)
+    });
+
+    let mut threads: Vec<ConversationThread<'_>> = Vec::new();
+    let mut by_subject: HashMap<String, usize> = HashMap::new();
+    for conv in ordered {
+        let key = conv.thread_subject();
+        let index = if key.is_empty() {
+            threads
+                .iter()
+                .rposition(|t| t.participants().contains(&conv.display_from()))
+        } else {
+            by_subject.get(&key).copied()
+        };
+        match index {
+            Some(index) => threads[index].messages.push(conv),
+            None => {
+                if !key.is_empty() {
+                    by_subject.insert(key, threads.len());
+                }
+                threads.push(ConversationThread {
+                    messages: vec![conv],
+                });
+            }
         }
     }
+    threads
 }
 
 /// Response wrapper for list conversations operations.
@@ -108,6 +234,10 @@ pub struct ListConversationsResponse {
     /// List of conversations.
     #[serde(default)]
     pub conversations: Vec<Conversation>,
+
+    /// Pagination info (if requested).
+    #[serde(default)]
+    pub list_info: Option<super::ListInfoResponse>,
 }
 
 #[cfg(test)]
@@ -183,6 +313,41 @@ mod tests {
         assert_eq!(outgoing.direction(), "Outgoing");
     }
 
+    #[test]
+    fn test_normalize_subject() {
+        assert_eq!(
+            normalize_subject("RE: SV:  VPN  drops [Request ID :##123##]"),
+            "vpn drops"
+        );
+        assert_eq!(normalize_subject("Fwd[2]: Printer"), "printer");
+        assert_eq!(normalize_subject("Meeting: Monday"), "meeting: monday");
+        assert_eq!(normalize_subject(""), "");
+    }
+
+    #[test]
+    fn test_group_threads() {
+        let conversations: Vec<Conversation> = serde_json::from_str(
+            r#"[{"id": "3", "subject": "RE: VPN drops [Request ID :##42##]", "from": {"name": "Jane"},
+                 "sent_time": {"value": "3000"}},
+                {"id": "1", "subject": "VPN drops", "from": {"name": "Jane"},
+                 "sent_time": {"value": "1000"}},
+                {"id": "2", "subject": "Laptop order", "from": {"name": "Mikkel"},
+                 "sent_time": {"value": "2000"}},
+                {"id": "4", "from": {"name": "Mikkel"}, "sent_time": {"value": "4000"}},
+                {"id": "5", "from": {"name": "Sofie"}, "sent_time": {"value": "5000"}}]"#,
+        )
+        .unwrap();
+
+        let threads = group_threads(&conversations);
+        let ids: Vec<Vec<&str>> = threads
+            .iter()
+            .map(|t| t.messages.iter().map(|m| m.id.as_str()).collect())
+            .collect();
+        assert_eq!(ids, vec![vec!["1", "3"], vec!["2", "4"], vec!["5"]]);
+        assert_eq!(threads[0].subject(), Some("VPN drops"));
+        assert_eq!(threads[1].participants(), vec!["Mikkel"]);
+    }
+
     #[test]
     fn test_conversation_deserialize() {
         let json = r#"{
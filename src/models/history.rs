@@ -0,0 +1,145 @@
+//! Request history models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for a request's audit trail,
+//! which records who changed which fields and when.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{display_json_value, NamedEntity, SdpTimestamp};
+
+/// A single entry in a request's history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Operation performed (e.g. "CREATE", "UPDATE", "ASSIGN").
+    #[serde(default)]
+    pub operation: Option<String>,
+
+    /// User who performed the operation.
+    #[serde(default)]
+    pub by: Option<NamedEntity>,
+
+    /// When the operation happened.
+    #[serde(default)]
+    pub time: Option<SdpTimestamp>,
+
+    /// Free-text description of the operation.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Field changes, either as a list or keyed by field name.
+    #[serde(default)]
+    pub diff: Option<serde_json::Value>,
+}
+
+impl HistoryEntry {
+    /// Returns the name of the user who made the change, or a placeholder.
+    pub fn display_by(&self) -> &str {
+        self.by
+            .as_ref()
+            .and_then(|b| b.name.as_deref())
+            .unwrap_or(tr("System"))
+    }
+
+    /// Returns the field changes in this entry.
+    ///
+    /// SDP reports changes either as a list of `{field, prev_value,
+    /// current_value}` objects or as an object keyed by field name; both
+    /// shapes are normalized here.
+    pub fn changes(&self) -> Vec<FieldChange> {
+        match &self.diff {
+            Some(serde_json::Value::Array(items)) => items
+                .iter()
+                .filter_map(|item| {
+                    let field = item
+                        .get("field_name")
+                        .or_else(|| item.get("field"))
+                        .and_then(display_json_value)?;
+                    Some(FieldChange::new(field, item))
+                })
+                .collect(),
+            Some(serde_json::Value::Object(map)) => map
+                .iter()
+                .map(|(field, item)| FieldChange::new(field.clone(), item))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A single field change within a history entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Name of the changed field.
+    pub field: String,
+
+    /// Value before the change, if any.
+    pub from: Option<String>,
+
+    /// Value after the change, if any.
+    pub to: Option<String>,
+}
+
+impl FieldChange {
+    fn new(field: String, item: &serde_json::Value) -> Self {
+        Self {
+            field,
+            from: item.get("prev_value").and_then(display_json_value),
+            to: item.get("current_value").and_then(display_json_value),
+        }
+    }
+}
+
+/// Response wrapper for request history operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListHistoryResponse {
+    /// History entries, oldest first.
+    #[serde(default)]
+    pub history: Vec<HistoryEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_entry_list_diff() {
+        let json = r#"{
+            "operation": "UPDATE",
+            "by": {"id": "5", "name": "Mikkel Holm"},
+            "time": {"value": "1700000000000", "display_value": "Nov 14, 2023 11:13 PM"},
+            "diff": [
+                {"field": "status", "prev_value": {"name": "Open"}, "current_value": {"name": "In Progress"}},
+                {"field_name": "Priority", "prev_value": null, "current_value": "High"}
+            ]
+        }"#;
+        let entry: HistoryEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.display_by(), "Mikkel Holm");
+        let changes = entry.changes();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "status");
+        assert_eq!(changes[0].from.as_deref(), Some("Open"));
+        assert_eq!(changes[0].to.as_deref(), Some("In Progress"));
+        assert_eq!(changes[1].field, "Priority");
+        assert!(changes[1].from.is_none());
+    }
+
+    #[test]
+    fn test_history_entry_keyed_diff() {
+        let json = r#"{
+            "operation": "ASSIGN",
+            "diff": {"technician": {"prev_value": null, "current_value": {"id": "9", "name": "Sofie Berg"}}}
+        }"#;
+        let entry: HistoryEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.display_by(), "System");
+        assert_eq!(
+            entry.changes(),
+            vec![FieldChange {
+                field: "technician".to_string(),
+                from: None,
+                to: Some("Sofie Berg".to_string()),
+            }]
+        );
+    }
+}
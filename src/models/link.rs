@@ -0,0 +1,129 @@
+//! Request link models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for links between requests,
+//! which relate tickets that share a cause or belong together.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity};
+
+/// Summary of a request on the other side of a link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedRequestSummary {
+    /// Unique request ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Request subject.
+    #[serde(default)]
+    pub subject: Option<String>,
+
+    /// Current status.
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+}
+
+/// A link from one request to another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestLink {
+    /// The linked request.
+    #[serde(default)]
+    pub linked_request: Option<LinkedRequestSummary>,
+
+    /// Comment explaining why the requests are linked.
+    #[serde(default)]
+    pub comments: Option<String>,
+}
+
+impl RequestLink {
+    /// Returns the linked request ID, if present.
+    pub fn linked_id(&self) -> Option<&str> {
+        self.linked_request.as_ref().map(|r| r.id.as_str())
+    }
+
+    /// Returns a one-line summary of the linked request.
+    pub fn display_summary(&self) -> String {
+        match &self.linked_request {
+            Some(linked) => {
+                let subject = linked.subject.as_deref().unwrap_or(tr("(No subject)"));
+                let status = linked
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.name.as_deref())
+                    .unwrap_or(tr("Unknown"));
+                format!("#{}: {} [{}]", linked.id, subject, status)
+            }
+            None => "(Unknown request)".to_string(),
+        }
+    }
+}
+
+/// A single entry in a link or unlink request body.
+#[derive(Debug, Clone, Serialize)]
+pub struct LinkRequestEntry {
+    /// The request to link to (by ID).
+    pub linked_request: NamedEntity,
+
+    /// Comment explaining the link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
+
+impl LinkRequestEntry {
+    /// Creates a link entry for the given request ID.
+    pub fn new(linked_request_id: impl Into<String>) -> Self {
+        Self {
+            linked_request: NamedEntity {
+                id: Some(linked_request_id.into()),
+                name: None,
+                email_id: None,
+            },
+            comments: None,
+        }
+    }
+
+    /// Sets the link comment.
+    pub fn with_comments(mut self, comments: impl Into<String>) -> Self {
+        self.comments = Some(comments.into());
+        self
+    }
+}
+
+/// Response wrapper for list request links operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRequestLinksResponse {
+    /// List of links.
+    #[serde(default)]
+    pub link_requests: Vec<RequestLink>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_link_deserialize() {
+        let json = r#"{
+            "linked_request": {"id": 456, "subject": "VPN down", "status": {"name": "Open"}},
+            "comments": "Same outage"
+        }"#;
+        let link: RequestLink = serde_json::from_str(json).unwrap();
+        assert_eq!(link.linked_id(), Some("456"));
+        assert_eq!(link.display_summary(), "#456: VPN down [Open]");
+        assert_eq!(link.comments.as_deref(), Some("Same outage"));
+    }
+
+    #[test]
+    fn test_link_request_entry_serialization() {
+        let entry = LinkRequestEntry::new("456").with_comments("Duplicate");
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["linked_request"]["id"], "456");
+        assert!(json["linked_request"].get("name").is_none());
+        assert_eq!(json["comments"], "Duplicate");
+
+        let bare = serde_json::to_value(LinkRequestEntry::new("7")).unwrap();
+        assert!(bare.get("comments").is_none());
+    }
+}
@@ -0,0 +1,528 @@
+//! Metadata models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for instance configuration
+//! that constrains valid field values, such as the category tree, sites,
+//! departments, closure codes, custom field definitions, and the priority
+//! matrix.
+
+use serde::{Deserialize, Serialize};
+
+use super::{deserialize_string_or_int, display_json_value, NamedEntity};
+
+/// A category, subcategory, or item in the category tree.
+///
+/// All three levels share the same shape in SDP.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryEntry {
+    /// Unique ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Optional description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Whether the entry has been deleted (kept for historical tickets).
+    #[serde(default)]
+    pub deleted: Option<bool>,
+}
+
+impl CategoryEntry {
+    /// Returns the name, falling back to the ID.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Returns true if the entry can be used on new or updated tickets.
+    pub fn is_active(&self) -> bool {
+        self.deleted != Some(true)
+    }
+
+    /// Returns true if `needle` matches this entry's ID or name (case-insensitive).
+    pub fn matches(&self, needle: &str) -> bool {
+        self.id == needle
+            || self
+                .name
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(needle))
+    }
+}
+
+/// Response wrapper for list categories operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListCategoriesResponse {
+    /// List of categories.
+    #[serde(default)]
+    pub categories: Vec<CategoryEntry>,
+}
+
+/// Response wrapper for list subcategories operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListSubcategoriesResponse {
+    /// List of subcategories.
+    #[serde(default)]
+    pub subcategories: Vec<CategoryEntry>,
+}
+
+/// Response wrapper for list items operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListItemsResponse {
+    /// List of items.
+    #[serde(default)]
+    pub items: Vec<CategoryEntry>,
+}
+
+/// A site (location) in a multi-site deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Site {
+    /// Unique site ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Site name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Optional description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Region the site belongs to.
+    #[serde(default)]
+    pub region: Option<NamedEntity>,
+
+    /// Whether the site has been deleted.
+    #[serde(default)]
+    pub deleted: Option<bool>,
+}
+
+impl Site {
+    /// Returns the name, falling back to the ID.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Returns the region name, if any.
+    pub fn display_region(&self) -> Option<&str> {
+        self.region.as_ref().and_then(|r| r.name.as_deref())
+    }
+}
+
+/// Response wrapper for list sites operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListSitesResponse {
+    /// List of sites.
+    #[serde(default)]
+    pub sites: Vec<Site>,
+}
+
+/// A department that requesters belong to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Department {
+    /// Unique department ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Department name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Optional description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Site the department belongs to.
+    #[serde(default)]
+    pub site: Option<NamedEntity>,
+}
+
+impl Department {
+    /// Returns the name, falling back to the ID.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Returns the site name, if any.
+    pub fn display_site(&self) -> Option<&str> {
+        self.site.as_ref().and_then(|s| s.name.as_deref())
+    }
+}
+
+/// Response wrapper for list departments operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListDepartmentsResponse {
+    /// List of departments.
+    #[serde(default)]
+    pub departments: Vec<Department>,
+}
+
+/// A simple named value from a lookup list, such as a status, priority,
+/// or support group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LookupValue {
+    /// Unique ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Whether the value has been deleted (kept for historical tickets).
+    #[serde(default)]
+    pub deleted: Option<bool>,
+}
+
+impl LookupValue {
+    /// Returns the name, falling back to the ID.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Returns true if the value can be used on new or updated tickets.
+    pub fn is_active(&self) -> bool {
+        self.deleted != Some(true)
+    }
+}
+
+/// Response wrapper for list statuses operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListStatusesResponse {
+    /// List of request statuses.
+    #[serde(default)]
+    pub statuses: Vec<LookupValue>,
+}
+
+/// Response wrapper for list priorities operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListPrioritiesResponse {
+    /// List of priorities.
+    #[serde(default)]
+    pub priorities: Vec<LookupValue>,
+}
+
+/// Response wrapper for list support groups operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListGroupsResponse {
+    /// List of support groups.
+    #[serde(default)]
+    pub groups: Vec<LookupValue>,
+}
+
+/// A service level agreement applied to requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sla {
+    /// Unique SLA ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// SLA name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Optional description of the targets.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl Sla {
+    /// Returns the name, falling back to the ID.
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+}
+
+/// Response wrapper for single SLA operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlaResponse {
+    /// The SLA.
+    pub sla: Sla,
+}
+
+/// A closure code used when closing a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosureCode {
+    /// Unique closure code ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Closure code name (the value close_request expects).
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Optional description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Whether the closure code has been deleted.
+    #[serde(default)]
+    pub deleted: Option<bool>,
+}
+
+/// Response wrapper for list closure codes operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListClosureCodesResponse {
+    /// List of closure codes.
+    #[serde(default)]
+    pub request_closure_codes: Vec<ClosureCode>,
+}
+
+/// Definition of a custom (UDF) request field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomFieldDefinition {
+    /// API name used as the key in `udf_fields` (e.g. "udf_sline_301").
+    pub name: String,
+
+    /// Label shown in the SDP UI.
+    pub label: Option<String>,
+
+    /// Field type (e.g. "Single Line", "Pick List", "Date/Time").
+    pub field_type: Option<String>,
+
+    /// Allowed values for pick lists; empty for free-form fields.
+    pub allowed_values: Vec<String>,
+
+    /// Whether the field must be filled in.
+    pub mandatory: bool,
+}
+
+/// Response wrapper for the request metainfo operation.
+///
+/// The metainfo document is large and loosely structured, so it is kept as
+/// raw JSON and only the custom field definitions are extracted.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequestMetainfoResponse {
+    /// Raw field metadata for requests.
+    #[serde(default)]
+    pub metainfo: serde_json::Value,
+}
+
+impl RequestMetainfoResponse {
+    /// Returns the custom field definitions, sorted by API name.
+    pub fn custom_fields(&self) -> Vec<CustomFieldDefinition> {
+        let Some(fields) = self.metainfo["fields"]["udf_fields"]["fields"].as_object() else {
+            return Vec::new();
+        };
+
+        let text = |value: &serde_json::Value, keys: &[&str]| {
+            keys.iter()
+                .find_map(|key| value.get(*key).and_then(display_json_value))
+        };
+
+        let mut definitions: Vec<CustomFieldDefinition> = fields
+            .iter()
+            .map(|(name, field)| CustomFieldDefinition {
+                name: name.clone(),
+                label: text(field, &["display_name", "label"]),
+                field_type: text(field, &["type", "data_type"]),
+                allowed_values: field
+                    .get("allowed_values")
+                    .and_then(|v| v.as_array())
+                    .map(|values| values.iter().filter_map(display_json_value).collect())
+                    .unwrap_or_default(),
+                mandatory: ["is_mandatory", "mandatory"]
+                    .iter()
+                    .any(|key| field.get(*key).and_then(|v| v.as_bool()) == Some(true)),
+            })
+            .collect();
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        definitions
+    }
+}
+
+/// Maps impact and urgency to a priority, the way SDP's priority matrix
+/// sets a ticket's priority.
+///
+/// SDP does not expose its matrix through the API, so it is configured with
+/// `GLASS_PRIORITY_MATRIX`; [`PriorityMatrix::default`] is the common ITIL
+/// three-by-three matrix. Names are matched ignoring case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityMatrix {
+    /// `(impact, urgency, priority)` cells in configured order.
+    cells: Vec<(String, String, String)>,
+}
+
+impl PriorityMatrix {
+    /// Creates a matrix from `(impact, urgency, priority)` cells.
+    ///
+    /// A later cell for the same impact and urgency replaces an earlier one.
+    pub fn new<I, S>(cells: I) -> Self
+    where
+        I: IntoIterator<Item = (S, S, S)>,
+        S: Into<String>,
+    {
+        let mut matrix = Self { cells: Vec::new() };
+        for (impact, urgency, priority) in cells {
+            let (impact, urgency) = (impact.into(), urgency.into());
+            matrix.cells.retain(|(i, u, _)| {
+                !(i.eq_ignore_ascii_case(&impact) && u.eq_ignore_ascii_case(&urgency))
+            });
+            matrix.cells.push((impact, urgency, priority.into()));
+        }
+        matrix
+    }
+
+    /// Returns the priority for an impact and urgency, if the matrix has it.
+    pub fn priority(&self, impact: &str, urgency: &str) -> Option<&str> {
+        self.cell(impact, urgency).map(|(_, _, priority)| priority)
+    }
+
+    /// Returns the `(impact, urgency, priority)` cell for an impact and
+    /// urgency, with the names as configured.
+    pub fn cell(&self, impact: &str, urgency: &str) -> Option<(&str, &str, &str)> {
+        self.cells
+            .iter()
+            .find(|(i, u, _)| i.eq_ignore_ascii_case(impact) && u.eq_ignore_ascii_case(urgency))
+            .map(|(i, u, p)| (i.as_str(), u.as_str(), p.as_str()))
+    }
+
+    /// Returns the impact names in the order they were configured.
+    pub fn impacts(&self) -> Vec<&str> {
+        Self::distinct(self.cells.iter().map(|(impact, _, _)| impact.as_str()))
+    }
+
+    /// Returns the urgency names in the order they were configured.
+    pub fn urgencies(&self) -> Vec<&str> {
+        Self::distinct(self.cells.iter().map(|(_, urgency, _)| urgency.as_str()))
+    }
+
+    fn distinct<'a>(names: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut seen: Vec<&str> = Vec::new();
+        for name in names {
+            if !seen.iter().any(|s| s.eq_ignore_ascii_case(name)) {
+                seen.push(name);
+            }
+        }
+        seen
+    }
+}
+
+impl Default for PriorityMatrix {
+    fn default() -> Self {
+        Self::new([
+            ("High", "High", "High"),
+            ("High", "Medium", "High"),
+            ("High", "Low", "Medium"),
+            ("Medium", "High", "High"),
+            ("Medium", "Medium", "Medium"),
+            ("Medium", "Low", "Low"),
+            ("Low", "High", "Medium"),
+            ("Low", "Medium", "Low"),
+            ("Low", "Low", "Low"),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_matrix_lookup() {
+        let matrix = PriorityMatrix::default();
+        assert_eq!(matrix.priority("high", "MEDIUM"), Some("High"));
+        assert_eq!(matrix.priority("Low", "Low"), Some("Low"));
+        assert_eq!(matrix.priority("Critical", "Low"), None);
+        assert_eq!(matrix.impacts(), vec!["High", "Medium", "Low"]);
+
+        let matrix = PriorityMatrix::new([
+            ("Affects Business", "Urgent", "P2"),
+            ("affects business", "urgent", "P1"),
+        ]);
+        assert_eq!(matrix.priority("Affects Business", "Urgent"), Some("P1"));
+        assert_eq!(matrix.urgencies(), vec!["urgent"]);
+    }
+
+    #[test]
+    fn test_priority_matrix_cell() {
+        let matrix = PriorityMatrix::default();
+        assert_eq!(
+            matrix.cell("high", "MEDIUM"),
+            Some(("High", "Medium", "High"))
+        );
+        assert_eq!(matrix.cell("Critical", "Low"), None);
+    }
+
+    #[test]
+    fn test_category_entry_matches() {
+        let entry: CategoryEntry =
+            serde_json::from_str(r#"{"id": 4, "name": "Hardware"}"#).unwrap();
+        assert!(entry.matches("4"));
+        assert!(entry.matches("hardware"));
+        assert!(!entry.matches("Software"));
+        assert!(entry.is_active());
+    }
+
+    #[test]
+    fn test_list_subcategories_response_skips_missing_fields() {
+        let json = r#"{"subcategories": [{"id": "7", "deleted": true}]}"#;
+        let response: ListSubcategoriesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.subcategories[0].display_name(), "7");
+        assert!(!response.subcategories[0].is_active());
+    }
+
+    #[test]
+    fn test_site_deserialize() {
+        let json = r#"{"sites": [{"id": 301, "name": "Odense", "region": {"name": "Funen"}}]}"#;
+        let response: ListSitesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.sites[0].id, "301");
+        assert_eq!(response.sites[0].display_name(), "Odense");
+        assert_eq!(response.sites[0].display_region(), Some("Funen"));
+    }
+
+    #[test]
+    fn test_closure_codes_deserialize() {
+        let json = r#"{"request_closure_codes": [{"id": 1, "name": "Success", "description": "Resolved"}]}"#;
+        let response: ListClosureCodesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.request_closure_codes[0].id, "1");
+        assert_eq!(
+            response.request_closure_codes[0].name.as_deref(),
+            Some("Success")
+        );
+    }
+
+    #[test]
+    fn test_sla_response_deserialize() {
+        let json = r#"{"sla": {"id": 301, "name": "High SLA", "description": "Respond in 1h"}}"#;
+        let response: SlaResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.sla.id, "301");
+        assert_eq!(response.sla.display_name(), "High SLA");
+    }
+
+    #[test]
+    fn test_lookup_values_deserialize() {
+        let json = r#"{"statuses": [{"id": 1, "name": "Open"}, {"id": "2", "name": "Old", "deleted": true}]}"#;
+        let response: ListStatusesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.statuses.len(), 2);
+        assert_eq!(response.statuses[0].display_name(), "Open");
+        assert!(response.statuses[0].is_active());
+        assert!(!response.statuses[1].is_active());
+    }
+
+    #[test]
+    fn test_request_metainfo_custom_fields() {
+        let json = r#"{"metainfo": {"fields": {
+            "subject": {"display_name": "Subject"},
+            "udf_fields": {"fields": {
+                "udf_sline_301": {"display_name": "PO Number", "type": "Single Line", "is_mandatory": true},
+                "udf_pick_302": {"display_name": "Support Tier", "type": "Pick List",
+                                 "allowed_values": ["Bronze", {"name": "Gold"}]}
+            }}
+        }}}"#;
+        let response: RequestMetainfoResponse = serde_json::from_str(json).unwrap();
+        let fields = response.custom_fields();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].name, "udf_pick_302");
+        assert_eq!(fields[0].allowed_values, vec!["Bronze", "Gold"]);
+        assert!(!fields[0].mandatory);
+        assert_eq!(fields[1].label.as_deref(), Some("PO Number"));
+        assert!(fields[1].mandatory);
+
+        let empty: RequestMetainfoResponse = serde_json::from_str("{}").unwrap();
+        assert!(empty.custom_fields().is_empty());
+    }
+}
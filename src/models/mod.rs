@@ -1,17 +1,50 @@
 //! Data models for ServiceDesk Plus API.
 //!
 //! This module contains type definitions for the SDP API, including
-//! request/ticket models, technician models, note models, conversation models,
-//! and common response types.
+//! request/ticket models, problem and change association models, attachment models, request history models, request link models, asset models, approval models, technician
+//! models, requester models, note models, notification models, project models, solution (knowledge base) models, survey models, typed status and priority values, conversation models, task
+//! models, watcher models, worklog models, metadata models, and common response types.
 
+mod approval;
+mod asset;
+mod association;
+mod attachment;
 mod common;
 mod conversation;
+mod history;
+mod link;
+mod metadata;
 mod note;
+mod notification;
+mod project;
 mod request;
+mod requester;
+mod solution;
+mod status;
+mod survey;
+mod task;
 mod technician;
+mod watcher;
+mod worklog;
 
+pub use approval::*;
+pub use asset::*;
+pub use association::*;
+pub use attachment::*;
 pub use common::*;
 pub use conversation::*;
+pub use history::*;
+pub use link::*;
+pub use metadata::*;
 pub use note::*;
+pub use notification::*;
+pub use project::*;
 pub use request::*;
+pub use requester::*;
+pub use solution::*;
+pub use status::*;
+pub use survey::*;
+pub use task::*;
 pub use technician::*;
+pub use watcher::*;
+pub use worklog::*;
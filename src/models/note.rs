@@ -5,13 +5,15 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::tr;
+
 use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
 
 /// A note attached to a request/ticket.
 ///
 /// Notes can be internal (visible only to technicians) or
 /// visible to the requester.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Note {
     /// Unique note ID.
     #[serde(deserialize_with = "deserialize_string_or_int")]
@@ -55,9 +57,9 @@ impl Note {
         }
         // If content_url exists but we couldn't fetch, indicate that
         if self.content_url.is_some() {
-            return "(Content could not be fetched)".to_string();
+            return tr("(Content could not be fetched)").to_string();
         }
-        "(No content)".to_string()
+        tr("(No content)").to_string()
     }
 
     /// Returns who created the note.
@@ -65,7 +67,7 @@ impl Note {
         self.created_by
             .as_ref()
             .and_then(|c| c.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
     }
 }
 
@@ -84,6 +86,11 @@ pub struct CreateNoteRequest {
     /// Whether to notify the assigned technician.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notify_technician: Option<bool>,
+
+    /// Whether SDP should record this note as the ticket's first response,
+    /// which stops the first-response SLA clock.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_first_response: Option<bool>,
 }
 
 impl CreateNoteRequest {
@@ -93,6 +100,7 @@ impl CreateNoteRequest {
             description: content.into(),
             show_to_requester: None,
             notify_technician: None,
+            mark_first_response: None,
         }
     }
 
@@ -107,6 +115,12 @@ impl CreateNoteRequest {
         self.notify_technician = Some(notify);
         self
     }
+
+    /// Sets whether the note counts as the ticket's first response.
+    pub fn with_mark_first_response(mut self, mark: bool) -> Self {
+        self.mark_first_response = Some(mark);
+        self
+    }
 }
 
 /// Response wrapper for add note operations.
@@ -183,6 +197,17 @@ mod tests {
         assert!(json.get("notify_technician").is_none());
     }
 
+    #[test]
+    fn test_create_note_request_first_response() {
+        let req = CreateNoteRequest::new("Replied");
+        let json = serde_json::to_value(&req).unwrap();
+        assert!(json.get("mark_first_response").is_none());
+
+        let first = req.with_mark_first_response(true);
+        let json = serde_json::to_value(&first).unwrap();
+        assert_eq!(json["mark_first_response"], true);
+    }
+
     #[test]
     fn test_note_deserialize() {
         let json = r#"{
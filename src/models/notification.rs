@@ -0,0 +1,230 @@
+//! Notification models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP request notifications,
+//! which are the emails SDP sends on a ticket's behalf (replies, forwards,
+//! and system notifications).
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// An email recipient in a notification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailRecipient {
+    /// Recipient email address.
+    pub email_id: String,
+}
+
+impl EmailRecipient {
+    /// Creates a recipient from an email address.
+    pub fn new(email_id: impl Into<String>) -> Self {
+        Self {
+            email_id: email_id.into(),
+        }
+    }
+}
+
+/// A notification (outgoing email) sent on a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    /// Unique notification ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Email subject.
+    #[serde(default)]
+    pub subject: Option<String>,
+
+    /// Email body (may contain HTML).
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Primary recipients.
+    #[serde(default)]
+    pub to: Option<Vec<EmailRecipient>>,
+
+    /// Carbon-copy recipients.
+    #[serde(default)]
+    pub cc: Option<Vec<EmailRecipient>>,
+
+    /// Blind carbon-copy recipients, when SDP returns them.
+    #[serde(default)]
+    pub bcc: Option<Vec<EmailRecipient>>,
+
+    /// Who sent the notification.
+    #[serde(default)]
+    pub sender: Option<NamedEntity>,
+
+    /// When the notification was sent.
+    #[serde(default)]
+    pub sent_time: Option<SdpTimestamp>,
+
+    /// Notification type (e.g., "reply", "forward").
+    #[serde(default, rename = "type")]
+    pub notification_type: Option<String>,
+}
+
+impl Notification {
+    /// Returns the primary recipients as a comma-separated list.
+    pub fn display_to(&self) -> String {
+        Self::join_recipients(self.to.as_deref())
+    }
+
+    /// Returns the CC recipients as a comma-separated list.
+    pub fn display_cc(&self) -> String {
+        Self::join_recipients(self.cc.as_deref())
+    }
+
+    /// Returns the BCC recipients as a comma-separated list.
+    pub fn display_bcc(&self) -> String {
+        Self::join_recipients(self.bcc.as_deref())
+    }
+
+    /// Returns the sender name, or "System" for automatic notifications.
+    pub fn display_sender(&self) -> &str {
+        self.sender
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("System"))
+    }
+
+    fn join_recipients(recipients: Option<&[EmailRecipient]>) -> String {
+        recipients
+            .unwrap_or_default()
+            .iter()
+            .map(|r| r.email_id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Request body for sending a reply to the requester.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplyRequest {
+    /// Email subject.
+    pub subject: String,
+
+    /// Email body (HTML).
+    pub description: String,
+
+    /// Primary recipients.
+    pub to: Vec<EmailRecipient>,
+
+    /// Carbon-copy recipients.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<EmailRecipient>,
+
+    /// Notification type: "reply" for requester replies, "forward" for other recipients.
+    #[serde(rename = "type")]
+    pub notification_type: String,
+}
+
+impl ReplyRequest {
+    /// Creates a reply with the given subject, HTML body, and recipients.
+    pub fn new(
+        subject: impl Into<String>,
+        html_body: impl Into<String>,
+        to: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            subject: subject.into(),
+            description: html_body.into(),
+            to: to.into_iter().map(EmailRecipient::new).collect(),
+            cc: Vec::new(),
+            notification_type: "reply".to_string(),
+        }
+    }
+
+    /// Creates a notification to recipients other than the requester,
+    /// such as an escalation to a manager or vendor.
+    pub fn forward(
+        subject: impl Into<String>,
+        html_body: impl Into<String>,
+        to: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            notification_type: "forward".to_string(),
+            ..Self::new(subject, html_body, to)
+        }
+    }
+
+    /// Sets the CC recipients.
+    pub fn with_cc(mut self, cc: impl IntoIterator<Item = String>) -> Self {
+        self.cc = cc.into_iter().map(EmailRecipient::new).collect();
+        self
+    }
+}
+
+/// Response wrapper for single notification operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationResponse {
+    /// The notification.
+    pub notification: Notification,
+}
+
+/// Response wrapper for list notifications operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListNotificationsResponse {
+    /// List of notifications.
+    #[serde(default)]
+    pub notifications: Vec<Notification>,
+}
+
+/// Converts plain text to HTML suitable for an email body.
+///
+/// Escapes HTML special characters and turns line breaks into `<br>`.
+pub fn plain_text_to_html(text: &str) -> String {
+    let mut html = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\n' => html.push_str("<br>"),
+            '\r' => {}
+            _ => html.push(c),
+        }
+    }
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reply_request_serialization() {
+        let reply = ReplyRequest::new(
+            "Re: Printer",
+            "<p>Fixed</p>",
+            vec!["user@example.com".to_string()],
+        );
+        let json = serde_json::to_value(&reply).unwrap();
+        assert_eq!(json["type"], "reply");
+        assert_eq!(json["to"][0]["email_id"], "user@example.com");
+        assert!(json.get("cc").is_none());
+    }
+
+    #[test]
+    fn test_plain_text_to_html_escapes() {
+        assert_eq!(
+            plain_text_to_html("a < b & c\r\nnext"),
+            "a &lt; b &amp; c<br>next"
+        );
+    }
+
+    #[test]
+    fn test_notification_display_recipients() {
+        let json = r#"{
+            "id": 88985,
+            "to": [{"email_id": "a@example.com"}, {"email_id": "b@example.com"}]
+        }"#;
+        let notification: Notification = serde_json::from_str(json).unwrap();
+        assert_eq!(notification.id, "88985");
+        assert_eq!(notification.display_to(), "a@example.com, b@example.com");
+        assert_eq!(notification.display_cc(), "");
+    }
+}
@@ -0,0 +1,290 @@
+//! Project models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP projects and their
+//! milestones. Project tasks share the request `Task` model.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::task::by_name;
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// A project tracked in the SDP project module.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    /// Unique project ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Project title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Detailed description (may contain HTML).
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Technician who owns the project.
+    #[serde(default)]
+    pub owner: Option<NamedEntity>,
+
+    /// Current project status.
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+
+    /// Project priority.
+    #[serde(default)]
+    pub priority: Option<NamedEntity>,
+
+    /// Project type.
+    #[serde(default)]
+    pub project_type: Option<NamedEntity>,
+
+    /// Scheduled start time.
+    #[serde(default)]
+    pub scheduled_start_time: Option<SdpTimestamp>,
+
+    /// Scheduled end time.
+    #[serde(default)]
+    pub scheduled_end_time: Option<SdpTimestamp>,
+
+    /// When work on the project actually ended.
+    #[serde(default)]
+    pub actual_end_time: Option<SdpTimestamp>,
+
+    /// Completion percentage (0-100).
+    #[serde(default)]
+    pub percentage_completion: Option<serde_json::Value>,
+
+    /// Creation timestamp.
+    #[serde(default)]
+    pub created_time: Option<SdpTimestamp>,
+}
+
+impl Project {
+    /// Returns the title or a placeholder.
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(tr("(No title)"))
+    }
+
+    /// Returns the status name or "Unknown".
+    pub fn display_status(&self) -> &str {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the owner name or "Unassigned".
+    pub fn display_owner(&self) -> &str {
+        self.owner
+            .as_ref()
+            .and_then(|o| o.name.as_deref())
+            .unwrap_or(tr("Unassigned"))
+    }
+
+    /// Returns the scheduled end date for display, if set.
+    pub fn display_due(&self) -> Option<&str> {
+        self.scheduled_end_time.as_ref().and_then(|t| t.display())
+    }
+}
+
+/// A milestone within a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    /// Unique milestone ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Milestone title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Technician who owns the milestone.
+    #[serde(default)]
+    pub owner: Option<NamedEntity>,
+
+    /// Current milestone status.
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+
+    /// Scheduled end time.
+    #[serde(default)]
+    pub scheduled_end_time: Option<SdpTimestamp>,
+
+    /// Completion percentage (0-100).
+    #[serde(default)]
+    pub percentage_completion: Option<serde_json::Value>,
+}
+
+impl Milestone {
+    /// Returns the title or a placeholder.
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(tr("(No title)"))
+    }
+
+    /// Returns the status name or "Unknown".
+    pub fn display_status(&self) -> &str {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+}
+
+/// Request body for creating or updating a project.
+///
+/// Only fields that are set are serialized, so the same struct can be
+/// used for partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ProjectRequest {
+    /// Project title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Detailed description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Owning technician (by ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<NamedEntity>,
+
+    /// Project status (by name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<NamedEntity>,
+
+    /// Project priority (by name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<NamedEntity>,
+
+    /// Scheduled start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_start_time: Option<SdpTimestamp>,
+
+    /// Scheduled end time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<SdpTimestamp>,
+}
+
+impl ProjectRequest {
+    /// Creates an empty project request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the owning technician by ID.
+    pub fn with_owner_id(mut self, owner_id: impl Into<String>) -> Self {
+        self.owner = Some(NamedEntity {
+            id: Some(owner_id.into()),
+            name: None,
+            email_id: None,
+        });
+        self
+    }
+
+    /// Sets the status by name.
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(by_name(status));
+        self
+    }
+
+    /// Sets the priority by name.
+    pub fn with_priority(mut self, priority: impl Into<String>) -> Self {
+        self.priority = Some(by_name(priority));
+        self
+    }
+
+    /// Sets the scheduled start time.
+    pub fn with_start(mut self, start: SdpTimestamp) -> Self {
+        self.scheduled_start_time = Some(start);
+        self
+    }
+
+    /// Sets the scheduled end time.
+    pub fn with_end(mut self, end: SdpTimestamp) -> Self {
+        self.scheduled_end_time = Some(end);
+        self
+    }
+}
+
+/// Response wrapper for single project operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectResponse {
+    /// The project.
+    pub project: Project,
+}
+
+/// Response wrapper for list projects operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListProjectsResponse {
+    /// List of projects.
+    #[serde(default)]
+    pub projects: Vec<Project>,
+}
+
+/// Response wrapper for list milestones operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListMilestonesResponse {
+    /// List of milestones.
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_deserialize() {
+        let json = r#"{
+            "id": 61,
+            "title": "Laptop refresh 2026",
+            "owner": {"id": "5", "name": "Mikkel"},
+            "status": {"name": "In Progress"},
+            "scheduled_end_time": {"value": "1798761600000", "display_value": "Jan 1, 2027"},
+            "percentage_completion": 40
+        }"#;
+        let project: Project = serde_json::from_str(json).unwrap();
+        assert_eq!(project.id, "61");
+        assert_eq!(project.display_title(), "Laptop refresh 2026");
+        assert_eq!(project.display_status(), "In Progress");
+        assert_eq!(project.display_owner(), "Mikkel");
+        assert_eq!(project.display_due(), Some("Jan 1, 2027"));
+    }
+
+    #[test]
+    fn test_project_request_serialization() {
+        let request = ProjectRequest::new()
+            .with_title("Laptop refresh")
+            .with_owner_id("5")
+            .with_status("Open");
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["title"], "Laptop refresh");
+        assert_eq!(json["owner"]["id"], "5");
+        assert_eq!(json["status"]["name"], "Open");
+        assert!(json.get("description").is_none());
+        assert!(json.get("scheduled_end_time").is_none());
+    }
+
+    #[test]
+    fn test_milestone_deserialize() {
+        let json = r#"{"id": "7", "title": "Pilot group", "status": {"name": "Open"}}"#;
+        let milestone: Milestone = serde_json::from_str(json).unwrap();
+        assert_eq!(milestone.display_title(), "Pilot group");
+        assert_eq!(milestone.display_status(), "Open");
+    }
+}
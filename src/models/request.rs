@@ -5,6 +5,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::GlassError;
+use crate::i18n::tr;
+
+use super::{display_json_value, Attachment, Priority, Status};
+
 /// A named entity reference used throughout SDP API.
 ///
 /// Many SDP fields reference other entities by ID and name,
@@ -12,18 +17,22 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NamedEntity {
     /// Unique identifier.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 
     /// Display name.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+
+    /// Email address (present on user references such as requester and technician).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_id: Option<String>,
 }
 
 impl NamedEntity {
     /// Returns the name if present, otherwise a placeholder.
     pub fn display_name(&self) -> &str {
-        self.name.as_deref().unwrap_or("Unknown")
+        self.name.as_deref().unwrap_or(tr("Unknown"))
     }
 }
 
@@ -34,11 +43,15 @@ impl NamedEntity {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SdpTimestamp {
     /// Epoch milliseconds (can be string or integer in API response).
-    #[serde(default, deserialize_with = "deserialize_optional_string_or_int")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_string_or_int",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub value: Option<String>,
 
     /// Human-readable format.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub display_value: Option<String>,
 }
 
@@ -150,17 +163,110 @@ where
 }
 
 impl SdpTimestamp {
+    /// Creates a timestamp from epoch milliseconds, for sending to SDP.
+    pub fn from_epoch_millis(millis: i64) -> Self {
+        Self {
+            value: Some(millis.to_string()),
+            display_value: None,
+        }
+    }
+
+    /// Parses a user-supplied date into a timestamp.
+    ///
+    /// Accepts RFC 3339 (`2026-02-06T14:30:00+01:00`), `YYYY-MM-DD HH:MM`,
+    /// or `YYYY-MM-DD`. Values without an offset are interpreted in the
+    /// server's local time zone; date-only values mean local midnight.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GlassError::Validation` if the input is not a recognized date.
+    pub fn parse_input(input: &str) -> Result<Self, GlassError> {
+        use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+        let input = input.trim();
+        if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+            return Ok(Self::from_epoch_millis(dt.timestamp_millis()));
+        }
+
+        let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M")
+            .or_else(|_| NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M"))
+            .or_else(|_| {
+                NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                    .map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default())
+            })
+            .map_err(|_| {
+                GlassError::validation(format!(
+                    "invalid date {:?}: expected YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339",
+                    input.chars().take(50).collect::<String>()
+                ))
+            })?;
+
+        let local = Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .ok_or_else(|| {
+                GlassError::validation(format!("{} does not exist in local time", input))
+            })?;
+        Ok(Self::from_epoch_millis(local.timestamp_millis()))
+    }
+
     /// Returns the display value if present, otherwise the raw value.
     pub fn display(&self) -> Option<&str> {
         self.display_value.as_deref().or(self.value.as_deref())
     }
+
+    /// Returns the raw value as epoch milliseconds, if it is numeric.
+    pub fn epoch_millis(&self) -> Option<i64> {
+        self.value.as_deref().and_then(|v| v.trim().parse().ok())
+    }
+}
+
+/// Time left on an SLA target that has not been met yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlaCountdown {
+    /// The target is due in this many milliseconds.
+    Remaining(i64),
+    /// The target passed this many milliseconds ago without being met.
+    Breached(i64),
+}
+
+impl SlaCountdown {
+    /// Time left under which a target is about to breach (two hours).
+    pub const AT_RISK_MILLIS: i64 = 2 * 3_600_000;
+
+    /// Computes the countdown to `due` at `now` (epoch milliseconds).
+    ///
+    /// Returns `None` when the target was met (`done` is set) or the due
+    /// time has no numeric value to count from.
+    pub fn new(due: Option<&SdpTimestamp>, done: Option<&SdpTimestamp>, now: i64) -> Option<Self> {
+        if done.is_some() {
+            return None;
+        }
+        let due = due?.epoch_millis()?;
+        Some(if due >= now {
+            SlaCountdown::Remaining(due - now)
+        } else {
+            SlaCountdown::Breached(now - due)
+        })
+    }
+
+    /// Returns whether SLA time runs in `status`: not for resolved, closed,
+    /// or on-hold tickets. An unknown status counts as running.
+    pub fn is_running(status: Option<Status>) -> bool {
+        status.is_none_or(|s| s.is_open() && s != Status::OnHold)
+    }
+
+    /// Returns whether the target breaches within [`Self::AT_RISK_MILLIS`].
+    pub fn is_at_risk(self) -> bool {
+        matches!(self, SlaCountdown::Remaining(left) if left < Self::AT_RISK_MILLIS)
+    }
 }
 
 /// Summary of a request for list operations.
 ///
 /// This is a lighter-weight representation returned when listing
 /// requests, containing only the most commonly needed fields.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestSummary {
     /// Unique request ID.
     pub id: String,
@@ -197,6 +303,18 @@ pub struct RequestSummary {
     #[serde(default)]
     pub due_by_time: Option<SdpTimestamp>,
 
+    /// First response due time.
+    #[serde(default)]
+    pub first_response_due_by_time: Option<SdpTimestamp>,
+
+    /// When the first response was sent.
+    #[serde(default)]
+    pub responded_time: Option<SdpTimestamp>,
+
+    /// Completed time.
+    #[serde(default)]
+    pub completed_time: Option<SdpTimestamp>,
+
     /// Request type (Incident, Service Request, etc.).
     #[serde(default)]
     pub request_type: Option<NamedEntity>,
@@ -221,7 +339,7 @@ pub struct RequestSummary {
 impl RequestSummary {
     /// Returns the subject or a placeholder.
     pub fn display_subject(&self) -> &str {
-        self.subject.as_deref().unwrap_or("(No subject)")
+        self.subject.as_deref().unwrap_or(tr("(No subject)"))
     }
 
     /// Returns the status name or "Unknown".
@@ -229,7 +347,7 @@ impl RequestSummary {
         self.status
             .as_ref()
             .and_then(|s| s.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
     }
 
     /// Returns the priority name or "Unknown".
@@ -237,7 +355,23 @@ impl RequestSummary {
         self.priority
             .as_ref()
             .and_then(|p| p.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the typed status, if a status name is present.
+    pub fn parsed_status(&self) -> Option<Status> {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .map(Status::from_name)
+    }
+
+    /// Returns the typed priority, if a priority name is present.
+    pub fn parsed_priority(&self) -> Option<Priority> {
+        self.priority
+            .as_ref()
+            .and_then(|p| p.name.as_deref())
+            .map(Priority::from_name)
     }
 
     /// Returns the technician name or "Unassigned".
@@ -245,7 +379,7 @@ impl RequestSummary {
         self.technician
             .as_ref()
             .and_then(|t| t.name.as_deref())
-            .unwrap_or("Unassigned")
+            .unwrap_or(tr("Unassigned"))
     }
 
     /// Returns the requester name or "Unknown".
@@ -253,7 +387,29 @@ impl RequestSummary {
         self.requester
             .as_ref()
             .and_then(|r| r.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the time left until the first response is due, unless the
+    /// ticket was answered or its SLA clock is stopped.
+    pub fn first_response_countdown(&self, now: i64) -> Option<SlaCountdown> {
+        if !SlaCountdown::is_running(self.parsed_status()) {
+            return None;
+        }
+        SlaCountdown::new(
+            self.first_response_due_by_time.as_ref(),
+            self.responded_time.as_ref(),
+            now,
+        )
+    }
+
+    /// Returns the time left until the ticket is due, unless it was
+    /// completed or its SLA clock is stopped.
+    pub fn resolution_countdown(&self, now: i64) -> Option<SlaCountdown> {
+        if !SlaCountdown::is_running(self.parsed_status()) {
+            return None;
+        }
+        SlaCountdown::new(self.due_by_time.as_ref(), self.completed_time.as_ref(), now)
     }
 }
 
@@ -261,7 +417,7 @@ impl RequestSummary {
 ///
 /// This is the complete representation returned when fetching
 /// a specific request by ID.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Request {
     /// Unique request ID.
     pub id: String,
@@ -334,6 +490,10 @@ pub struct Request {
     #[serde(default)]
     pub service: Option<NamedEntity>,
 
+    /// Assets (configuration items) associated with the request.
+    #[serde(default)]
+    pub assets: Option<Vec<NamedEntity>>,
+
     /// Creation timestamp.
     #[serde(default)]
     pub created_time: Option<SdpTimestamp>,
@@ -358,6 +518,14 @@ pub struct Request {
     #[serde(default)]
     pub completed_time: Option<SdpTimestamp>,
 
+    /// When the first response was sent.
+    #[serde(default)]
+    pub responded_time: Option<SdpTimestamp>,
+
+    /// SLA applied to the request.
+    #[serde(default)]
+    pub sla: Option<NamedEntity>,
+
     /// Resolution details.
     #[serde(default)]
     pub resolution: Option<Resolution>,
@@ -366,10 +534,18 @@ pub struct Request {
     #[serde(default)]
     pub closure_info: Option<ClosureInfo>,
 
+    /// Scheduled resumption for an on-hold request.
+    #[serde(default)]
+    pub onhold_scheduler: Option<OnHoldScheduler>,
+
     /// Whether the request is overdue.
     #[serde(default)]
     pub is_overdue: Option<bool>,
 
+    /// Whether the first response is overdue.
+    #[serde(default)]
+    pub is_first_response_overdue: Option<bool>,
+
     /// Whether the request is marked as first call resolution.
     #[serde(default)]
     pub is_fcr: Option<bool>,
@@ -378,23 +554,31 @@ pub struct Request {
     #[serde(default)]
     pub has_attachments: Option<bool>,
 
+    /// Files attached to the request.
+    #[serde(default)]
+    pub attachments: Option<Vec<Attachment>>,
+
     /// Has notes.
     #[serde(default)]
     pub has_notes: Option<bool>,
 
-    /// Email IDs related to this request.
+    /// CC recipients notified about this request.
     #[serde(default)]
     pub email_ids_to_notify: Option<Vec<String>>,
 
     /// Approval status.
     #[serde(default)]
     pub approval_status: Option<NamedEntity>,
+
+    /// Custom (user-defined) field values keyed by field name (e.g. "udf_sline_301").
+    #[serde(default)]
+    pub udf_fields: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl Request {
     /// Returns the subject or a placeholder.
     pub fn display_subject(&self) -> &str {
-        self.subject.as_deref().unwrap_or("(No subject)")
+        self.subject.as_deref().unwrap_or(tr("(No subject)"))
     }
 
     /// Returns the status name or "Unknown".
@@ -402,7 +586,12 @@ impl Request {
         self.status
             .as_ref()
             .and_then(|s| s.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns true if the request status is an on-hold status.
+    pub fn is_on_hold(&self) -> bool {
+        self.display_status().to_ascii_lowercase().contains("hold")
     }
 
     /// Returns the priority name or "Unknown".
@@ -410,7 +599,51 @@ impl Request {
         self.priority
             .as_ref()
             .and_then(|p| p.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the typed status, if a status name is present.
+    pub fn parsed_status(&self) -> Option<Status> {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .map(Status::from_name)
+    }
+
+    /// Returns the typed priority, if a priority name is present.
+    pub fn parsed_priority(&self) -> Option<Priority> {
+        self.priority
+            .as_ref()
+            .and_then(|p| p.name.as_deref())
+            .map(Priority::from_name)
+    }
+
+    /// Returns the time left until the first response is due, unless the
+    /// ticket was answered or its SLA clock is stopped.
+    pub fn first_response_countdown(&self, now: i64) -> Option<SlaCountdown> {
+        if !SlaCountdown::is_running(self.parsed_status()) {
+            return None;
+        }
+        SlaCountdown::new(
+            self.first_response_due_by_time.as_ref(),
+            self.responded_time.as_ref(),
+            now,
+        )
+    }
+
+    /// Returns the time left until the ticket must be resolved, unless it
+    /// was completed or its SLA clock is stopped.
+    pub fn resolution_countdown(&self, now: i64) -> Option<SlaCountdown> {
+        if !SlaCountdown::is_running(self.parsed_status()) {
+            return None;
+        }
+        SlaCountdown::new(
+            self.resolution_due_by_time
+                .as_ref()
+                .or(self.due_by_time.as_ref()),
+            self.completed_time.as_ref(),
+            now,
+        )
     }
 
     /// Returns the technician name or "Unassigned".
@@ -418,7 +651,7 @@ impl Request {
         self.technician
             .as_ref()
             .and_then(|t| t.name.as_deref())
-            .unwrap_or("Unassigned")
+            .unwrap_or(tr("Unassigned"))
     }
 
     /// Returns the requester name or "Unknown".
@@ -426,7 +659,7 @@ impl Request {
         self.requester
             .as_ref()
             .and_then(|r| r.name.as_deref())
-            .unwrap_or("Unknown")
+            .unwrap_or(tr("Unknown"))
     }
 
     /// Returns the group name if assigned.
@@ -434,6 +667,35 @@ impl Request {
         self.group.as_ref().and_then(|g| g.name.as_deref())
     }
 
+    /// Returns the associated asset names as a comma-separated list, if any.
+    pub fn display_assets(&self) -> Option<String> {
+        let names: Vec<&str> = self
+            .assets
+            .iter()
+            .flatten()
+            .map(|a| {
+                a.name
+                    .as_deref()
+                    .or(a.id.as_deref())
+                    .unwrap_or(tr("Unknown"))
+            })
+            .collect();
+        if names.is_empty() {
+            None
+        } else {
+            Some(names.join(", "))
+        }
+    }
+
+    /// Returns the custom fields that have a value, as (field name, display text) pairs.
+    pub fn display_udf_fields(&self) -> Vec<(&str, String)> {
+        self.udf_fields
+            .iter()
+            .flatten()
+            .filter_map(|(name, value)| Some((name.as_str(), display_json_value(value)?)))
+            .collect()
+    }
+
     /// Returns the category path (category > subcategory > item).
     pub fn category_path(&self) -> String {
         let parts: Vec<&str> = [
@@ -446,7 +708,7 @@ impl Request {
         .collect();
 
         if parts.is_empty() {
-            "Uncategorized".to_string()
+            tr("Uncategorized").to_string()
         } else {
             parts.join(" > ")
         }
@@ -454,7 +716,7 @@ impl Request {
 }
 
 /// Resolution details for a completed request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Resolution {
     /// Resolution content (may contain HTML).
     #[serde(default)]
@@ -470,7 +732,7 @@ pub struct Resolution {
 }
 
 /// Closure information for a closed request.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClosureInfo {
     /// Closure code.
     #[serde(default)]
@@ -489,6 +751,45 @@ pub struct ClosureInfo {
     pub closed_time: Option<SdpTimestamp>,
 }
 
+/// Schedule for automatically taking a request off hold.
+///
+/// Sent with an on-hold status change and returned on held requests.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OnHoldScheduler {
+    /// When the request should come off hold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduled_time: Option<SdpTimestamp>,
+
+    /// Status to change to when the schedule fires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_to_status: Option<NamedEntity>,
+
+    /// Reason for putting the request on hold.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
+
+impl OnHoldScheduler {
+    /// Creates a schedule that resumes the request to `status` at `time`.
+    pub fn resume_at(time: SdpTimestamp, status: impl Into<String>) -> Self {
+        Self {
+            scheduled_time: Some(time),
+            change_to_status: Some(NamedEntity {
+                id: None,
+                name: Some(status.into()),
+                email_id: None,
+            }),
+            comments: None,
+        }
+    }
+
+    /// Sets the hold comments.
+    pub fn with_comments(mut self, comments: impl Into<String>) -> Self {
+        self.comments = Some(comments.into());
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -498,12 +799,14 @@ mod tests {
         let entity = NamedEntity {
             id: Some("1".to_string()),
             name: Some("Test Name".to_string()),
+            email_id: None,
         };
         assert_eq!(entity.display_name(), "Test Name");
 
         let empty = NamedEntity {
             id: None,
             name: None,
+            email_id: None,
         };
         assert_eq!(empty.display_name(), "Unknown");
     }
@@ -523,6 +826,66 @@ mod tests {
         assert_eq!(ts_value_only.display(), Some("1706745600000"));
     }
 
+    #[test]
+    fn test_sdp_timestamp_parse_input() {
+        let ts = SdpTimestamp::parse_input("2026-02-06T14:30:00+00:00").unwrap();
+        assert_eq!(ts.epoch_millis(), Some(1_770_388_200_000));
+        assert!(ts.display_value.is_none());
+
+        assert!(SdpTimestamp::parse_input("2026-02-06").is_ok());
+        assert!(SdpTimestamp::parse_input("2026-02-06 09:15").is_ok());
+
+        let err = SdpTimestamp::parse_input("next tuesday").unwrap_err();
+        assert!(err.to_string().contains("invalid date"));
+    }
+
+    #[test]
+    fn test_sla_countdowns() {
+        let ts = |millis: i64| SdpTimestamp::from_epoch_millis(millis);
+        let now = 10 * 3_600_000;
+        assert_eq!(
+            SlaCountdown::new(Some(&ts(now + 3_600_000)), None, now),
+            Some(SlaCountdown::Remaining(3_600_000))
+        );
+        assert_eq!(
+            SlaCountdown::new(Some(&ts(now - 60_000)), None, now),
+            Some(SlaCountdown::Breached(60_000))
+        );
+        assert_eq!(
+            SlaCountdown::new(Some(&ts(now - 60_000)), Some(&ts(now)), now),
+            None
+        );
+        assert!(SlaCountdown::Remaining(3_600_000).is_at_risk());
+        assert!(!SlaCountdown::Remaining(3 * 3_600_000).is_at_risk());
+        assert!(!SlaCountdown::Breached(1).is_at_risk());
+
+        let summary: RequestSummary = serde_json::from_str(&format!(
+            r#"{{"id": "1", "status": {{"name": "Open"}},
+                "due_by_time": {{"value": "{}"}},
+                "first_response_due_by_time": {{"value": "{}"}},
+                "responded_time": {{"value": "{}"}}}}"#,
+            now + 1_800_000,
+            now - 1,
+            now - 2
+        ))
+        .unwrap();
+        assert_eq!(
+            summary.resolution_countdown(now),
+            Some(SlaCountdown::Remaining(1_800_000))
+        );
+        assert_eq!(summary.first_response_countdown(now), None);
+
+        let on_hold = RequestSummary {
+            status: Some(NamedEntity {
+                id: None,
+                name: Some("On Hold".to_string()),
+                email_id: None,
+            }),
+            ..summary
+        };
+        assert_eq!(on_hold.resolution_countdown(now), None);
+    }
+
     #[test]
     fn test_request_summary_display_methods() {
         let summary = RequestSummary {
@@ -531,19 +894,25 @@ mod tests {
             status: Some(NamedEntity {
                 id: Some("1".to_string()),
                 name: Some("Open".to_string()),
+                email_id: None,
             }),
             priority: Some(NamedEntity {
                 id: Some("2".to_string()),
                 name: Some("High".to_string()),
+                email_id: None,
             }),
             technician: None,
             requester: Some(NamedEntity {
                 id: Some("3".to_string()),
                 name: Some("John Doe".to_string()),
+                email_id: None,
             }),
             created_time: None,
             last_updated_time: None,
             due_by_time: None,
+            first_response_due_by_time: None,
+            responded_time: None,
+            completed_time: None,
             request_type: None,
             category: None,
             subcategory: None,
@@ -574,14 +943,17 @@ mod tests {
             category: Some(NamedEntity {
                 id: Some("1".to_string()),
                 name: Some("Hardware".to_string()),
+                email_id: None,
             }),
             subcategory: Some(NamedEntity {
                 id: Some("2".to_string()),
                 name: Some("Laptop".to_string()),
+                email_id: None,
             }),
             item: Some(NamedEntity {
                 id: Some("3".to_string()),
                 name: Some("Screen".to_string()),
+                email_id: None,
             }),
             site: None,
             group: None,
@@ -596,14 +968,51 @@ mod tests {
             completed_time: None,
             resolution: None,
             closure_info: None,
+            onhold_scheduler: None,
+            assets: None,
+            responded_time: None,
+            sla: None,
+            is_first_response_overdue: None,
             is_overdue: None,
             is_fcr: None,
             has_attachments: None,
+            attachments: None,
             has_notes: None,
             email_ids_to_notify: None,
             approval_status: None,
+            udf_fields: None,
         };
 
         assert_eq!(request.category_path(), "Hardware > Laptop > Screen");
     }
+
+    #[test]
+    fn test_onhold_scheduler_serialization() {
+        let scheduler = OnHoldScheduler::resume_at(SdpTimestamp::from_epoch_millis(1_000), "Open")
+            .with_comments("Waiting for vendor");
+        let json = serde_json::to_value(&scheduler).unwrap();
+        assert_eq!(json["scheduled_time"]["value"], "1000");
+        assert_eq!(json["change_to_status"]["name"], "Open");
+        assert_eq!(json["comments"], "Waiting for vendor");
+    }
+
+    #[test]
+    fn test_request_display_udf_fields() {
+        let json = r#"{
+            "id": "1",
+            "udf_fields": {
+                "udf_sline_301": "PO-7781",
+                "udf_pick_302": {"id": "5", "name": "Gold"},
+                "udf_date_303": null
+            }
+        }"#;
+        let request: Request = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.display_udf_fields(),
+            vec![
+                ("udf_pick_302", "Gold".to_string()),
+                ("udf_sline_301", "PO-7781".to_string()),
+            ]
+        );
+    }
 }
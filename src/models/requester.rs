@@ -0,0 +1,123 @@
+//! Requester models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP requesters,
+//! the end users who raise requests/tickets.
+
+use serde::{Deserialize, Serialize};
+
+use super::{deserialize_string_or_int, NamedEntity};
+
+/// A requester (end user) who can raise tickets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Requester {
+    /// Unique requester ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Email address.
+    #[serde(default)]
+    pub email_id: Option<String>,
+
+    /// Phone number.
+    #[serde(default)]
+    pub phone: Option<String>,
+
+    /// Mobile number.
+    #[serde(default)]
+    pub mobile: Option<String>,
+
+    /// Job title (SDP returns this as "jobtitle").
+    #[serde(default, alias = "jobtitle")]
+    pub job_title: Option<String>,
+
+    /// Employee ID.
+    #[serde(default)]
+    pub employee_id: Option<String>,
+
+    /// Department the requester belongs to.
+    #[serde(default)]
+    pub department: Option<NamedEntity>,
+
+    /// Site the requester is located at.
+    #[serde(default)]
+    pub site: Option<NamedEntity>,
+
+    /// Whether the requester is flagged as a VIP.
+    #[serde(default)]
+    pub is_vipuser: Option<bool>,
+}
+
+impl Requester {
+    /// Returns the display name, falling back to email or ID.
+    pub fn display_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .or(self.email_id.as_deref())
+            .unwrap_or(&self.id)
+    }
+
+    /// Returns the department name, if any.
+    pub fn display_department(&self) -> Option<&str> {
+        self.department.as_ref().and_then(|d| d.name.as_deref())
+    }
+
+    /// Returns the site name, if any.
+    pub fn display_site(&self) -> Option<&str> {
+        self.site.as_ref().and_then(|s| s.name.as_deref())
+    }
+
+    /// Returns true if the requester is a VIP.
+    pub fn is_vip(&self) -> bool {
+        self.is_vipuser == Some(true)
+    }
+}
+
+/// Response wrapper for single requester operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequesterResponse {
+    /// The requester.
+    pub requester: Requester,
+}
+
+/// Response wrapper for list requesters operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRequestersResponse {
+    /// List of requesters.
+    #[serde(default)]
+    pub requesters: Vec<Requester>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requester_deserialize() {
+        let json = r#"{
+            "id": 901,
+            "name": "Anna Jensen",
+            "email_id": "anna@example.com",
+            "jobtitle": "Dispatcher",
+            "department": {"id": "3", "name": "Operations"},
+            "is_vipuser": true
+        }"#;
+        let requester: Requester = serde_json::from_str(json).unwrap();
+        assert_eq!(requester.id, "901");
+        assert_eq!(requester.display_name(), "Anna Jensen");
+        assert_eq!(requester.job_title.as_deref(), Some("Dispatcher"));
+        assert_eq!(requester.display_department(), Some("Operations"));
+        assert!(requester.is_vip());
+    }
+
+    #[test]
+    fn test_requester_display_name_falls_back_to_email() {
+        let json = r#"{"id": "5", "email_id": "x@example.com"}"#;
+        let requester: Requester = serde_json::from_str(json).unwrap();
+        assert_eq!(requester.display_name(), "x@example.com");
+        assert!(!requester.is_vip());
+    }
+}
@@ -0,0 +1,119 @@
+//! Solution (knowledge base) models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP solutions,
+//! the knowledge base articles that document fixes for known issues.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// A knowledge base article.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Solution {
+    /// Unique solution ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Article title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Article content (may contain HTML).
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Topic the article is filed under.
+    #[serde(default)]
+    pub topic: Option<NamedEntity>,
+
+    /// Search keywords.
+    #[serde(default)]
+    pub keywords: Option<String>,
+
+    /// Approval status (e.g. "Approved", "Unapproved").
+    #[serde(default)]
+    pub approval_status: Option<NamedEntity>,
+
+    /// Whether the article is visible to requesters.
+    #[serde(default)]
+    pub is_public: Option<bool>,
+
+    /// Technician who wrote the article.
+    #[serde(default)]
+    pub created_by: Option<NamedEntity>,
+
+    /// Creation timestamp.
+    #[serde(default)]
+    pub created_time: Option<SdpTimestamp>,
+
+    /// Last update timestamp.
+    #[serde(default)]
+    pub last_updated_time: Option<SdpTimestamp>,
+}
+
+impl Solution {
+    /// Returns the title or a placeholder.
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(tr("(No title)"))
+    }
+
+    /// Returns the topic name, if any.
+    pub fn display_topic(&self) -> Option<&str> {
+        self.topic.as_ref().and_then(|t| t.name.as_deref())
+    }
+
+    /// Returns the approval status name, if any.
+    pub fn display_approval_status(&self) -> Option<&str> {
+        self.approval_status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+    }
+}
+
+/// Response wrapper for single solution operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SolutionResponse {
+    /// The solution.
+    pub solution: Solution,
+}
+
+/// Response wrapper for list solutions operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListSolutionsResponse {
+    /// List of solutions.
+    #[serde(default)]
+    pub solutions: Vec<Solution>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solution_deserialize() {
+        let json = r#"{
+            "id": 4501,
+            "title": "VPN drops after sleep",
+            "description": "<p>Disable power saving on the adapter.</p>",
+            "topic": {"id": "7", "name": "Network"},
+            "keywords": "vpn, sleep",
+            "approval_status": {"name": "Approved"},
+            "is_public": true
+        }"#;
+        let solution: Solution = serde_json::from_str(json).unwrap();
+        assert_eq!(solution.id, "4501");
+        assert_eq!(solution.display_title(), "VPN drops after sleep");
+        assert_eq!(solution.display_topic(), Some("Network"));
+        assert_eq!(solution.display_approval_status(), Some("Approved"));
+        assert_eq!(solution.is_public, Some(true));
+    }
+
+    #[test]
+    fn test_solution_minimal() {
+        let solution: Solution = serde_json::from_str(r#"{"id": "9"}"#).unwrap();
+        assert_eq!(solution.display_title(), "(No title)");
+        assert!(solution.display_topic().is_none());
+    }
+}
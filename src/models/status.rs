@@ -0,0 +1,201 @@
+//! Typed status and priority models for ServiceDesk Plus API.
+//!
+//! SDP returns statuses and priorities as named entities whose names are
+//! configured per instance (ours uses Danish status names). This module maps
+//! known names onto typed values through lookup tables, keeping any other
+//! name as a custom value instead of failing.
+
+/// The lifecycle state of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Open, including assigned and in-progress requests.
+    Open,
+    /// Paused while waiting on someone else.
+    OnHold,
+    /// Work is done and awaiting confirmation.
+    Resolved,
+    /// Closed or cancelled.
+    Closed,
+    /// A status name not in the mapping table.
+    Custom(String),
+}
+
+/// Known status names and the status they map to, matched case-insensitively.
+const STATUS_NAMES: &[(&str, Status)] = &[
+    ("Open", Status::Open),
+    ("Åben", Status::Open),
+    ("Assigned", Status::Open),
+    ("Tildelt", Status::Open),
+    ("In Progress", Status::Open),
+    ("I gang", Status::Open),
+    ("On Hold", Status::OnHold),
+    ("Onhold", Status::OnHold),
+    ("På hold", Status::OnHold),
+    ("Resolved", Status::Resolved),
+    ("Udført, afventer godkendelse", Status::Resolved),
+    ("Closed", Status::Closed),
+    ("Lukket", Status::Closed),
+    ("Canceled", Status::Closed),
+    ("Cancelled", Status::Closed),
+    ("Annulleret", Status::Closed),
+];
+
+impl Status {
+    /// Maps an SDP status name to a typed status.
+    pub fn from_name(name: &str) -> Self {
+        let name = name.trim();
+        STATUS_NAMES
+            .iter()
+            .find(|(known, _)| known.to_lowercase() == name.to_lowercase())
+            .map(|(_, status)| status.clone())
+            .unwrap_or_else(|| Status::Custom(name.to_string()))
+    }
+
+    /// Returns the canonical name, or the original name for custom statuses.
+    pub fn name(&self) -> &str {
+        match self {
+            Status::Open => "Open",
+            Status::OnHold => "On Hold",
+            Status::Resolved => "Resolved",
+            Status::Closed => "Closed",
+            Status::Custom(name) => name,
+        }
+    }
+
+    /// Returns true unless the request is resolved or closed.
+    ///
+    /// Custom statuses count as open, so unknown names are never hidden.
+    pub fn is_open(&self) -> bool {
+        !matches!(self, Status::Resolved | Status::Closed)
+    }
+
+    /// Returns the first of `names` that maps to this status.
+    ///
+    /// Used to find the instance's own name for a status, such as "Åben"
+    /// for `Status::Open`, among the statuses configured in SDP.
+    pub fn find_name<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+        names
+            .into_iter()
+            .find(|name| Status::from_name(name) == *self)
+    }
+
+    /// Returns every known status name that is not open.
+    ///
+    /// Used to exclude finished requests in search criteria.
+    pub fn finished_names() -> impl Iterator<Item = &'static str> {
+        STATUS_NAMES
+            .iter()
+            .filter(|(_, status)| !status.is_open())
+            .map(|(name, _)| *name)
+    }
+}
+
+/// The priority of a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Priority {
+    /// Low priority.
+    Low,
+    /// Medium (normal) priority.
+    Medium,
+    /// High priority.
+    High,
+    /// Urgent priority.
+    Urgent,
+    /// A priority name not in the mapping table.
+    Custom(String),
+}
+
+/// Known priority names and the priority they map to, matched case-insensitively.
+const PRIORITY_NAMES: &[(&str, Priority)] = &[
+    ("Low", Priority::Low),
+    ("Medium", Priority::Medium),
+    ("Normal", Priority::Medium),
+    ("High", Priority::High),
+    ("Urgent", Priority::Urgent),
+];
+
+impl Priority {
+    /// Maps an SDP priority name to a typed priority.
+    pub fn from_name(name: &str) -> Self {
+        let name = name.trim();
+        PRIORITY_NAMES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(name))
+            .map(|(_, priority)| priority.clone())
+            .unwrap_or_else(|| Priority::Custom(name.to_string()))
+    }
+
+    /// Returns the canonical name, or the original name for custom priorities.
+    pub fn name(&self) -> &str {
+        match self {
+            Priority::Low => "Low",
+            Priority::Medium => "Medium",
+            Priority::High => "High",
+            Priority::Urgent => "Urgent",
+            Priority::Custom(name) => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_from_name() {
+        assert_eq!(Status::from_name("Åben"), Status::Open);
+        assert_eq!(Status::from_name("ÅBEN"), Status::Open);
+        assert_eq!(Status::from_name(" lukket "), Status::Closed);
+        assert_eq!(
+            Status::from_name("Udført, afventer godkendelse"),
+            Status::Resolved
+        );
+        let custom = Status::from_name("Awaiting Vendor");
+        assert_eq!(custom, Status::Custom("Awaiting Vendor".to_string()));
+        assert_eq!(custom.name(), "Awaiting Vendor");
+        assert!(custom.is_open());
+        assert!(!Status::Closed.is_open());
+    }
+
+    #[test]
+    fn test_status_finished_names() {
+        let names: Vec<&str> = Status::finished_names().collect();
+        assert!(names.contains(&"Lukket"));
+        assert!(names.contains(&"Annulleret"));
+        assert!(names.contains(&"Resolved"));
+        assert!(!names.contains(&"I gang"));
+        assert!(!names.contains(&"On Hold"));
+    }
+
+    #[test]
+    fn test_status_find_name() {
+        let names = ["Åben", "I gang", "På hold", "Lukket"];
+        assert_eq!(Status::Open.find_name(names), Some("Åben"));
+        assert_eq!(Status::OnHold.find_name(names), Some("På hold"));
+        assert_eq!(Status::Closed.find_name(names), Some("Lukket"));
+        assert_eq!(Status::Resolved.find_name(names), None);
+    }
+
+    #[test]
+    fn test_request_summary_maps_status_and_priority() {
+        let summary: crate::models::RequestSummary = serde_json::from_str(
+            r#"{"id": "1", "status": {"name": "Åben"}, "priority": {"name": "High"}}"#,
+        )
+        .unwrap();
+        assert_eq!(summary.parsed_status(), Some(Status::Open));
+        assert_eq!(summary.parsed_priority(), Some(Priority::High));
+
+        let unset: crate::models::RequestSummary = serde_json::from_str(r#"{"id": "2"}"#).unwrap();
+        assert_eq!(unset.parsed_status(), None);
+    }
+
+    #[test]
+    fn test_priority_from_name() {
+        assert_eq!(Priority::from_name("high"), Priority::High);
+        assert_eq!(Priority::from_name("Normal").name(), "Medium");
+        assert_eq!(
+            Priority::from_name("P1"),
+            Priority::Custom("P1".to_string())
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Survey models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for requester satisfaction
+//! survey responses, which SDP collects when a ticket is closed.
+
+use serde::{Deserialize, Serialize};
+
+use super::{display_json_value, NamedEntity, SdpTimestamp};
+
+/// One answered question in a survey response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyAnswer {
+    /// The question, as text or a `{ "name": ... }` object.
+    #[serde(default)]
+    pub question: Option<serde_json::Value>,
+
+    /// The chosen answer or free-text reply.
+    #[serde(default, alias = "value")]
+    pub answer: Option<serde_json::Value>,
+}
+
+impl SurveyAnswer {
+    /// Returns the question text, if present.
+    pub fn display_question(&self) -> Option<String> {
+        self.question.as_ref().and_then(display_json_value)
+    }
+
+    /// Returns the answer text, if present.
+    pub fn display_answer(&self) -> Option<String> {
+        self.answer.as_ref().and_then(display_json_value)
+    }
+}
+
+/// A requester's response to the satisfaction survey of a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyResponse {
+    /// Who answered the survey.
+    #[serde(default, alias = "responder")]
+    pub requester: Option<NamedEntity>,
+
+    /// When the survey was answered.
+    #[serde(default, alias = "responded_time")]
+    pub responded_on: Option<SdpTimestamp>,
+
+    /// Overall satisfaction rating, as a number, numeric text, or a
+    /// `{ "name": "4 - Good", "value": 4 }` object.
+    #[serde(default, alias = "overall_rating")]
+    pub rating: Option<serde_json::Value>,
+
+    /// Free-text comments from the requester.
+    #[serde(default)]
+    pub comments: Option<String>,
+
+    /// Answers to the individual survey questions.
+    #[serde(default, alias = "responses")]
+    pub answers: Vec<SurveyAnswer>,
+}
+
+impl SurveyResponse {
+    /// Returns the rating as SDP labels it (e.g., "4 - Good").
+    pub fn display_rating(&self) -> Option<String> {
+        self.rating.as_ref().and_then(display_json_value)
+    }
+
+    /// Returns the rating as a number, for filtering poor ratings.
+    ///
+    /// Reads a numeric value, an object's `value` field, or the leading
+    /// number of a label such as "2 - Poor".
+    pub fn rating_score(&self) -> Option<f64> {
+        fn score(value: &serde_json::Value) -> Option<f64> {
+            match value {
+                serde_json::Value::Number(n) => n.as_f64(),
+                serde_json::Value::String(s) => {
+                    let digits: String = s
+                        .trim()
+                        .chars()
+                        .take_while(|c| c.is_ascii_digit() || *c == '.')
+                        .collect();
+                    digits.parse().ok()
+                }
+                serde_json::Value::Object(map) => ["value", "name"]
+                    .iter()
+                    .find_map(|key| map.get(*key).and_then(score)),
+                _ => None,
+            }
+        }
+        self.rating.as_ref().and_then(score)
+    }
+
+    /// Returns who answered the survey, if known.
+    pub fn display_requester(&self) -> Option<&str> {
+        self.requester.as_ref().and_then(|r| r.name.as_deref())
+    }
+}
+
+/// Response wrapper for survey response operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SurveyResponseWrapper {
+    /// The survey response; absent when the requester has not answered.
+    #[serde(default)]
+    pub survey_response: Option<SurveyResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_survey_rating_score() {
+        let response: SurveyResponse = serde_json::from_str(
+            r#"{"overall_rating": {"name": "2 - Poor"}, "comments": "Took a week",
+                "responses": [{"question": {"name": "Was the technician polite?"}, "value": "Yes"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(response.display_rating().as_deref(), Some("2 - Poor"));
+        assert_eq!(response.rating_score(), Some(2.0));
+        assert_eq!(
+            response.answers[0].display_question().as_deref(),
+            Some("Was the technician polite?")
+        );
+        assert_eq!(response.answers[0].display_answer().as_deref(), Some("Yes"));
+
+        let numeric: SurveyResponse = serde_json::from_str(r#"{"rating": 4}"#).unwrap();
+        assert_eq!(numeric.rating_score(), Some(4.0));
+
+        let unrated: SurveyResponse = serde_json::from_str(r#"{"rating": "n/a"}"#).unwrap();
+        assert_eq!(unrated.rating_score(), None);
+    }
+}
@@ -0,0 +1,259 @@
+//! Task models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP request tasks,
+//! which break a ticket into smaller units of work with their own
+//! owners and due dates.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// A task attached to a request/ticket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    /// Unique task ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Task title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Detailed description (may contain HTML).
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Technician who owns the task.
+    #[serde(default)]
+    pub owner: Option<NamedEntity>,
+
+    /// Support group the task is assigned to.
+    #[serde(default)]
+    pub group: Option<NamedEntity>,
+
+    /// Current task status.
+    #[serde(default)]
+    pub status: Option<NamedEntity>,
+
+    /// Task priority.
+    #[serde(default)]
+    pub priority: Option<NamedEntity>,
+
+    /// Scheduled start time.
+    #[serde(default)]
+    pub scheduled_start_time: Option<SdpTimestamp>,
+
+    /// Scheduled end time (the task's due date).
+    #[serde(default)]
+    pub scheduled_end_time: Option<SdpTimestamp>,
+
+    /// When the task was actually completed.
+    #[serde(default)]
+    pub actual_end_time: Option<SdpTimestamp>,
+
+    /// Completion percentage (0-100).
+    #[serde(default)]
+    pub percentage_completion: Option<serde_json::Value>,
+
+    /// Whether the task is overdue.
+    #[serde(default)]
+    pub overdue: Option<bool>,
+
+    /// Creation timestamp.
+    #[serde(default)]
+    pub created_time: Option<SdpTimestamp>,
+}
+
+impl Task {
+    /// Returns the title or a placeholder.
+    pub fn display_title(&self) -> &str {
+        self.title.as_deref().unwrap_or(tr("(No title)"))
+    }
+
+    /// Returns the status name or "Unknown".
+    pub fn display_status(&self) -> &str {
+        self.status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the owner name or "Unassigned".
+    pub fn display_owner(&self) -> &str {
+        self.owner
+            .as_ref()
+            .and_then(|o| o.name.as_deref())
+            .unwrap_or(tr("Unassigned"))
+    }
+
+    /// Returns the due date for display, if scheduled.
+    pub fn display_due(&self) -> Option<&str> {
+        self.scheduled_end_time.as_ref().and_then(|t| t.display())
+    }
+}
+
+/// Request body for creating or updating a task.
+///
+/// Only fields that are set are serialized, so the same struct can be
+/// used for partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TaskRequest {
+    /// Task title.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Detailed description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Owning technician (by ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<NamedEntity>,
+
+    /// Support group (by name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<NamedEntity>,
+
+    /// Task status (by name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<NamedEntity>,
+
+    /// Task priority (by name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<NamedEntity>,
+
+    /// Due date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<SdpTimestamp>,
+
+    /// Completion percentage (0-100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentage_completion: Option<u8>,
+}
+
+/// Creates a `NamedEntity` reference by name only.
+pub(super) fn by_name(name: impl Into<String>) -> NamedEntity {
+    NamedEntity {
+        id: None,
+        name: Some(name.into()),
+        email_id: None,
+    }
+}
+
+impl TaskRequest {
+    /// Creates an empty task request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the owning technician by ID.
+    pub fn with_owner_id(mut self, owner_id: impl Into<String>) -> Self {
+        self.owner = Some(NamedEntity {
+            id: Some(owner_id.into()),
+            name: None,
+            email_id: None,
+        });
+        self
+    }
+
+    /// Sets the support group by name.
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(by_name(group));
+        self
+    }
+
+    /// Sets the status by name.
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(by_name(status));
+        self
+    }
+
+    /// Sets the priority by name.
+    pub fn with_priority(mut self, priority: impl Into<String>) -> Self {
+        self.priority = Some(by_name(priority));
+        self
+    }
+
+    /// Sets the due date.
+    pub fn with_due(mut self, due: SdpTimestamp) -> Self {
+        self.scheduled_end_time = Some(due);
+        self
+    }
+
+    /// Sets the completion percentage.
+    pub fn with_percentage_completion(mut self, percentage: u8) -> Self {
+        self.percentage_completion = Some(percentage.min(100));
+        self
+    }
+}
+
+/// Response wrapper for single task operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskResponse {
+    /// The task.
+    pub task: Task,
+}
+
+/// Response wrapper for list tasks operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTasksResponse {
+    /// List of tasks.
+    #[serde(default)]
+    pub tasks: Vec<Task>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_deserialize() {
+        let json = r#"{
+            "id": 321,
+            "title": "Order replacement screen",
+            "owner": {"id": "5", "name": "Jane Smith"},
+            "status": {"id": "1", "name": "Open"},
+            "scheduled_end_time": {"value": "1770388200000", "display_value": "Feb 6, 2026 02:30 PM"}
+        }"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.id, "321");
+        assert_eq!(task.display_title(), "Order replacement screen");
+        assert_eq!(task.display_owner(), "Jane Smith");
+        assert_eq!(task.display_status(), "Open");
+        assert_eq!(task.display_due(), Some("Feb 6, 2026 02:30 PM"));
+    }
+
+    #[test]
+    fn test_task_request_serialization_skips_unset() {
+        let req = TaskRequest::new()
+            .with_title("Check cabling")
+            .with_owner_id("42")
+            .with_due(SdpTimestamp::from_epoch_millis(1_770_388_200_000));
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["title"], "Check cabling");
+        assert_eq!(json["owner"]["id"], "42");
+        assert!(json["owner"].get("name").is_none());
+        assert_eq!(json["scheduled_end_time"]["value"], "1770388200000");
+        assert!(json.get("status").is_none());
+    }
+
+    #[test]
+    fn test_task_request_caps_percentage() {
+        let req = TaskRequest::new().with_percentage_completion(150);
+        assert_eq!(req.percentage_completion, Some(100));
+    }
+}
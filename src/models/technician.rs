@@ -3,13 +3,13 @@
 //! This module defines the data structures for SDP technicians,
 //! who can be assigned to handle requests/tickets.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// A technician who can be assigned to handle requests.
 ///
 /// Note: The SDP API returns many fields as nested objects.
 /// We only capture the essential fields needed for display and assignment.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Technician {
     /// Unique technician ID.
     pub id: String,
@@ -70,6 +70,13 @@ impl Technician {
     }
 }
 
+/// Response wrapper for single technician operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TechnicianResponse {
+    /// The technician.
+    pub technician: Technician,
+}
+
 /// Response wrapper for list technicians operations.
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListTechniciansResponse {
@@ -0,0 +1,59 @@
+//! Watcher models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for request watchers, the users
+//! subscribed to updates on a ticket without being its requester or
+//! technician.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::deserialize_string_or_int;
+
+/// A user watching a request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Watcher {
+    /// The watcher's user ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Email address.
+    #[serde(default)]
+    pub email_id: Option<String>,
+}
+
+impl Watcher {
+    /// Returns the name, falling back to the email address.
+    pub fn display_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .or(self.email_id.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+}
+
+/// A user reference sent when adding or removing watchers.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatcherEntry {
+    /// The user ID.
+    pub id: String,
+}
+
+impl WatcherEntry {
+    /// Creates an entry for the given user ID.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+/// Response wrapper for list watchers operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListWatchersResponse {
+    /// List of watchers.
+    #[serde(default)]
+    pub watchers: Vec<Watcher>,
+}
@@ -0,0 +1,225 @@
+//! Worklog models for ServiceDesk Plus API.
+//!
+//! This module defines the data structures for SDP request worklogs,
+//! which record time spent by technicians on a ticket.
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::tr;
+
+use super::{deserialize_string_or_int, NamedEntity, SdpTimestamp};
+
+/// Time spent on a worklog entry.
+///
+/// SDP returns hours and minutes separately, sometimes as strings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeSpent {
+    /// Whole hours spent.
+    #[serde(default, deserialize_with = "deserialize_optional_u32")]
+    pub hours: Option<u32>,
+
+    /// Remaining minutes spent.
+    #[serde(default, deserialize_with = "deserialize_optional_u32")]
+    pub minutes: Option<u32>,
+}
+
+impl TimeSpent {
+    /// Creates a time spent value from a total number of minutes.
+    pub fn from_minutes(total: u32) -> Self {
+        Self {
+            hours: Some(total / 60),
+            minutes: Some(total % 60),
+        }
+    }
+
+    /// Returns the total number of minutes.
+    pub fn total_minutes(&self) -> u32 {
+        self.hours.unwrap_or(0) * 60 + self.minutes.unwrap_or(0)
+    }
+
+    /// Returns a human-readable duration (e.g., "1h 30m").
+    pub fn display(&self) -> String {
+        let total = self.total_minutes();
+        format!("{}h {:02}m", total / 60, total % 60)
+    }
+}
+
+/// Deserializes an optional u32 that SDP may send as a string or a number.
+fn deserialize_optional_u32<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let value: Option<serde_json::Value> = Option::deserialize(deserializer)?;
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| D::Error::custom("expected a non-negative integer")),
+        Some(serde_json::Value::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(serde_json::Value::String(s)) => s.trim().parse().map(Some).map_err(D::Error::custom),
+        Some(other) => Err(D::Error::custom(format!(
+            "expected a string or integer, got {}",
+            other
+        ))),
+    }
+}
+
+/// A worklog entry attached to a request/ticket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Worklog {
+    /// Unique worklog ID.
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,
+
+    /// Description of the work performed.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Technician who performed the work.
+    #[serde(default)]
+    pub owner: Option<NamedEntity>,
+
+    /// When the work started.
+    #[serde(default)]
+    pub start_time: Option<SdpTimestamp>,
+
+    /// When the work ended.
+    #[serde(default)]
+    pub end_time: Option<SdpTimestamp>,
+
+    /// Time spent on the work.
+    #[serde(default)]
+    pub time_spent: Option<TimeSpent>,
+
+    /// Type of worklog (e.g., "Regular", "Overtime").
+    #[serde(default)]
+    pub worklog_type: Option<NamedEntity>,
+}
+
+impl Worklog {
+    /// Returns the owner name or "Unknown".
+    pub fn display_owner(&self) -> &str {
+        self.owner
+            .as_ref()
+            .and_then(|o| o.name.as_deref())
+            .unwrap_or(tr("Unknown"))
+    }
+
+    /// Returns the time spent as a human-readable duration.
+    pub fn display_time_spent(&self) -> String {
+        self.time_spent
+            .as_ref()
+            .map(TimeSpent::display)
+            .unwrap_or_else(|| "0h 00m".to_string())
+    }
+}
+
+/// Request body for creating or updating a worklog.
+///
+/// Only fields that are set are serialized, so the same struct can be
+/// used for partial updates.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorklogRequest {
+    /// Description of the work performed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Technician who performed the work.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<NamedEntity>,
+
+    /// Time spent on the work.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_spent: Option<TimeSpent>,
+}
+
+impl WorklogRequest {
+    /// Creates an empty worklog request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the owner technician by ID.
+    pub fn with_owner_id(mut self, owner_id: impl Into<String>) -> Self {
+        self.owner = Some(NamedEntity {
+            id: Some(owner_id.into()),
+            name: None,
+            email_id: None,
+        });
+        self
+    }
+
+    /// Sets the time spent from a total number of minutes.
+    pub fn with_time_spent_minutes(mut self, minutes: u32) -> Self {
+        self.time_spent = Some(TimeSpent::from_minutes(minutes));
+        self
+    }
+
+    /// Returns true if no fields are set.
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.owner.is_none() && self.time_spent.is_none()
+    }
+}
+
+/// Response wrapper for single worklog operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorklogResponse {
+    /// The worklog.
+    pub worklog: Worklog,
+}
+
+/// Response wrapper for list worklogs operations.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListWorklogsResponse {
+    /// List of worklogs.
+    #[serde(default)]
+    pub worklogs: Vec<Worklog>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_spent_from_minutes() {
+        let t = TimeSpent::from_minutes(95);
+        assert_eq!(t.hours, Some(1));
+        assert_eq!(t.minutes, Some(35));
+        assert_eq!(t.total_minutes(), 95);
+        assert_eq!(t.display(), "1h 35m");
+    }
+
+    #[test]
+    fn test_worklog_deserialize_string_time_spent() {
+        let json = r#"{
+            "id": 789,
+            "description": "Replaced toner",
+            "owner": {"id": "5", "name": "Jane Smith"},
+            "time_spent": {"hours": "2", "minutes": "15"}
+        }"#;
+        let worklog: Worklog = serde_json::from_str(json).unwrap();
+        assert_eq!(worklog.id, "789");
+        assert_eq!(worklog.display_owner(), "Jane Smith");
+        assert_eq!(worklog.display_time_spent(), "2h 15m");
+    }
+
+    #[test]
+    fn test_worklog_request_serialization_skips_unset() {
+        let req = WorklogRequest::new().with_time_spent_minutes(30);
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(json["time_spent"]["hours"], 0);
+        assert_eq!(json["time_spent"]["minutes"], 30);
+        assert!(json.get("description").is_none());
+        assert!(json.get("owner").is_none());
+    }
+}
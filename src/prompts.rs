@@ -0,0 +1,134 @@
+//! MCP prompts for common service desk workflows.
+//!
+//! Each prompt is a ready-made user message: instructions for the workflow
+//! followed by ticket data the server fetched beforehand, so the assistant
+//! starts from the same facts every time instead of deciding what to look up.
+//! This module defines the prompt arguments and builds the message text;
+//! fetching happens in [`crate::server`].
+
+use rmcp::schemars::{self, JsonSchema};
+use serde::Deserialize;
+
+/// Arguments for the triage_ticket prompt.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct TriageTicketArgs {
+    /// The ID of the ticket to triage.
+    pub request_id: String,
+}
+
+/// Arguments for the draft_reply prompt.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DraftReplyArgs {
+    /// The ID of the ticket to reply on.
+    pub request_id: String,
+
+    /// What the reply should achieve (e.g., "ask for the error message").
+    #[serde(default)]
+    pub goal: Option<String>,
+}
+
+/// Formats one block of pre-fetched data, or why it is missing.
+fn section(title: &str, data: &Result<String, String>) -> String {
+    match data {
+        Ok(text) => format!("## {}\n\n{}\n", title, text.trim_end()),
+        Err(e) => format!("## {}\n\n(Could not load: {})\n", title, e),
+    }
+}
+
+/// Builds the triage_ticket message from the ticket, duplicate candidates,
+/// and SLA deadlines.
+pub fn triage_ticket_text(
+    request_id: &str,
+    details: &str,
+    duplicates: &Result<String, String>,
+    sla: &Result<String, String>,
+) -> String {
+    format!(
+        "Triage ServiceDesk Plus ticket #{id}.\n\n\
+         1. Summarize the problem in one or two sentences.\n\
+         2. Say whether it duplicates an existing ticket; if so, recommend linking it \
+         with link_requests instead of working it separately.\n\
+         3. Recommend a category (check list_categories), priority, support group, and \
+         technician, with a short reason for each.\n\
+         4. Point out SLA deadlines that are at risk.\n\
+         5. List what information is missing from the requester, if any.\n\n\
+         Do not change the ticket until I confirm the recommendations.\n\n\
+         {details}\n{duplicates}\n{sla}",
+        id = request_id,
+        details = section("Ticket", &Ok(details.to_string())),
+        duplicates = section("Possible duplicates", duplicates),
+        sla = section("SLA", sla),
+    )
+}
+
+/// Builds the draft_reply message from the ticket and the reply's goal.
+pub fn draft_reply_text(request_id: &str, details: &str, goal: Option<&str>) -> String {
+    let goal = match goal {
+        Some(goal) => format!("The reply should: {}\n\n", goal),
+        None => String::new(),
+    };
+    format!(
+        "Draft an email reply to the requester of ServiceDesk Plus ticket #{id}.\n\n\
+         {goal}\
+         Write in the language the requester used, in a friendly and plain tone \
+         without internal jargon. Acknowledge what they reported, say what happens \
+         next, and ask only for information the ticket does not already contain. \
+         Never include internal notes or other requesters' details.\n\n\
+         Show me the draft first. Only send it with reply_to_requester after I \
+         approve it.\n\n\
+         {details}",
+        id = request_id,
+        goal = goal,
+        details = section("Ticket", &Ok(details.to_string())),
+    )
+}
+
+/// Builds the daily_standup message from the technician's queues.
+pub fn daily_standup_text(
+    my_queue: &Result<String, String>,
+    overdue: &Result<String, String>,
+    due_soon: &Result<String, String>,
+    unassigned: &Result<String, String>,
+) -> String {
+    format!(
+        "Prepare my daily service desk standup from the queues below.\n\n\
+         Give a short summary of my open workload, then list the tickets I should \
+         handle first today (overdue, then due today, then the oldest), with one line \
+         each on why. Call out unassigned tickets that need someone to pick them up. \
+         Keep it brief enough to read out in a minute.\n\n\
+         {mine}\n{overdue}\n{due_soon}\n{unassigned}",
+        mine = section("My open tickets", my_queue),
+        overdue = section("Overdue", overdue),
+        due_soon = section("Due today", due_soon),
+        unassigned = section("Unassigned", unassigned),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triage_ticket_text_includes_data_and_failures() {
+        let text = triage_ticket_text(
+            "42",
+            "Subject: Printer jammed",
+            &Ok("No duplicates found.".to_string()),
+            &Err("timeout".to_string()),
+        );
+        assert!(text.starts_with("Triage ServiceDesk Plus ticket #42."));
+        assert!(text.contains("## Ticket\n\nSubject: Printer jammed\n"));
+        assert!(text.contains("## Possible duplicates\n\nNo duplicates found.\n"));
+        assert!(text.contains("## SLA\n\n(Could not load: timeout)\n"));
+    }
+
+    #[test]
+    fn test_draft_reply_text_goal_is_optional() {
+        let with_goal = draft_reply_text("42", "Subject: VPN", Some("ask for a screenshot"));
+        assert!(with_goal.contains("The reply should: ask for a screenshot\n"));
+
+        let without_goal = draft_reply_text("42", "Subject: VPN", None);
+        assert!(!without_goal.contains("The reply should"));
+        assert!(without_goal.contains("## Ticket\n\nSubject: VPN\n"));
+    }
+}
@@ -0,0 +1,501 @@
+//! Plain-text rendering of SDP HTML content.
+//!
+//! SDP stores request descriptions, resolutions, notes, and email
+//! conversations as HTML. Tool output shows them as plain text instead: the
+//! markup adds nothing for the reader and costs far more tokens than the text
+//! it wraps. Paragraphs and line breaks survive as blank lines and newlines,
+//! links keep their target, and list items keep their bullet or number.
+//! Inline images, whose SDP URLs are dead links outside an authenticated
+//! session, become `[Image: name]` references.
+
+use crate::i18n::tr;
+
+/// Elements whose content is never shown.
+const SKIPPED_ELEMENTS: [&str; 4] = ["script", "style", "head", "title"];
+
+/// Elements that start a new paragraph.
+const PARAGRAPH_ELEMENTS: [&str; 10] = [
+    "p",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "blockquote",
+    "table",
+    "pre",
+];
+
+/// Elements that start a new line.
+const LINE_ELEMENTS: [&str; 5] = ["div", "tr", "dt", "dd", "section"];
+
+/// Converts SDP HTML content to plain text.
+///
+/// Text without markup only has its character entities decoded.
+pub fn html_to_text(html: &str) -> String {
+    if !looks_like_html(html) {
+        return decode_entities(html);
+    }
+
+    let mut renderer = Renderer::default();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        renderer.text(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+        let is_tag = rest[1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!');
+        let Some(end) = rest.find('>').filter(|_| is_tag) else {
+            renderer.text("<");
+            rest = &rest[1..];
+            continue;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let (closing, name, attributes) = parse_tag(tag);
+        if !closing && SKIPPED_ELEMENTS.contains(&name.as_str()) {
+            let close = format!("</{}", name);
+            rest = match rest.to_ascii_lowercase().find(&close) {
+                Some(i) => rest[i..].find('>').map_or("", |e| &rest[i + e + 1..]),
+                None => "",
+            };
+            continue;
+        }
+        renderer.tag(closing, &name, attributes);
+    }
+    renderer.text(rest);
+    renderer.finish()
+}
+
+/// Returns the `src` of each inline image in HTML content, in order and
+/// without duplicates. Images embedded as `data:` URIs are left out.
+pub fn inline_image_sources(html: &str) -> Vec<String> {
+    let lower = html.to_ascii_lowercase();
+    let mut sources: Vec<String> = Vec::new();
+    let mut search = 0;
+    while let Some(found) = lower[search..].find("<img") {
+        let start = search + found + "<img".len();
+        let Some(end) = lower[start..].find('>').map(|end| start + end) else {
+            break;
+        };
+        search = end;
+        if !html[start..].starts_with(|c: char| c.is_whitespace() || c == '/') {
+            continue;
+        }
+        if let Some(src) = attribute(&html[start..end], "src") {
+            if !src.is_empty() && !src.starts_with("data:") && !sources.contains(&src) {
+                sources.push(src);
+            }
+        }
+    }
+    sources
+}
+
+/// Names an inline image by its alt text or file name.
+fn image_label(attributes: &str) -> Option<String> {
+    if let Some(alt) = attribute(attributes, "alt").filter(|alt| !alt.is_empty()) {
+        return Some(alt);
+    }
+    let src = attribute(attributes, "src")?;
+    if src.starts_with("data:") {
+        return None;
+    }
+    let (path, query) = src.split_once('?').unwrap_or((&src, ""));
+    let from_query = query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        ["filename", "name"]
+            .contains(&key.to_ascii_lowercase().as_str())
+            .then(|| value.to_string())
+    });
+    from_query
+        .or_else(|| path.rsplit('/').next().map(str::to_string))
+        .filter(|name| !name.is_empty())
+}
+
+/// Returns true if the text contains something that looks like a tag.
+fn looks_like_html(text: &str) -> bool {
+    text.match_indices('<').any(|(i, _)| {
+        let next = text[i + 1..].chars().next();
+        next.is_some_and(|c| c.is_ascii_alphabetic() || c == '/' || c == '!')
+            && text[i..].contains('>')
+    })
+}
+
+/// Splits a tag's inner text into (is closing, lowercase name, attributes).
+fn parse_tag(tag: &str) -> (bool, String, &str) {
+    let tag = tag.trim();
+    let (closing, tag) = match tag.strip_prefix('/') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, tag),
+    };
+    let name_end = tag
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or(tag.len());
+    (
+        closing,
+        tag[..name_end].to_ascii_lowercase(),
+        &tag[name_end..],
+    )
+}
+
+/// Reads an attribute value (quoted or bare) from a tag's attribute text.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let lower = attributes.to_ascii_lowercase();
+    let mut search = 0;
+    while let Some(found) = lower[search..].find(name) {
+        let start = search + found;
+        search = start + name.len();
+        let preceded_by_space = lower[..start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        let after = attributes[search..].trim_start();
+        let Some(value) = after.strip_prefix('=').map(str::trim_start) else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let raw = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or(""),
+            _ => value
+                .split(|c: char| c.is_whitespace() || c == '>')
+                .next()
+                .unwrap_or(""),
+        };
+        return Some(decode_entities(raw.trim()));
+    }
+    None
+}
+
+/// Decodes named and numeric character entities; unknown ones are kept as-is.
+fn decode_entities(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end + 1])?, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                output.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Decodes one entity name (without `&` and `;`).
+fn decode_entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "bull" => '•',
+        "copy" => '©',
+        "euro" => '€',
+        "aelig" => 'æ',
+        "AElig" => 'Æ',
+        "oslash" => 'ø',
+        "Oslash" => 'Ø',
+        "aring" => 'å',
+        "Aring" => 'Å',
+        "auml" => 'ä',
+        "Auml" => 'Ä',
+        "ouml" => 'ö',
+        "Ouml" => 'Ö',
+        "uuml" => 'ü',
+        "Uuml" => 'Ü',
+        "eacute" => 'é',
+        _ => return None,
+    })
+}
+
+/// Builds the plain-text output as tags and text are read.
+#[derive(Default)]
+struct Renderer {
+    output: String,
+    /// Whitespace was seen since the last word.
+    pending_space: bool,
+    /// Open lists, innermost last: `Some(next number)` for `<ol>`, `None` for `<ul>`.
+    lists: Vec<Option<usize>>,
+    /// Target of the open link and where its text starts in the output.
+    link: Option<(String, usize)>,
+    /// Depth of open `<pre>` elements, inside which whitespace is kept.
+    preformatted: usize,
+}
+
+impl Renderer {
+    /// Appends text, collapsing whitespace outside `<pre>`.
+    fn text(&mut self, raw: &str) {
+        let text = decode_entities(raw);
+        if self.preformatted > 0 {
+            self.output.push_str(&text);
+            return;
+        }
+        for c in text.chars() {
+            if c.is_whitespace() {
+                self.pending_space = true;
+                continue;
+            }
+            if self.pending_space && !self.output.is_empty() && !self.output.ends_with(['\n', ' '])
+            {
+                self.output.push(' ');
+            }
+            self.pending_space = false;
+            self.output.push(c);
+        }
+    }
+
+    /// Handles an opening or closing tag.
+    fn tag(&mut self, closing: bool, name: &str, attributes: &str) {
+        match name {
+            "br" => self.line_break(),
+            "hr" => {
+                self.start_line();
+                self.output.push_str("---\n");
+            }
+            "ul" | "ol" => {
+                if closing {
+                    self.lists.pop();
+                } else {
+                    self.lists.push((name == "ol").then_some(1));
+                }
+                if self.lists.is_empty() {
+                    self.start_paragraph();
+                } else {
+                    self.start_line();
+                }
+            }
+            "li" if !closing => {
+                self.start_line();
+                let depth = self.lists.len().saturating_sub(1);
+                self.output.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        self.output.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => self.output.push_str("- "),
+                }
+            }
+            "td" | "th" if !closing && !self.output.is_empty() && !self.output.ends_with('\n') => {
+                self.output.push_str(" | ");
+                self.pending_space = false;
+            }
+            "a" => {
+                if closing {
+                    self.close_link();
+                } else {
+                    self.link = attribute(attributes, "href").map(|href| (href, self.output.len()));
+                }
+            }
+            "img" if !closing => {
+                let reference = match image_label(attributes) {
+                    Some(label) => format!("[{}: {}]", tr("Image"), label),
+                    None => format!("[{}]", tr("Image")),
+                };
+                self.pending_space = true;
+                self.text(&reference.replace('&', "&amp;"));
+                self.pending_space = true;
+            }
+            "pre" => {
+                self.start_paragraph();
+                if closing {
+                    self.preformatted = self.preformatted.saturating_sub(1);
+                } else {
+                    self.preformatted += 1;
+                }
+            }
+            _ if PARAGRAPH_ELEMENTS.contains(&name) => self.start_paragraph(),
+            _ if LINE_ELEMENTS.contains(&name) => self.start_line(),
+            _ => {}
+        }
+    }
+
+    /// Adds the link target after its text, unless the text already shows it.
+    fn close_link(&mut self) {
+        let Some((href, start)) = self.link.take() else {
+            return;
+        };
+        if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+            return;
+        }
+        let text = self.output[start..].trim();
+        let target = href.strip_prefix("mailto:").unwrap_or(&href);
+        if text.is_empty() {
+            self.output.push_str(target);
+        } else if text != target && text != href {
+            self.output.push_str(&format!(" ({})", href));
+        }
+    }
+
+    /// Ends the current line.
+    fn line_break(&mut self) {
+        self.trim_trailing_spaces();
+        self.output.push('\n');
+        self.pending_space = false;
+    }
+
+    /// Moves to the start of a line unless already there.
+    fn start_line(&mut self) {
+        self.trim_trailing_spaces();
+        if !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push('\n');
+        }
+        self.pending_space = false;
+    }
+
+    /// Leaves a blank line before what follows, unless at the start.
+    fn start_paragraph(&mut self) {
+        self.start_line();
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let trimmed = self.output.trim_end_matches([' ', '\t']).len();
+        self.output.truncate(trimmed);
+    }
+
+    /// Returns the text with at most one blank line in a row and no
+    /// trailing whitespace.
+    fn finish(self) -> String {
+        let mut output = String::with_capacity(self.output.len());
+        let mut blank_lines = 0;
+        for line in self.output.trim_matches('\n').lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                blank_lines += 1;
+                if blank_lines > 1 {
+                    continue;
+                }
+            } else {
+                blank_lines = 0;
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+        output.truncate(output.trim_end().len());
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text_is_unchanged() {
+        assert_eq!(
+            html_to_text("VPN drops\nafter sleep"),
+            "VPN drops\nafter sleep"
+        );
+        assert_eq!(html_to_text("a < b && c > d"), "a < b && c > d");
+        assert_eq!(html_to_text("Tom &amp; Jerry"), "Tom & Jerry");
+    }
+
+    #[test]
+    fn test_paragraphs_and_line_breaks() {
+        let html = "<div>Hi,</div><div><br></div><p>The printer   is\n jammed.</p>\
+                    <p>Line one<br/>Line two</p>";
+        assert_eq!(
+            html_to_text(html),
+            "Hi,\n\nThe printer is jammed.\n\nLine one\nLine two"
+        );
+    }
+
+    #[test]
+    fn test_links_keep_their_target() {
+        let html = r#"See <a href="https://kb.example.com/1?a=1&amp;b=2">the guide</a>, mail
+            <a href='mailto:it@example.com'>it@example.com</a> or
+            <a href="https://example.com">https://example.com</a>."#;
+        assert_eq!(
+            html_to_text(html),
+            "See the guide (https://kb.example.com/1?a=1&b=2), mail it@example.com or https://example.com."
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let html =
+            "<p>Steps:</p><ol><li>Restart</li><li>Log in<ul><li>with MFA</li></ul></li></ol>\
+                    <ul><li>Done</li></ul>";
+        assert_eq!(
+            html_to_text(html),
+            "Steps:\n\n1. Restart\n2. Log in\n  - with MFA\n\n- Done"
+        );
+    }
+
+    #[test]
+    fn test_skips_styles_scripts_and_comments() {
+        let html = "<html><head><title>Mail</title><style>p { color: red; }</style></head>\
+                    <body><!-- quoted --><script>alert(1)</script><p>Hello&nbsp;there&#33; \
+                    K&oslash;benhavn &#x2013; OK</p></body></html>";
+        assert_eq!(html_to_text(html), "Hello there! København – OK");
+    }
+
+    #[test]
+    fn test_tables_and_preformatted_text() {
+        let html = "<table><tr><th>Host</th><th>State</th></tr><tr><td>srv1</td><td>down</td></tr>\
+                    </table><pre>  indented\n    code</pre>";
+        assert_eq!(
+            html_to_text(html),
+            "Host | State\nsrv1 | down\n\n  indented\n    code"
+        );
+    }
+
+    #[test]
+    fn test_inline_images() {
+        let html = r#"<p>Error:<img src="/api/v3/requests/7/inline_images/3?fileName=error.png"
+            style="width: 400px">then <IMG alt="Dialog" src='/inline/dialog.jpg'/>
+            <img src="data:image/png;base64,iVBORw0KGgo="></p>
+            <img src="/api/v3/requests/7/inline_images/3?fileName=error.png">"#;
+        assert_eq!(
+            html_to_text(html),
+            "Error: [Image: error.png] then [Image: Dialog] [Image]\n\n[Image: error.png]"
+        );
+        assert_eq!(
+            inline_image_sources(html),
+            vec![
+                "/api/v3/requests/7/inline_images/3?fileName=error.png",
+                "/inline/dialog.jpg",
+            ]
+        );
+    }
+}
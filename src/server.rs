@@ -1,21 +1,260 @@
 //! MCP server implementation for Glass.
 //!
 //! This module defines the `GlassServer` struct that implements the MCP
-//! `ServerHandler` trait, exposing ServiceDesk Plus operations as tools.
+//! `ServerHandler` trait, exposing ServiceDesk Plus operations as tools
+//! and workflow prompts.
 
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use futures::{StreamExt, TryStreamExt};
 use rmcp::{
-    handler::server::{router::tool::ToolRouter, wrapper::Parameters},
-    model::{ServerCapabilities, ServerInfo},
-    tool, tool_handler, tool_router, ServerHandler,
+    handler::server::{
+        prompt::PromptContext,
+        router::{prompt::PromptRouter, tool::ToolRouter},
+        tool::ToolCallContext,
+        wrapper::Parameters,
+    },
+    model::{
+        CallToolRequestParams, CallToolResult, Content, GetPromptRequestParams, GetPromptResult,
+        ListPromptsResult, ListToolsResult, PaginatedRequestParams, ProgressNotificationParam,
+        ProgressToken, PromptMessage, PromptMessageRole, ServerCapabilities, ServerInfo, Tool,
+    },
+    prompt, prompt_router,
+    schemars::{self, JsonSchema},
+    service::{ElicitationError, RequestContext},
+    tool, tool_router, ErrorData, Peer, RoleServer, ServerHandler,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tracing::Instrument;
+
+use crate::i18n::{tr, tr_fill};
+use crate::models::{
+    display_json_value, group_threads, plain_text_to_html, Approval, ApprovalAction, ApprovalLevel,
+    Asset, AssociatedRecord, AssociationKind, Attachment, CategoryEntry, ClosureCode, Conversation,
+    CreateNoteRequest, CustomFieldDefinition, Department, HistoryEntry, Milestone, NamedEntity,
+    Note, Notification, OnHoldScheduler, PriorityMatrix, Project, ProjectRequest, ReplyRequest,
+    Request, RequestAssociations, RequestLink, RequestSummary, Requester, Resolution, SdpTimestamp,
+    Site, Sla, SlaCountdown, Solution, Status, SurveyResponse, Task, TaskRequest, Technician,
+    Watcher, Worklog, WorklogRequest,
+};
+use crate::prompts::{
+    daily_standup_text, draft_reply_text, triage_ticket_text, DraftReplyArgs, TriageTicketArgs,
+};
+use crate::rendering::{html_to_text, inline_image_sources};
+use crate::sdp_client::{
+    ClientSettings, HealthStatus, ListParams, RawNotification, SdpClient, WithContent,
 };
-
-use crate::models::{Conversation, Note, Request, RequestSummary, Technician};
-use crate::sdp_client::{ListParams, SdpClient};
 use crate::tools::{
-    AddNoteInput, AssignRequestInput, CloseRequestInput, CreateRequestInput, GetRequestInput,
-    ListRequestsInput, ListTechniciansInput, UpdateRequestInput,
+    AddNoteInput, AddResolutionInput, AddWorklogInput, ApprovalDecisionInput, AssignRequestInput,
+    AssociateRequestInput, AttachAssetInput, BulkAssignInput, BulkCloseInput,
+    BulkUpdateRequestsInput, CcRecipientsInput, ChangeRequesterInput, CloseRequestInput,
+    CompleteTaskInput, CreateProjectInput, CreateProjectTaskInput, CreateRequestInput,
+    CreateSolutionInput, CreateTaskInput, DeleteRequestInput, DeleteWorklogInput,
+    DissociateRequestInput, DueSoonInput, EntryOrder, EscalateRequestInput,
+    ExportRequestMarkdownInput, ExportRequestsCsvInput, FindDuplicateRequestsInput,
+    FindSimilarRequestsInput, GetAssetInput, GetNotificationInput, GetProjectInput,
+    GetRequestHistoryInput, GetRequestInput, GetRequestTimelineInput, GetRequestUrlInput,
+    GetRequesterInput, GetSlaInfoInput, GetSolutionInput, HoldRequestInput, LinkRequestsInput,
+    ListApprovalsInput, ListCategoriesInput, ListConversationsInput, ListNotesInput,
+    ListNotificationsInput, ListProjectTasksInput, ListProjectsInput, ListRequestsInput,
+    ListTasksInput, ListTechniciansInput, ListTrashedRequestsInput, ListUserAssetsInput,
+    ListWatchersInput, ListWorklogsInput, MyQueueInput, NotifyInput, OutputFormat,
+    OverdueTicketsInput, PickupRequestInput, PortalInput, ReplyToRequesterInput,
+    ResolveRequestInput, RestoreRequestInput, ResumeRequestInput, SearchAssetsInput,
+    SearchRequestersInput, SearchRequestsInput, SearchSolutionsInput, SetDueDateInput,
+    SubmitForApprovalInput, SuggestPriorityInput, SurveyResultsInput, TicketStatisticsInput,
+    UnassignedTicketsInput, UnlinkRequestsInput, UpdateProjectInput, UpdateProjectTaskInput,
+    UpdateRequestInput, UpdateTaskInput, UpdateWorklogInput, WatchersInput, MAX_EXPORT_ROWS,
 };
 
+/// Prefix SDP uses to thread email replies back to a ticket.
+///
+/// The `{id}` placeholder is replaced with the request ID.
+const REPLY_SUBJECT_TAG: &str = "[Request ID :##{id}##]";
+
+/// Priority used by `escalate_request` when none is given.
+const DEFAULT_ESCALATION_PRIORITY: &str = "High";
+
+/// Minimum similarity score for `find_duplicate_requests` and
+/// `find_similar_requests` to report a ticket.
+const DUPLICATE_MIN_SCORE: f64 = 0.3;
+
+/// Number of recent tickets `find_duplicate_requests` fetches to compare against.
+const DUPLICATE_CANDIDATE_LIMIT: u32 = 100;
+
+/// Number of finished tickets `find_similar_requests` fetches to compare against.
+const SIMILAR_CANDIDATE_LIMIT: u32 = 100;
+
+/// Characters of each resolution `find_similar_requests` shows.
+const SIMILAR_RESOLUTION_LENGTH: usize = 600;
+
+/// Common words ignored when comparing ticket subjects.
+const SUBJECT_STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "not", "can", "cannot", "from", "into", "after", "when", "this",
+    "that", "our", "are", "was", "has", "have", "does", "doesn", "don", "please", "help", "issue",
+    "problem", "request",
+];
+
+/// Days of closed tickets `survey_results` checks when no range is given.
+const SURVEY_DEFAULT_DAYS: i64 = 7;
+
+/// Closed tickets `survey_results` checks by default, and at most.
+const SURVEY_DEFAULT_LIMIT: u32 = 20;
+const SURVEY_MAX_LIMIT: u32 = 50;
+
+/// Notes and conversations get_request and get_request_timeline show, the
+/// most SDP returns in one page.
+const DETAIL_PAGE_SIZE: u32 = 100;
+
+/// Most inline images get_request attaches.
+const MAX_INLINE_IMAGES: usize = 5;
+
+/// Largest inline image get_request attaches, in bytes.
+const MAX_INLINE_IMAGE_BYTES: usize = 512 * 1024;
+
+/// Number of tickets export_requests_csv exports unless told otherwise.
+const DEFAULT_EXPORT_ROWS: u32 = 1_000;
+
+/// Maximum number of tickets a bulk tool updates concurrently.
+const BULK_CONCURRENCY: usize = 4;
+
+/// Page size of list and search tools when neither the caller nor the
+/// configuration sets one.
+pub const DEFAULT_LIST_LIMIT: u32 = 20;
+
+/// Filter value that lifts a configured default group or site.
+const ANY_SCOPE: &str = "any";
+
+/// Page size and filters applied to list and search tools when the caller
+/// leaves them out, so a team-scoped deployment sees its own queue by default.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListDefaults {
+    /// Number of results per page.
+    pub limit: u32,
+    /// Support group tickets are filtered to.
+    pub group: Option<String>,
+    /// Site tickets are filtered to.
+    pub site: Option<String>,
+}
+
+impl Default for ListDefaults {
+    fn default() -> Self {
+        Self {
+            limit: DEFAULT_LIST_LIMIT,
+            group: None,
+            site: None,
+        }
+    }
+}
+
+impl ListDefaults {
+    /// Returns the requested page size, or the default.
+    fn limit(&self, requested: Option<u32>) -> u32 {
+        requested.unwrap_or(self.limit)
+    }
+
+    /// Returns the support group to filter by.
+    fn group(&self, requested: Option<&str>) -> Option<String> {
+        scope_filter(requested, self.group.as_deref())
+    }
+
+    /// Returns the site to filter by.
+    fn site(&self, requested: Option<&str>) -> Option<String> {
+        scope_filter(requested, self.site.as_deref())
+    }
+
+    /// Filters `params` to the requested (or default) support group and site.
+    fn scope(&self, params: ListParams, group: Option<&str>, site: Option<&str>) -> ListParams {
+        let mut params = params;
+        if let Some(group) = self.group(group) {
+            params = params.with_group(group);
+        }
+        if let Some(site) = self.site(site) {
+            params = params.with_site(site);
+        }
+        params
+    }
+}
+
+/// Signature appended to replies and requester-visible notes, so email sent
+/// through the server reads like the desk's own correspondence.
+///
+/// The template may contain `{technician}`, `{email}`, and `{job_title}`,
+/// filled in from the API key's technician, and `\n` for a line break.
+#[derive(Debug, Clone)]
+pub struct ReplySignature {
+    template: String,
+}
+
+impl ReplySignature {
+    /// Placeholders filled in from the technician record.
+    const PLACEHOLDERS: [&'static str; 3] = ["{technician}", "{email}", "{job_title}"];
+
+    /// Creates a signature from a template.
+    pub fn new(template: &str) -> Self {
+        Self {
+            template: template.replace("\\n", "\n"),
+        }
+    }
+
+    /// Returns whether rendering needs the technician record.
+    fn needs_technician(&self) -> bool {
+        Self::PLACEHOLDERS
+            .iter()
+            .any(|placeholder| self.template.contains(placeholder))
+    }
+
+    /// Fills in the placeholders as plain text.
+    ///
+    /// Values the technician record lacks are left empty, and lines that end
+    /// up blank are dropped.
+    fn render(&self, technician: Option<&Technician>) -> String {
+        let name = technician.map(|t| t.display_name()).unwrap_or_default();
+        let email = technician
+            .and_then(|t| t.email_id.as_deref())
+            .unwrap_or_default();
+        let job_title = technician
+            .and_then(|t| t.job_title.as_deref())
+            .unwrap_or_default();
+
+        let rendered = self
+            .template
+            .replace("{technician}", name)
+            .replace("{email}", email)
+            .replace("{job_title}", job_title);
+        rendered
+            .lines()
+            .map(str::trim_end)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Appends the rendered signature to an HTML body.
+    fn append_to(&self, body: &str, technician: Option<&Technician>) -> String {
+        format!(
+            "{}<br><br>{}",
+            body,
+            plain_text_to_html(&self.render(technician))
+        )
+    }
+}
+
+/// Picks a group or site filter: the caller's value, none for "any", or the
+/// configured default.
+fn scope_filter(requested: Option<&str>, default: Option<&str>) -> Option<String> {
+    match requested {
+        Some(value) if value.eq_ignore_ascii_case(ANY_SCOPE) => None,
+        Some(value) => Some(value.to_string()),
+        None => default.map(str::to_string),
+    }
+}
+
 /// The Glass MCP server.
 ///
 /// This server exposes ServiceDesk Plus operations as MCP tools.
@@ -24,7 +263,19 @@ pub struct GlassServer {
     /// SDP client for API operations.
     sdp_client: SdpClient,
     /// Tool router for MCP tool dispatch.
-    tool_router: ToolRouter<Self>,
+    tool_router: Arc<ToolRouter<Self>>,
+    /// Prompt router for MCP prompt dispatch.
+    prompt_router: Arc<PromptRouter<Self>>,
+    /// Characters of a description or resolution shown before truncating.
+    max_description_length: usize,
+    /// Page size and filters for list and search tools.
+    list_defaults: ListDefaults,
+    /// Signature appended to outgoing replies and requester-visible notes.
+    reply_signature: Option<ReplySignature>,
+    /// Directory export tools may save files to.
+    export_dir: Option<PathBuf>,
+    /// Impact and urgency to priority mapping used by suggest_priority.
+    priority_matrix: PriorityMatrix,
 }
 
 #[tool_router]
@@ -37,10 +288,55 @@ impl GlassServer {
     pub fn new(sdp_client: SdpClient) -> Self {
         Self {
             sdp_client,
-            tool_router: Self::tool_router(),
+            tool_router: Arc::new(Self::tool_router()),
+            prompt_router: Arc::new(Self::prompt_router()),
+            max_description_length: DEFAULT_DESCRIPTION_LENGTH,
+            list_defaults: ListDefaults::default(),
+            reply_signature: None,
+            export_dir: None,
+            priority_matrix: PriorityMatrix::default(),
         }
     }
 
+    /// Sets how many characters of a description, resolution, or article
+    /// tool output shows before truncating (default 2000).
+    #[must_use]
+    pub fn with_max_description_length(mut self, length: usize) -> Self {
+        self.max_description_length = length;
+        self
+    }
+
+    /// Sets the page size and group and site filters that list and search
+    /// tools use when the caller leaves them out.
+    #[must_use]
+    pub fn with_list_defaults(mut self, defaults: ListDefaults) -> Self {
+        self.list_defaults = defaults;
+        self
+    }
+
+    /// Sets the signature appended to replies and requester-visible notes.
+    #[must_use]
+    pub fn with_reply_signature(mut self, signature: ReplySignature) -> Self {
+        self.reply_signature = Some(signature);
+        self
+    }
+
+    /// Sets the directory export_requests_csv saves files to. Without one,
+    /// exports are only returned as text.
+    #[must_use]
+    pub fn with_export_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.export_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets the priority matrix suggest_priority applies, replacing the
+    /// default ITIL matrix.
+    #[must_use]
+    pub fn with_priority_matrix(mut self, matrix: PriorityMatrix) -> Self {
+        self.priority_matrix = matrix;
+        self
+    }
+
     /// A simple ping tool to verify the server is running.
     ///
     /// This tool is useful for testing connectivity and validating
@@ -48,57 +344,132 @@ impl GlassServer {
     ///
     /// Returns "pong" on success.
     #[tool(
-        description = "Test connectivity to the Glass MCP server. Returns 'pong' if the server is running correctly."
+        description = "Test connectivity to the Glass MCP server. Returns 'pong' if the server is running correctly.",
+        annotations(read_only_hint = true)
     )]
     fn ping(&self) -> String {
         tracing::debug!("ping tool called");
         "pong".to_string()
     }
 
+    /// Check that SDP is reachable and report the client's health.
+    ///
+    /// Unlike `ping`, this makes a real SDP call and measures it.
+    #[tool(
+        description = "Check the connection to ServiceDesk Plus: runs a test call and reports whether SDP is reachable, the round-trip latency, circuit breaker state, retries since startup, the last error seen, and endpoints this SDP version lacks. Use when tools fail or seem slow.",
+        annotations(read_only_hint = true)
+    )]
+    async fn health(&self) -> Result<CallToolResult, ErrorData> {
+        structured(self.health_output()).await
+    }
+
+    /// Runs `health`, returning its text and structured content.
+    async fn health_output(&self) -> Result<ToolOutput, String> {
+        tracing::debug!("health tool called");
+
+        let started = std::time::Instant::now();
+        let connection = self
+            .sdp_client
+            .test_connection()
+            .await
+            .map_err(|e| self.sanitize_error(&e));
+        let latency = started.elapsed();
+        let health = self.sdp_client.health();
+        let read_only = self.sdp_client.is_read_only();
+
+        Ok(ToolOutput::new(
+            format_health(&connection, latency, &health, read_only),
+            json!({
+                "reachable": connection.is_ok(),
+                "latency_ms": latency.as_millis() as u64,
+                "error": connection.err(),
+                "read_only": read_only,
+                "health": health,
+            }),
+        ))
+    }
+
+    /// Report the server's version, configuration, and tools.
+    #[tool(
+        description = "Show Glass's version and configuration: SDP URL, Cloud or on-prem, portal, read-only mode, caching, transport, endpoints this SDP lacks, and which tools are enabled. Use to explain why a tool is missing or refused.",
+        annotations(read_only_hint = true)
+    )]
+    fn server_info(&self) -> Result<CallToolResult, ErrorData> {
+        tracing::debug!("server_info tool called");
+
+        let settings = self.sdp_client.settings();
+        let unsupported = self.sdp_client.health().unsupported_endpoints;
+        let tools: Vec<(String, bool)> = self
+            .tool_router
+            .list_all()
+            .into_iter()
+            .map(|tool| {
+                let read_only = tool
+                    .annotations
+                    .as_ref()
+                    .is_some_and(|a| a.read_only_hint == Some(true));
+                (tool.name.to_string(), read_only)
+            })
+            .collect();
+        let disabled: Vec<&str> = tools
+            .iter()
+            .filter(|(_, read_only)| settings.read_only && !read_only)
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        let text = format_server_info(
+            &settings,
+            &unsupported,
+            &tools,
+            self.max_description_length,
+            &self.list_defaults,
+        );
+        let data = json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "transport": "stdio",
+            "otel": cfg!(feature = "otel"),
+            "settings": settings,
+            "max_description_length": self.max_description_length,
+            "list_defaults": self.list_defaults,
+            "language": crate::i18n::language().code(),
+            "unsupported_endpoints": unsupported,
+            "tools": tools.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            "disabled_tools": disabled,
+        });
+        Ok(CallToolResult {
+            content: vec![Content::text(text)],
+            structured_content: Some(data),
+            is_error: Some(false),
+            meta: None,
+        })
+    }
+
     /// List service desk tickets with optional filters.
     ///
     /// Can filter by status, priority, technician, requester, or date range.
     /// Returns paginated results.
     #[tool(
-        description = "List service desk tickets. Can filter by status, priority, technician name, or requester name. Use open_only=true to exclude closed tickets. Returns paginated results with ticket ID, subject, status, and assignee."
+        description = "List service desk tickets. Use query to match keywords in the subject or description. Can filter by status, priority, technician name, requester name, support group, or request type (Incident vs Service Request); separate several statuses or priorities with | to match any of them (e.g. priority=\"High|Urgent\"). Use open_only=true to exclude closed tickets, overdue_only=true for tickets past their due date, has_attachments=true for tickets with files attached, updated_after to see what changed since a given time, and sort_by/sort_order (e.g. created_time asc for oldest first) to order results. Returns paginated results with ticket ID, subject, status, and assignee.",
+        annotations(read_only_hint = true)
     )]
     async fn list_requests(
         &self,
         Parameters(input): Parameters<ListRequestsInput>,
-    ) -> Result<String, String> {
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_requests_output(input)).await
+    }
+
+    /// Runs `list_requests`, returning its text and structured content.
+    async fn list_requests_output(&self, input: ListRequestsInput) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
         input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
         tracing::debug!(?input, "list_requests tool called");
 
-        // Build ListParams from input - all filters are applied as search criteria
-        let mut params = ListParams::new();
-
-        if let Some(ref technician) = input.technician {
-            params = params.with_technician(technician);
-        }
-        if let Some(ref requester) = input.requester {
-            params = params.with_requester(requester);
-        }
-        if let Some(ref status) = input.status {
-            params = params.with_status(status);
-        }
-        if let Some(ref priority) = input.priority {
-            params = params.with_priority(priority);
-        }
-        if let Some(ref created_after) = input.created_after {
-            params = params.with_created_after(created_after);
-        }
-        if let Some(ref created_before) = input.created_before {
-            params = params.with_created_before(created_before);
-        }
-
-        // Use server-side filtering for open_only
-        if input.open_only == Some(true) {
-            params = params.with_open_only();
-        }
+        let mut params = self.request_list_params(&client, &input).await?;
 
-        let requested_limit = input.limit.unwrap_or(20).min(100);
+        let requested_limit = self.list_defaults.limit(input.limit).min(100);
         params = params.with_limit(requested_limit);
 
         if let Some(offset) = input.offset {
@@ -106,1044 +477,12031 @@ impl GlassServer {
         }
 
         // Execute the request
-        let requests = self.sdp_client.list_requests(params).await.map_err(|e| {
+        let requests = client.list_requests(params).await.map_err(|e| {
             let sanitized = self.sanitize_error(&e);
             tracing::error!(error = %sanitized, "Failed to list requests");
             format!("Failed to list requests: {}", sanitized)
         })?;
 
         // Format the response
-        Ok(format_request_list(&requests))
+        Ok(ToolOutput::new(
+            format_request_list(&requests, |id| Some(client.request_web_url(id))),
+            json!({"requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
     }
 
-    /// Get full details of a single service desk ticket.
+    /// Search tickets by free text.
     ///
-    /// Returns complete information including description, notes, conversations, and history.
+    /// Matches the query against subject and description, and optionally notes.
     #[tool(
-        description = "Get full details of a single service desk ticket including description, notes, and history."
+        description = "Search service desk tickets by free text in the subject and description. Set include_notes=true to also search note contents, and open_only=true to skip closed tickets. Returns the same summary as list_requests.",
+        annotations(read_only_hint = true)
     )]
-    async fn get_request(
+    async fn search_requests(
         &self,
-        Parameters(input): Parameters<GetRequestInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<SearchRequestsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.search_requests_output(input)).await
+    }
+
+    /// Runs `search_requests`, returning its text and structured content.
+    async fn search_requests_output(
+        &self,
+        input: SearchRequestsInput,
+    ) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
         input.validate().map_err(|e| e.to_string())?;
-        tracing::debug!(request_id = %input.request_id, "get_request tool called");
-
-        let request = self
-            .sdp_client
-            .get_request(&input.request_id)
-            .await
-            .map_err(|e| {
-                let sanitized = self.sanitize_error(&e);
-                tracing::error!(error = %sanitized, request_id = %input.request_id, "Failed to get request");
-                format!("Failed to get request {}: {}", input.request_id, sanitized)
-            })?;
-
-        // Fetch notes for this request, including content from content_url
-        let (notes, notes_error) = match self
-            .sdp_client
-            .list_notes_with_content(&input.request_id)
-            .await
-        {
-            Ok(n) => (n, None),
-            Err(e) => {
-                let err_msg = self.sanitize_error(&e);
-                tracing::warn!(error = %err_msg, request_id = %input.request_id, "Failed to fetch notes");
-                (vec![], Some(format!("Notes: {}", err_msg)))
-            }
-        };
-
-        // Fetch conversations (email replies) for this request, including content
-        let (conversations, conv_error) = match self
-            .sdp_client
-            .list_conversations_with_content(&input.request_id)
-            .await
-        {
-            Ok(c) => (c, None),
-            Err(e) => {
-                let err_msg = self.sanitize_error(&e);
-                tracing::warn!(error = %err_msg, request_id = %input.request_id, "Failed to fetch conversations");
-                (vec![], Some(format!("Conversations: {}", err_msg)))
-            }
-        };
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "search_requests tool called");
 
-        // Collect any fetch errors
-        let fetch_errors: Vec<String> = [notes_error, conv_error].into_iter().flatten().collect();
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+        let mut params = ListParams::new()
+            .with_text(&input.query, input.include_notes == Some(true))
+            .with_limit(limit);
+        if input.open_only == Some(true) {
+            params = params.with_open_only();
+        }
+        if let Some(group) = self.list_defaults.group(None) {
+            params = params.with_group(group);
+        }
+        if let Some(site) = self.list_defaults.site(None) {
+            params = params.with_site(site);
+        }
 
-        // Get the web URL for this request
-        let web_url = self.sdp_client.request_web_url(&input.request_id);
+        let requests = client.list_requests(params).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to search requests");
+            format!("Failed to search requests: {}", sanitized)
+        })?;
 
-        // Format the response
-        Ok(format_request_details(
-            &request,
-            &notes,
-            &conversations,
-            &web_url,
-            &fetch_errors,
-        ))
+        Ok(ToolOutput::new(
+            format_request_list(&requests, |id| Some(client.request_web_url(id))),
+            json!({"requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
     }
 
-    /// List technicians available for ticket assignment.
-    ///
-    /// Returns IDs and names so you can assign tickets to specific technicians.
+    /// Export every ticket matching list_requests filters as CSV.
     #[tool(
-        description = "List all technicians available for ticket assignment. Returns IDs and names. Use the ID when assigning tickets."
+        description = "Export service desk tickets as CSV for spreadsheets. Takes the same filters as list_requests and pages through all matching tickets (up to max_rows, default 1000). Returns the CSV text, or with save=true writes it to a new file in the server's export directory and returns the file path.",
+        annotations(destructive_hint = false, idempotent_hint = false)
     )]
-    async fn list_technicians(
+    async fn export_requests_csv(
         &self,
-        Parameters(input): Parameters<ListTechniciansInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<ExportRequestsCsvInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.export_requests_csv_output(input)).await
+    }
+
+    /// Runs `export_requests_csv`, returning its text and structured content.
+    async fn export_requests_csv_output(
+        &self,
+        input: ExportRequestsCsvInput,
+    ) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
         input.validate().map_err(|e| e.to_string())?;
-        tracing::debug!(?input, "list_technicians tool called");
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "export_requests_csv tool called");
+
+        let export_path = match (input.save == Some(true), &self.export_dir) {
+            (false, _) => None,
+            (true, Some(dir)) => Some(dir.join(export_file_name(
+                input.file_name.as_deref(),
+                &chrono::Local::now(),
+            ))),
+            (true, None) => {
+                return Err(
+                    "Saving exports is not enabled: set GLASS_EXPORT_DIR on the \
+                     server, or leave save unset to get the CSV as text"
+                        .to_string(),
+                )
+            }
+        };
 
-        let technicians = self
-            .sdp_client
-            .list_technicians(input.group.as_deref(), input.limit)
+        let params = self.request_list_params(&client, &input.filters()).await?;
+        let max_rows = input.max_rows.unwrap_or(DEFAULT_EXPORT_ROWS);
+        let requests: Vec<RequestSummary> = client
+            .list_all_requests(params, max_rows as usize)
+            .try_collect()
             .await
             .map_err(|e| {
                 let sanitized = self.sanitize_error(&e);
-                tracing::error!(error = %sanitized, "Failed to list technicians");
-                format!("Failed to list technicians: {}", sanitized)
+                tracing::error!(error = %sanitized, "Failed to export requests");
+                format!("Failed to export requests: {}", sanitized)
             })?;
 
-        // Format the response
-        Ok(format_technician_list(&technicians))
-    }
+        let csv = requests_csv(&requests, |id| client.request_web_url(id));
+        let count = requests.len().to_string();
+        let capped = requests.len() == max_rows as usize;
+
+        let (mut summary, structured) = match &export_path {
+            Some(path) => {
+                write_new_file(path, csv.as_bytes()).await.map_err(|e| {
+                    tracing::error!(error = %e, path = %path.display(), "Failed to write export");
+                    match e.kind() {
+                        std::io::ErrorKind::AlreadyExists => format!(
+                            "{} already exists; choose another file_name",
+                            path.display()
+                        ),
+                        _ => format!("Failed to write {}: {}", path.display(), e),
+                    }
+                })?;
+                let path_text = path.display().to_string();
+                (
+                    tr_fill("Exported {} ticket(s) to {}.", &[&count, &path_text]),
+                    json!({"count": requests.len(), "capped": capped, "path": path}),
+                )
+            }
+            None => (
+                tr_fill("Exported {} ticket(s) as CSV.", &[&count]),
+                json!({"count": requests.len(), "capped": capped, "csv": csv}),
+            ),
+        };
+        if capped {
+            summary.push(' ');
+            summary.push_str(&tr_fill(
+                "Stopped at max_rows ({}); narrow the filters or raise it (up to {}).",
+                &[&max_rows.to_string(), &MAX_EXPORT_ROWS.to_string()],
+            ));
+        }
+        let text = match export_path {
+            Some(_) => summary,
+            None => format!("{}\n\n{}", summary, csv),
+        };
 
-    // ========================================================================
-    // Write tools (M4)
-    // ========================================================================
+        Ok(ToolOutput::new(text, structured))
+    }
 
-    /// Create a new service desk ticket.
+    /// Find recent tickets that look like duplicates.
     ///
-    /// Subject is required. Returns the created ticket with its assigned ID.
+    /// Compares subject words (and requester, for a ticket) against recent
+    /// tickets and scores each candidate.
     #[tool(
-        description = "Create a new service desk ticket. Subject is required. Returns the created ticket with its assigned ID."
+        description = "Find likely duplicates of a ticket (request_id) or of a subject text among recent tickets. Scores subject word overlap, with a bonus for the same requester, and lists matches best first. Use before merging or linking duplicates.",
+        annotations(read_only_hint = true)
     )]
-    async fn create_request(
+    async fn find_duplicate_requests(
         &self,
-        Parameters(input): Parameters<CreateRequestInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<FindDuplicateRequestsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.find_duplicate_requests_output(input)).await
+    }
+
+    /// Runs `find_duplicate_requests`, returning its text and structured content.
+    async fn find_duplicate_requests_output(
+        &self,
+        input: FindDuplicateRequestsInput,
+    ) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
-        tracing::debug!(subject = %input.subject, "create_request tool called");
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "find_duplicate_requests tool called");
 
-        // Validate subject is non-empty and all fields are within length limits
-        if input.subject.is_empty() {
-            return Err("Subject is required and cannot be empty.".to_string());
+        let source = match &input.request_id {
+            Some(request_id) => Some(client.get_request(request_id).await.map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %request_id,
+                    "Failed to get request for duplicate search"
+                );
+                format!("Failed to get request {}: {}", request_id, sanitized)
+            })?),
+            None => None,
+        };
+        let subject = source
+            .as_ref()
+            .map(|r| r.display_subject().to_string())
+            .or_else(|| input.subject.clone())
+            .unwrap_or_default();
+
+        let tokens = subject_tokens(&subject);
+        if tokens.is_empty() {
+            return Err(format!(
+                "The subject {:?} has no distinctive words to compare.",
+                subject
+            ));
         }
-        input.validate().map_err(|e| e.to_string())?;
 
-        let request = self.sdp_client.create_request(&input).await.map_err(|e| {
+        let days = input.days.unwrap_or(30).clamp(1, 365);
+        let since = chrono::Utc::now().timestamp_millis() - i64::from(days) * 86_400_000;
+        let mut keywords: Vec<String> = tokens.iter().cloned().collect();
+        keywords.sort_by_key(|w| std::cmp::Reverse(w.len()));
+        keywords.truncate(5);
+        let params = ListParams::new()
+            .with_subject_containing_any(&keywords)
+            .with_created_after(since)
+            .with_sort("created_time", "desc")
+            .with_limit(DUPLICATE_CANDIDATE_LIMIT);
+        let params = self.list_defaults.scope(params, None, None);
+
+        let candidates = client.list_requests(params).await.map_err(|e| {
             let sanitized = self.sanitize_error(&e);
-            tracing::error!(error = %sanitized, "Failed to create request");
-            format!("Failed to create request: {}", sanitized)
+            tracing::error!(error = %sanitized, "Failed to list duplicate candidates");
+            format!("Failed to search for duplicates: {}", sanitized)
         })?;
 
-        Ok(format_create_result(&request))
+        let limit = input.limit.unwrap_or(10).clamp(1, 50) as usize;
+        let matches = rank_duplicates(source.as_ref(), &tokens, &candidates, limit);
+        let source_id = source.as_ref().map(|r| r.id.as_str());
+        Ok(ToolOutput::new(
+            format_duplicate_matches(source_id, &subject, days, &matches),
+            json!({
+                "request_id": source_id,
+                "subject": subject,
+                "days": days,
+                "matches": matches
+                    .iter()
+                    .map(|(request, score)| json!({"request": request, "score": score}))
+                    .collect::<Vec<_>>(),
+            }),
+        ))
     }
 
-    /// Update an existing ticket's properties.
+    /// Find finished tickets like a ticket or text, with their resolutions.
     ///
-    /// Request ID is required. At least one field must be provided for update.
+    /// Compares subject words (and category) against resolved and closed
+    /// tickets, so known fixes turn up before work starts.
     #[tool(
-        description = "Update an existing ticket's properties such as priority, status, category, or assignment. Request ID is required."
+        description = "Find resolved or closed tickets similar to a ticket (request_id) or free text, with their resolutions, so known fixes surface before work starts. Scores subject word overlap, with a bonus for the same category, and lists matches best first.",
+        annotations(read_only_hint = true)
     )]
-    async fn update_request(
+    async fn find_similar_requests(
         &self,
-        Parameters(input): Parameters<UpdateRequestInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<FindSimilarRequestsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.find_similar_requests_output(input)).await
+    }
+
+    /// Runs `find_similar_requests`, returning its text and structured content.
+    async fn find_similar_requests_output(
+        &self,
+        input: FindSimilarRequestsInput,
+    ) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
-        tracing::debug!(request_id = %input.request_id, "update_request tool called");
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "find_similar_requests tool called");
 
-        // Validate that at least one field is being updated
-        if !input.has_updates() {
-            return Err(
-                "At least one field must be provided for update (subject, description, priority, status, category, subcategory, group, or technician_id).".to_string()
-            );
-        }
+        let source = match &input.request_id {
+            Some(request_id) => Some(client.get_request(request_id).await.map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %request_id,
+                    "Failed to get request for similar search"
+                );
+                format!("Failed to get request {}: {}", request_id, sanitized)
+            })?),
+            None => None,
+        };
+        let subject = source
+            .as_ref()
+            .map(|r| r.display_subject().to_string())
+            .or_else(|| input.text.clone())
+            .unwrap_or_default();
+        let category = input.category.clone().or_else(|| {
+            source
+                .as_ref()
+                .and_then(|r| r.category.as_ref())
+                .and_then(|c| c.name.clone())
+        });
 
-        // Validate subject is non-empty if provided, and all fields within length limits
-        if let Some(ref subject) = input.subject {
-            if subject.is_empty() {
-                return Err("Subject cannot be empty.".to_string());
-            }
+        let tokens = subject_tokens(&subject);
+        if tokens.is_empty() {
+            return Err(format!(
+                "The text {:?} has no distinctive words to compare.",
+                subject
+            ));
         }
-        input.validate().map_err(|e| e.to_string())?;
 
-        let request = self
-            .sdp_client
-            .update_request(&input.request_id, &input)
-            .await
-            .map_err(|e| {
-                let sanitized = self.sanitize_error(&e);
-                tracing::error!(error = %sanitized, request_id = %input.request_id, "Failed to update request");
-                format!("Failed to update request {}: {}", input.request_id, sanitized)
-            })?;
+        let days = input.days.unwrap_or(365).clamp(1, 1825);
+        let since = chrono::Utc::now().timestamp_millis() - i64::from(days) * 86_400_000;
+        let mut keywords: Vec<String> = tokens.iter().cloned().collect();
+        keywords.sort_by_key(|w| std::cmp::Reverse(w.len()));
+        keywords.truncate(5);
+        let finished: Vec<String> = Status::finished_names().map(String::from).collect();
+        let params = ListParams::new()
+            .with_subject_containing_any(&keywords)
+            .with_status_in(&finished)
+            .with_completed_after(since)
+            .with_sort("completed_time", "desc")
+            .with_limit(SIMILAR_CANDIDATE_LIMIT);
+        let params = self.list_defaults.scope(params, None, None);
+
+        let candidates = client.list_requests(params).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list similar candidates");
+            format!("Failed to search for similar tickets: {}", sanitized)
+        })?;
 
-        Ok(format_update_result(&request))
+        let limit = input.limit.unwrap_or(5).clamp(1, 20) as usize;
+        let matches = rank_similar(
+            source.as_ref().map(|r| r.id.as_str()),
+            &tokens,
+            category.as_deref(),
+            &candidates,
+            limit,
+        );
+
+        // Resolutions are only on the full ticket; a failed fetch is shown per match
+        let fetches: Vec<_> = matches
+            .iter()
+            .map(|(c, _)| client.get_request(&c.id))
+            .collect();
+        let details: Vec<_> = futures::stream::iter(fetches)
+            .buffered(BULK_CONCURRENCY)
+            .collect()
+            .await;
+        let resolved: Vec<_> = matches
+            .iter()
+            .zip(details)
+            .map(|(&(summary, score), detail)| {
+                let resolution = detail
+                    .map(|request| {
+                        request
+                            .resolution
+                            .and_then(|r| r.content)
+                            .map(|content| html_to_text(&content))
+                            .filter(|text| !text.trim().is_empty())
+                    })
+                    .map_err(|e| {
+                        let sanitized = self.sanitize_error(&e);
+                        tracing::warn!(
+                            error = %sanitized,
+                            request_id = %summary.id,
+                            "Failed to fetch resolution"
+                        );
+                        sanitized
+                    });
+                (summary, score, resolution)
+            })
+            .collect();
+
+        let source_id = source.as_ref().map(|r| r.id.as_str());
+        Ok(ToolOutput::new(
+            format_similar_requests(source_id, &subject, days, &resolved),
+            json!({
+                "request_id": source_id,
+                "subject": subject,
+                "days": days,
+                "matches": similar_matches_json(&resolved),
+            }),
+        ))
     }
 
-    /// Close a ticket with closure reason and comments.
+    /// Get full details of a single service desk ticket.
     ///
-    /// Request ID is required. Closure code and comments are optional.
+    /// Returns complete information including description, notes, conversations, and history.
     #[tool(
-        description = "Close a ticket with closure reason and comments. Request ID is required."
+        description = "Get full details of a single service desk ticket including description, attachments (file names and sizes), notes, and history. Inline images show as [Image: name]; set include_images=true to attach small ones as images.",
+        annotations(read_only_hint = true)
     )]
-    async fn close_request(
+    async fn get_request(
         &self,
-        Parameters(input): Parameters<CloseRequestInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<GetRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_request_output(input)).await
+    }
+
+    /// Runs `get_request`, returning its text and structured content.
+    async fn get_request_output(&self, input: GetRequestInput) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
         input.validate().map_err(|e| e.to_string())?;
-        tracing::debug!(request_id = %input.request_id, "close_request tool called");
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "get_request tool called");
 
-        let request = self
-            .sdp_client
-            .close_request(
-                &input.request_id,
-                input.closure_code.as_deref(),
-                input.closure_comments.as_deref(),
+        let request = client.get_request(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to get request"
+            );
+            format!("Failed to get request {}: {}", input.request_id, sanitized)
+        })?;
+
+        // Content that could not be fetched for individual notes and conversations
+        let mut content_errors = Vec::new();
+
+        // Fetch notes for this request, including content from content_url
+        let mut more_notes = false;
+        let (notes, notes_error) = match client
+            .list_notes_with_content(
+                &input.request_id,
+                DETAIL_PAGE_SIZE,
+                0,
+                EntryOrder::newest_first(input.order),
             )
             .await
-            .map_err(|e| {
-                let sanitized = self.sanitize_error(&e);
-                tracing::error!(error = %sanitized, request_id = %input.request_id, "Failed to close request");
-                format!("Failed to close request {}: {}", input.request_id, sanitized)
-            })?;
+        {
+            Ok(n) => {
+                content_errors.extend(
+                    n.failures
+                        .iter()
+                        .map(|f| format!("Note {}: {}", f.id, f.error)),
+                );
+                more_notes = n.has_more;
+                (n.items, None)
+            }
+            Err(e) => {
+                let err_msg = self.sanitize_error(&e);
+                tracing::warn!(
+                    error = %err_msg,
+                    request_id = %input.request_id,
+                    "Failed to fetch notes"
+                );
+                (vec![], Some(format!("Notes: {}", err_msg)))
+            }
+        };
+
+        // Fetch conversations (email replies) for this request, including content
+        let mut more_conversations = false;
+        let (conversations, conv_error) = match client
+            .list_conversations_with_content(
+                &input.request_id,
+                DETAIL_PAGE_SIZE,
+                0,
+                EntryOrder::newest_first(input.order),
+            )
+            .await
+        {
+            Ok(c) => {
+                content_errors.extend(
+                    c.failures
+                        .iter()
+                        .map(|f| format!("Conversation {}: {}", f.id, f.error)),
+                );
+                more_conversations = c.has_more;
+                (c.items, None)
+            }
+            Err(e) => {
+                let err_msg = self.sanitize_error(&e);
+                tracing::warn!(
+                    error = %err_msg,
+                    request_id = %input.request_id,
+                    "Failed to fetch conversations"
+                );
+                (vec![], Some(format!("Conversations: {}", err_msg)))
+            }
+        };
+
+        // Fetch requests linked to this one
+        let (links, links_error) = match client.list_request_links(&input.request_id).await {
+            Ok(l) => (l, None),
+            Err(e) => {
+                let err_msg = self.sanitize_error(&e);
+                tracing::warn!(
+                    error = %err_msg,
+                    request_id = %input.request_id,
+                    "Failed to fetch linked requests"
+                );
+                (vec![], Some(format!("Linked requests: {}", err_msg)))
+            }
+        };
+
+        // Fetch the problem and change this request is associated with
+        let (associations, associations_error) =
+            match client.get_request_associations(&input.request_id).await {
+                Ok(a) => (a, None),
+                Err(e) => {
+                    let err_msg = self.sanitize_error(&e);
+                    tracing::warn!(
+                        error = %err_msg,
+                        request_id = %input.request_id,
+                        "Failed to fetch associations"
+                    );
+                    (
+                        RequestAssociations::default(),
+                        Some(format!("Associations: {}", err_msg)),
+                    )
+                }
+            };
+
+        // Collect any fetch errors
+        let fetch_errors: Vec<String> = [notes_error, conv_error, links_error, associations_error]
+            .into_iter()
+            .flatten()
+            .chain(content_errors)
+            .collect();
+
+        // Get the web URL for this request
+        let web_url = client.request_web_url(&input.request_id);
+
+        // Format the response
+        let mut text = format_request_details(
+            &request,
+            &notes,
+            &conversations,
+            &links,
+            &associations,
+            &web_url,
+            &fetch_errors,
+            input.max_length.unwrap_or(self.max_description_length),
+        );
+        text.push_str(&format_more_entries_hint(
+            &input.request_id,
+            more_conversations,
+            more_notes,
+        ));
+        let images = if input.include_images == Some(true) {
+            let mut sources = Vec::new();
+            let contents = request
+                .description
+                .iter()
+                .cloned()
+                .chain(notes.iter().map(|n| n.display_content()))
+                .chain(conversations.iter().map(|c| c.display_content()));
+            for content in contents {
+                for src in inline_image_sources(&content) {
+                    if !sources.contains(&src) {
+                        sources.push(src);
+                    }
+                }
+            }
+            let images = self.fetch_inline_images(&client, &sources).await;
+            text.push_str(&format_inline_images_note(images.len(), sources.len()));
+            images
+        } else {
+            Vec::new()
+        };
 
-        Ok(format_close_result(&request))
+        Ok(ToolOutput::new(
+            text,
+            json!({
+                "request": request,
+                "notes": notes,
+                "conversations": conversations,
+                "links": links,
+                "associations": associations,
+                "web_url": web_url,
+            }),
+        )
+        .with_images(images))
     }
 
-    /// Add a note to a ticket.
-    ///
-    /// Notes can be internal or visible to requester.
+    /// Get the ServiceDesk Plus web link for a ticket.
     #[tool(
-        description = "Add a note to a ticket. Notes can be internal (technicians only) or visible to the requester. Request ID and content are required."
+        description = "Get the ServiceDesk Plus web UI link for a ticket, so a human can open it in a browser.",
+        annotations(read_only_hint = true)
     )]
-    async fn add_note(
+    async fn get_request_url(
         &self,
-        Parameters(input): Parameters<AddNoteInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<GetRequestUrlInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_request_url_output(input)).await
+    }
+
+    /// Runs `get_request_url`, returning its text and structured content.
+    async fn get_request_url_output(
+        &self,
+        input: GetRequestUrlInput,
+    ) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
-        tracing::debug!(request_id = %input.request_id, "add_note tool called");
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "get_request_url tool called");
 
-        // Validate content is non-empty and all fields within length limits
-        if input.content.is_empty() {
-            return Err("Note content is required and cannot be empty.".to_string());
-        }
+        let url = client.request_web_url(&input.request_id);
+        Ok(ToolOutput::new(
+            format!("Ticket #{}: {}", input.request_id, url),
+            json!({"request_id": input.request_id, "url": url}),
+        ))
+    }
+
+    /// Show the audit trail of a ticket.
+    ///
+    /// Lists who changed what and when, including status transitions,
+    /// reassignments, and priority changes.
+    #[tool(
+        description = "Show the history (audit trail) of a service desk ticket: who changed what and when, including status transitions, reassignments, and priority changes. Useful for incident postmortems.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_request_history(
+        &self,
+        Parameters(input): Parameters<GetRequestHistoryInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_request_history_output(input)).await
+    }
+
+    /// Runs `get_request_history`, returning its text and structured content.
+    async fn get_request_history_output(
+        &self,
+        input: GetRequestHistoryInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
         input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "get_request_history tool called");
 
-        let note = self
-            .sdp_client
-            .add_note(
-                &input.request_id,
-                &input.content,
-                input.show_to_requester,
-                input.notify_technician,
-            )
+        let history = client
+            .get_request_history(&input.request_id)
             .await
             .map_err(|e| {
                 let sanitized = self.sanitize_error(&e);
-                tracing::error!(error = %sanitized, request_id = %input.request_id, "Failed to add note");
-                format!("Failed to add note to request {}: {}", input.request_id, sanitized)
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to get request history"
+                );
+                format!(
+                    "Failed to get history for request {}: {}",
+                    input.request_id, sanitized
+                )
             })?;
 
-        Ok(format_add_note_result(&input.request_id, &note))
+        Ok(ToolOutput::new(
+            format_request_history(&input.request_id, &history),
+            json!({"request_id": input.request_id, "history": history}),
+        ))
     }
 
-    /// Assign a ticket to a technician or support group.
+    /// Show everything that happened on a ticket in one chronological view.
     ///
-    /// At least one of technician_id or group must be provided.
+    /// Merges notes, conversations, worklogs, and history. Each source is
+    /// fetched separately; a failing source is reported rather than fatal.
     #[tool(
-        description = "Assign a ticket to a technician or support group. At least one of technician_id or group must be provided."
+        description = "Show everything that happened on a service desk ticket as one chronological timeline: notes, email conversations, worklogs, and field changes from the history. Use this to summarize a ticket in one call instead of several.",
+        annotations(read_only_hint = true)
     )]
-    async fn assign_request(
+    async fn get_request_timeline(
         &self,
-        Parameters(input): Parameters<AssignRequestInput>,
-    ) -> Result<String, String> {
+        Parameters(input): Parameters<GetRequestTimelineInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_request_timeline_output(input)).await
+    }
+
+    /// Runs `get_request_timeline`, returning its text and structured content.
+    async fn get_request_timeline_output(
+        &self,
+        input: GetRequestTimelineInput,
+    ) -> Result<ToolOutput, String> {
         // Sanitize and validate input
         let input = input.sanitize();
         input.validate().map_err(|e| e.to_string())?;
-        tracing::debug!(request_id = %input.request_id, "assign_request tool called");
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "get_request_timeline tool called");
+
+        let timeline = self.fetch_timeline(&client, &input.request_id).await?;
+        let mut output =
+            format_timeline(&input.request_id, &timeline.events, &timeline.fetch_errors);
+        output.push_str(&timeline.more_hint);
+        Ok(ToolOutput::new(
+            output,
+            json!({
+                "request_id": input.request_id,
+                "events": timeline.events,
+                "fetch_errors": timeline.fetch_errors,
+            }),
+        ))
+    }
 
-        // Validate that at least one assignment target is provided
-        if !input.has_assignment() {
-            return Err(
-                "At least one of technician_id or group must be provided for assignment."
-                    .to_string(),
+    /// Export a whole ticket as a markdown document.
+    #[tool(
+        description = "Export a complete service desk ticket as a markdown document: details, description, the full timeline (notes, emails, worklogs, and changes), resolution, and closure info. Use it to paste a ticket into a postmortem, handover note, or wiki page.",
+        annotations(read_only_hint = true)
+    )]
+    async fn export_request_markdown(
+        &self,
+        Parameters(input): Parameters<ExportRequestMarkdownInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.export_request_markdown_output(input)).await
+    }
+
+    /// Runs `export_request_markdown`, returning its text and structured content.
+    async fn export_request_markdown_output(
+        &self,
+        input: ExportRequestMarkdownInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "export_request_markdown tool called");
+
+        let request = client.get_request(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to get request"
             );
-        }
+            format!("Failed to get request {}: {}", input.request_id, sanitized)
+        })?;
+        let timeline = self
+            .fetch_timeline(&client, &input.request_id)
+            .await
+            .unwrap_or_else(|e| Timeline {
+                events: Vec::new(),
+                fetch_errors: vec![e],
+                more_hint: String::new(),
+            });
+
+        Ok(ToolOutput::new(
+            format_request_markdown(
+                &request,
+                &timeline,
+                &client.request_web_url(&input.request_id),
+            ),
+            json!({
+                "request": request,
+                "events": timeline.events,
+                "fetch_errors": timeline.fetch_errors,
+            }),
+        ))
+    }
 
-        let request = self
-            .sdp_client
-            .assign_request(
+    /// List a ticket's notes one page at a time.
+    #[tool(
+        description = "List the notes on a ticket, one page at a time, with their full content. Use this for tickets with more notes than get_request shows; the output says which offset to request next.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_notes(
+        &self,
+        Parameters(input): Parameters<ListNotesInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_notes_output(input)).await
+    }
+
+    /// Runs `list_notes`, returning its text and structured content.
+    async fn list_notes_output(&self, input: ListNotesInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_notes tool called");
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+        let offset = input.offset.unwrap_or(0);
+        let notes = client
+            .list_notes_with_content(
                 &input.request_id,
-                input.technician_id.as_deref(),
-                input.group.as_deref(),
+                limit,
+                offset,
+                EntryOrder::newest_first(input.order),
             )
             .await
             .map_err(|e| {
                 let sanitized = self.sanitize_error(&e);
-                tracing::error!(error = %sanitized, request_id = %input.request_id, "Failed to assign request");
-                format!("Failed to assign request {}: {}", input.request_id, sanitized)
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to list notes"
+                );
+                format!(
+                    "Failed to list notes of request {}: {}",
+                    input.request_id, sanitized
+                )
             })?;
 
-        Ok(format_assign_result(&request, &input))
+        Ok(ToolOutput::new(
+            format_entry_page(
+                &input.request_id,
+                EntryKind::Notes,
+                offset,
+                &notes,
+                format_note_entry,
+            ),
+            json!({
+                "request_id": input.request_id,
+                "notes": notes.items,
+                "has_more": notes.has_more,
+                "next_offset": notes.has_more.then(|| offset + notes.items.len() as u32),
+            }),
+        ))
     }
 
-    /// Sanitizes an error message to remove any API key.
-    fn sanitize_error(&self, error: &crate::error::GlassError) -> String {
-        error.sanitized_display(self.sdp_client.api_key_for_sanitization())
+    /// List a ticket's email conversations one page at a time.
+    #[tool(
+        description = "List the email conversations on a ticket, one page at a time, with their full content. Use this for tickets with more conversations than get_request shows; the output says which offset to request next.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_conversations(
+        &self,
+        Parameters(input): Parameters<ListConversationsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_conversations_output(input)).await
     }
-}
 
-#[tool_handler]
-impl ServerHandler for GlassServer {
-    /// Returns server information for the MCP initialize handshake.
-    fn get_info(&self) -> ServerInfo {
-        ServerInfo {
-            instructions: Some(
-                "Glass provides access to ServiceDesk Plus tickets. \
-                 Use list_requests to find tickets, get_request for details, \
-                 and list_technicians to see available assignees. \
-                 Create tickets with create_request, modify with update_request, \
-                 close with close_request, add notes with add_note, and \
-                 assign with assign_request. Start with 'ping' to verify connectivity."
-                    .into(),
+    /// Runs `list_conversations`, returning its text and structured content.
+    async fn list_conversations_output(
+        &self,
+        input: ListConversationsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_conversations tool called");
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+        let offset = input.offset.unwrap_or(0);
+        let conversations = client
+            .list_conversations_with_content(
+                &input.request_id,
+                limit,
+                offset,
+                EntryOrder::newest_first(input.order),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to list conversations"
+                );
+                format!(
+                    "Failed to list conversations of request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_entry_page(
+                &input.request_id,
+                EntryKind::Conversations,
+                offset,
+                &conversations,
+                format_conversation_entry,
             ),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
-            ..Default::default()
-        }
+            json!({
+                "request_id": input.request_id,
+                "conversations": conversations.items,
+                "has_more": conversations.has_more,
+                "next_offset": conversations
+                    .has_more
+                    .then(|| offset + conversations.items.len() as u32),
+            }),
+        ))
     }
-}
 
-// ============================================================================
-// Response formatting helpers
-// ============================================================================
+    /// List technicians available for ticket assignment.
+    ///
+    /// Returns IDs and names so you can assign tickets to specific technicians.
+    #[tool(
+        description = "List all technicians available for ticket assignment. Returns IDs and names. Use the ID when assigning tickets.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_technicians(
+        &self,
+        Parameters(input): Parameters<ListTechniciansInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_technicians_output(input)).await
+    }
 
-/// Maximum length for description fields before truncation.
-const MAX_DESCRIPTION_LENGTH: usize = 2000;
+    /// Runs `list_technicians`, returning its text and structured content.
+    async fn list_technicians_output(
+        &self,
+        input: ListTechniciansInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "list_technicians tool called");
 
-/// Truncates a string if it exceeds the maximum length.
-///
-/// If truncated, appends "... [truncated]" to indicate the content was cut.
-fn truncate_text(text: &str, max_length: usize) -> String {
-    if text.len() <= max_length {
-        text.to_string()
-    } else {
-        // Leave room for "... [truncated]" suffix
-        let target = max_length.saturating_sub(15);
-        // Find a valid UTF-8 boundary at or before target
-        let safe_end = text.floor_char_boundary(target);
-        // Try to break at a word boundary for cleaner output
-        let end = text[..safe_end]
-            .rfind(char::is_whitespace)
-            .unwrap_or(safe_end);
-        format!("{}... [truncated]", &text[..end])
-    }
-}
+        let technicians = client
+            .list_technicians(input.group.as_deref(), input.limit)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, "Failed to list technicians");
+                format!("Failed to list technicians: {}", sanitized)
+            })?;
 
-/// Formats a list of request summaries as human-readable text.
-fn format_request_list(requests: &[RequestSummary]) -> String {
-    if requests.is_empty() {
-        return "No tickets found matching the criteria.".to_string();
+        // Format the response
+        Ok(ToolOutput::new(
+            format_technician_list(&technicians),
+            json!({"technicians": technicians}),
+        )
+        .formatted(input.format, || markdown_technician_table(&technicians)))
     }
 
-    let mut output = format!("Found {} ticket(s):\n\n", requests.len());
+    /// Show the technician tied to the API key.
+    #[tool(
+        description = "Show who you are in ServiceDesk Plus: the technician that owns the configured API key, with name, ID, and email. Use the ID as technician_id to assign tickets to yourself, or technician=\"me\" in list_requests.",
+        annotations(read_only_hint = true)
+    )]
+    async fn whoami(
+        &self,
+        Parameters(input): Parameters<PortalInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.whoami_output(input)).await
+    }
 
-    for req in requests {
-        output.push_str(&format!("#{} - {}\n", req.id, req.display_subject()));
-        output.push_str(&format!(
-            "   Status: {} | Priority: {} | Assignee: {}\n",
-            req.display_status(),
-            req.display_priority(),
-            req.display_technician()
-        ));
-        output.push_str(&format!("   Requester: {}\n", req.display_requester()));
+    /// Runs `whoami`, returning its text and structured content.
+    async fn whoami_output(&self, input: PortalInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!("whoami tool called");
 
-        if let Some(created) = req.created_time.as_ref().and_then(|t| t.display()) {
-            output.push_str(&format!("   Created: {}\n", created));
-        }
+        let technician = client.current_technician().await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to resolve current technician");
+            format!("Failed to identify you: {}", sanitized)
+        })?;
 
-        output.push('\n');
+        Ok(ToolOutput::new(
+            format_whoami(technician.as_ref(), client.technician_id().is_some()),
+            json!({"technician": technician}),
+        ))
     }
 
-    output
-}
+    /// List open tickets assigned to the API key's technician.
+    #[tool(
+        description = "List your own open tickets: those assigned to the technician that owns the configured API key. Use for 'what's on my plate?' instead of guessing a technician name for list_requests.",
+        annotations(read_only_hint = true)
+    )]
+    async fn my_queue(
+        &self,
+        Parameters(input): Parameters<MyQueueInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.my_queue_output(input)).await
+    }
 
-/// Formats full request details as human-readable text.
-fn format_request_details(
-    request: &Request,
-    notes: &[Note],
-    conversations: &[Conversation],
-    web_url: &str,
-    fetch_errors: &[String],
-) -> String {
-    let mut output = String::new();
+    /// Runs `my_queue`, returning its text and structured content.
+    async fn my_queue_output(&self, input: MyQueueInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "my_queue tool called");
 
-    // Header
-    output.push_str(&format!(
-        "Ticket #{}: {}\n",
-        request.id,
-        request.display_subject()
-    ));
-    output.push_str(&"=".repeat(60));
-    output.push('\n');
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
 
-    // Direct link
-    output.push_str(&format!("\nLink: {}\n", web_url));
+        // The name is only used for the heading, so a lookup failure is not fatal
+        let technician = match client.current_technician().await {
+            Ok(t) => t,
+            Err(e) => {
+                let err_msg = self.sanitize_error(&e);
+                tracing::warn!(error = %err_msg, "Failed to resolve current technician");
+                None
+            }
+        };
 
-    // Status information
-    output.push_str(&format!("\nStatus: {}\n", request.display_status()));
-    output.push_str(&format!("Priority: {}\n", request.display_priority()));
+        let requests = client.list_my_queue(limit).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list my queue");
+            format!("Failed to list your queue: {}", sanitized)
+        })?;
 
-    if let Some(urgency) = request.urgency.as_ref().and_then(|u| u.name.as_deref()) {
-        output.push_str(&format!("Urgency: {}\n", urgency));
+        Ok(ToolOutput::new(
+            format_my_queue(technician.as_ref(), &requests),
+            json!({"technician": technician, "requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
     }
-    if let Some(impact) = request.impact.as_ref().and_then(|i| i.name.as_deref()) {
-        output.push_str(&format!("Impact: {}\n", impact));
+
+    /// List open tickets nobody has picked up yet.
+    #[tool(
+        description = "List open tickets with no technician assigned, oldest first, optionally limited to one support group. This is the dispatcher's queue; assign from it with assign_request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn unassigned_tickets(
+        &self,
+        Parameters(input): Parameters<UnassignedTicketsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.unassigned_tickets_output(input)).await
     }
 
-    // Category path
-    let category_path = request.category_path();
-    if category_path != "Uncategorized" {
-        output.push_str(&format!("Category: {}\n", category_path));
+    /// Runs `unassigned_tickets`, returning its text and structured content.
+    async fn unassigned_tickets_output(
+        &self,
+        input: UnassignedTicketsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "unassigned_tickets tool called");
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+        let mut params = ListParams::new()
+            .with_unassigned()
+            .with_open_only()
+            .with_sort("created_time", "asc")
+            .with_limit(limit);
+        let group = self.list_defaults.group(input.group.as_deref());
+        if let Some(ref group) = group {
+            params = params.with_group(group);
+        }
+        if let Some(site) = self.list_defaults.site(None) {
+            params = params.with_site(site);
+        }
+
+        let requests = client.list_requests(params).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list unassigned tickets");
+            format!("Failed to list unassigned tickets: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_unassigned_tickets(group.as_deref(), &requests),
+            json!({"requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
     }
 
-    // People
-    output.push_str(&format!("\nRequester: {}\n", request.display_requester()));
-    output.push_str(&format!("Assigned to: {}\n", request.display_technician()));
+    /// List open tickets that are past their due date.
+    #[tool(
+        description = "List open tickets that are past their due date, most overdue first, with the due date and how late each one is. Optionally limit to a support group or technician name.",
+        annotations(read_only_hint = true)
+    )]
+    async fn overdue_tickets(
+        &self,
+        Parameters(input): Parameters<OverdueTicketsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.overdue_tickets_output(input)).await
+    }
 
-    if let Some(group) = request.display_group() {
-        output.push_str(&format!("Group: {}\n", group));
+    /// Runs `overdue_tickets`, returning its text and structured content.
+    async fn overdue_tickets_output(
+        &self,
+        input: OverdueTicketsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "overdue_tickets tool called");
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+        let mut params = ListParams::new()
+            .with_overdue()
+            .with_open_only()
+            .with_sort("due_by_time", "asc")
+            .with_limit(limit);
+        if let Some(group) = self.list_defaults.group(input.group.as_deref()) {
+            params = params.with_group(group);
+        }
+        if let Some(site) = self.list_defaults.site(None) {
+            params = params.with_site(site);
+        }
+        if let Some(ref technician) = input.technician {
+            params = params.with_technician(technician);
+        }
+
+        let requests = client.list_requests(params).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list overdue tickets");
+            format!("Failed to list overdue tickets: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_overdue_tickets(&requests, chrono::Utc::now().timestamp_millis()),
+            json!({"requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
     }
 
-    // Timestamps
-    output.push_str("\n--- Timestamps ---\n");
-    if let Some(created) = request.created_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("Created: {}\n", created));
+    /// List open tickets that fall due soon.
+    #[tool(
+        description = "List open tickets due before the end of today, or within the next N hours, soonest first. Use this for morning triage to catch tickets about to breach. Optionally limit to a support group or technician name.",
+        annotations(read_only_hint = true)
+    )]
+    async fn due_soon(
+        &self,
+        Parameters(input): Parameters<DueSoonInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.due_soon_output(input)).await
     }
-    if let Some(updated) = request.last_updated_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("Last Updated: {}\n", updated));
+
+    /// Runs `due_soon`, returning its text and structured content.
+    async fn due_soon_output(&self, input: DueSoonInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "due_soon tool called");
+
+        let now = chrono::Local::now();
+        let window_end = due_window_end(&now, input.hours);
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+        let mut params = ListParams::new()
+            .with_due_between(now.timestamp_millis(), window_end)
+            .with_open_only()
+            .with_sort("due_by_time", "asc")
+            .with_limit(limit);
+        if let Some(group) = self.list_defaults.group(input.group.as_deref()) {
+            params = params.with_group(group);
+        }
+        if let Some(site) = self.list_defaults.site(None) {
+            params = params.with_site(site);
+        }
+        if let Some(ref technician) = input.technician {
+            params = params.with_technician(technician);
+        }
+
+        let requests = client.list_requests(params).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list tickets due soon");
+            format!("Failed to list tickets due soon: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_due_soon(&requests, now.timestamp_millis(), input.hours),
+            json!({"requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
     }
-    if let Some(due) = request.due_by_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("Due By: {}\n", due));
+
+    /// Count tickets grouped by status, priority, technician, or group.
+    #[tool(
+        description = "Count tickets grouped by status, priority, technician, or group, optionally over a created date range or only open tickets. Runs count queries instead of listing tickets, so use it for questions like 'how many open tickets per technician?'.",
+        annotations(read_only_hint = true)
+    )]
+    async fn ticket_statistics(
+        &self,
+        Parameters(input): Parameters<TicketStatisticsInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.ticket_statistics_output(input, context)).await
     }
 
-    // Flags
-    if request.is_overdue == Some(true) {
-        output.push_str("\n[OVERDUE]\n");
+    /// Runs `ticket_statistics`, returning its text and structured content.
+    async fn ticket_statistics_output(
+        &self,
+        input: TicketStatisticsInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "ticket_statistics tool called");
+
+        let (created_after, created_before) = input.created_range().map_err(|e| e.to_string())?;
+        let base_params = || {
+            let mut params = ListParams::new();
+            if let Some(millis) = created_after {
+                params = params.with_created_after(millis);
+            }
+            if let Some(millis) = created_before {
+                params = params.with_created_before(millis);
+            }
+            if input.open_only == Some(true) {
+                params = params.with_open_only();
+            }
+            // Counting by group spans every group, so only the site default applies
+            let group = (input.group_by == "group").then_some(ANY_SCOPE);
+            self.list_defaults.scope(params, group, None)
+        };
+
+        let map_err = |e: crate::error::GlassError| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                group_by = %input.group_by,
+                "Failed to compute ticket statistics"
+            );
+            format!("Failed to compute ticket statistics: {}", sanitized)
+        };
+
+        // Resolve the values to count by
+        let values: Vec<String> = match input.group_by.as_str() {
+            "technician" => client
+                .list_technicians(None, Some(100))
+                .await
+                .map_err(map_err)?
+                .iter()
+                .map(|t| t.display_name().to_string())
+                .collect(),
+            dimension => {
+                let lookup = match dimension {
+                    "status" => client.list_statuses().await,
+                    "priority" => client.list_priorities().await,
+                    _ => client.list_groups().await,
+                };
+                lookup
+                    .map_err(map_err)?
+                    .iter()
+                    .filter(|v| v.is_active())
+                    .map(|v| v.display_name().to_string())
+                    .collect()
+            }
+        };
+
+        let total = client
+            .count_requests(base_params())
+            .await
+            .map_err(map_err)?;
+
+        let progress = Progress::new(&context);
+        let mut counts = Vec::new();
+        for (index, value) in values.iter().enumerate() {
+            let params = base_params();
+            let params = match input.group_by.as_str() {
+                "status" => params.with_status(value),
+                "priority" => params.with_priority(value),
+                "technician" => params.with_technician(value),
+                _ => params.with_group(value),
+            };
+            let count = client.count_requests(params).await.map_err(map_err)?;
+            if count > 0 {
+                counts.push((value.clone(), count));
+            }
+            progress
+                .report(
+                    index + 1,
+                    values.len(),
+                    format!(
+                        "counted {}/{} {} values",
+                        index + 1,
+                        values.len(),
+                        input.group_by
+                    ),
+                )
+                .await;
+        }
+
+        Ok(ToolOutput::new(
+            format_ticket_statistics(&input, &counts, total),
+            ticket_statistics_json(&input, &counts, total),
+        ))
     }
 
-    // Description (truncated if too long)
-    if let Some(description) = &request.description {
-        output.push_str("\n--- Description ---\n");
-        output.push_str(&truncate_text(description, MAX_DESCRIPTION_LENGTH));
-        output.push('\n');
+    /// Report a ticket's SLA state.
+    #[tool(
+        description = "Get a ticket's SLA state: the applied SLA, first response and resolution due times, time remaining or overdue, elapsed time since creation, and violation flags. Request ID is required.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_sla_info(
+        &self,
+        Parameters(input): Parameters<GetSlaInfoInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_sla_info_output(input)).await
     }
 
-    // Show any fetch errors so user knows why notes/conversations might be missing
-    if !fetch_errors.is_empty() {
-        output.push_str("\n--- Fetch Errors ---\n");
-        for err in fetch_errors {
-            output.push_str(&format!("Warning: Failed to fetch {}\n", err));
+    /// Runs `get_sla_info`, returning its text and structured content.
+    async fn get_sla_info_output(&self, input: GetSlaInfoInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "get_sla_info tool called");
+
+        let request = client.get_request(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to get request"
+            );
+            format!("Failed to get request {}: {}", input.request_id, sanitized)
+        })?;
+
+        // SLA details are informational; fall back to the name on the request
+        let sla_id = request.sla.as_ref().and_then(|s| s.id.clone());
+        let sla = match sla_id {
+            Some(id) => match client.get_sla(&id).await {
+                Ok(sla) => Some(sla),
+                Err(e) => {
+                    let err_msg = self.sanitize_error(&e);
+                    tracing::warn!(error = %err_msg, sla_id = %id, "Failed to fetch SLA");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        Ok(ToolOutput::new(
+            format_sla_info(
+                &request,
+                sla.as_ref(),
+                chrono::Utc::now().timestamp_millis(),
+            ),
+            json!({"request": request, "sla": sla}),
+        ))
+    }
+
+    /// Suggest a priority from impact and urgency using the priority matrix.
+    #[tool(
+        description = "Suggest a ticket's priority from its impact and urgency using the configured priority matrix, the way SDP computes it. Give impact and urgency, or a request_id to use the ticket's values (impact or urgency given alongside override them). Use before setting priority with update_request so priorities stay consistent.",
+        annotations(read_only_hint = true)
+    )]
+    async fn suggest_priority(
+        &self,
+        Parameters(input): Parameters<SuggestPriorityInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.suggest_priority_output(input)).await
+    }
+
+    /// Runs `suggest_priority`, returning its text and structured content.
+    async fn suggest_priority_output(
+        &self,
+        input: SuggestPriorityInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = ?input.request_id, "suggest_priority tool called");
+
+        let request = match &input.request_id {
+            Some(id) => Some(client.get_request(id).await.map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, request_id = %id, "Failed to get request");
+                format!("Failed to get request {}: {}", id, sanitized)
+            })?),
+            None => None,
+        };
+
+        let field = |given: &Option<String>, on_ticket: fn(&Request) -> Option<&NamedEntity>| {
+            given.clone().or_else(|| {
+                request
+                    .as_ref()
+                    .and_then(on_ticket)
+                    .and_then(|e| e.name.clone())
+            })
+        };
+        let impact = field(&input.impact, |r| r.impact.as_ref());
+        let urgency = field(&input.urgency, |r| r.urgency.as_ref());
+
+        priority_suggestion(
+            &self.priority_matrix,
+            request.as_ref(),
+            impact.as_deref(),
+            urgency.as_deref(),
+        )
+    }
+
+    /// Search requesters by name or email.
+    #[tool(
+        description = "Search requesters (end users) by name or email address (partial match). Use before create_request to find the right requester_email.",
+        annotations(read_only_hint = true)
+    )]
+    async fn search_requesters(
+        &self,
+        Parameters(input): Parameters<SearchRequestersInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.search_requesters_output(input)).await
+    }
+
+    /// Runs `search_requesters`, returning its text and structured content.
+    async fn search_requesters_output(
+        &self,
+        input: SearchRequestersInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(?input, "search_requesters tool called");
+
+        if input.query.is_empty() {
+            return Err("Search query is required and cannot be empty.".to_string());
         }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+
+        let requesters = client
+            .search_requesters(&input.query, limit)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, "Failed to search requesters");
+                format!("Failed to search requesters: {}", sanitized)
+            })?;
+
+        Ok(ToolOutput::new(
+            format_requester_list(&requesters),
+            json!({"requesters": requesters}),
+        )
+        .formatted(input.format, || markdown_requester_table(&requesters)))
     }
 
-    // Conversations section (email replies - chronological communication)
-    if !conversations.is_empty() {
-        output.push_str("\n--- Conversations ---\n");
-        for conv in conversations {
-            // Conversation header with sender, direction and timestamp
-            let from = conv.display_from();
-            let timestamp = conv.display_time().unwrap_or("Unknown time");
-            let direction = conv.direction();
-            output.push_str(&format!("\n[{}] {} ({})\n", timestamp, from, direction));
-
-            // Conversation content (truncated if needed)
-            let content = conv.display_content();
-            output.push_str(&truncate_text(&content, 1500));
-            output.push('\n');
+    /// Get a requester's profile and contact details.
+    #[tool(
+        description = "Get a requester's profile by ID: email, phone, mobile, job title, department, site, and VIP flag. Use search_requesters to find the ID.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_requester(
+        &self,
+        Parameters(input): Parameters<GetRequesterInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_requester_output(input)).await
+    }
+
+    /// Runs `get_requester`, returning its text and structured content.
+    async fn get_requester_output(&self, input: GetRequesterInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(requester_id = %input.requester_id, "get_requester tool called");
+
+        let requester = client
+            .get_requester(&input.requester_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    requester_id = %input.requester_id,
+                    "Failed to get requester"
+                );
+                format!(
+                    "Failed to get requester {}: {}",
+                    input.requester_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_requester_details(&requester),
+            json!({"requester": requester}),
+        ))
+    }
+
+    /// Search assets by name, tag, serial number, or barcode.
+    #[tool(
+        description = "Search assets (laptops, phones, and other configuration items) by name, asset tag, serial number, or barcode (partial match). Use get_asset for full details.",
+        annotations(read_only_hint = true)
+    )]
+    async fn search_assets(
+        &self,
+        Parameters(input): Parameters<SearchAssetsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.search_assets_output(input)).await
+    }
+
+    /// Runs `search_assets`, returning its text and structured content.
+    async fn search_assets_output(&self, input: SearchAssetsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(?input, "search_assets tool called");
+
+        if input.query.is_empty() {
+            return Err("Search query is required and cannot be empty.".to_string());
         }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+
+        let assets = client
+            .search_assets(&input.query, limit)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, "Failed to search assets");
+                format!("Failed to search assets: {}", sanitized)
+            })?;
+
+        Ok(
+            ToolOutput::new(format_asset_list(&assets), json!({"assets": assets}))
+                .formatted(input.format, || markdown_asset_table(&assets)),
+        )
     }
 
-    // Notes section (internal technician notes)
-    if !notes.is_empty() {
-        output.push_str("\n--- Notes ---\n");
-        for note in notes {
-            // Note header with author and timestamp
-            let author = note.display_created_by();
-            let timestamp = note
-                .created_time
-                .as_ref()
-                .and_then(|t| t.display())
-                .unwrap_or("Unknown time");
-            let visibility = if note.show_to_requester == Some(true) {
-                ""
-            } else {
-                " [Internal]"
-            };
-            output.push_str(&format!("\n[{}] {}{}\n", timestamp, author, visibility));
+    /// Get an asset's details.
+    #[tool(
+        description = "Get an asset by ID: product, type, state, serial number, barcode, assigned user, department, site, and location. Use search_assets to find the ID.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_asset(
+        &self,
+        Parameters(input): Parameters<GetAssetInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_asset_output(input)).await
+    }
 
-            // Note content (truncated if needed)
-            let content = note.display_content();
-            output.push_str(&truncate_text(&content, 1000));
-            output.push('\n');
+    /// Runs `get_asset`, returning its text and structured content.
+    async fn get_asset_output(&self, input: GetAssetInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(asset_id = %input.asset_id, "get_asset tool called");
+
+        let asset = client.get_asset(&input.asset_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, asset_id = %input.asset_id, "Failed to get asset");
+            format!("Failed to get asset {}: {}", input.asset_id, sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_asset_details(&asset),
+            json!({"asset": asset}),
+        ))
+    }
+
+    /// List the assets assigned to a user.
+    #[tool(
+        description = "List the assets (hardware and other configuration items) assigned to a user, identified by email address, requester ID, or exact name. Use to find the affected device before attach_asset_to_request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_user_assets(
+        &self,
+        Parameters(input): Parameters<ListUserAssetsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_user_assets_output(input)).await
+    }
+
+    /// Runs `list_user_assets`, returning its text and structured content.
+    async fn list_user_assets_output(
+        &self,
+        input: ListUserAssetsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(?input, "list_user_assets tool called");
+
+        if input.requester.is_empty() {
+            return Err("Requester is required and cannot be empty.".to_string());
         }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+
+        let assets = client
+            .list_user_assets(&input.requester, limit)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, "Failed to list user assets");
+                format!(
+                    "Failed to list assets for {}: {}",
+                    input.requester, sanitized
+                )
+            })?;
+
+        Ok(
+            ToolOutput::new(format_asset_list(&assets), json!({"assets": assets}))
+                .formatted(input.format, || markdown_asset_table(&assets)),
+        )
     }
 
-    // Resolution (if present, truncated if too long)
-    if let Some(resolution) = &request.resolution {
-        if let Some(content) = &resolution.content {
-            output.push_str("\n--- Resolution ---\n");
-            output.push_str(&truncate_text(content, MAX_DESCRIPTION_LENGTH));
-            output.push('\n');
+    /// List valid category / subcategory / item combinations.
+    #[tool(
+        description = "List the category tree (category > subcategory > item). SDP rejects invalid combinations, so check here before setting category fields. Without filters, returns categories with their subcategories; pass category (and optionally subcategory) to drill down to items.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_categories(
+        &self,
+        Parameters(input): Parameters<ListCategoriesInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_categories_output(input)).await
+    }
 
-            if let Some(submitted_by) = resolution
-                .submitted_by
-                .as_ref()
-                .and_then(|s| s.name.as_deref())
-            {
-                output.push_str(&format!("Submitted by: {}\n", submitted_by));
+    /// Runs `list_categories`, returning its text and structured content.
+    async fn list_categories_output(
+        &self,
+        input: ListCategoriesInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "list_categories tool called");
+
+        let to_error = |e: crate::error::GlassError, what: &str| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list {}", what);
+            format!("Failed to list {}: {}", what, sanitized)
+        };
+
+        let categories: Vec<CategoryEntry> = client
+            .list_categories()
+            .await
+            .map_err(|e| to_error(e, "categories"))?
+            .into_iter()
+            .filter(CategoryEntry::is_active)
+            .collect();
+
+        let Some(category_filter) = &input.category else {
+            // Whole tree, two levels deep
+            let mut tree = Vec::with_capacity(categories.len());
+            for category in categories {
+                let children = client
+                    .list_subcategories(&category.id)
+                    .await
+                    .map_err(|e| to_error(e, "subcategories"))?
+                    .into_iter()
+                    .filter(CategoryEntry::is_active)
+                    .map(|sub| (sub, Vec::new()))
+                    .collect();
+                tree.push((category, children));
             }
-            if let Some(submitted_on) = resolution.submitted_on.as_ref().and_then(|t| t.display()) {
-                output.push_str(&format!("Submitted on: {}\n", submitted_on));
+            return Ok(ToolOutput::new(
+                format_category_tree(&tree),
+                json!({"categories": category_tree_json(&tree)}),
+            ));
+        };
+
+        let category = categories
+            .into_iter()
+            .find(|c| c.matches(category_filter))
+            .ok_or_else(|| {
+                format!(
+                    "Category '{}' not found. Call list_categories without filters to see valid \
+                     names.",
+                    category_filter
+                )
+            })?;
+
+        let mut subcategories: Vec<CategoryEntry> = client
+            .list_subcategories(&category.id)
+            .await
+            .map_err(|e| to_error(e, "subcategories"))?
+            .into_iter()
+            .filter(CategoryEntry::is_active)
+            .collect();
+
+        if let Some(subcategory_filter) = &input.subcategory {
+            subcategories.retain(|s| s.matches(subcategory_filter));
+            if subcategories.is_empty() {
+                return Err(format!(
+                    "Subcategory '{}' not found under category '{}'.",
+                    subcategory_filter,
+                    category.display_name()
+                ));
             }
         }
-    }
 
-    // Closure info (if present)
-    if let Some(closure) = &request.closure_info {
-        output.push_str("\n--- Closure Info ---\n");
-        if let Some(code) = closure
-            .closure_code
-            .as_ref()
-            .and_then(|c| c.name.as_deref())
-        {
-            output.push_str(&format!("Closure Code: {}\n", code));
-        }
-        if let Some(comments) = &closure.closure_comments {
-            output.push_str(&format!("Comments: {}\n", comments));
-        }
-        if let Some(closed_by) = closure.closed_by.as_ref().and_then(|c| c.name.as_deref()) {
-            output.push_str(&format!("Closed by: {}\n", closed_by));
-        }
-        if let Some(closed_time) = closure.closed_time.as_ref().and_then(|t| t.display()) {
-            output.push_str(&format!("Closed at: {}\n", closed_time));
+        let mut children = Vec::with_capacity(subcategories.len());
+        for subcategory in subcategories {
+            let items = client
+                .list_items(&subcategory.id)
+                .await
+                .map_err(|e| to_error(e, "items"))?
+                .into_iter()
+                .filter(CategoryEntry::is_active)
+                .collect();
+            children.push((subcategory, items));
         }
+
+        let tree = [(category, children)];
+        Ok(ToolOutput::new(
+            format_category_tree(&tree),
+            json!({"categories": category_tree_json(&tree)}),
+        ))
+    }
+
+    /// List sites for multi-site deployments.
+    #[tool(
+        description = "List the sites (locations) configured in ServiceDesk Plus. Use the site names with list_requests and create_request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_sites(
+        &self,
+        Parameters(input): Parameters<PortalInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_sites_output(input)).await
+    }
+
+    /// Runs `list_sites`, returning its text and structured content.
+    async fn list_sites_output(&self, input: PortalInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!("list_sites tool called");
+
+        let sites = client.list_sites().await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list sites");
+            format!("Failed to list sites: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_site_list(&sites),
+            json!({"sites": sites}),
+        ))
+    }
+
+    /// List departments.
+    #[tool(
+        description = "List the departments (ID and name) configured in ServiceDesk Plus, with the site each belongs to.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_departments(
+        &self,
+        Parameters(input): Parameters<PortalInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_departments_output(input)).await
+    }
+
+    /// Runs `list_departments`, returning its text and structured content.
+    async fn list_departments_output(&self, input: PortalInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!("list_departments tool called");
+
+        let departments = client.list_departments().await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list departments");
+            format!("Failed to list departments: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_department_list(&departments),
+            json!({"departments": departments}),
+        ))
+    }
+
+    /// List the closure codes accepted by close_request.
+    #[tool(
+        description = "List the closure codes configured in ServiceDesk Plus. Use one of these names as closure_code in close_request or resolve_request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_closure_codes(
+        &self,
+        Parameters(input): Parameters<PortalInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_closure_codes_output(input)).await
+    }
+
+    /// Runs `list_closure_codes`, returning its text and structured content.
+    async fn list_closure_codes_output(&self, input: PortalInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!("list_closure_codes tool called");
+
+        let codes = client.list_closure_codes().await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list closure codes");
+            format!("Failed to list closure codes: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_closure_code_list(&codes),
+            json!({"closure_codes": codes}),
+        ))
+    }
+
+    /// List the custom (UDF) fields defined for tickets.
+    #[tool(
+        description = "List the custom (UDF) fields defined for tickets: API name, label, type, whether it is mandatory, and allowed values for pick lists. Use the API names as keys in custom_fields on create_request and update_request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_custom_fields(
+        &self,
+        Parameters(input): Parameters<PortalInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_custom_fields_output(input)).await
+    }
+
+    /// Runs `list_custom_fields`, returning its text and structured content.
+    async fn list_custom_fields_output(&self, input: PortalInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!("list_custom_fields tool called");
+
+        let fields = client.list_custom_fields().await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list custom fields");
+            format!("Failed to list custom fields: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_custom_field_list(&fields),
+            json!({"fields": fields}),
+        ))
+    }
+
+    // ========================================================================
+    // Write tools (M4)
+    // ========================================================================
+
+    /// Create a new service desk ticket.
+    ///
+    /// Subject is required. Returns the created ticket with its assigned ID.
+    #[tool(
+        description = "Create a new service desk ticket. Subject is required. Set custom (UDF) fields with custom_fields, keyed by field name such as udf_sline_301. Returns the created ticket with its assigned ID.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn create_request(
+        &self,
+        Parameters(input): Parameters<CreateRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.create_request_output(input)).await
+    }
+
+    /// Runs `create_request`, returning its text and structured content.
+    async fn create_request_output(&self, input: CreateRequestInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(subject = %input.subject, "create_request tool called");
+
+        // Validate subject is non-empty and all fields are within length limits
+        if input.subject.is_empty() {
+            return Err("Subject is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let request = client.create_request(&input).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to create request");
+            format!("Failed to create request: {}", sanitized)
+        })?;
+
+        let web_url = client.request_web_url(&request.id);
+        Ok(ToolOutput::new(
+            format_create_result(&request, &web_url),
+            json!({"request": request, "web_url": web_url}),
+        ))
+    }
+
+    /// Update an existing ticket's properties.
+    ///
+    /// Request ID is required. At least one field must be provided for update.
+    #[tool(
+        description = "Update an existing ticket's properties such as priority, status, category, assignment, or custom (UDF) fields via custom_fields. Request ID is required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn update_request(
+        &self,
+        Parameters(input): Parameters<UpdateRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.update_request_output(input)).await
+    }
+
+    /// Runs `update_request`, returning its text and structured content.
+    async fn update_request_output(&self, input: UpdateRequestInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "update_request tool called");
+
+        // Validate that at least one field is being updated
+        if !input.has_updates() {
+            return Err(
+                "At least one field must be provided for update (subject, description, priority, \
+                 status, category, subcategory, group, technician_id, or custom_fields)."
+                    .to_string(),
+            );
+        }
+
+        // Validate subject is non-empty if provided, and all fields within length limits
+        if let Some(ref subject) = input.subject {
+            if subject.is_empty() {
+                return Err("Subject cannot be empty.".to_string());
+            }
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let request = client
+            .update_request(&input.request_id, &input)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to update request"
+                );
+                format!(
+                    "Failed to update request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_update_result(&request),
+            json!({"request": request}),
+        ))
+    }
+
+    /// Close a ticket with closure reason and comments.
+    ///
+    /// Request ID is required. Closure code and comments are optional.
+    #[tool(
+        description = "Close a ticket with closure reason and comments. Request ID is required. Use list_closure_codes to find a valid closure_code.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn close_request(
+        &self,
+        Parameters(input): Parameters<CloseRequestInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.close_request_output(input, context)).await
+    }
+
+    /// Runs `close_request`, returning its text and structured content.
+    async fn close_request_output(
+        &self,
+        input: CloseRequestInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "close_request tool called");
+
+        confirm_action(&context, format!("Close ticket #{}?", input.request_id)).await?;
+
+        let request = client
+            .close_request(
+                &input.request_id,
+                input.closure_code.as_deref(),
+                input.closure_comments.as_deref(),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to close request"
+                );
+                format!(
+                    "Failed to close request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_close_result(&request),
+            json!({"request": request}),
+        ))
+    }
+
+    /// Add a note to a ticket.
+    ///
+    /// Notes can be internal or visible to requester.
+    #[tool(
+        description = "Add a note to a ticket. Notes can be internal (technicians only) or visible to the requester. Request ID and content are required. Set mark_first_response=true to record the note as the ticket's first response, stopping the first-response SLA clock.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn add_note(
+        &self,
+        Parameters(input): Parameters<AddNoteInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.add_note_output(input)).await
+    }
+
+    /// Runs `add_note`, returning its text and structured content.
+    async fn add_note_output(&self, input: AddNoteInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "add_note tool called");
+
+        // Validate content is non-empty and all fields within length limits
+        if input.content.is_empty() {
+            return Err("Note content is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let content = if input.show_to_requester == Some(true) {
+            self.sign(&client, &input.content).await
+        } else {
+            input.content.clone()
+        };
+
+        let mut note_request = CreateNoteRequest::new(content);
+        note_request.show_to_requester = input.show_to_requester;
+        note_request.notify_technician = input.notify_technician;
+        note_request.mark_first_response = input.mark_first_response;
+
+        let note = client
+            .create_note(&input.request_id, &note_request)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to add note"
+                );
+                format!(
+                    "Failed to add note to request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_add_note_result(&input.request_id, &note),
+            json!({"request_id": input.request_id, "note": note}),
+        ))
+    }
+
+    /// Assign a ticket to a technician or support group.
+    ///
+    /// At least one of technician_id or group must be provided.
+    #[tool(
+        description = "Assign a ticket to a technician or support group. At least one of technician_id or group must be provided.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn assign_request(
+        &self,
+        Parameters(input): Parameters<AssignRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.assign_request_output(input)).await
+    }
+
+    /// Runs `assign_request`, returning its text and structured content.
+    async fn assign_request_output(&self, input: AssignRequestInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "assign_request tool called");
+
+        // Validate that at least one assignment target is provided
+        if !input.has_assignment() {
+            return Err(
+                "At least one of technician_id or group must be provided for assignment."
+                    .to_string(),
+            );
+        }
+
+        let request = client
+            .assign_request(
+                &input.request_id,
+                input.technician_id.as_deref(),
+                input.group.as_deref(),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to assign request"
+                );
+                format!(
+                    "Failed to assign request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_assign_result(&request, &input),
+            json!({"request": request}),
+        ))
+    }
+
+    /// Change the requester of a ticket.
+    #[tool(
+        description = "Change the requester of a ticket, e.g. when it was forwarded from a shared mailbox and lists the wrong person. Give the new requester's email address or requester ID (find IDs with search_requesters).",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn change_requester(
+        &self,
+        Parameters(input): Parameters<ChangeRequesterInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.change_requester_output(input)).await
+    }
+
+    /// Runs `change_requester`, returning its text and structured content.
+    async fn change_requester_output(
+        &self,
+        input: ChangeRequesterInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "change_requester tool called");
+
+        let request = client
+            .change_requester(&input.request_id, &input.requester)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to change requester"
+                );
+                format!(
+                    "Failed to change requester of request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_change_requester_result(&request),
+            json!({"request": request}),
+        ))
+    }
+
+    /// Add CC recipients to a ticket.
+    #[tool(
+        description = "Add email addresses to a ticket's CC list (email_ids_to_notify) so additional stakeholders receive its notifications. Addresses already on the list are skipped.",
+        annotations(destructive_hint = false, idempotent_hint = true)
+    )]
+    async fn add_cc(
+        &self,
+        Parameters(input): Parameters<CcRecipientsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.add_cc_output(input)).await
+    }
+
+    /// Runs `add_cc`, returning its text and structured content.
+    async fn add_cc_output(&self, input: CcRecipientsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "add_cc tool called");
+
+        let request = client
+            .add_cc(&input.request_id, &input.emails)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to add CC recipients"
+                );
+                format!(
+                    "Failed to add CC recipients to request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_cc_result(&request),
+            json!({"request": request}),
+        ))
+    }
+
+    /// Remove CC recipients from a ticket.
+    #[tool(
+        description = "Remove email addresses from a ticket's CC list (email_ids_to_notify) so they stop receiving its notifications.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn remove_cc(
+        &self,
+        Parameters(input): Parameters<CcRecipientsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.remove_cc_output(input)).await
+    }
+
+    /// Runs `remove_cc`, returning its text and structured content.
+    async fn remove_cc_output(&self, input: CcRecipientsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "remove_cc tool called");
+
+        let request = client
+            .remove_cc(&input.request_id, &input.emails)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to remove CC recipients"
+                );
+                format!(
+                    "Failed to remove CC recipients from request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_cc_result(&request),
+            json!({"request": request}),
+        ))
+    }
+
+    /// List the users watching a ticket.
+    #[tool(
+        description = "List the watchers of a ticket: technicians and requesters subscribed to its updates, with their user IDs.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_watchers(
+        &self,
+        Parameters(input): Parameters<ListWatchersInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_watchers_output(input)).await
+    }
+
+    /// Runs `list_watchers`, returning its text and structured content.
+    async fn list_watchers_output(&self, input: ListWatchersInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_watchers tool called");
+
+        let watchers = self.fetch_watchers(&client, &input.request_id).await?;
+
+        Ok(ToolOutput::new(
+            format_watcher_list(&input.request_id, &watchers),
+            json!({"request_id": input.request_id, "watchers": watchers}),
+        ))
+    }
+
+    /// Subscribe users to a ticket's updates.
+    #[tool(
+        description = "Add technicians or requesters as watchers of a ticket (by user ID) so they are kept informed of its updates. Use add_cc instead for people who only have an email address.",
+        annotations(destructive_hint = false, idempotent_hint = true)
+    )]
+    async fn add_watchers(
+        &self,
+        Parameters(input): Parameters<WatchersInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.add_watchers_output(input)).await
+    }
+
+    /// Runs `add_watchers`, returning its text and structured content.
+    async fn add_watchers_output(&self, input: WatchersInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "add_watchers tool called");
+
+        client
+            .add_watchers(&input.request_id, &input.user_ids)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to add watchers"
+                );
+                format!(
+                    "Failed to add watchers to request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        let watchers = self.fetch_watchers(&client, &input.request_id).await?;
+        Ok(ToolOutput::new(
+            format_watcher_list(&input.request_id, &watchers),
+            json!({"request_id": input.request_id, "watchers": watchers}),
+        ))
+    }
+
+    /// Unsubscribe users from a ticket's updates.
+    #[tool(
+        description = "Remove watchers (by user ID) from a ticket so they stop receiving its updates.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn remove_watchers(
+        &self,
+        Parameters(input): Parameters<WatchersInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.remove_watchers_output(input)).await
+    }
+
+    /// Runs `remove_watchers`, returning its text and structured content.
+    async fn remove_watchers_output(&self, input: WatchersInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "remove_watchers tool called");
+
+        client
+            .remove_watchers(&input.request_id, &input.user_ids)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to remove watchers"
+                );
+                format!(
+                    "Failed to remove watchers from request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        let watchers = self.fetch_watchers(&client, &input.request_id).await?;
+        Ok(ToolOutput::new(
+            format_watcher_list(&input.request_id, &watchers),
+            json!({"request_id": input.request_id, "watchers": watchers}),
+        ))
+    }
+
+    /// Escalate a ticket in one call.
+    ///
+    /// Raises the priority, optionally reassigns, and records the reason as
+    /// an internal note.
+    #[tool(
+        description = "Escalate a ticket in one call: raise its priority (default 'High'), optionally reassign it to an escalation group and/or technician_id, and record the reason as an internal note that notifies the technician. Request ID and reason are required.",
+        annotations(destructive_hint = true, idempotent_hint = false)
+    )]
+    async fn escalate_request(
+        &self,
+        Parameters(input): Parameters<EscalateRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.escalate_request_output(input)).await
+    }
+
+    /// Runs `escalate_request`, returning its text and structured content.
+    async fn escalate_request_output(
+        &self,
+        input: EscalateRequestInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "escalate_request tool called");
+
+        let update = UpdateRequestInput {
+            request_id: input.request_id.clone(),
+            subject: None,
+            description: None,
+            priority: Some(
+                input
+                    .priority
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_ESCALATION_PRIORITY.to_string()),
+            ),
+            status: None,
+            category: None,
+            subcategory: None,
+            group: input.group.clone(),
+            technician_id: input.technician_id.clone(),
+            custom_fields: None,
+            portal: None,
+        };
+
+        let request = client
+            .update_request(&input.request_id, &update)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to escalate request"
+                );
+                format!(
+                    "Failed to escalate request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        // The ticket is already escalated at this point, so a failed note is reported, not fatal
+        let note_content = plain_text_to_html(&format!("Escalated: {}", input.reason));
+        let note_error = match client
+            .add_note(&input.request_id, &note_content, Some(false), Some(true))
+            .await
+        {
+            Ok(_) => None,
+            Err(e) => {
+                let sanitized = self.sanitize_error(&e);
+                tracing::warn!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to add escalation note"
+                );
+                Some(sanitized)
+            }
+        };
+
+        Ok(ToolOutput::new(
+            format_escalate_result(&request, &input.reason, note_error.as_deref()),
+            json!({"request": request, "note_error": note_error}),
+        ))
+    }
+
+    /// Assign a ticket to the technician that owns the API key.
+    #[tool(
+        description = "Pick up a ticket: assign it to yourself (the technician that owns the configured API key) in one step. Request ID is required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn pickup_request(
+        &self,
+        Parameters(input): Parameters<PickupRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.pickup_request_output(input)).await
+    }
+
+    /// Runs `pickup_request`, returning its text and structured content.
+    async fn pickup_request_output(&self, input: PickupRequestInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "pickup_request tool called");
+
+        let request = client
+            .pickup_request(&input.request_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to pick up request"
+                );
+                format!(
+                    "Failed to pick up request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_pickup_result(&request),
+            json!({"request": request}),
+        ))
+    }
+
+    /// Record the resolution of a ticket.
+    ///
+    /// Writes the Resolution section shown by get_request without closing the ticket.
+    #[tool(
+        description = "Set the resolution text of a ticket (the Resolution section shown by get_request). Replaces any existing resolution; does not change the status or close the ticket. Request ID and content are required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn add_resolution(
+        &self,
+        Parameters(input): Parameters<AddResolutionInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.add_resolution_output(input)).await
+    }
+
+    /// Runs `add_resolution`, returning its text and structured content.
+    async fn add_resolution_output(&self, input: AddResolutionInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "add_resolution tool called");
+
+        if input.content.is_empty() {
+            return Err("Resolution content is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let resolution = client
+            .set_resolution(&input.request_id, &input.content)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to set resolution"
+                );
+                format!(
+                    "Failed to set resolution on request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_resolution_result(&input.request_id, &resolution),
+            json!({"request_id": input.request_id, "resolution": resolution}),
+        ))
+    }
+
+    /// Resolve a ticket in one step.
+    ///
+    /// Writes the resolution, then sets status and closure info. If the
+    /// status update fails, the previous resolution is restored when possible.
+    #[tool(
+        description = "Resolve a ticket in one step: write the resolution, then set the status (default: the instance's resolved status; use 'Closed' to close) with optional closure code and comments. If the status update fails, the previous resolution is restored where possible and the error says exactly what changed. Request ID and resolution are required.",
+        annotations(destructive_hint = true, idempotent_hint = false)
+    )]
+    async fn resolve_request(
+        &self,
+        Parameters(input): Parameters<ResolveRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.resolve_request_output(input)).await
+    }
+
+    /// Runs `resolve_request`, returning its text and structured content.
+    async fn resolve_request_output(
+        &self,
+        input: ResolveRequestInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "resolve_request tool called");
+
+        if input.resolution.is_empty() {
+            return Err("Resolution content is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let status = match &input.status {
+            Some(status) => status.clone(),
+            None => self.status_name(&client, Status::Resolved).await,
+        };
+        let status = status.as_str();
+
+        // Remember the current resolution so it can be restored on failure
+        let previous = client.get_request(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to get request for resolve"
+            );
+            format!(
+                "Failed to resolve request {}: {}",
+                input.request_id, sanitized
+            )
+        })?;
+        let previous_resolution = previous.resolution.and_then(|r| r.content);
+
+        let resolution = client
+            .set_resolution(&input.request_id, &input.resolution)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to set resolution"
+                );
+                format!(
+                    "Failed to resolve request {}: could not save the resolution: {}. Nothing was \
+                     changed.",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        let result = client
+            .resolve_request(
+                &input.request_id,
+                status,
+                input.closure_code.as_deref(),
+                input.closure_comments.as_deref(),
+            )
+            .await;
+
+        match result {
+            Ok(request) => Ok(ToolOutput::new(
+                format_resolve_result(&request, &resolution),
+                json!({"request": request, "resolution": resolution}),
+            )),
+            Err(e) => {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    status = %status,
+                    "Failed to set status after resolution"
+                );
+
+                let rollback = match &previous_resolution {
+                    Some(content) => Some(
+                        client
+                            .set_resolution(&input.request_id, content)
+                            .await
+                            .map(|_| ())
+                            .map_err(|e| self.sanitize_error(&e)),
+                    ),
+                    None => None,
+                };
+
+                Err(format_resolve_failure(
+                    &input.request_id,
+                    status,
+                    &sanitized,
+                    rollback,
+                ))
+            }
+        }
+    }
+
+    /// Put a ticket on hold, optionally scheduling when it resumes.
+    #[tool(
+        description = "Put a ticket on hold (pauses the SLA clock) with an optional reason; status overrides the on-hold status name. Set resume_at to have SDP take it off hold automatically at that time (to resume_status, default: the instance's open status). Request ID is required.",
+        annotations(destructive_hint = true, idempotent_hint = false)
+    )]
+    async fn hold_request(
+        &self,
+        Parameters(input): Parameters<HoldRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.hold_request_output(input)).await
+    }
+
+    /// Runs `hold_request`, returning its text and structured content.
+    async fn hold_request_output(&self, input: HoldRequestInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "hold_request tool called");
+
+        let scheduler = match &input.resume_at {
+            Some(resume_at) => {
+                let time = SdpTimestamp::parse_input(resume_at).map_err(|e| e.to_string())?;
+                let status = match &input.resume_status {
+                    Some(status) => status.clone(),
+                    None => self.status_name(&client, Status::Open).await,
+                };
+                let mut scheduler = OnHoldScheduler::resume_at(time, &status);
+                if let Some(reason) = &input.reason {
+                    scheduler = scheduler.with_comments(reason);
+                }
+                Some(scheduler)
+            }
+            None => None,
+        };
+
+        let hold_status = match &input.status {
+            Some(status) => status.clone(),
+            None => self.status_name(&client, Status::OnHold).await,
+        };
+        let request = client
+            .hold_request(
+                &input.request_id,
+                &hold_status,
+                input.reason.as_deref(),
+                scheduler.as_ref(),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to put request on hold"
+                );
+                format!(
+                    "Failed to put request {} on hold: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_hold_result(&request, scheduler.as_ref()),
+            json!({"request": request, "scheduler": scheduler}),
+        ))
+    }
+
+    /// Change a ticket's due date.
+    #[tool(
+        description = "Change a ticket's due date (due_by_time), e.g. after renegotiating the deadline with the requester. Request ID and due_by (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339) are required; an optional reason is recorded as an internal note.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn set_due_date(
+        &self,
+        Parameters(input): Parameters<SetDueDateInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.set_due_date_output(input)).await
+    }
+
+    /// Runs `set_due_date`, returning its text and structured content.
+    async fn set_due_date_output(&self, input: SetDueDateInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "set_due_date tool called");
+
+        let due_by = input.due_timestamp().map_err(|e| e.to_string())?;
+        let request = client
+            .set_due_date(&input.request_id, &due_by)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to set due date"
+                );
+                format!(
+                    "Failed to set the due date of request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        // The deadline has already moved at this point, so a failed note is reported, not fatal
+        let note_error = match &input.reason {
+            Some(reason) => {
+                let note_content = plain_text_to_html(&format!(
+                    "Due date changed to {}: {}",
+                    input.due_by, reason
+                ));
+                match client
+                    .add_note(&input.request_id, &note_content, Some(false), Some(false))
+                    .await
+                {
+                    Ok(_) => None,
+                    Err(e) => {
+                        let sanitized = self.sanitize_error(&e);
+                        tracing::warn!(
+                            error = %sanitized,
+                            request_id = %input.request_id,
+                            "Failed to add due date note"
+                        );
+                        Some(sanitized)
+                    }
+                }
+            }
+            None => None,
+        };
+
+        Ok(ToolOutput::new(
+            format_due_date_result(&request, &due_by, note_error.as_deref()),
+            json!({"request": request, "due_by": due_by, "note_error": note_error}),
+        ))
+    }
+
+    /// Take a ticket off hold.
+    #[tool(
+        description = "Take a ticket off hold, returning it to the instance's open status (or the given status). Request ID is required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn resume_request(
+        &self,
+        Parameters(input): Parameters<ResumeRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.resume_request_output(input)).await
+    }
+
+    /// Runs `resume_request`, returning its text and structured content.
+    async fn resume_request_output(&self, input: ResumeRequestInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "resume_request tool called");
+
+        let status = match &input.status {
+            Some(status) => status.clone(),
+            None => self.status_name(&client, Status::Open).await,
+        };
+
+        let request = client
+            .resume_request(&input.request_id, &status, input.comments.as_deref())
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to resume request"
+                );
+                format!(
+                    "Failed to resume request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        let mut output = tr_fill(
+            "Successfully resumed ticket #{}: {}",
+            &[&request.id, request.display_subject()],
+        );
+        output.push_str(&format!(
+            "\n\n{}: {}\n",
+            tr("Status"),
+            request.display_status()
+        ));
+        Ok(ToolOutput::new(output, json!({"request": request})))
+    }
+
+    /// Move a ticket to the trash.
+    ///
+    /// Guarded by a required `confirm: true` and by read-only mode.
+    #[tool(
+        description = "Move a ticket to the trash. Only use when the user explicitly asks to delete a ticket; 'confirm' must be true. Request ID is required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn delete_request(
+        &self,
+        Parameters(input): Parameters<DeleteRequestInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.delete_request_output(input, context)).await
+    }
+
+    /// Runs `delete_request`, returning its text and structured content.
+    async fn delete_request_output(
+        &self,
+        input: DeleteRequestInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "delete_request tool called");
+
+        if client.is_read_only() {
+            return Err(crate::error::GlassError::ReadOnly.to_string());
+        }
+        if !input.confirm {
+            return Err(format!(
+                "Deletion of request {} was not confirmed. Ask the user to confirm, then call \
+                 again with confirm: true.",
+                input.request_id
+            ));
+        }
+        confirm_action(
+            &context,
+            format!("Move ticket #{} to the trash?", input.request_id),
+        )
+        .await?;
+
+        client
+            .delete_request(&input.request_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to delete request"
+                );
+                format!(
+                    "Failed to delete request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        tracing::info!(request_id = %input.request_id, "Request moved to trash");
+        Ok(ToolOutput::new(
+            format_delete_result(&input.request_id),
+            json!({"request_id": input.request_id}),
+        ))
+    }
+
+    /// List tickets in the trash.
+    #[tool(
+        description = "List tickets that have been moved to the trash (e.g., by delete_request). Use restore_request to recover one.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_trashed_requests(
+        &self,
+        Parameters(input): Parameters<ListTrashedRequestsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_trashed_requests_output(input)).await
+    }
+
+    /// Runs `list_trashed_requests`, returning its text and structured content.
+    async fn list_trashed_requests_output(
+        &self,
+        input: ListTrashedRequestsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "list_trashed_requests tool called");
+
+        let mut params =
+            ListParams::new().with_limit(self.list_defaults.limit(input.limit).min(100));
+        if let Some(offset) = input.offset {
+            params = params.with_offset(offset);
+        }
+
+        let requests = client.list_trashed_requests(params).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to list trashed requests");
+            format!("Failed to list trashed requests: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_trashed_request_list(&requests),
+            json!({"requests": requests}),
+        )
+        .formatted(input.format, || markdown_request_table(&requests)))
+    }
+
+    /// Restore a ticket from the trash.
+    #[tool(
+        description = "Restore a ticket from the trash. Request ID is required; use list_trashed_requests to find it.",
+        annotations(destructive_hint = false, idempotent_hint = true)
+    )]
+    async fn restore_request(
+        &self,
+        Parameters(input): Parameters<RestoreRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.restore_request_output(input)).await
+    }
+
+    /// Runs `restore_request`, returning its text and structured content.
+    async fn restore_request_output(
+        &self,
+        input: RestoreRequestInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "restore_request tool called");
+
+        let request = client
+            .restore_request(&input.request_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to restore request"
+                );
+                format!(
+                    "Failed to restore request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        tracing::info!(request_id = %input.request_id, "Request restored from trash");
+        let mut output = tr_fill(
+            "Successfully restored ticket #{}: {}",
+            &[&request.id, request.display_subject()],
+        );
+        output.push_str(&format!(
+            "\n\n{}: {}\n",
+            tr("Status"),
+            request.display_status()
+        ));
+        Ok(ToolOutput::new(output, json!({"request": request})))
+    }
+
+    /// Associate an asset with a ticket.
+    #[tool(
+        description = "Attach an asset (the affected device or configuration item) to a ticket. Existing asset associations are kept. Request ID and asset ID are required; find asset IDs with search_assets or list_user_assets.",
+        annotations(destructive_hint = false, idempotent_hint = true)
+    )]
+    async fn attach_asset_to_request(
+        &self,
+        Parameters(input): Parameters<AttachAssetInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.attach_asset_to_request_output(input)).await
+    }
+
+    /// Runs `attach_asset_to_request`, returning its text and structured content.
+    async fn attach_asset_to_request_output(
+        &self,
+        input: AttachAssetInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            asset_id = %input.asset_id,
+            "attach_asset_to_request tool called"
+        );
+
+        let request = client
+            .attach_asset(&input.request_id, &input.asset_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to attach asset"
+                );
+                format!(
+                    "Failed to attach asset {} to request {}: {}",
+                    input.asset_id, input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_attach_asset_result(&request, &input.asset_id),
+            json!({"request": request, "asset_id": input.asset_id}),
+        ))
+    }
+
+    // ========================================================================
+    // Request link tools
+    // ========================================================================
+
+    /// Link two related tickets.
+    #[tool(
+        description = "Link two related tickets (e.g., same outage or duplicate reports) with an optional comment. Linked tickets are shown in get_request. Both request IDs are required.",
+        annotations(destructive_hint = false, idempotent_hint = true)
+    )]
+    async fn link_requests(
+        &self,
+        Parameters(input): Parameters<LinkRequestsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.link_requests_output(input)).await
+    }
+
+    /// Runs `link_requests`, returning its text and structured content.
+    async fn link_requests_output(&self, input: LinkRequestsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            linked_request_id = %input.linked_request_id,
+            "link_requests tool called"
+        );
+
+        client
+            .link_requests(
+                &input.request_id,
+                &input.linked_request_id,
+                input.comments.as_deref(),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    linked_request_id = %input.linked_request_id,
+                    "Failed to link requests"
+                );
+                format!(
+                    "Failed to link request {} to {}: {}",
+                    input.request_id, input.linked_request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_link_result("linked", &input.request_id, &input.linked_request_id),
+            json!({"request_id": input.request_id, "linked_request_id": input.linked_request_id}),
+        ))
+    }
+
+    /// Remove the link between two tickets.
+    #[tool(
+        description = "Remove the link between two tickets. Both request IDs are required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn unlink_requests(
+        &self,
+        Parameters(input): Parameters<UnlinkRequestsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.unlink_requests_output(input)).await
+    }
+
+    /// Runs `unlink_requests`, returning its text and structured content.
+    async fn unlink_requests_output(
+        &self,
+        input: UnlinkRequestsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            linked_request_id = %input.linked_request_id,
+            "unlink_requests tool called"
+        );
+
+        client
+            .unlink_requests(&input.request_id, &input.linked_request_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    linked_request_id = %input.linked_request_id,
+                    "Failed to unlink requests"
+                );
+                format!(
+                    "Failed to unlink request {} from {}: {}",
+                    input.request_id, input.linked_request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_link_result("unlinked", &input.request_id, &input.linked_request_id),
+            json!({"request_id": input.request_id, "linked_request_id": input.linked_request_id}),
+        ))
+    }
+
+    // ========================================================================
+    // Problem and change association tools
+    // ========================================================================
+
+    /// Associate a ticket with a problem or change.
+    #[tool(
+        description = "Associate an incident ticket with the problem behind it (problem_id) or the change that fixes it (change_id). Give exactly one of the two. A ticket has at most one problem and one change; associations are shown in get_request.",
+        annotations(destructive_hint = false, idempotent_hint = true)
+    )]
+    async fn associate_request(
+        &self,
+        Parameters(input): Parameters<AssociateRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.associate_request_output(input)).await
+    }
+
+    /// Runs `associate_request`, returning its text and structured content.
+    async fn associate_request_output(
+        &self,
+        input: AssociateRequestInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        let (kind, target_id) = input.target().map_err(|e| e.to_string())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            kind = kind.key(),
+            target_id = %target_id,
+            "associate_request tool called"
+        );
+
+        let record = client
+            .associate_request(&input.request_id, kind, target_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    kind = kind.key(),
+                    target_id = %target_id,
+                    "Failed to associate request"
+                );
+                format!(
+                    "Failed to associate request {} with {} {}: {}",
+                    input.request_id,
+                    kind.key(),
+                    target_id,
+                    sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_association_result(true, &input.request_id, kind, target_id, record.as_ref()),
+            json!({
+                "request_id": input.request_id,
+                "kind": kind,
+                "id": target_id,
+                "record": record,
+            }),
+        ))
+    }
+
+    /// Remove a ticket's association with a problem or change.
+    #[tool(
+        description = "Remove a ticket's association with a problem (problem_id) or change (change_id). Give exactly one of the two.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn dissociate_request(
+        &self,
+        Parameters(input): Parameters<DissociateRequestInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.dissociate_request_output(input)).await
+    }
+
+    /// Runs `dissociate_request`, returning its text and structured content.
+    async fn dissociate_request_output(
+        &self,
+        input: DissociateRequestInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        let (kind, target_id) = input.target().map_err(|e| e.to_string())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            kind = kind.key(),
+            target_id = %target_id,
+            "dissociate_request tool called"
+        );
+
+        client
+            .dissociate_request(&input.request_id, kind, target_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    kind = kind.key(),
+                    target_id = %target_id,
+                    "Failed to dissociate request"
+                );
+                format!(
+                    "Failed to remove {} {} from request {}: {}",
+                    kind.key(),
+                    target_id,
+                    input.request_id,
+                    sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_association_result(false, &input.request_id, kind, target_id, None),
+            json!({"request_id": input.request_id, "kind": kind, "id": target_id}),
+        ))
+    }
+
+    // ========================================================================
+    // Worklog tools
+    // ========================================================================
+
+    /// List worklogs (time entries) recorded on a ticket.
+    #[tool(
+        description = "List worklogs (time entries) recorded on a ticket. Returns worklog IDs, technician, time spent, and description. Use the IDs with update_worklog or delete_worklog.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_worklogs(
+        &self,
+        Parameters(input): Parameters<ListWorklogsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_worklogs_output(input)).await
+    }
+
+    /// Runs `list_worklogs`, returning its text and structured content.
+    async fn list_worklogs_output(&self, input: ListWorklogsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_worklogs tool called");
+
+        let worklogs = client.list_worklogs(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to list worklogs"
+            );
+            format!(
+                "Failed to list worklogs for request {}: {}",
+                input.request_id, sanitized
+            )
+        })?;
+
+        Ok(ToolOutput::new(
+            format_worklog_list(&input.request_id, &worklogs),
+            json!({"request_id": input.request_id, "worklogs": worklogs}),
+        ))
+    }
+
+    /// Log time spent on a ticket.
+    ///
+    /// Request ID and time spent are required.
+    #[tool(
+        description = "Log time spent on a ticket as a worklog entry. Request ID and time_spent_minutes are required.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn add_worklog(
+        &self,
+        Parameters(input): Parameters<AddWorklogInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.add_worklog_output(input)).await
+    }
+
+    /// Runs `add_worklog`, returning its text and structured content.
+    async fn add_worklog_output(&self, input: AddWorklogInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "add_worklog tool called");
+
+        if input.time_spent_minutes == 0 {
+            return Err("time_spent_minutes must be greater than zero.".to_string());
+        }
+
+        let mut worklog = WorklogRequest::new().with_time_spent_minutes(input.time_spent_minutes);
+        if let Some(ref description) = input.description {
+            worklog = worklog.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            worklog = worklog.with_owner_id(technician_id);
+        }
+
+        let created = client
+            .add_worklog(&input.request_id, &worklog)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to add worklog"
+                );
+                format!(
+                    "Failed to add worklog to request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_worklog_result("added", &input.request_id, &created),
+            json!({"request_id": input.request_id, "worklog": created}),
+        ))
+    }
+
+    /// Correct an existing worklog on a ticket.
+    ///
+    /// At least one of time spent, description, or technician must be provided.
+    #[tool(
+        description = "Correct an existing worklog (time entry) on a ticket. Request ID and worklog ID are required, plus at least one of time_spent_minutes, description, or technician_id.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn update_worklog(
+        &self,
+        Parameters(input): Parameters<UpdateWorklogInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.update_worklog_output(input)).await
+    }
+
+    /// Runs `update_worklog`, returning its text and structured content.
+    async fn update_worklog_output(&self, input: UpdateWorklogInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            worklog_id = %input.worklog_id,
+            "update_worklog tool called"
+        );
+
+        if !input.has_updates() {
+            return Err(
+                "At least one field must be provided for update (time_spent_minutes, description, \
+                 or technician_id)."
+                    .to_string(),
+            );
+        }
+        if input.time_spent_minutes == Some(0) {
+            return Err("time_spent_minutes must be greater than zero.".to_string());
+        }
+
+        let mut worklog = WorklogRequest::new();
+        if let Some(minutes) = input.time_spent_minutes {
+            worklog = worklog.with_time_spent_minutes(minutes);
+        }
+        if let Some(ref description) = input.description {
+            worklog = worklog.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            worklog = worklog.with_owner_id(technician_id);
+        }
+
+        let updated = client
+            .update_worklog(&input.request_id, &input.worklog_id, &worklog)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    worklog_id = %input.worklog_id,
+                    "Failed to update worklog"
+                );
+                format!(
+                    "Failed to update worklog {} on request {}: {}",
+                    input.worklog_id, input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_worklog_result("updated", &input.request_id, &updated),
+            json!({"request_id": input.request_id, "worklog": updated}),
+        ))
+    }
+
+    /// Delete a worklog from a ticket.
+    #[tool(
+        description = "Delete an incorrect worklog (time entry) from a ticket. Request ID and worklog ID are required. This cannot be undone.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn delete_worklog(
+        &self,
+        Parameters(input): Parameters<DeleteWorklogInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.delete_worklog_output(input)).await
+    }
+
+    /// Runs `delete_worklog`, returning its text and structured content.
+    async fn delete_worklog_output(&self, input: DeleteWorklogInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            worklog_id = %input.worklog_id,
+            "delete_worklog tool called"
+        );
+
+        client
+            .delete_worklog(&input.request_id, &input.worklog_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    worklog_id = %input.worklog_id,
+                    "Failed to delete worklog"
+                );
+                format!(
+                    "Failed to delete worklog {} on request {}: {}",
+                    input.worklog_id, input.request_id, sanitized
+                )
+            })?;
+
+        let mut output = tr_fill(
+            "Successfully deleted worklog #{} from ticket #{}.",
+            &[&input.worklog_id, &input.request_id],
+        );
+        output.push('\n');
+        Ok(ToolOutput::new(
+            output,
+            json!({"request_id": input.request_id, "worklog_id": input.worklog_id}),
+        ))
+    }
+
+    // ========================================================================
+    // Task tools
+    // ========================================================================
+
+    /// List tasks attached to a ticket.
+    #[tool(
+        description = "List tasks attached to a ticket, with their owners, status, and due dates. Use the task IDs with update_task or complete_task.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_tasks(
+        &self,
+        Parameters(input): Parameters<ListTasksInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_tasks_output(input)).await
+    }
+
+    /// Runs `list_tasks`, returning its text and structured content.
+    async fn list_tasks_output(&self, input: ListTasksInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_tasks tool called");
+
+        let tasks = client.list_tasks(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to list tasks"
+            );
+            format!(
+                "Failed to list tasks for request {}: {}",
+                input.request_id, sanitized
+            )
+        })?;
+
+        Ok(ToolOutput::new(
+            format_task_list(&input.request_id, &tasks),
+            json!({"request_id": input.request_id, "tasks": tasks}),
+        ))
+    }
+
+    /// Create a task on a ticket.
+    ///
+    /// Request ID and title are required.
+    #[tool(
+        description = "Create a task on a ticket. Request ID and title are required. Optionally set an owner (technician_id), group, priority, and due_date (YYYY-MM-DD or YYYY-MM-DD HH:MM).",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn create_task(
+        &self,
+        Parameters(input): Parameters<CreateTaskInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.create_task_output(input)).await
+    }
+
+    /// Runs `create_task`, returning its text and structured content.
+    async fn create_task_output(&self, input: CreateTaskInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "create_task tool called");
+
+        if input.title.is_empty() {
+            return Err("Task title is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let mut task = TaskRequest::new().with_title(&input.title);
+        if let Some(ref description) = input.description {
+            task = task.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            task = task.with_owner_id(technician_id);
+        }
+        if let Some(ref group) = input.group {
+            task = task.with_group(group);
+        }
+        if let Some(ref priority) = input.priority {
+            task = task.with_priority(priority);
+        }
+        if let Some(ref due_date) = input.due_date {
+            task = task.with_due(SdpTimestamp::parse_input(due_date).map_err(|e| e.to_string())?);
+        }
+
+        let created = client
+            .create_task(&input.request_id, &task)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to create task"
+                );
+                format!(
+                    "Failed to create task on request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_task_result("created", &input.request_id, &created),
+            json!({"request_id": input.request_id, "task": created}),
+        ))
+    }
+
+    /// Update a task on a ticket.
+    ///
+    /// At least one field besides the IDs must be provided.
+    #[tool(
+        description = "Update a task on a ticket: title, description, owner (technician_id), group, status, priority, due_date, or percentage_completion. Request ID and task ID are required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn update_task(
+        &self,
+        Parameters(input): Parameters<UpdateTaskInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.update_task_output(input)).await
+    }
+
+    /// Runs `update_task`, returning its text and structured content.
+    async fn update_task_output(&self, input: UpdateTaskInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            task_id = %input.task_id,
+            "update_task tool called"
+        );
+
+        if !input.has_updates() {
+            return Err(
+                "At least one field must be provided for update (title, description, \
+                 technician_id, group, status, priority, due_date, or \
+                 percentage_completion)."
+                    .to_string(),
+            );
+        }
+
+        let mut task = TaskRequest::new();
+        if let Some(ref title) = input.title {
+            task = task.with_title(title);
+        }
+        if let Some(ref description) = input.description {
+            task = task.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            task = task.with_owner_id(technician_id);
+        }
+        if let Some(ref group) = input.group {
+            task = task.with_group(group);
+        }
+        if let Some(ref status) = input.status {
+            task = task.with_status(status);
+        }
+        if let Some(ref priority) = input.priority {
+            task = task.with_priority(priority);
+        }
+        if let Some(ref due_date) = input.due_date {
+            task = task.with_due(SdpTimestamp::parse_input(due_date).map_err(|e| e.to_string())?);
+        }
+        if let Some(percentage) = input.percentage_completion {
+            task = task.with_percentage_completion(percentage);
+        }
+
+        let updated = client
+            .update_task(&input.request_id, &input.task_id, &task)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    task_id = %input.task_id,
+                    "Failed to update task"
+                );
+                format!(
+                    "Failed to update task {} on request {}: {}",
+                    input.task_id, input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_task_result("updated", &input.request_id, &updated),
+            json!({"request_id": input.request_id, "task": updated}),
+        ))
+    }
+
+    /// Mark a task on a ticket as complete.
+    #[tool(
+        description = "Mark a task on a ticket as complete (status 'Closed' and 100% done). Request ID and task ID are required; pass status if this instance uses a different completed status name.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn complete_task(
+        &self,
+        Parameters(input): Parameters<CompleteTaskInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.complete_task_output(input)).await
+    }
+
+    /// Runs `complete_task`, returning its text and structured content.
+    async fn complete_task_output(&self, input: CompleteTaskInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            task_id = %input.task_id,
+            "complete_task tool called"
+        );
+
+        let status = match &input.status {
+            Some(status) => status.clone(),
+            None => self.status_name(&client, Status::Closed).await,
+        };
+
+        let completed = client
+            .complete_task(&input.request_id, &input.task_id, &status)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    task_id = %input.task_id,
+                    "Failed to complete task"
+                );
+                format!(
+                    "Failed to complete task {} on request {}: {}",
+                    input.task_id, input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_task_result("completed", &input.request_id, &completed),
+            json!({"request_id": input.request_id, "task": completed}),
+        ))
+    }
+
+    // ========================================================================
+    // Project tools
+    // ========================================================================
+
+    /// List projects.
+    #[tool(
+        description = "List projects from the project module, with owner, status, and end date. Optionally filter by status. Use get_project for milestones.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_projects(
+        &self,
+        Parameters(input): Parameters<ListProjectsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_projects_output(input)).await
+    }
+
+    /// Runs `list_projects`, returning its text and structured content.
+    async fn list_projects_output(&self, input: ListProjectsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "list_projects tool called");
+
+        let limit = self.list_defaults.limit(input.limit).clamp(1, 100);
+
+        let projects = client
+            .list_projects(input.status.as_deref(), limit)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, "Failed to list projects");
+                format!("Failed to list projects: {}", sanitized)
+            })?;
+
+        Ok(ToolOutput::new(
+            format_project_list(&projects),
+            json!({"projects": projects}),
+        )
+        .formatted(input.format, || markdown_project_table(&projects)))
+    }
+
+    /// Get a project with its milestones.
+    #[tool(
+        description = "Get a project by ID: description, owner, status, schedule, completion, and its milestones. Use list_project_tasks for the project's tasks.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_project(
+        &self,
+        Parameters(input): Parameters<GetProjectInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_project_output(input)).await
+    }
+
+    /// Runs `get_project`, returning its text and structured content.
+    async fn get_project_output(&self, input: GetProjectInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(project_id = %input.project_id, "get_project tool called");
+
+        let project = client.get_project(&input.project_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                project_id = %input.project_id,
+                "Failed to get project"
+            );
+            format!("Failed to get project {}: {}", input.project_id, sanitized)
+        })?;
+
+        let (milestones, milestones_error) = match client.list_milestones(&input.project_id).await {
+            Ok(m) => (m, None),
+            Err(e) => {
+                let err_msg = self.sanitize_error(&e);
+                tracing::warn!(
+                    error = %err_msg,
+                    project_id = %input.project_id,
+                    "Failed to fetch milestones"
+                );
+                (vec![], Some(err_msg))
+            }
+        };
+
+        Ok(ToolOutput::new(
+            format_project_details(
+                &project,
+                &milestones,
+                milestones_error.as_deref(),
+                self.max_description_length,
+            ),
+            json!({"project": project, "milestones": milestones}),
+        ))
+    }
+
+    /// List the tasks of a project.
+    #[tool(
+        description = "List the tasks of a project, or only those under one milestone, with owners, status, and due dates. Use the task IDs with update_project_task.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_project_tasks(
+        &self,
+        Parameters(input): Parameters<ListProjectTasksInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_project_tasks_output(input)).await
+    }
+
+    /// Runs `list_project_tasks`, returning its text and structured content.
+    async fn list_project_tasks_output(
+        &self,
+        input: ListProjectTasksInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(project_id = %input.project_id, "list_project_tasks tool called");
+
+        let tasks = client
+            .list_project_tasks(&input.project_id, input.milestone_id.as_deref())
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    project_id = %input.project_id,
+                    "Failed to list project tasks"
+                );
+                format!(
+                    "Failed to list tasks for project {}: {}",
+                    input.project_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_project_task_list(&input.project_id, &tasks),
+            json!({"project_id": input.project_id, "tasks": tasks}),
+        ))
+    }
+
+    /// Create a project.
+    ///
+    /// Title is required.
+    #[tool(
+        description = "Create a project. Title is required. Optionally set an owner (technician_id), priority, start_date, and end_date (YYYY-MM-DD or YYYY-MM-DD HH:MM).",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn create_project(
+        &self,
+        Parameters(input): Parameters<CreateProjectInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.create_project_output(input)).await
+    }
+
+    /// Runs `create_project`, returning its text and structured content.
+    async fn create_project_output(&self, input: CreateProjectInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(title = %input.title, "create_project tool called");
+
+        if input.title.is_empty() {
+            return Err("Project title is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let mut project = ProjectRequest::new().with_title(&input.title);
+        if let Some(ref description) = input.description {
+            project = project.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            project = project.with_owner_id(technician_id);
+        }
+        if let Some(ref priority) = input.priority {
+            project = project.with_priority(priority);
+        }
+        if let Some(ref start_date) = input.start_date {
+            project = project
+                .with_start(SdpTimestamp::parse_input(start_date).map_err(|e| e.to_string())?);
+        }
+        if let Some(ref end_date) = input.end_date {
+            project =
+                project.with_end(SdpTimestamp::parse_input(end_date).map_err(|e| e.to_string())?);
+        }
+
+        let created = client.create_project(&project).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(error = %sanitized, "Failed to create project");
+            format!("Failed to create project: {}", sanitized)
+        })?;
+
+        Ok(ToolOutput::new(
+            format_project_result("created", &created),
+            json!({"project": created}),
+        ))
+    }
+
+    /// Update a project.
+    ///
+    /// At least one field besides the ID must be provided.
+    #[tool(
+        description = "Update a project: title, description, owner (technician_id), status, priority, start_date, or end_date. Project ID is required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn update_project(
+        &self,
+        Parameters(input): Parameters<UpdateProjectInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.update_project_output(input)).await
+    }
+
+    /// Runs `update_project`, returning its text and structured content.
+    async fn update_project_output(&self, input: UpdateProjectInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(project_id = %input.project_id, "update_project tool called");
+
+        if !input.has_updates() {
+            return Err(
+                "At least one field must be provided for update (title, description, \
+                 technician_id, status, priority, start_date, or end_date)."
+                    .to_string(),
+            );
+        }
+
+        let mut project = ProjectRequest::new();
+        if let Some(ref title) = input.title {
+            project = project.with_title(title);
+        }
+        if let Some(ref description) = input.description {
+            project = project.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            project = project.with_owner_id(technician_id);
+        }
+        if let Some(ref status) = input.status {
+            project = project.with_status(status);
+        }
+        if let Some(ref priority) = input.priority {
+            project = project.with_priority(priority);
+        }
+        if let Some(ref start_date) = input.start_date {
+            project = project
+                .with_start(SdpTimestamp::parse_input(start_date).map_err(|e| e.to_string())?);
+        }
+        if let Some(ref end_date) = input.end_date {
+            project =
+                project.with_end(SdpTimestamp::parse_input(end_date).map_err(|e| e.to_string())?);
+        }
+
+        let updated = client
+            .update_project(&input.project_id, &project)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    project_id = %input.project_id,
+                    "Failed to update project"
+                );
+                format!(
+                    "Failed to update project {}: {}",
+                    input.project_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_project_result("updated", &updated),
+            json!({"project": updated}),
+        ))
+    }
+
+    /// Create a task on a project.
+    ///
+    /// Project ID and title are required.
+    #[tool(
+        description = "Create a task on a project, optionally under a milestone. Project ID and title are required. Optionally set an owner (technician_id), priority, and due_date (YYYY-MM-DD or YYYY-MM-DD HH:MM).",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn create_project_task(
+        &self,
+        Parameters(input): Parameters<CreateProjectTaskInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.create_project_task_output(input)).await
+    }
+
+    /// Runs `create_project_task`, returning its text and structured content.
+    async fn create_project_task_output(
+        &self,
+        input: CreateProjectTaskInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(project_id = %input.project_id, "create_project_task tool called");
+
+        if input.title.is_empty() {
+            return Err("Task title is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let mut task = TaskRequest::new().with_title(&input.title);
+        if let Some(ref description) = input.description {
+            task = task.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            task = task.with_owner_id(technician_id);
+        }
+        if let Some(ref priority) = input.priority {
+            task = task.with_priority(priority);
+        }
+        if let Some(ref due_date) = input.due_date {
+            task = task.with_due(SdpTimestamp::parse_input(due_date).map_err(|e| e.to_string())?);
+        }
+
+        let created = client
+            .create_project_task(&input.project_id, input.milestone_id.as_deref(), &task)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    project_id = %input.project_id,
+                    "Failed to create project task"
+                );
+                format!(
+                    "Failed to create task on project {}: {}",
+                    input.project_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_project_task_result("created", &input.project_id, &created),
+            json!({"project_id": input.project_id, "task": created}),
+        ))
+    }
+
+    /// Update a task on a project.
+    ///
+    /// At least one field besides the IDs must be provided.
+    #[tool(
+        description = "Update a task on a project: title, description, owner (technician_id), status, priority, due_date, or percentage_completion. Project ID and task ID are required.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn update_project_task(
+        &self,
+        Parameters(input): Parameters<UpdateProjectTaskInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.update_project_task_output(input)).await
+    }
+
+    /// Runs `update_project_task`, returning its text and structured content.
+    async fn update_project_task_output(
+        &self,
+        input: UpdateProjectTaskInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            project_id = %input.project_id,
+            task_id = %input.task_id,
+            "update_project_task tool called"
+        );
+
+        if !input.has_updates() {
+            return Err(
+                "At least one field must be provided for update (title, description, \
+                 technician_id, status, priority, due_date, or percentage_completion)."
+                    .to_string(),
+            );
+        }
+
+        let mut task = TaskRequest::new();
+        if let Some(ref title) = input.title {
+            task = task.with_title(title);
+        }
+        if let Some(ref description) = input.description {
+            task = task.with_description(description);
+        }
+        if let Some(ref technician_id) = input.technician_id {
+            task = task.with_owner_id(technician_id);
+        }
+        if let Some(ref status) = input.status {
+            task = task.with_status(status);
+        }
+        if let Some(ref priority) = input.priority {
+            task = task.with_priority(priority);
+        }
+        if let Some(ref due_date) = input.due_date {
+            task = task.with_due(SdpTimestamp::parse_input(due_date).map_err(|e| e.to_string())?);
+        }
+        if let Some(percentage) = input.percentage_completion {
+            task = task.with_percentage_completion(percentage);
+        }
+
+        let updated = client
+            .update_project_task(&input.project_id, &input.task_id, &task)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    project_id = %input.project_id,
+                    task_id = %input.task_id,
+                    "Failed to update project task"
+                );
+                format!(
+                    "Failed to update task {} on project {}: {}",
+                    input.task_id, input.project_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_project_task_result("updated", &input.project_id, &updated),
+            json!({"project_id": input.project_id, "task": updated}),
+        ))
+    }
+
+    // ========================================================================
+    // Approval tools
+    // ========================================================================
+
+    /// List approval levels and approvals on a ticket.
+    #[tool(
+        description = "List the approval levels and individual approvals on a ticket, with approver, status, and comments. Use the level and approval IDs with approve_request or reject_request.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_approvals(
+        &self,
+        Parameters(input): Parameters<ListApprovalsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_approvals_output(input)).await
+    }
+
+    /// Runs `list_approvals`, returning its text and structured content.
+    async fn list_approvals_output(&self, input: ListApprovalsInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_approvals tool called");
+
+        let levels = client
+            .list_approval_levels(&input.request_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to list approval levels"
+                );
+                format!(
+                    "Failed to list approvals for request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        // Fetch approvals per level; a failure on one level is reported inline
+        let mut approvals_by_level = Vec::with_capacity(levels.len());
+        for level in &levels {
+            let approvals = client
+                .list_approvals(&input.request_id, &level.id)
+                .await
+                .map_err(|e| {
+                    let sanitized = self.sanitize_error(&e);
+                    tracing::warn!(
+                        error = %sanitized,
+                        request_id = %input.request_id,
+                        level_id = %level.id,
+                        "Failed to fetch approvals"
+                    );
+                    sanitized
+                });
+            approvals_by_level.push(approvals);
+        }
+
+        Ok(ToolOutput::new(
+            format_approval_list(&input.request_id, &levels, &approvals_by_level),
+            json!({
+                "request_id": input.request_id,
+                "levels": approval_levels_json(&levels, &approvals_by_level),
+            }),
+        ))
+    }
+
+    /// Submit a ticket for approval.
+    #[tool(
+        description = "Submit a ticket for approval by one or more approvers (by email). Adds a new approval level unless approval_level_id is given. Optional comments are sent to the approvers.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn submit_for_approval(
+        &self,
+        Parameters(input): Parameters<SubmitForApprovalInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.submit_for_approval_output(input)).await
+    }
+
+    /// Runs `submit_for_approval`, returning its text and structured content.
+    async fn submit_for_approval_output(
+        &self,
+        input: SubmitForApprovalInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "submit_for_approval tool called");
+
+        if input.approver_emails.is_empty() {
+            return Err("At least one approver email is required.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let (level_id, approvals) = client
+            .submit_for_approval(
+                &input.request_id,
+                input.approval_level_id.as_deref(),
+                &input.approver_emails,
+                input.comments.as_deref(),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to submit for approval"
+                );
+                format!(
+                    "Failed to submit request {} for approval: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        let mut output = tr_fill(
+            "Successfully submitted ticket #{} for approval (level {}).",
+            &[&input.request_id, &level_id],
+        );
+        output.push_str("\n\n");
+        for approval in &approvals {
+            output.push_str(&format!(
+                "{}: {} | {}: {} | {}: {}\n",
+                tr("Approval ID"),
+                approval.id,
+                tr("Approver"),
+                approval.display_approver(),
+                tr("Status"),
+                approval.display_status()
+            ));
+        }
+        Ok(ToolOutput::new(
+            output,
+            json!({
+                "request_id": input.request_id,
+                "approval_level_id": level_id,
+                "approvals": approvals,
+            }),
+        ))
+    }
+
+    /// Approve a pending approval on a ticket.
+    #[tool(
+        description = "Approve a pending approval on a ticket. Requires request_id, approval_level_id, and approval_id (from list_approvals). Optional comments are recorded with the decision.",
+        annotations(destructive_hint = true, idempotent_hint = false)
+    )]
+    async fn approve_request(
+        &self,
+        Parameters(input): Parameters<ApprovalDecisionInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.decide_approval(input, ApprovalAction::Approve)).await
+    }
+
+    /// Reject a pending approval on a ticket.
+    #[tool(
+        description = "Reject a pending approval on a ticket. Requires request_id, approval_level_id, and approval_id (from list_approvals). Comments explaining the rejection are recommended.",
+        annotations(destructive_hint = true, idempotent_hint = false)
+    )]
+    async fn reject_request(
+        &self,
+        Parameters(input): Parameters<ApprovalDecisionInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.decide_approval(input, ApprovalAction::Reject)).await
+    }
+
+    /// Shared implementation for approve_request and reject_request.
+    async fn decide_approval(
+        &self,
+        input: ApprovalDecisionInput,
+        action: ApprovalAction,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            approval_id = %input.approval_id,
+            ?action,
+            "approval decision tool called"
+        );
+
+        let approval = client
+            .act_on_approval(
+                &input.request_id,
+                &input.approval_level_id,
+                &input.approval_id,
+                action,
+                input.comments.as_deref(),
+            )
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    approval_id = %input.approval_id,
+                    "Failed to act on approval"
+                );
+                format!(
+                    "Failed to {} approval {} on request {}: {}",
+                    action.path_segment(),
+                    input.approval_id,
+                    input.request_id,
+                    sanitized
+                )
+            })?;
+
+        let mut output = tr_fill(
+            "Successfully {} approval #{} on ticket #{}.",
+            &[tr(action.past_tense()), &approval.id, &input.request_id],
+        );
+        output.push_str("\n\n");
+        output.push_str(&format!(
+            "{}: {}\n",
+            tr("Status"),
+            approval.display_status()
+        ));
+        if let Some(comments) = &approval.comments {
+            output.push_str(&format!("{}: {}\n", tr("Comments"), comments));
+        }
+        Ok(ToolOutput::new(
+            output,
+            json!({"request_id": input.request_id, "approval": approval}),
+        ))
+    }
+
+    // ========================================================================
+    // Survey tools
+    // ========================================================================
+
+    /// Read requester satisfaction survey responses.
+    ///
+    /// Checks one ticket, or the tickets closed in a date range.
+    #[tool(
+        description = "Read requester satisfaction survey responses: the rating, comments, and answers. Give request_id for one ticket, or a closed_after/closed_before range (default: the last 7 days) to check recently closed tickets; set max_rating (e.g., 2) to find poorly rated ones for quality follow-up.",
+        annotations(read_only_hint = true)
+    )]
+    async fn survey_results(
+        &self,
+        Parameters(input): Parameters<SurveyResultsInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.survey_results_output(input, context)).await
+    }
+
+    /// Runs `survey_results`, returning its text and structured content.
+    async fn survey_results_output(
+        &self,
+        input: SurveyResultsInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "survey_results tool called");
+
+        // The tickets to check, with their subjects when known
+        let tickets: Vec<(String, Option<String>)> = match &input.request_id {
+            Some(request_id) => vec![(request_id.clone(), None)],
+            None => {
+                let (after, before) = input.closed_range().map_err(|e| e.to_string())?;
+                let after = after.unwrap_or_else(|| {
+                    (chrono::Utc::now() - chrono::Duration::days(SURVEY_DEFAULT_DAYS))
+                        .timestamp_millis()
+                });
+                let limit = input
+                    .limit
+                    .unwrap_or(SURVEY_DEFAULT_LIMIT)
+                    .clamp(1, SURVEY_MAX_LIMIT);
+                let mut params = ListParams::new()
+                    .with_completed_after(after)
+                    .with_sort("completed_time", "desc")
+                    .with_limit(limit);
+                if let Some(before) = before {
+                    params = params.with_completed_before(before);
+                }
+                let params = self.list_defaults.scope(params, None, None);
+                let requests = client.list_requests(params).await.map_err(|e| {
+                    let sanitized = self.sanitize_error(&e);
+                    tracing::error!(error = %sanitized, "Failed to list closed requests");
+                    format!("Failed to list closed tickets: {}", sanitized)
+                })?;
+                requests
+                    .into_iter()
+                    .map(|r| {
+                        let subject = r.display_subject().to_string();
+                        (r.id, Some(subject))
+                    })
+                    .collect()
+            }
+        };
+
+        let ids: Vec<String> = tickets.iter().map(|(id, _)| id.clone()).collect();
+        let client = client.clone();
+        let progress = Progress::new(&context);
+        let results = run_bulk(&ids, &progress, "checked", move |id| {
+            let client = client.clone();
+            async move { client.get_survey_response(&id).await }
+        })
+        .await;
+
+        let mut responses = Vec::new();
+        let mut failed = Vec::new();
+        for ((id, subject), (_, result)) in tickets.into_iter().zip(results) {
+            match result {
+                Ok(Some(response)) => responses.push(SurveyResult {
+                    request_id: id,
+                    subject,
+                    response,
+                }),
+                Ok(None) => {}
+                Err(e) => failed.push(format!("#{}: {}", id, self.sanitize_error(&e))),
+            }
+        }
+        if let Some(max) = input.max_rating {
+            responses.retain(|r| r.response.rating_score().is_some_and(|s| s <= max as f64));
+        }
+
+        Ok(ToolOutput::new(
+            format_survey_results(&responses, ids.len(), input.max_rating, &failed),
+            json!({"checked": ids.len(), "responses": responses, "errors": failed}),
+        ))
+    }
+
+    // ========================================================================
+    // Reply tools
+    // ========================================================================
+
+    /// List the emails SDP sent on a ticket.
+    #[tool(
+        description = "List the emails (notifications) ServiceDesk Plus actually sent on a ticket: replies, forwards, and system notifications, with sender, recipients, subject, and when each was sent. Use it to check whether the requester was told something.",
+        annotations(read_only_hint = true)
+    )]
+    async fn list_notifications(
+        &self,
+        Parameters(input): Parameters<ListNotificationsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.list_notifications_output(input)).await
+    }
+
+    /// Runs `list_notifications`, returning its text and structured content.
+    async fn list_notifications_output(
+        &self,
+        input: ListNotificationsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "list_notifications tool called");
+
+        let notifications = client
+            .list_notifications(&input.request_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to list notifications"
+                );
+                format!(
+                    "Failed to list notifications for request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_notification_list(&input.request_id, &notifications),
+            json!({"request_id": input.request_id, "notifications": notifications}),
+        ))
+    }
+
+    /// Get the full content of one email SDP sent on a ticket.
+    #[tool(
+        description = "Get one email (notification) ServiceDesk Plus sent on a ticket, in full: sender, To/Cc/Bcc, subject, sent time, any other header fields SDP recorded, and the complete body. Use it to audit exactly what the requester was told. Get the notification ID from list_notifications; set include_html=true to also see the body as sent.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_notification(
+        &self,
+        Parameters(input): Parameters<GetNotificationInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_notification_output(input)).await
+    }
+
+    /// Runs `get_notification`, returning its text and structured content.
+    async fn get_notification_output(
+        &self,
+        input: GetNotificationInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(
+            request_id = %input.request_id,
+            notification_id = %input.notification_id,
+            "get_notification tool called"
+        );
+
+        let fetched = client
+            .get_notification(&input.request_id, &input.notification_id)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    notification_id = %input.notification_id,
+                    "Failed to get notification"
+                );
+                format!(
+                    "Failed to get notification {} on request {}: {}",
+                    input.notification_id, input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_notification_details(
+                &input.request_id,
+                &fetched,
+                input.include_html.unwrap_or(false),
+            ),
+            json!({"request_id": input.request_id, "notification": fetched.raw}),
+        ))
+    }
+
+    /// Send an email reply to the ticket's requester.
+    ///
+    /// Unlike add_note, this actually emails the requester.
+    #[tool(
+        description = "Send an email reply to the requester of a ticket (unlike add_note, this emails them). Request ID and content are required. 'to' defaults to the requester and 'subject' to the ticket subject; set html=true to send content as HTML. Set mark_first_response=true when this is the initial reply, so the first-response SLA clock stops.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn reply_to_requester(
+        &self,
+        Parameters(input): Parameters<ReplyToRequesterInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.reply_to_requester_output(input)).await
+    }
+
+    /// Runs `reply_to_requester`, returning its text and structured content.
+    async fn reply_to_requester_output(
+        &self,
+        input: ReplyToRequesterInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "reply_to_requester tool called");
+
+        if input.content.is_empty() {
+            return Err("Reply content is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        // Only fetch the ticket when a default has to be filled in
+        let request = if input.to.is_empty() || input.subject.is_none() {
+            Some(client.get_request(&input.request_id).await.map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to get request for reply"
+                );
+                format!("Failed to get request {}: {}", input.request_id, sanitized)
+            })?)
+        } else {
+            None
+        };
+
+        let to = if input.to.is_empty() {
+            let requester_email = request
+                .as_ref()
+                .and_then(|r| r.requester.as_ref())
+                .and_then(|r| r.email_id.clone())
+                .ok_or_else(|| {
+                    format!(
+                        "Ticket #{} has no requester email address. Provide recipients in 'to'.",
+                        input.request_id
+                    )
+                })?;
+            vec![requester_email]
+        } else {
+            input.to.clone()
+        };
+
+        let subject = match (&input.subject, &request) {
+            (Some(subject), _) => subject.clone(),
+            (None, Some(request)) => default_reply_subject(request),
+            (None, None) => unreachable!("request is fetched when subject is missing"),
+        };
+
+        let body = if input.html == Some(true) {
+            input.content.clone()
+        } else {
+            plain_text_to_html(&input.content)
+        };
+        let body = self.sign(&client, &body).await;
+
+        let reply = ReplyRequest::new(subject, body, to).with_cc(input.cc.clone());
+
+        let notification = client
+            .send_reply(&input.request_id, &reply)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to send reply"
+                );
+                format!(
+                    "Failed to send reply on request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        let mut output = format_reply_result(&input.request_id, &reply, &notification);
+        let mut first_response = false;
+        if input.mark_first_response == Some(true) {
+            // The reply is already sent at this point, so a failure is reported, not fatal
+            let note_request = CreateNoteRequest::new(plain_text_to_html(&format!(
+                "First response sent by email: {}",
+                reply.subject
+            )))
+            .with_show_to_requester(false)
+            .with_mark_first_response(true);
+            output.push('\n');
+            match client.create_note(&input.request_id, &note_request).await {
+                Ok(_) => {
+                    first_response = true;
+                    output.push_str(tr("Recorded as the ticket's first response."));
+                }
+                Err(e) => {
+                    let sanitized = self.sanitize_error(&e);
+                    tracing::warn!(
+                        error = %sanitized,
+                        request_id = %input.request_id,
+                        "Failed to mark first response"
+                    );
+                    output.push_str(&tr_fill(
+                        "Warning: the reply could not be recorded as the first response ({}).",
+                        &[&sanitized],
+                    ));
+                    output.push(' ');
+                    output.push_str(tr(
+                        "Add a note with mark_first_response=true to stop the SLA clock.",
+                    ));
+                }
+            }
+            output.push('\n');
+        }
+
+        Ok(ToolOutput::new(
+            output,
+            json!({
+                "request_id": input.request_id,
+                "reply": reply,
+                "notification": notification,
+                "first_response": first_response,
+            }),
+        ))
+    }
+
+    /// Email arbitrary recipients about a ticket.
+    ///
+    /// Unlike reply_to_requester, this goes to people other than the requester.
+    #[tool(
+        description = "Send an email about a ticket to people other than the requester, e.g. to escalate to a manager or vendor. The email is recorded on the ticket. Request ID, to, subject, and content are required; set html=true to send content as HTML. Use reply_to_requester to answer the requester.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn notify(
+        &self,
+        Parameters(input): Parameters<NotifyInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.notify_output(input)).await
+    }
+
+    /// Runs `notify`, returning its text and structured content.
+    async fn notify_output(&self, input: NotifyInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(request_id = %input.request_id, "notify tool called");
+
+        let body = if input.html == Some(true) {
+            input.content.clone()
+        } else {
+            plain_text_to_html(&input.content)
+        };
+        let body = self.sign(&client, &body).await;
+        let notification =
+            ReplyRequest::forward(&input.subject, body, input.to.clone()).with_cc(input.cc.clone());
+
+        let sent = client
+            .send_reply(&input.request_id, &notification)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to send notification"
+                );
+                format!(
+                    "Failed to send notification on request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_reply_result(&input.request_id, &notification, &sent),
+            json!({"request_id": input.request_id, "notification": sent}),
+        ))
+    }
+
+    /// Resolves "me" to the API key's technician name.
+    async fn own_name(&self, client: &SdpClient) -> Result<String, String> {
+        match client.current_technician().await {
+            Ok(Some(technician)) => Ok(technician.display_name().to_string()),
+            Ok(None) => Err(UNKNOWN_TECHNICIAN_MESSAGE.to_string()),
+            Err(e) => Err(format!(
+                "Failed to identify you: {}",
+                self.sanitize_error(&e)
+            )),
+        }
+    }
+
+    /// Appends the configured reply signature to an HTML body.
+    ///
+    /// A failed technician lookup leaves its placeholders empty rather than
+    /// holding back the message.
+    async fn sign(&self, client: &SdpClient, body: &str) -> String {
+        let Some(signature) = &self.reply_signature else {
+            return body.to_string();
+        };
+        let technician = if signature.needs_technician() {
+            client.current_technician().await.unwrap_or_else(|e| {
+                tracing::warn!(
+                    error = %self.sanitize_error(&e),
+                    "Failed to look up technician for reply signature"
+                );
+                None
+            })
+        } else {
+            None
+        };
+        signature.append_to(body, technician.as_ref())
+    }
+
+    /// Downloads inline images as MCP image content, skipping those that
+    /// fail, are too large, or exceed the per-call limit.
+    async fn fetch_inline_images(&self, client: &SdpClient, sources: &[String]) -> Vec<Content> {
+        let mut images = Vec::new();
+        for src in sources.iter().take(MAX_INLINE_IMAGES) {
+            match client.fetch_inline_image(src, MAX_INLINE_IMAGE_BYTES).await {
+                Ok(Some(image)) => {
+                    images.push(Content::image(BASE64.encode(&image.data), image.mime_type));
+                }
+                Ok(None) => tracing::debug!(src = %src, "Skipped inline image"),
+                Err(e) => {
+                    tracing::warn!(
+                        error = %self.sanitize_error(&e),
+                        src = %src,
+                        "Failed to fetch inline image"
+                    );
+                }
+            }
+        }
+        images
+    }
+
+    /// Fetches a ticket's watchers, as an error message on failure.
+    async fn fetch_watchers(
+        &self,
+        client: &SdpClient,
+        request_id: &str,
+    ) -> Result<Vec<Watcher>, String> {
+        client.list_watchers(request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %request_id,
+                "Failed to list watchers"
+            );
+            format!(
+                "Failed to list watchers of request {}: {}",
+                request_id, sanitized
+            )
+        })
+    }
+
+    /// Fetches notes, conversations, worklogs, and history and merges them
+    /// into a timeline.
+    ///
+    /// A failing source is recorded in `fetch_errors`; only when all four
+    /// fail is the timeline an error.
+    async fn fetch_timeline(
+        &self,
+        client: &SdpClient,
+        request_id: &str,
+    ) -> Result<Timeline, String> {
+        let mut fetch_errors = Vec::new();
+        let mut content_errors = Vec::new();
+        let (mut more_notes, mut more_conversations) = (false, false);
+        let mut warn = |source: &str, e: &crate::error::GlassError| {
+            let err_msg = self.sanitize_error(e);
+            tracing::warn!(
+                error = %err_msg,
+                request_id = %request_id,
+                "Failed to fetch {}",
+                source
+            );
+            fetch_errors.push(format!("{}: {}", source, err_msg));
+        };
+
+        let notes = client
+            .list_notes_with_content(request_id, DETAIL_PAGE_SIZE, 0, false)
+            .await
+            .map(|n| {
+                more_notes = n.has_more;
+                content_errors.extend(
+                    n.failures
+                        .iter()
+                        .map(|f| format!("Note {}: {}", f.id, f.error)),
+                );
+                n.items
+            })
+            .unwrap_or_else(|e| {
+                warn("Notes", &e);
+                vec![]
+            });
+        let conversations = client
+            .list_conversations_with_content(request_id, DETAIL_PAGE_SIZE, 0, false)
+            .await
+            .map(|c| {
+                more_conversations = c.has_more;
+                content_errors.extend(
+                    c.failures
+                        .iter()
+                        .map(|f| format!("Conversation {}: {}", f.id, f.error)),
+                );
+                c.items
+            })
+            .unwrap_or_else(|e| {
+                warn("Conversations", &e);
+                vec![]
+            });
+        let worklogs = client.list_worklogs(request_id).await.unwrap_or_else(|e| {
+            warn("Worklogs", &e);
+            vec![]
+        });
+        let history = client
+            .get_request_history(request_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn("History", &e);
+                vec![]
+            });
+
+        if fetch_errors.len() == 4 {
+            return Err(format!(
+                "Failed to build timeline for request {}: {}",
+                request_id,
+                fetch_errors.join("; ")
+            ));
+        }
+        fetch_errors.extend(content_errors);
+
+        Ok(Timeline {
+            events: build_timeline(&notes, &conversations, &worklogs, &history),
+            fetch_errors,
+            more_hint: format_more_entries_hint(request_id, more_conversations, more_notes),
+        })
+    }
+
+    /// Builds list parameters from list_requests filters, applying the
+    /// configured default group and site.
+    async fn request_list_params(
+        &self,
+        client: &SdpClient,
+        input: &ListRequestsInput,
+    ) -> Result<ListParams, String> {
+        let mut params = ListParams::new();
+
+        if let Some(ref query) = input.query {
+            params = params.with_text(query, false);
+        }
+
+        if let Some(ref technician) = input.technician {
+            if technician.eq_ignore_ascii_case("me") {
+                params = params.with_technician(&self.own_name(client).await?);
+            } else {
+                params = params.with_technician(technician);
+            }
+        }
+        if let Some(ref requester) = input.requester {
+            params = params.with_requester(requester);
+        }
+        params = self
+            .list_defaults
+            .scope(params, input.group.as_deref(), input.site.as_deref());
+        if let Some(ref request_type) = input.request_type {
+            params = params.with_request_type(request_type);
+        }
+        if let Some(ref category) = input.category {
+            params = params.with_category(category);
+        }
+        if let Some(ref subcategory) = input.subcategory {
+            params = params.with_subcategory(subcategory);
+        }
+        if let Some(ref item) = input.item {
+            params = params.with_item(item);
+        }
+        if let Some(ref status) = input.status {
+            params = params.with_status_in(&split_alternatives(status));
+        }
+        if let Some(ref priority) = input.priority {
+            params = params.with_priority_in(&split_alternatives(priority));
+        }
+        let (created_after, created_before) = input.created_range().map_err(|e| e.to_string())?;
+        if let Some(millis) = created_after {
+            params = params.with_created_after(millis);
+        }
+        if let Some(millis) = created_before {
+            params = params.with_created_before(millis);
+        }
+        if let Some(millis) = input.updated_after_millis().map_err(|e| e.to_string())? {
+            params = params.with_updated_after(millis);
+        }
+
+        // Use server-side filtering for open_only
+        if input.open_only == Some(true) {
+            params = params.with_open_only();
+        }
+        if input.overdue_only == Some(true) {
+            params = params.with_overdue();
+        }
+        if let Some(has_attachments) = input.has_attachments {
+            params = params.with_has_attachments(has_attachments);
+        }
+
+        if input.sort_by.is_some() || input.sort_order.is_some() {
+            params = params.with_sort(
+                input.sort_by.as_deref().unwrap_or("created_time"),
+                input.sort_order.as_deref().unwrap_or("desc"),
+            );
+        }
+
+        Ok(params)
+    }
+
+    /// Returns the SDP client for an MSP portal, or the default client.
+    fn client_for(&self, portal: Option<&str>) -> Result<SdpClient, String> {
+        match portal {
+            Some(portal) => self
+                .sdp_client
+                .with_portal(portal)
+                .map_err(|e| e.to_string()),
+            None => Ok(self.sdp_client.clone()),
+        }
+    }
+
+    /// Returns the instance's name for `status`, such as "Åben" for open.
+    ///
+    /// Looks the status up among the statuses configured in SDP, so tools
+    /// work on instances with translated status names. Falls back to the
+    /// English name when the list cannot be fetched or has no match.
+    async fn status_name(&self, client: &SdpClient, status: Status) -> String {
+        let statuses = client.list_statuses().await.unwrap_or_else(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::warn!(error = %sanitized, "Failed to list statuses, using the default name");
+            Vec::new()
+        });
+        let names = statuses
+            .iter()
+            .filter(|s| s.deleted != Some(true))
+            .filter_map(|s| s.name.as_deref());
+        status.find_name(names).unwrap_or(status.name()).to_string()
+    }
+
+    /// Sanitizes an error message to remove any API key.
+    fn sanitize_error(&self, error: &crate::error::GlassError) -> String {
+        error.sanitized_display(self.sdp_client.secret_for_sanitization())
+    }
+
+    /// Converts raw bulk results into per-ticket outcomes with sanitized errors.
+    fn bulk_outcomes(
+        &self,
+        results: Vec<(String, Result<Request, crate::error::GlassError>)>,
+    ) -> Vec<BulkOutcome> {
+        results
+            .into_iter()
+            .map(|(id, result)| {
+                let result = result.map_err(|e| {
+                    let sanitized = self.sanitize_error(&e);
+                    tracing::warn!(
+                        error = %sanitized,
+                        request_id = %id,
+                        "Bulk operation failed for request"
+                    );
+                    sanitized
+                });
+                (id, result)
+            })
+            .collect()
+    }
+
+    // ========================================================================
+    // Knowledge base tools
+    // ========================================================================
+
+    /// Search the knowledge base.
+    #[tool(
+        description = "Search knowledge base solutions (KB articles) by title, keywords, or content. Check here before resolving a ticket so known fixes are reused. Use get_solution to read an article.",
+        annotations(read_only_hint = true)
+    )]
+    async fn search_solutions(
+        &self,
+        Parameters(input): Parameters<SearchSolutionsInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.search_solutions_output(input)).await
+    }
+
+    /// Runs `search_solutions`, returning its text and structured content.
+    async fn search_solutions_output(
+        &self,
+        input: SearchSolutionsInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(?input, "search_solutions tool called");
+
+        if input.query.is_empty() {
+            return Err("Search query is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let limit = input.limit.unwrap_or(10).clamp(1, 50);
+
+        let solutions = client
+            .search_solutions(&input.query, limit)
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(error = %sanitized, "Failed to search solutions");
+                format!("Failed to search solutions: {}", sanitized)
+            })?;
+
+        Ok(ToolOutput::new(
+            format_solution_list(&solutions),
+            json!({"solutions": solutions}),
+        )
+        .formatted(input.format, || markdown_solution_table(&solutions)))
+    }
+
+    /// Read a knowledge base article.
+    #[tool(
+        description = "Get a knowledge base solution by ID: title, topic, keywords, approval status, and the full article content. Use search_solutions to find the ID.",
+        annotations(read_only_hint = true)
+    )]
+    async fn get_solution(
+        &self,
+        Parameters(input): Parameters<GetSolutionInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.get_solution_output(input)).await
+    }
+
+    /// Runs `get_solution`, returning its text and structured content.
+    async fn get_solution_output(&self, input: GetSolutionInput) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(solution_id = %input.solution_id, "get_solution tool called");
+
+        let solution = client.get_solution(&input.solution_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                solution_id = %input.solution_id,
+                "Failed to get solution"
+            );
+            format!(
+                "Failed to get solution {}: {}",
+                input.solution_id, sanitized
+            )
+        })?;
+
+        Ok(ToolOutput::new(
+            format_solution_details(
+                &solution,
+                input.max_length.unwrap_or(self.max_description_length),
+            ),
+            json!({"solution": solution}),
+        ))
+    }
+
+    /// Turn a resolved ticket into a knowledge base article.
+    #[tool(
+        description = "Create a knowledge base solution from a resolved ticket. The article content is built from the ticket's description (the problem) and resolution (the fix); the title defaults to the ticket subject. Request ID and topic are required; the ticket must have a resolution.",
+        annotations(destructive_hint = false, idempotent_hint = false)
+    )]
+    async fn create_solution(
+        &self,
+        Parameters(input): Parameters<CreateSolutionInput>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.create_solution_output(input)).await
+    }
+
+    /// Runs `create_solution`, returning its text and structured content.
+    async fn create_solution_output(
+        &self,
+        input: CreateSolutionInput,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        tracing::debug!(request_id = %input.request_id, "create_solution tool called");
+
+        if input.topic.is_empty() {
+            return Err("Topic is required and cannot be empty.".to_string());
+        }
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+
+        let request = client.get_request(&input.request_id).await.map_err(|e| {
+            let sanitized = self.sanitize_error(&e);
+            tracing::error!(
+                error = %sanitized,
+                request_id = %input.request_id,
+                "Failed to get request"
+            );
+            format!("Failed to get request {}: {}", input.request_id, sanitized)
+        })?;
+
+        let content = build_solution_content(&request).ok_or_else(|| {
+            format!(
+                "Ticket #{} has no resolution. Record one with add_resolution or resolve_request \
+                 first.",
+                request.id
+            )
+        })?;
+        let title = input
+            .title
+            .clone()
+            .unwrap_or_else(|| request.display_subject().to_string());
+
+        let solution = client
+            .create_solution(&title, &content, &input.topic, input.keywords.as_deref())
+            .await
+            .map_err(|e| {
+                let sanitized = self.sanitize_error(&e);
+                tracing::error!(
+                    error = %sanitized,
+                    request_id = %input.request_id,
+                    "Failed to create solution"
+                );
+                format!(
+                    "Failed to create solution from request {}: {}",
+                    input.request_id, sanitized
+                )
+            })?;
+
+        Ok(ToolOutput::new(
+            format_create_solution_result(&solution, &request.id),
+            json!({"request_id": request.id, "solution": solution}),
+        ))
+    }
+
+    // ========================================================================
+    // Bulk tools
+    // ========================================================================
+
+    /// Apply the same update to many tickets.
+    ///
+    /// Runs with bounded concurrency and reports each ticket's outcome.
+    #[tool(
+        description = "Apply the same update (priority, status, category, subcategory, group, or technician_id) to a list of up to 50 ticket IDs in one call. Reports success or failure per ticket; one failure does not stop the others.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn bulk_update_requests(
+        &self,
+        Parameters(input): Parameters<BulkUpdateRequestsInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.bulk_update_requests_output(input, context)).await
+    }
+
+    /// Runs `bulk_update_requests`, returning its text and structured content.
+    async fn bulk_update_requests_output(
+        &self,
+        input: BulkUpdateRequestsInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "bulk_update_requests tool called");
+
+        confirm_action(
+            &context,
+            format!("Update {}?", ticket_list(&input.request_ids)),
+        )
+        .await?;
+
+        let client = client.clone();
+        let update = input.clone();
+        let progress = Progress::new(&context);
+        let results = run_bulk(&input.request_ids, &progress, "updated", move |id| {
+            let client = client.clone();
+            let update = update.to_update(&id);
+            async move { client.update_request(&id, &update).await }
+        })
+        .await;
+
+        let outcomes = self.bulk_outcomes(results);
+        Ok(ToolOutput::new(
+            format_bulk_results("update", &outcomes),
+            json!({"action": "update", "results": bulk_results_json(&outcomes)}),
+        ))
+    }
+
+    /// Close many tickets with a shared closure code and comment.
+    ///
+    /// Requires explicit confirmation; reports each ticket's outcome.
+    #[tool(
+        description = "Close up to 50 tickets in one call with a shared closure code and comment, e.g. to clean up a stale backlog. Requires confirm: true; only set it after the user has reviewed the list. Reports success or failure per ticket.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn bulk_close(
+        &self,
+        Parameters(input): Parameters<BulkCloseInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.bulk_close_output(input, context)).await
+    }
+
+    /// Runs `bulk_close`, returning its text and structured content.
+    async fn bulk_close_output(
+        &self,
+        input: BulkCloseInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "bulk_close tool called");
+
+        if client.is_read_only() {
+            return Err(crate::error::GlassError::ReadOnly.to_string());
+        }
+        if !input.confirm {
+            return Err(format!(
+                "Closing {} ticket(s) was not confirmed. Show the user the list, then call again \
+                 with confirm: true.",
+                input.request_ids.len()
+            ));
+        }
+        confirm_action(
+            &context,
+            format!("Close {}?", ticket_list(&input.request_ids)),
+        )
+        .await?;
+
+        let client = client.clone();
+        let closure_code = input.closure_code.clone();
+        let closure_comments = input.closure_comments.clone();
+        let progress = Progress::new(&context);
+        let results = run_bulk(&input.request_ids, &progress, "closed", move |id| {
+            let client = client.clone();
+            let closure_code = closure_code.clone();
+            let closure_comments = closure_comments.clone();
+            async move {
+                client
+                    .close_request(&id, closure_code.as_deref(), closure_comments.as_deref())
+                    .await
+            }
+        })
+        .await;
+
+        let outcomes = self.bulk_outcomes(results);
+        Ok(ToolOutput::new(
+            format_bulk_results("close", &outcomes),
+            json!({"action": "close", "results": bulk_results_json(&outcomes)}),
+        ))
+    }
+
+    /// Assign many tickets to one technician or group.
+    ///
+    /// Runs with bounded concurrency and reports each ticket's outcome.
+    #[tool(
+        description = "Assign up to 50 tickets to one technician (technician_id) and/or support group in one call, e.g. to hand over your queue before a holiday (get the IDs from my_queue). Reports success or failure per ticket.",
+        annotations(destructive_hint = true, idempotent_hint = true)
+    )]
+    async fn bulk_assign(
+        &self,
+        Parameters(input): Parameters<BulkAssignInput>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        structured(self.bulk_assign_output(input, context)).await
+    }
+
+    /// Runs `bulk_assign`, returning its text and structured content.
+    async fn bulk_assign_output(
+        &self,
+        input: BulkAssignInput,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ToolOutput, String> {
+        // Sanitize and validate input
+        let input = input.sanitize();
+        input.validate().map_err(|e| e.to_string())?;
+        let client = self.client_for(input.portal.as_deref())?;
+        tracing::debug!(?input, "bulk_assign tool called");
+
+        confirm_action(
+            &context,
+            format!("Reassign {}?", ticket_list(&input.request_ids)),
+        )
+        .await?;
+
+        let client = client.clone();
+        let technician_id = input.technician_id.clone();
+        let group = input.group.clone();
+        let progress = Progress::new(&context);
+        let results = run_bulk(&input.request_ids, &progress, "assigned", move |id| {
+            let client = client.clone();
+            let technician_id = technician_id.clone();
+            let group = group.clone();
+            async move {
+                client
+                    .assign_request(&id, technician_id.as_deref(), group.as_deref())
+                    .await
+            }
+        })
+        .await;
+
+        let outcomes = self.bulk_outcomes(results);
+        Ok(ToolOutput::new(
+            format_bulk_results("assign", &outcomes),
+            json!({"action": "assign", "results": bulk_results_json(&outcomes)}),
+        ))
+    }
+}
+
+// `#[prompt]` generates an undocumented public function per prompt
+#[allow(missing_docs)]
+#[prompt_router]
+impl GlassServer {
+    /// Prompt for triaging a ticket, with its details, duplicates, and SLA.
+    #[prompt(
+        name = "triage_ticket",
+        description = "Triage a ticket: summarize it, spot duplicates, and recommend category, priority, group, and technician. The ticket, possible duplicates, and SLA deadlines are included."
+    )]
+    async fn triage_ticket_prompt(
+        &self,
+        Parameters(args): Parameters<TriageTicketArgs>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        let request_id = args.request_id.trim().to_string();
+        tracing::debug!(request_id = %request_id, "triage_ticket prompt requested");
+
+        let details = result_text(
+            self.get_request(Parameters(GetRequestInput {
+                request_id: request_id.clone(),
+                portal: None,
+                max_length: None,
+                include_images: None,
+                order: None,
+            }))
+            .await,
+        )
+        .map_err(|e| ErrorData::internal_error(e, None))?;
+        let duplicates = result_text(
+            self.find_duplicate_requests(Parameters(FindDuplicateRequestsInput {
+                request_id: Some(request_id.clone()),
+                subject: None,
+                days: None,
+                limit: None,
+                portal: None,
+            }))
+            .await,
+        );
+        let sla = result_text(
+            self.get_sla_info(Parameters(GetSlaInfoInput {
+                request_id: request_id.clone(),
+                portal: None,
+            }))
+            .await,
+        );
+
+        Ok(prompt_result(
+            format!("Triage ticket #{}", request_id),
+            triage_ticket_text(&request_id, &details, &duplicates, &sla),
+        ))
+    }
+
+    /// Prompt for drafting an email reply to a ticket's requester.
+    #[prompt(
+        name = "draft_reply",
+        description = "Draft an email reply to a ticket's requester for your review before it is sent. The ticket with its notes and conversation is included."
+    )]
+    async fn draft_reply_prompt(
+        &self,
+        Parameters(args): Parameters<DraftReplyArgs>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        let request_id = args.request_id.trim().to_string();
+        tracing::debug!(request_id = %request_id, "draft_reply prompt requested");
+
+        let details = result_text(
+            self.get_request(Parameters(GetRequestInput {
+                request_id: request_id.clone(),
+                portal: None,
+                max_length: None,
+                include_images: None,
+                order: None,
+            }))
+            .await,
+        )
+        .map_err(|e| ErrorData::internal_error(e, None))?;
+        let goal = args
+            .goal
+            .as_deref()
+            .map(str::trim)
+            .filter(|g| !g.is_empty());
+
+        Ok(prompt_result(
+            format!("Draft a reply on ticket #{}", request_id),
+            draft_reply_text(&request_id, &details, goal),
+        ))
+    }
+
+    /// Prompt for a daily standup over your queue.
+    #[prompt(
+        name = "daily_standup",
+        description = "Daily standup: your open tickets, what is overdue or due today, and unassigned tickets waiting for someone, with a suggested order of work."
+    )]
+    async fn daily_standup_prompt(&self) -> Result<GetPromptResult, ErrorData> {
+        tracing::debug!("daily_standup prompt requested");
+
+        // Scope the deadline lists to you when the technician is known
+        let technician = match self.sdp_client.current_technician().await {
+            Ok(t) => t.map(|t| t.display_name().to_string()),
+            Err(e) => {
+                let err_msg = self.sanitize_error(&e);
+                tracing::warn!(error = %err_msg, "Failed to resolve current technician");
+                None
+            }
+        };
+
+        let my_queue = result_text(
+            self.my_queue(Parameters(MyQueueInput {
+                limit: None,
+                format: None,
+                portal: None,
+            }))
+            .await,
+        );
+        let overdue = result_text(
+            self.overdue_tickets(Parameters(OverdueTicketsInput {
+                group: None,
+                technician: technician.clone(),
+                limit: None,
+                format: None,
+                portal: None,
+            }))
+            .await,
+        );
+        let due_soon = result_text(
+            self.due_soon(Parameters(DueSoonInput {
+                hours: None,
+                group: None,
+                technician,
+                limit: None,
+                format: None,
+                portal: None,
+            }))
+            .await,
+        );
+        let unassigned = result_text(
+            self.unassigned_tickets(Parameters(UnassignedTicketsInput {
+                group: None,
+                limit: None,
+                format: None,
+                portal: None,
+            }))
+            .await,
+        );
+
+        Ok(prompt_result(
+            "Daily standup".to_string(),
+            daily_standup_text(&my_queue, &overdue, &due_soon, &unassigned),
+        ))
+    }
+}
+
+/// A tool's text response with the data it was formatted from.
+///
+/// The data is returned as MCP structured content, so automations can read
+/// results without parsing the text.
+#[derive(Debug)]
+struct ToolOutput {
+    text: String,
+    data: serde_json::Value,
+    images: Vec<Content>,
+}
+
+impl ToolOutput {
+    /// Pairs the text response with its data (a JSON object).
+    fn new(text: String, data: serde_json::Value) -> Self {
+        Self {
+            text,
+            data,
+            images: Vec::new(),
+        }
+    }
+
+    /// Adds image content shown after the text.
+    fn with_images(self, images: Vec<Content>) -> Self {
+        Self { images, ..self }
+    }
+
+    /// Replaces the text with the requested output format.
+    ///
+    /// JSON renders the structured data; `markdown` builds the table only
+    /// when asked for.
+    fn formatted(self, format: Option<OutputFormat>, markdown: impl FnOnce() -> String) -> Self {
+        let text = match format.unwrap_or_default() {
+            OutputFormat::Text => self.text,
+            OutputFormat::Json => {
+                serde_json::to_string_pretty(&self.data).unwrap_or_else(|_| self.data.to_string())
+            }
+            OutputFormat::Markdown => markdown(),
+        };
+        Self { text, ..self }
+    }
+}
+
+/// Runs a tool body and returns its text with structured content.
+///
+/// Failures become error results carrying the message, as for tools
+/// returning `Result<String, String>`.
+async fn structured(
+    body: impl Future<Output = Result<ToolOutput, String>>,
+) -> Result<CallToolResult, ErrorData> {
+    Ok(match body.await {
+        Ok(output) => CallToolResult {
+            content: std::iter::once(Content::text(output.text))
+                .chain(output.images)
+                .collect(),
+            structured_content: Some(output.data),
+            is_error: Some(false),
+            meta: None,
+        },
+        Err(message) => CallToolResult::error(vec![Content::text(message)]),
+    })
+}
+
+/// Returns the text of a structured tool result, or its error message.
+fn result_text(result: Result<CallToolResult, ErrorData>) -> Result<String, String> {
+    let result = result.map_err(|e| e.message.to_string())?;
+    let text = result
+        .content
+        .iter()
+        .filter_map(|c| c.as_text())
+        .map(|t| t.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if result.is_error == Some(true) {
+        Err(text)
+    } else {
+        Ok(text)
+    }
+}
+
+/// Wraps prompt text as a single user message.
+fn prompt_result(description: String, text: String) -> GetPromptResult {
+    GetPromptResult {
+        description: Some(description),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    }
+}
+
+/// Sequence number for tool call correlation IDs.
+static NEXT_CALL_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Generates a correlation ID for one tool call.
+///
+/// The process ID keeps IDs from different server runs apart in shared logs.
+fn next_correlation_id() -> String {
+    format!(
+        "{:x}-{}",
+        std::process::id(),
+        NEXT_CALL_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Returns the `request_id` argument of a tool call, if it has one.
+fn request_id_argument(request: &CallToolRequestParams) -> Option<String> {
+    match request.arguments.as_ref()?.get("request_id")? {
+        serde_json::Value::String(id) => Some(id.clone()),
+        serde_json::Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+impl ServerHandler for GlassServer {
+    /// Dispatches a tool call inside a span carrying its correlation ID.
+    ///
+    /// SDP calls made by the tool open child spans, so every log line of
+    /// a failed call can be found by the correlation ID.
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, ErrorData> {
+        let span = tracing::info_span!(
+            "tool_call",
+            correlation_id = %next_correlation_id(),
+            tool = %request.name,
+            request_id = tracing::field::Empty,
+        );
+        if let Some(id) = request_id_argument(&request) {
+            span.record("request_id", id.as_str());
+        }
+
+        // SDP calls made for this tool stop when the client cancels it
+        let server = Self {
+            sdp_client: self.sdp_client.with_cancellation(context.ct.clone()),
+            ..self.clone()
+        };
+
+        let tool = request.name.clone();
+        let started = std::time::Instant::now();
+        let tcc = ToolCallContext::new(&server, request, context);
+        let result = server.tool_router.call(tcc).instrument(span).await;
+
+        let failed = !matches!(&result, Ok(r) if r.is_error != Some(true));
+        crate::telemetry::record_tool_call(&tool, started.elapsed(), failed);
+        result
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, ErrorData> {
+        Ok(ListToolsResult {
+            tools: self.tool_router.list_all(),
+            meta: None,
+            next_cursor: None,
+        })
+    }
+
+    fn get_tool(&self, name: &str) -> Option<Tool> {
+        self.tool_router.get(name).cloned()
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, ErrorData> {
+        let pcx = PromptContext::new(self, request.name, request.arguments, context);
+        self.prompt_router.get_prompt(pcx).await
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, ErrorData> {
+        Ok(ListPromptsResult::with_all_items(
+            self.prompt_router.list_all(),
+        ))
+    }
+
+    /// Returns server information for the MCP initialize handshake.
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            instructions: Some(
+                "Glass provides access to ServiceDesk Plus tickets. \
+                 Use list_requests to find tickets, search_requests to find them \
+                 by text, export_requests_csv for spreadsheet extracts, \
+                 find_duplicate_requests to spot duplicates, \
+                 find_similar_requests for how similar tickets were resolved, \
+                 get_request for details (get_request_url for just the web link), \
+                 get_request_history for the \
+                 audit trail, get_request_timeline for everything in order, \
+                 export_request_markdown for a ticket as a shareable document, \
+                 list_notes and list_conversations to page through long threads, and \
+                 list_technicians to see available assignees. Use whoami to learn your own \
+                 technician ID, see your own open tickets with my_queue, and \
+                 tickets nobody has taken with unassigned_tickets; find late ones \
+                 with overdue_tickets and ones about to breach with due_soon. \
+                 Check deadlines with get_sla_info and get counts per status, priority, \
+                 technician, or group with ticket_statistics. Before setting a priority, get it \
+                 from impact and urgency with suggest_priority. Find requesters \
+                 with search_requesters and look up their contact details with \
+                 get_requester. Look up hardware with search_assets, get_asset, and \
+                 list_user_assets, and record the affected device with attach_asset_to_request. \
+                 Check valid category/subcategory/item combinations with \
+                 list_categories, site names with list_sites, and departments with \
+                 list_departments. Pick closure codes from list_closure_codes \
+                 and discover custom fields with list_custom_fields. \
+                 Create tickets with create_request, modify with update_request \
+                 (or bulk_update_requests for many tickets at once), \
+                 close with close_request (bulk_close for a reviewed list, which \
+                 requires confirm: true), add notes with add_note, and \
+                 assign with assign_request (or pickup_request to take a ticket \
+                 yourself, and bulk_assign to hand over many at once). \
+                 Escalate with escalate_request, which also records why, and fix \
+                 a wrong requester with change_requester. Keep stakeholders on the \
+                 notification thread with add_cc and remove_cc, and subscribe users \
+                 to updates with list_watchers, add_watchers, and remove_watchers. Relate tickets \
+                 with link_requests and unlink_requests, and tie an incident to its problem or \
+                 change with associate_request and dissociate_request. \
+                 delete_request trashes a ticket and requires confirm: true; only \
+                 use it when the user explicitly asks. Recover trashed tickets with \
+                 list_trashed_requests and restore_request. Pause a ticket with \
+                 hold_request (optionally scheduling when it resumes) and resume it \
+                 with resume_request. Move a renegotiated deadline with set_due_date. \
+                 Record a resolution with \
+                 add_resolution, or resolve and close in one \
+                 step with resolve_request. Track time with list_worklogs, \
+                 add_worklog, update_worklog, and delete_worklog. Manage ticket \
+                 tasks with list_tasks, create_task, update_task, and complete_task. \
+                 Review project work with list_projects, get_project, and \
+                 list_project_tasks; manage it with create_project, update_project, \
+                 create_project_task, and update_project_task. \
+                 Handle approvals with list_approvals, submit_for_approval, \
+                 approve_request, and reject_request. Email the requester with \
+                 reply_to_requester (add_note never sends email to the requester). \
+                 Email anyone else about a ticket, such as a manager or vendor, \
+                 with notify. Check which emails SDP sent on a ticket with list_notifications, \
+                 and read one in full with get_notification. \
+                 Review requester satisfaction with survey_results. \
+                 Search the knowledge base with search_solutions and read articles \
+                 with get_solution before resolving known issues, and turn a fix into \
+                 a new article with create_solution. \
+                 On ServiceDesk Plus MSP, pass portal to work in another account. \
+                 Start with 'ping' to verify connectivity. The triage_ticket, \
+                 draft_reply, and daily_standup prompts run common workflows."
+                    .into(),
+            ),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_prompts()
+                .build(),
+            ..Default::default()
+        }
+    }
+}
+
+// ============================================================================
+// Response formatting helpers
+// ============================================================================
+
+/// Default length in characters for description fields before truncation.
+const DEFAULT_DESCRIPTION_LENGTH: usize = 2000;
+
+/// Marker appended to truncated text.
+const TRUNCATION_MARKER: &str = "... [truncated]";
+
+/// Truncates a string if it has more than `max_length` characters.
+///
+/// Lengths count characters, not bytes, so Danish text gets the same room as
+/// ASCII. The cut never splits a character or separates one from a following
+/// combining mark, and falls back to the last word boundary when there is
+/// one. If truncated, appends "... [truncated]" to indicate the content was cut.
+fn truncate_text(text: &str, max_length: usize) -> String {
+    if text.chars().count() <= max_length {
+        return text.to_string();
+    }
+    // Leave room for the marker
+    let target = max_length.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let mut cut = text
+        .char_indices()
+        .nth(target)
+        .map_or(text.len(), |(i, _)| i);
+    while cut > 0 && text[cut..].chars().next().is_some_and(is_combining_mark) {
+        cut = text.floor_char_boundary(cut - 1);
+    }
+    // Try to break at a word boundary for cleaner output
+    let end = text[..cut].rfind(char::is_whitespace).unwrap_or(cut);
+    format!("{}{}", &text[..end], TRUNCATION_MARKER)
+}
+
+/// Returns true for characters that attach to the one before them
+/// (combining accents, variation selectors, and the zero-width joiner).
+fn is_combining_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE00}'..='\u{FE0F}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{200D}')
+}
+
+/// Splits a filter value like "High | Urgent" into the values to match any of.
+fn split_alternatives(value: &str) -> Vec<String> {
+    value
+        .split('|')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Formats the server_info tool's report.
+///
+/// `tools` pairs each tool name with whether it is read-only; in read-only
+/// mode the others are listed as disabled.
+fn format_server_info(
+    settings: &ClientSettings,
+    unsupported: &[String],
+    tools: &[(String, bool)],
+    max_description_length: usize,
+    defaults: &ListDefaults,
+) -> String {
+    let on_off = |flag: bool| if flag { "on" } else { "off" };
+    let mut output = format!(
+        "Glass {} (MCP server for ServiceDesk Plus)\n\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    output.push_str(&format!(
+        "SDP API: {} ({})\n",
+        settings.base_url,
+        if settings.cloud {
+            "SDP Cloud, OAuth"
+        } else {
+            "on-premises, API key"
+        }
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Portal"),
+        settings.portal.as_deref().unwrap_or("default")
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Technician ID"),
+        settings
+            .technician_id
+            .as_deref()
+            .unwrap_or("not configured (looked up from your open tickets)")
+    ));
+    output.push_str(&format!("Read-only mode: {}\n", on_off(settings.read_only)));
+    match settings.cache_ttl_secs {
+        Some(secs) => output.push_str(&format!("Cache: on ({} s)\n", secs)),
+        None => output.push_str("Cache: off\n"),
+    }
+    output.push_str(&format!(
+        "{}: {} characters\n",
+        tr("Description length"),
+        max_description_length
+    ));
+    output.push_str(&format!(
+        "List defaults: {} results, group {}, site {}\n",
+        defaults.limit,
+        defaults.group.as_deref().unwrap_or("any"),
+        defaults.site.as_deref().unwrap_or("any")
+    ));
+    output.push_str(&format!(
+        "Output language: {}\n",
+        crate::i18n::language().code()
+    ));
+    output.push_str("Transport: stdio\n");
+    output.push_str(&format!(
+        "OpenTelemetry export: {}\n",
+        if cfg!(feature = "otel") {
+            "built in"
+        } else {
+            "not built in"
+        }
+    ));
+    if unsupported.is_empty() {
+        output.push_str("Unsupported endpoints: none\n");
+    } else {
+        output.push_str(&format!(
+            "{}: {} (their tools report \"not supported\")\n",
+            tr("Unsupported endpoints"),
+            unsupported.join(", ")
+        ));
+    }
+
+    let (enabled, disabled): (Vec<&str>, Vec<&str>) = if settings.read_only {
+        let enabled = tools.iter().filter(|(_, ro)| *ro).map(|(n, _)| n.as_str());
+        let disabled = tools.iter().filter(|(_, ro)| !ro).map(|(n, _)| n.as_str());
+        (enabled.collect(), disabled.collect())
+    } else {
+        (tools.iter().map(|(n, _)| n.as_str()).collect(), Vec::new())
+    };
+    output.push_str(&format!(
+        "\nEnabled tools ({}): {}\n",
+        enabled.len(),
+        enabled.join(", ")
+    ));
+    if !disabled.is_empty() {
+        output.push_str(&format!(
+            "Disabled by read-only mode ({}): {}\n",
+            disabled.len(),
+            disabled.join(", ")
+        ));
+    }
+    output
+}
+
+/// Formats the health tool's report.
+fn format_health(
+    connection: &Result<(), String>,
+    latency: std::time::Duration,
+    health: &HealthStatus,
+    read_only: bool,
+) -> String {
+    let mut output = String::from("ServiceDesk Plus health\n\n");
+    match connection {
+        Ok(()) => output.push_str(&format!(
+            "Connection: OK (round trip {} ms)\n",
+            latency.as_millis()
+        )),
+        Err(e) => output.push_str(&format!(
+            "Connection: FAILED after {} ms - {}\n",
+            latency.as_millis(),
+            e
+        )),
+    }
+    match &health.circuit_open_since {
+        Some(since) => output.push_str(&format!(
+            "Circuit breaker: open since {} ({} consecutive failures; calls fail fast until SDP \
+             answers)\n",
+            since, health.consecutive_failures
+        )),
+        None if health.consecutive_failures > 0 => output.push_str(&format!(
+            "Circuit breaker: closed ({} consecutive failure(s))\n",
+            health.consecutive_failures
+        )),
+        None => output.push_str("Circuit breaker: closed\n"),
+    }
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Retries since startup"),
+        health.retries
+    ));
+    match (&health.last_error_at, &health.last_error) {
+        (Some(at), Some(message)) => {
+            output.push_str(&format!("{}: {} - {}\n", tr("Last error"), at, message))
+        }
+        _ => output.push_str("Last error: none\n"),
+    }
+    if health.unsupported_endpoints.is_empty() {
+        output.push_str("Unsupported endpoints: none\n");
+    } else {
+        output.push_str(&format!(
+            "{}: {}\n",
+            tr("Unsupported endpoints"),
+            health.unsupported_endpoints.join(", ")
+        ));
+    }
+    output.push_str(&format!(
+        "Read-only mode: {}\n",
+        if read_only { "on" } else { "off" }
+    ));
+    output
+}
+
+/// Formats a list of request summaries as human-readable text.
+///
+/// `web_url` maps a request ID to its ServiceDesk Plus link, if one should be shown.
+fn format_request_list(
+    requests: &[RequestSummary],
+    web_url: impl Fn(&str) -> Option<String>,
+) -> String {
+    if requests.is_empty() {
+        return tr("No tickets found matching the criteria.").to_string();
+    }
+
+    let mut output = tr_fill("Found {} ticket(s):", &[&requests.len().to_string()]);
+    output.push_str("\n\n");
+    let now = chrono::Utc::now().timestamp_millis();
+
+    for req in requests {
+        let targets = [
+            (
+                tr("First response due"),
+                req.first_response_due_by_time.as_ref(),
+                req.first_response_countdown(now),
+            ),
+            (
+                tr("Due"),
+                req.due_by_time.as_ref(),
+                req.resolution_countdown(now),
+            ),
+        ];
+        output.push_str(&format!("#{} - {}", req.id, req.display_subject()));
+        if targets
+            .iter()
+            .any(|(_, _, c)| c.is_some_and(|c| c.is_at_risk()))
+        {
+            output.push_str(&format!(" [{}]", tr("BREACHES IN UNDER 2H")));
+        }
+        output.push('\n');
+        output.push_str(&format!(
+            "   {}: {} | {}: {} | {}: {}\n",
+            tr("Status"),
+            req.display_status(),
+            tr("Priority"),
+            req.display_priority(),
+            tr("Assignee"),
+            req.display_technician()
+        ));
+        output.push_str(&format!(
+            "   {}: {}\n",
+            tr("Requester"),
+            req.display_requester()
+        ));
+
+        if let Some(created) = req.created_time.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!("   {}: {}\n", tr("Created"), created));
+        }
+        for (label, due, countdown) in targets {
+            if let (Some(due), Some(countdown)) = (due, countdown) {
+                output.push_str(&format!(
+                    "   {}: {} {}\n",
+                    label,
+                    due.display().unwrap_or(tr("Unknown")),
+                    format_countdown(countdown)
+                ));
+            }
+        }
+
+        if let Some(url) = web_url(&req.id) {
+            output.push_str(&format!("   {}: {}\n", tr("Link"), url));
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a request's history entries, oldest first.
+fn format_request_history(request_id: &str, history: &[HistoryEntry]) -> String {
+    if history.is_empty() {
+        return tr_fill("No history recorded for request #{}.", &[request_id]);
+    }
+
+    let mut output = format!(
+        "History for request #{} ({} entries):\n\n",
+        request_id,
+        history.len()
+    );
+
+    for entry in history {
+        let time = entry
+            .time
+            .as_ref()
+            .and_then(|t| t.display())
+            .unwrap_or(tr("Unknown time"));
+        output.push_str(&format!("[{}] {}", time, entry.display_by()));
+        if let Some(operation) = &entry.operation {
+            output.push_str(&format!(" - {}", operation));
+        }
+        output.push('\n');
+        if let Some(description) = entry.description.as_deref().filter(|d| !d.is_empty()) {
+            output.push_str(&format!("   {}\n", truncate_text(description, 500)));
+        }
+        for change in entry.changes() {
+            output.push_str(&format!(
+                "   {}: {} -> {}\n",
+                change.field,
+                change.from.as_deref().unwrap_or("(none)"),
+                change.to.as_deref().unwrap_or("(none)")
+            ));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// A ticket's merged timeline and what could not be included in it.
+struct Timeline {
+    /// Events sorted by time.
+    events: Vec<TimelineEvent>,
+    /// Sources or entries that failed to load.
+    fetch_errors: Vec<String>,
+    /// Hint about notes or conversations past the first page, or empty.
+    more_hint: String,
+}
+
+/// A single entry in a merged ticket timeline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TimelineEvent {
+    /// Sort key in epoch milliseconds; events without one sort last.
+    millis: Option<i64>,
+    /// Human-readable timestamp.
+    time: String,
+    /// Event kind label, e.g. "Note" or "Worklog".
+    kind: String,
+    /// Who performed the event.
+    actor: String,
+    /// Event body, possibly spanning several lines.
+    body: String,
+}
+
+/// Merges notes, conversations, worklogs, and history into one list sorted by time.
+fn build_timeline(
+    notes: &[Note],
+    conversations: &[Conversation],
+    worklogs: &[Worklog],
+    history: &[HistoryEntry],
+) -> Vec<TimelineEvent> {
+    fn event(
+        time: Option<&SdpTimestamp>,
+        kind: String,
+        actor: &str,
+        body: String,
+    ) -> TimelineEvent {
+        TimelineEvent {
+            millis: time.and_then(|t| t.epoch_millis()),
+            time: time
+                .and_then(|t| t.display())
+                .unwrap_or(tr("Unknown time"))
+                .to_string(),
+            kind,
+            actor: actor.to_string(),
+            body,
+        }
+    }
+
+    let mut events = Vec::new();
+
+    for note in notes {
+        let kind = if note.show_to_requester == Some(true) {
+            tr("Note")
+        } else {
+            tr("Note [Internal]")
+        };
+        events.push(event(
+            note.created_time.as_ref(),
+            kind.to_string(),
+            note.display_created_by(),
+            truncate_text(&html_to_text(&note.display_content()), 1000),
+        ));
+    }
+
+    for thread in group_threads(conversations) {
+        if let [conv] = thread.messages.as_slice() {
+            events.push(event(
+                conv.sent_time.as_ref(),
+                tr_fill("Email ({})", &[conv.direction()]),
+                conv.display_from(),
+                truncate_text(&html_to_text(&conv.display_content()), 1500),
+            ));
+            continue;
+        }
+
+        let mut lines = Vec::new();
+        if let Some(subject) = thread.subject() {
+            lines.push(format!("{}: {}", tr("Subject"), subject));
+        }
+        for conv in &thread.messages {
+            lines.push(format!(
+                "[{}] {} ({}):",
+                conv.display_time().unwrap_or(tr("Unknown time")),
+                conv.display_from(),
+                conv.direction()
+            ));
+            let text = truncate_text(&html_to_text(&conv.display_content()), 500);
+            lines.extend(text.lines().map(|line| format!("  {}", line)));
+        }
+        events.push(event(
+            thread.messages[0].sent_time.as_ref(),
+            tr_fill(
+                "Email thread ({} messages)",
+                &[&thread.messages.len().to_string()],
+            ),
+            &thread.participants().join(", "),
+            lines.join("\n"),
+        ));
+    }
+
+    for worklog in worklogs {
+        let mut body = tr_fill("Logged {}", &[&worklog.display_time_spent()]);
+        if let Some(description) = worklog.description.as_deref().filter(|d| !d.is_empty()) {
+            body.push_str(&format!(": {}", truncate_text(description, 500)));
+        }
+        events.push(event(
+            worklog.start_time.as_ref().or(worklog.end_time.as_ref()),
+            tr("Worklog").to_string(),
+            worklog.display_owner(),
+            body,
+        ));
+    }
+
+    for entry in history {
+        let mut lines: Vec<String> = entry
+            .changes()
+            .into_iter()
+            .map(|change| {
+                format!(
+                    "{}: {} -> {}",
+                    change.field,
+                    change.from.as_deref().unwrap_or(tr("(none)")),
+                    change.to.as_deref().unwrap_or(tr("(none)"))
+                )
+            })
+            .collect();
+        if lines.is_empty() {
+            if let Some(description) = entry.description.as_deref().filter(|d| !d.is_empty()) {
+                lines.push(truncate_text(description, 500));
+            }
+        }
+        let kind = match entry.operation.as_deref() {
+            Some(operation) => tr_fill("Change ({})", &[operation]),
+            None => tr("Change").to_string(),
+        };
+        events.push(event(
+            entry.time.as_ref(),
+            kind,
+            entry.display_by(),
+            lines.join("\n"),
+        ));
+    }
+
+    // Stable sort keeps source order for events with equal or missing times
+    events.sort_by_key(|e| (e.millis.is_none(), e.millis));
+    events
+}
+
+/// Formats a merged ticket timeline.
+fn format_timeline(request_id: &str, events: &[TimelineEvent], fetch_errors: &[String]) -> String {
+    let mut output = if events.is_empty() {
+        tr_fill(
+            "Timeline for request #{}: no activity recorded.",
+            &[request_id],
+        )
+    } else {
+        tr_fill(
+            "Timeline for request #{} ({} events):",
+            &[request_id, &events.len().to_string()],
+        )
+    };
+    output.push('\n');
+
+    for event in events {
+        output.push_str(&format!(
+            "\n[{}] {} - {}\n",
+            event.time, event.kind, event.actor
+        ));
+        for line in event.body.lines() {
+            output.push_str(&format!("   {}\n", line));
+        }
+    }
+
+    if !fetch_errors.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Fetch Errors")));
+        for err in fetch_errors {
+            output.push_str(&tr_fill("Warning: Failed to fetch {}", &[err]));
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Formats full request details as human-readable text.
+#[allow(clippy::too_many_arguments)]
+fn format_request_details(
+    request: &Request,
+    notes: &[Note],
+    conversations: &[Conversation],
+    links: &[RequestLink],
+    associations: &RequestAssociations,
+    web_url: &str,
+    fetch_errors: &[String],
+    max_length: usize,
+) -> String {
+    let mut output = String::new();
+
+    // Header
+    output.push_str(&format!(
+        "{} #{}: {}\n",
+        tr("Ticket"),
+        request.id,
+        request.display_subject()
+    ));
+    output.push_str(&"=".repeat(60));
+    output.push('\n');
+
+    // Direct link
+    output.push_str(&format!("\n{}: {}\n", tr("Link"), web_url));
+
+    // SLA targets about to breach, before anything else
+    let now = chrono::Utc::now().timestamp_millis();
+    let first_response = request.first_response_countdown(now);
+    let resolution = request.resolution_countdown(now);
+    for (label, countdown) in [
+        (tr("First response due"), first_response),
+        (tr("Resolution due"), resolution),
+    ] {
+        if let Some(countdown) = countdown.filter(|c| c.is_at_risk()) {
+            output.push_str(&format!(
+                "\n[{}] {} {}\n",
+                tr("BREACHES IN UNDER 2H"),
+                label,
+                format_countdown(countdown)
+            ));
+        }
+    }
+
+    // Status information
+    output.push_str(&format!(
+        "\n{}: {}\n",
+        tr("Status"),
+        request.display_status()
+    ));
+    if request.is_on_hold() {
+        if let Some(scheduler) = &request.onhold_scheduler {
+            output.push_str(&format_onhold_schedule(scheduler));
+        }
+    }
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Priority"),
+        request.display_priority()
+    ));
+
+    if let Some(urgency) = request.urgency.as_ref().and_then(|u| u.name.as_deref()) {
+        output.push_str(&format!("{}: {}\n", tr("Urgency"), urgency));
+    }
+    if let Some(impact) = request.impact.as_ref().and_then(|i| i.name.as_deref()) {
+        output.push_str(&format!("{}: {}\n", tr("Impact"), impact));
+    }
+
+    // Category path
+    let category_path = request.category_path();
+    if category_path != tr("Uncategorized") {
+        output.push_str(&format!("{}: {}\n", tr("Category"), category_path));
+    }
+
+    // People
+    output.push_str(&format!(
+        "\n{}: {}\n",
+        tr("Requester"),
+        request.display_requester()
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Assigned to"),
+        request.display_technician()
+    ));
+
+    if let Some(group) = request.display_group() {
+        output.push_str(&format!("{}: {}\n", tr("Group"), group));
+    }
+
+    if let Some(assets) = request.display_assets() {
+        output.push_str(&format!("{}: {}\n", tr("Assets"), assets));
+    }
+
+    // Timestamps
+    output.push_str(&format!("\n--- {} ---\n", tr("Timestamps")));
+    if let Some(created) = request.created_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}\n", tr("Created"), created));
+    }
+    if let Some(updated) = request.last_updated_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}\n", tr("Last Updated"), updated));
+    }
+    if let Some(due) = request
+        .first_response_due_by_time
+        .as_ref()
+        .filter(|_| first_response.is_some())
+    {
+        output.push_str(&format!(
+            "{}: {}",
+            tr("First response due"),
+            due.display().unwrap_or(tr("Unknown"))
+        ));
+        if let Some(countdown) = first_response {
+            output.push_str(&format!(" {}", format_countdown(countdown)));
+        }
+        output.push('\n');
+    }
+    if let Some(due) = request.due_by_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}", tr("Due By"), due));
+        if let Some(countdown) = resolution {
+            output.push_str(&format!(" {}", format_countdown(countdown)));
+        }
+        output.push('\n');
+    }
+
+    // Flags
+    if request.is_overdue == Some(true) {
+        output.push_str(&format!("\n[{}]\n", tr("OVERDUE")));
+    }
+
+    // Custom fields
+    let udf_fields = request.display_udf_fields();
+    if !udf_fields.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Custom Fields")));
+        for (name, value) in udf_fields {
+            output.push_str(&format!("{}: {}\n", name, truncate_text(&value, 500)));
+        }
+    }
+
+    // Description (truncated if too long)
+    if let Some(description) = &request.description {
+        output.push_str(&format!("\n--- {} ---\n", tr("Description")));
+        output.push_str(&truncate_text(&html_to_text(description), max_length));
+        output.push('\n');
+    }
+
+    // Attachments (so the reader knows what evidence is on the ticket)
+    let attachments = request.attachments.as_deref().unwrap_or_default();
+    if !attachments.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Attachments")));
+        for attachment in attachments {
+            output.push_str(&format_attachment(attachment));
+        }
+    } else if request.has_attachments == Some(true) {
+        output.push_str(&format!("\n--- {} ---\n", tr("Attachments")));
+        output.push_str(tr("The ticket has attachments, but SDP did not list them."));
+        output.push('\n');
+    }
+
+    // Linked requests
+    if !links.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Linked Requests")));
+        for link in links {
+            output.push_str(&link.display_summary());
+            if let Some(comments) = &link.comments {
+                output.push_str(&format!(" - {}", comments));
+            }
+            output.push('\n');
+        }
+    }
+
+    // Problem and change associations
+    if !associations.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Associations")));
+        for kind in [AssociationKind::Problem, AssociationKind::Change] {
+            if let Some(record) = associations.get(kind) {
+                output.push_str(&format!(
+                    "{}: {}\n",
+                    tr(kind.label()),
+                    record.display_summary()
+                ));
+            }
+        }
+    }
+
+    // Show any fetch errors so user knows why notes/conversations might be missing
+    if !fetch_errors.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Fetch Errors")));
+        for err in fetch_errors {
+            output.push_str(&tr_fill("Warning: Failed to fetch {}", &[err]));
+            output.push('\n');
+        }
+    }
+
+    // Conversations section (email replies - chronological communication)
+    if !conversations.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Conversations")));
+        for conv in conversations {
+            output.push_str(&format_conversation_entry(conv));
+        }
+    }
+
+    // Notes section (internal technician notes)
+    if !notes.is_empty() {
+        output.push_str(&format!("\n--- {} ---\n", tr("Notes")));
+        for note in notes {
+            output.push_str(&format_note_entry(note));
+        }
+    }
+
+    // Resolution (if present, truncated if too long)
+    if let Some(resolution) = &request.resolution {
+        if let Some(content) = &resolution.content {
+            output.push_str(&format!("\n--- {} ---\n", tr("Resolution")));
+            output.push_str(&truncate_text(&html_to_text(content), max_length));
+            output.push('\n');
+
+            if let Some(submitted_by) = resolution
+                .submitted_by
+                .as_ref()
+                .and_then(|s| s.name.as_deref())
+            {
+                output.push_str(&format!("{}: {}\n", tr("Submitted by"), submitted_by));
+            }
+            if let Some(submitted_on) = resolution.submitted_on.as_ref().and_then(|t| t.display()) {
+                output.push_str(&format!("{}: {}\n", tr("Submitted on"), submitted_on));
+            }
+        }
+    }
+
+    // Closure info (if present)
+    if let Some(closure) = &request.closure_info {
+        output.push_str(&format!("\n--- {} ---\n", tr("Closure Info")));
+        if let Some(code) = closure
+            .closure_code
+            .as_ref()
+            .and_then(|c| c.name.as_deref())
+        {
+            output.push_str(&format!("{}: {}\n", tr("Closure Code"), code));
+        }
+        if let Some(comments) = &closure.closure_comments {
+            output.push_str(&format!("{}: {}\n", tr("Comments"), comments));
+        }
+        if let Some(closed_by) = closure.closed_by.as_ref().and_then(|c| c.name.as_deref()) {
+            output.push_str(&format!("{}: {}\n", tr("Closed by"), closed_by));
+        }
+        if let Some(closed_time) = closure.closed_time.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!("{}: {}\n", tr("Closed at"), closed_time));
+        }
+    }
+
+    output
+}
+
+/// Formats a whole ticket as a markdown document for postmortems and
+/// handover notes.
+///
+/// Unlike [`format_request_details`], nothing is truncated beyond what the
+/// timeline itself shortens. Timeline bodies are block quotes, so text from
+/// emails cannot turn into document headings.
+fn format_request_markdown(request: &Request, timeline: &Timeline, web_url: &str) -> String {
+    let mut output = format!(
+        "# {} #{}: {}\n\n",
+        tr("Ticket"),
+        request.id,
+        request.display_subject()
+    );
+
+    let time = |t: &Option<SdpTimestamp>| t.as_ref().and_then(|t| t.display()).map(str::to_string);
+    let category_path = request.category_path();
+    let fields = [
+        (tr("Link"), Some(web_url.to_string())),
+        (tr("Status"), Some(request.display_status().to_string())),
+        (tr("Priority"), Some(request.display_priority().to_string())),
+        (
+            tr("Category"),
+            (category_path != tr("Uncategorized")).then(|| category_path.to_string()),
+        ),
+        (
+            tr("Requester"),
+            Some(request.display_requester().to_string()),
+        ),
+        (
+            tr("Assigned to"),
+            Some(request.display_technician().to_string()),
+        ),
+        (tr("Group"), request.display_group().map(str::to_string)),
+        (tr("Assets"), request.display_assets()),
+        (tr("Created"), time(&request.created_time)),
+        (tr("Due By"), time(&request.due_by_time)),
+        (tr("Last updated"), time(&request.last_updated_time)),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value {
+            output.push_str(&format!("- **{}:** {}\n", label, value));
+        }
+    }
+
+    if let Some(description) = &request.description {
+        output.push_str(&format!("\n## {}\n\n", tr("Description")));
+        output.push_str(html_to_text(description).trim());
+        output.push('\n');
+    }
+
+    output.push_str(&format!("\n## {}\n", tr("Timeline")));
+    if timeline.events.is_empty() {
+        output.push_str("\n_No activity recorded._\n");
+    }
+    for event in &timeline.events {
+        output.push_str(&format!(
+            "\n### {} - {} ({})\n\n",
+            event.kind, event.actor, event.time
+        ));
+        for line in event.body.lines() {
+            output.push_str(format!("> {}", line).trim_end());
+            output.push('\n');
+        }
+    }
+    if !timeline.more_hint.is_empty() {
+        output.push_str(&format!("\n_{}_\n", timeline.more_hint.trim()));
+    }
+
+    if let Some(resolution) = &request.resolution {
+        if let Some(content) = &resolution.content {
+            output.push_str(&format!("\n## {}\n\n", tr("Resolution")));
+            output.push_str(html_to_text(content).trim());
+            output.push('\n');
+            let by = resolution
+                .submitted_by
+                .as_ref()
+                .and_then(|s| s.name.as_deref());
+            let on = resolution.submitted_on.as_ref().and_then(|t| t.display());
+            if let Some(by) = by {
+                output.push_str(&format!("\n_{}: {}", tr("Submitted by"), by));
+                if let Some(on) = on {
+                    output.push_str(&format!(", {}", on));
+                }
+                output.push_str("_\n");
+            }
+        }
+    }
+
+    if let Some(closure) = &request.closure_info {
+        output.push_str(&format!("\n## {}\n\n", tr("Closure Info")));
+        let fields = [
+            (
+                tr("Closure Code"),
+                closure.closure_code.as_ref().and_then(|c| c.name.clone()),
+            ),
+            (tr("Comments"), closure.closure_comments.clone()),
+            (
+                tr("Closed by"),
+                closure.closed_by.as_ref().and_then(|c| c.name.clone()),
+            ),
+            (tr("Closed at"), time(&closure.closed_time)),
+        ];
+        for (label, value) in fields {
+            if let Some(value) = value {
+                output.push_str(&format!("- **{}:** {}\n", label, value));
+            }
+        }
+    }
+
+    if !timeline.fetch_errors.is_empty() {
+        output.push_str(&format!("\n## {}\n\n", tr("Fetch Errors")));
+        for err in &timeline.fetch_errors {
+            output.push_str(&format!(
+                "- {}\n",
+                tr_fill("Warning: Failed to fetch {}", &[err])
+            ));
+        }
+    }
+
+    output
+}
+
+/// Formats one attachment line: name, size, and who attached it when.
+fn format_attachment(attachment: &Attachment) -> String {
+    let mut line = format!("- {}", attachment.display_name());
+    if let Some(size) = attachment.display_size() {
+        line.push_str(&format!(" ({})", size));
+    }
+    if let Some(by) = attachment.display_attached_by() {
+        line.push_str(&format!(" - {}", by));
+    }
+    if let Some(on) = attachment.attached_on.as_ref().and_then(|t| t.display()) {
+        line.push_str(&format!(", {}", on));
+    }
+    line.push('\n');
+    line
+}
+
+/// Formats a list of technicians as human-readable text.
+fn format_technician_list(technicians: &[Technician]) -> String {
+    if technicians.is_empty() {
+        return "No technicians found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} technician(s):", &[&technicians.len().to_string()]);
+    output.push_str("\n\n");
+
+    for tech in technicians {
+        output.push_str(&format!(
+            "{}: {} | {}: {}",
+            tr("ID"),
+            tech.id,
+            tr("Name"),
+            tech.display_name()
+        ));
+
+        if let Some(email) = tech.email() {
+            output.push_str(&format!(" | {}: {}", tr("Email"), email));
+        }
+
+        if let Some(active) = tech.is_active {
+            if !active {
+                output.push_str(" [INACTIVE]");
+            }
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a list of requesters as human-readable text.
+fn format_requester_list(requesters: &[Requester]) -> String {
+    if requesters.is_empty() {
+        return "No requesters found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} requester(s):", &[&requesters.len().to_string()]);
+    output.push_str("\n\n");
+
+    for requester in requesters {
+        output.push_str(&format!(
+            "{}: {} | {}: {}",
+            tr("ID"),
+            requester.id,
+            tr("Name"),
+            requester.display_name()
+        ));
+        if let Some(email) = &requester.email_id {
+            output.push_str(&format!(" | {}: {}", tr("Email"), email));
+        }
+        if let Some(department) = requester.display_department() {
+            output.push_str(&format!(" | {}: {}", tr("Department"), department));
+        }
+        if requester.is_vip() {
+            output.push_str(" [VIP]");
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a requester's profile as human-readable text.
+fn format_requester_details(requester: &Requester) -> String {
+    let mut output = format!(
+        "{} #{}: {}",
+        tr("Requester"),
+        requester.id,
+        requester.display_name()
+    );
+    if requester.is_vip() {
+        output.push_str(" [VIP]");
+    }
+    output.push('\n');
+
+    let fields = [
+        ("Email", requester.email_id.as_deref()),
+        ("Phone", requester.phone.as_deref()),
+        ("Mobile", requester.mobile.as_deref()),
+        ("Job Title", requester.job_title.as_deref()),
+        ("Employee ID", requester.employee_id.as_deref()),
+        ("Department", requester.display_department()),
+        ("Site", requester.display_site()),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            output.push_str(&format!("{}: {}\n", tr(label), value));
+        }
+    }
+
+    output
+}
+
+/// Formats a list of assets as human-readable text.
+fn format_asset_list(assets: &[Asset]) -> String {
+    if assets.is_empty() {
+        return tr("No assets found.").to_string();
+    }
+
+    let mut output = tr_fill("Found {} asset(s):", &[&assets.len().to_string()]);
+    output.push_str("\n\n");
+
+    for asset in assets {
+        output.push_str(&format!(
+            "{}: {} | {}: {}",
+            tr("ID"),
+            asset.id,
+            tr("Name"),
+            asset.display_name()
+        ));
+        if let Some(product) = asset.display_product() {
+            output.push_str(&format!(" | {}: {}", tr("Product"), product));
+        }
+        if let Some(serial) = &asset.serial_number {
+            output.push_str(&format!(" | {}: {}", tr("Serial"), serial));
+        }
+        if let Some(user) = asset.display_user() {
+            output.push_str(&format!(" | {}: {}", tr("User"), user));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats an asset's details as human-readable text.
+fn format_asset_details(asset: &Asset) -> String {
+    let mut output = format!("{} #{}: {}\n", tr("Asset"), asset.id, asset.display_name());
+
+    let fields = [
+        ("Product", asset.display_product()),
+        ("Type", asset.display_product_type()),
+        ("State", asset.display_state()),
+        ("Asset Tag", asset.asset_tag.as_deref()),
+        ("Serial Number", asset.serial_number.as_deref()),
+        ("Barcode", asset.barcode.as_deref()),
+        ("User", asset.display_user()),
+        (
+            "Department",
+            asset.department.as_ref().and_then(|d| d.name.as_deref()),
+        ),
+        ("Site", asset.site.as_ref().and_then(|s| s.name.as_deref())),
+        ("Location", asset.location.as_deref()),
+        ("IP Address", asset.ip_address.as_deref()),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value.filter(|v| !v.is_empty()) {
+            output.push_str(&format!("{}: {}\n", tr(label), value));
+        }
+    }
+
+    output
+}
+
+/// A category with its subcategories, each with its items.
+type CategoryTree = (CategoryEntry, Vec<(CategoryEntry, Vec<CategoryEntry>)>);
+
+/// Formats a category tree as an indented list.
+fn format_category_tree(tree: &[CategoryTree]) -> String {
+    if tree.is_empty() {
+        return tr("No categories found.").to_string();
+    }
+
+    let mut output = tr_fill("Found {} category(ies):", &[&tree.len().to_string()]);
+    output.push_str("\n\n");
+
+    for (category, subcategories) in tree {
+        output.push_str(&format!(
+            "{} ({}: {})\n",
+            category.display_name(),
+            tr("ID"),
+            category.id
+        ));
+        for (subcategory, items) in subcategories {
+            output.push_str(&format!(
+                "  - {} ({}: {})\n",
+                subcategory.display_name(),
+                tr("ID"),
+                subcategory.id
+            ));
+            for item in items {
+                output.push_str(&format!(
+                    "      - {} ({}: {})\n",
+                    item.display_name(),
+                    tr("ID"),
+                    item.id
+                ));
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns a category tree as JSON, with items nested under subcategories.
+fn category_tree_json(tree: &[CategoryTree]) -> serde_json::Value {
+    tree.iter()
+        .map(|(category, subcategories)| {
+            let subcategories: Vec<_> = subcategories
+                .iter()
+                .map(|(subcategory, items)| json!({"subcategory": subcategory, "items": items}))
+                .collect();
+            json!({"category": category, "subcategories": subcategories})
+        })
+        .collect()
+}
+
+/// Formats a list of sites as human-readable text.
+fn format_site_list(sites: &[Site]) -> String {
+    let active: Vec<&Site> = sites.iter().filter(|s| s.deleted != Some(true)).collect();
+    if active.is_empty() {
+        return "No sites found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} site(s):", &[&active.len().to_string()]);
+    output.push_str("\n\n");
+
+    for site in active {
+        output.push_str(&format!(
+            "{}: {} | {}: {}",
+            tr("ID"),
+            site.id,
+            tr("Name"),
+            site.display_name()
+        ));
+        if let Some(region) = site.display_region() {
+            output.push_str(&format!(" | {}: {}", tr("Region"), region));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a list of departments as human-readable text.
+fn format_department_list(departments: &[Department]) -> String {
+    if departments.is_empty() {
+        return "No departments found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} department(s):", &[&departments.len().to_string()]);
+    output.push_str("\n\n");
+
+    for department in departments {
+        output.push_str(&format!(
+            "{}: {} | {}: {}",
+            tr("ID"),
+            department.id,
+            tr("Name"),
+            department.display_name()
+        ));
+        if let Some(site) = department.display_site() {
+            output.push_str(&format!(" | {}: {}", tr("Site"), site));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a list of closure codes as human-readable text.
+fn format_closure_code_list(codes: &[ClosureCode]) -> String {
+    let active: Vec<&ClosureCode> = codes.iter().filter(|c| c.deleted != Some(true)).collect();
+    if active.is_empty() {
+        return "No closure codes found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} closure code(s):", &[&active.len().to_string()]);
+    output.push_str("\n\n");
+
+    for code in active {
+        output.push_str(&format!(
+            "- {}",
+            code.name.as_deref().unwrap_or(code.id.as_str())
+        ));
+        if let Some(description) = code.description.as_deref().filter(|d| !d.is_empty()) {
+            output.push_str(&format!(": {}", description));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats custom field definitions as human-readable text.
+fn format_custom_field_list(fields: &[CustomFieldDefinition]) -> String {
+    if fields.is_empty() {
+        return "No custom fields are defined for tickets.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} custom field(s):", &[&fields.len().to_string()]);
+    output.push_str("\n\n");
+
+    for field in fields {
+        output.push_str(&format!("- {}", field.name));
+        if let Some(label) = &field.label {
+            output.push_str(&format!(" ({})", label));
+        }
+        if let Some(field_type) = &field.field_type {
+            output.push_str(&format!(" | {}: {}", tr("Type"), field_type));
+        }
+        if field.mandatory {
+            output.push_str(" | Mandatory");
+        }
+        output.push('\n');
+        if !field.allowed_values.is_empty() {
+            output.push_str(&format!(
+                "   {}: {}\n",
+                tr("Allowed values"),
+                field.allowed_values.join(", ")
+            ));
+        }
+    }
+
+    output
+}
+
+/// Formats the open tickets assigned to the current technician.
+fn format_my_queue(technician: Option<&Technician>, requests: &[RequestSummary]) -> String {
+    let owner = technician.map_or(tr("you"), |t| t.display_name());
+    if requests.is_empty() {
+        return tr_fill("No open tickets assigned to {}.", &[owner]);
+    }
+
+    format!(
+        "{}\n\n{}",
+        tr_fill("Open tickets assigned to {}:", &[owner]),
+        format_request_list(requests, |_| None)
+    )
+}
+
+/// Shown when the API key's technician can't be determined.
+const UNKNOWN_TECHNICIAN_MESSAGE: &str = "Could not determine your technician account: no open \
+     tickets are assigned to you to infer it from. Set GLASS_TECHNICIAN_ID to your SDP user ID.";
+
+/// Formats the technician that owns the API key.
+fn format_whoami(technician: Option<&Technician>, configured: bool) -> String {
+    let Some(technician) = technician else {
+        return UNKNOWN_TECHNICIAN_MESSAGE.to_string();
+    };
+
+    let mut output = format!(
+        "You are {} (technician {}: {})\n",
+        technician.display_name(),
+        tr("ID"),
+        technician.id
+    );
+    if let Some(email) = technician.email() {
+        output.push_str(&format!("{}: {}\n", tr("Email"), email));
+    }
+    if let Some(job_title) = &technician.job_title {
+        output.push_str(&format!("{}: {}\n", tr("Job title"), job_title));
+    }
+    output.push_str(if configured {
+        "Source: GLASS_TECHNICIAN_ID"
+    } else {
+        "Source: looked up from your open tickets"
+    });
+    output
+}
+
+/// Formats the unassigned ticket queue.
+fn format_unassigned_tickets(group: Option<&str>, requests: &[RequestSummary]) -> String {
+    let scope = group.map(|g| tr_fill(" in {}", &[g])).unwrap_or_default();
+    if requests.is_empty() {
+        return tr_fill("No unassigned open tickets{}.", &[&scope]);
+    }
+
+    format!(
+        "{}\n\n{}",
+        tr_fill("Unassigned open tickets{} (oldest first):", &[&scope]),
+        format_request_list(requests, |_| None)
+    )
+}
+
+/// Formats overdue tickets with how late each one is relative to `now` (epoch milliseconds).
+fn format_overdue_tickets(requests: &[RequestSummary], now: i64) -> String {
+    if requests.is_empty() {
+        return tr("No overdue open tickets.").to_string();
+    }
+
+    let mut output = tr_fill(
+        "Found {} overdue ticket(s):",
+        &[&requests.len().to_string()],
+    );
+    output.push_str("\n\n");
+
+    for req in requests {
+        output.push_str(&format!("#{} - {}\n", req.id, req.display_subject()));
+        if let Some(due) = &req.due_by_time {
+            output.push_str(&format!(
+                "   {}: {}",
+                tr("Due"),
+                due.display().unwrap_or(tr("Unknown"))
+            ));
+            if let Some(due_millis) = due.epoch_millis().filter(|d| *d < now) {
+                output.push(' ');
+                output.push_str(&tr_fill("({} late)", &[&format_duration(now - due_millis)]));
+            }
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "   {}: {} | {}: {} | {}: {}\n\n",
+            tr("Status"),
+            req.display_status(),
+            tr("Priority"),
+            req.display_priority(),
+            tr("Assignee"),
+            req.display_technician()
+        ));
+    }
+
+    output
+}
+
+/// Returns the end of the due_soon window in epoch milliseconds.
+///
+/// With `hours` the window ends that many hours after `now`; otherwise it
+/// ends at midnight at the end of `now`'s day in its own time zone.
+fn due_window_end<Tz: chrono::TimeZone>(now: &chrono::DateTime<Tz>, hours: Option<u32>) -> i64 {
+    if let Some(hours) = hours {
+        return now.timestamp_millis() + i64::from(hours) * 3_600_000;
+    }
+    now.date_naive()
+        .succ_opt()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .and_then(|midnight| now.timezone().from_local_datetime(&midnight).earliest())
+        .map(|end| end.timestamp_millis())
+        .unwrap_or_else(|| now.timestamp_millis() + 24 * 3_600_000)
+}
+
+/// Formats tickets due soon with how long remains relative to `now` (epoch milliseconds).
+fn format_due_soon(requests: &[RequestSummary], now: i64, hours: Option<u32>) -> String {
+    let window = match hours {
+        Some(h) => tr_fill("in the next {} hour(s)", &[&h.to_string()]),
+        None => tr("today").to_string(),
+    };
+    if requests.is_empty() {
+        return tr_fill("No open tickets due {}.", &[&window]);
+    }
+
+    let mut output = tr_fill(
+        "Found {} ticket(s) due {}:",
+        &[&requests.len().to_string(), &window],
+    );
+    output.push_str("\n\n");
+
+    for req in requests {
+        output.push_str(&format!("#{} - {}\n", req.id, req.display_subject()));
+        if let Some(due) = &req.due_by_time {
+            output.push_str(&format!(
+                "   {}: {}",
+                tr("Due"),
+                due.display().unwrap_or(tr("Unknown"))
+            ));
+            if let Some(due_millis) = due.epoch_millis().filter(|d| *d > now) {
+                output.push(' ');
+                output.push_str(&tr_fill("(in {})", &[&format_duration(due_millis - now)]));
+            }
+            output.push('\n');
+        }
+        output.push_str(&format!(
+            "   {}: {} | {}: {} | {}: {}\n\n",
+            tr("Status"),
+            req.display_status(),
+            tr("Priority"),
+            req.display_priority(),
+            tr("Assignee"),
+            req.display_technician()
+        ));
+    }
+
+    output
+}
+
+/// Splits a subject into lowercase words for similarity comparison.
+///
+/// Drops short words, numbers, and common stopwords.
+fn subject_tokens(subject: &str) -> std::collections::BTreeSet<String> {
+    subject
+        .split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|w| w.chars().count() >= 3)
+        .filter(|w| !w.chars().all(|c| c.is_ascii_digit()))
+        .filter(|w| !SUBJECT_STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Scores how likely two tickets are duplicates, from 0.0 to 1.0.
+///
+/// Uses the Jaccard overlap of subject words, plus 0.2 for the same requester
+/// (or, when finding similar tickets, the same category).
+fn duplicate_score(
+    a: &std::collections::BTreeSet<String>,
+    b: &std::collections::BTreeSet<String>,
+    same_requester: bool,
+) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    let overlap = a.intersection(b).count() as f64 / union as f64;
+    let bonus = if same_requester && overlap > 0.0 {
+        0.2
+    } else {
+        0.0
+    };
+    (overlap + bonus).min(1.0)
+}
+
+/// Scores candidates against the source and returns the best matches first.
+///
+/// The source ticket itself is skipped, as are matches below `DUPLICATE_MIN_SCORE`.
+fn rank_duplicates<'a>(
+    source: Option<&Request>,
+    tokens: &std::collections::BTreeSet<String>,
+    candidates: &'a [RequestSummary],
+    limit: usize,
+) -> Vec<(&'a RequestSummary, f64)> {
+    let source_requester = source.and_then(|r| r.requester.as_ref());
+    let mut matches: Vec<(&RequestSummary, f64)> = candidates
+        .iter()
+        .filter(|c| source.is_none_or(|s| s.id != c.id))
+        .map(|c| {
+            let same_requester = match (source_requester, c.requester.as_ref()) {
+                (Some(a), Some(b)) => match (&a.id, &b.id) {
+                    (Some(x), Some(y)) => x == y,
+                    _ => a.name.is_some() && a.name == b.name,
+                },
+                _ => false,
+            };
+            let score =
+                duplicate_score(tokens, &subject_tokens(c.display_subject()), same_requester);
+            (c, score)
+        })
+        .filter(|(_, score)| *score >= DUPLICATE_MIN_SCORE)
+        .collect();
+    matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+    matches.truncate(limit);
+    matches
+}
+
+/// Scores finished tickets against the source subject and returns the best
+/// matches first.
+///
+/// Uses [`duplicate_score`] with the same-category bonus in place of the
+/// same-requester one. The source ticket itself is skipped, as are matches
+/// below `DUPLICATE_MIN_SCORE`.
+fn rank_similar<'a>(
+    source_id: Option<&str>,
+    tokens: &std::collections::BTreeSet<String>,
+    category: Option<&str>,
+    candidates: &'a [RequestSummary],
+    limit: usize,
+) -> Vec<(&'a RequestSummary, f64)> {
+    let mut matches: Vec<(&RequestSummary, f64)> = candidates
+        .iter()
+        .filter(|c| source_id != Some(c.id.as_str()))
+        .map(|c| {
+            let same_category = match (
+                category,
+                c.category.as_ref().and_then(|e| e.name.as_deref()),
+            ) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                _ => false,
+            };
+            let score =
+                duplicate_score(tokens, &subject_tokens(c.display_subject()), same_category);
+            (c, score)
+        })
+        .filter(|(_, score)| *score >= DUPLICATE_MIN_SCORE)
+        .collect();
+    matches.sort_by(|a, b| b.1.total_cmp(&a.1));
+    matches.truncate(limit);
+    matches
+}
+
+/// A similar finished ticket: its summary, score, and resolution text,
+/// or why the resolution could not be fetched.
+type SimilarMatch<'a> = (&'a RequestSummary, f64, Result<Option<String>, String>);
+
+/// Formats similar finished tickets with their scores and resolutions.
+fn format_similar_requests(
+    source_id: Option<&str>,
+    subject: &str,
+    days: u32,
+    matches: &[SimilarMatch<'_>],
+) -> String {
+    let target = match source_id {
+        Some(id) => format!("#{} \"{}\"", id, subject),
+        None => format!("\"{}\"", subject),
+    };
+    if matches.is_empty() {
+        return format!(
+            "No resolved or closed tickets similar to {} in the last {} day(s).",
+            target, days
+        );
+    }
+
+    let mut output = format!(
+        "Found {} resolved or closed ticket(s) similar to {} in the last {} day(s):\n\n",
+        matches.len(),
+        target,
+        days
+    );
+
+    for (req, score, resolution) in matches {
+        output.push_str(&format!(
+            "#{} - {} ({:.0}% match)\n",
+            req.id,
+            req.display_subject(),
+            score * 100.0
+        ));
+        output.push_str(&format!("   {}: {}", tr("Status"), req.display_status()));
+        if let Some(category) = req.category.as_ref().and_then(|c| c.name.as_deref()) {
+            output.push_str(&format!(" | {}: {}", tr("Category"), category));
+        }
+        if let Some(completed) = req.completed_time.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!(" | {}: {}", tr("Closed at"), completed));
+        }
+        output.push('\n');
+        match resolution {
+            Ok(Some(text)) => {
+                let text = truncate_text(text.trim(), SIMILAR_RESOLUTION_LENGTH);
+                output.push_str(&format!(
+                    "   {}: {}\n",
+                    tr("Resolution"),
+                    text.replace('\n', "\n   ")
+                ));
+            }
+            Ok(None) => output.push_str("   (No resolution recorded)\n"),
+            Err(e) => output.push_str(&format!("   (Resolution could not be loaded: {})\n", e)),
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Returns similar tickets as JSON, each with its resolution or the error
+/// fetching it.
+fn similar_matches_json(matches: &[SimilarMatch<'_>]) -> serde_json::Value {
+    matches
+        .iter()
+        .map(|(request, score, resolution)| match resolution {
+            Ok(resolution) => json!({"request": request, "score": score, "resolution": resolution}),
+            Err(error) => json!({"request": request, "score": score, "resolution_error": error}),
+        })
+        .collect()
+}
+
+/// Formats likely duplicate tickets with their similarity scores.
+fn format_duplicate_matches(
+    source_id: Option<&str>,
+    subject: &str,
+    days: u32,
+    matches: &[(&RequestSummary, f64)],
+) -> String {
+    let target = match source_id {
+        Some(id) => format!("#{} \"{}\"", id, subject),
+        None => format!("\"{}\"", subject),
+    };
+    if matches.is_empty() {
+        return format!(
+            "No likely duplicates of {} in the last {} day(s).",
+            target, days
+        );
+    }
+
+    let mut output = format!(
+        "Found {} likely duplicate(s) of {} in the last {} day(s):\n\n",
+        matches.len(),
+        target,
+        days
+    );
+
+    for (req, score) in matches {
+        output.push_str(&format!(
+            "#{} - {} ({:.0}% match)\n",
+            req.id,
+            req.display_subject(),
+            score * 100.0
+        ));
+        output.push_str(&format!(
+            "   {}: {} | {}: {}",
+            tr("Status"),
+            req.display_status(),
+            tr("Requester"),
+            req.display_requester()
+        ));
+        if let Some(created) = req.created_time.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!(" | {}: {}", tr("Created"), created));
+        }
+        output.push_str("\n\n");
+    }
+
+    output
+}
+
+/// Formats ticket counts per group value, largest first.
+fn format_ticket_statistics(
+    input: &TicketStatisticsInput,
+    counts: &[(String, u32)],
+    total: u32,
+) -> String {
+    let mut output = format!(
+        "Ticket counts by {}{}",
+        input.group_by,
+        if input.open_only == Some(true) {
+            " (open tickets only)"
+        } else {
+            ""
+        }
+    );
+    match (&input.created_after, &input.created_before) {
+        (Some(after), Some(before)) => {
+            output.push_str(&format!(", created {} to {}", after, before))
+        }
+        (Some(after), None) => output.push_str(&format!(", created after {}", after)),
+        (None, Some(before)) => output.push_str(&format!(", created before {}", before)),
+        (None, None) => {}
+    }
+    output.push_str(&format!(":\n\n{}: {}\n\n", tr("Total"), total));
+
+    if counts.is_empty() {
+        output.push_str("No matching tickets.\n");
+        return output;
+    }
+
+    let mut sorted: Vec<&(String, u32)> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    for (value, count) in sorted {
+        output.push_str(&format!("{}: {}\n", value, count));
+    }
+
+    let counted: u32 = counts.iter().map(|(_, c)| c).sum();
+    if total > counted {
+        let other = if matches!(input.group_by.as_str(), "technician" | "group") {
+            "Unassigned / other"
+        } else {
+            "Other"
+        };
+        output.push_str(&format!("{}: {}\n", other, total - counted));
+    }
+
+    output
+}
+
+/// Returns ticket counts per group value as JSON.
+fn ticket_statistics_json(
+    input: &TicketStatisticsInput,
+    counts: &[(String, u32)],
+    total: u32,
+) -> serde_json::Value {
+    let counts: Vec<_> = counts
+        .iter()
+        .map(|(value, count)| json!({"value": value, "count": count}))
+        .collect();
+    json!({
+        "group_by": input.group_by,
+        "open_only": input.open_only == Some(true),
+        "created_after": input.created_after,
+        "created_before": input.created_before,
+        "total": total,
+        "counts": counts,
+    })
+}
+
+/// Formats the time left on an SLA target, e.g. "(in 1h 20m)" or "(2d 3h late)".
+fn format_countdown(countdown: SlaCountdown) -> String {
+    match countdown {
+        SlaCountdown::Remaining(left) => tr_fill("(in {})", &[&format_duration(left)]),
+        SlaCountdown::Breached(late) => tr_fill("({} late)", &[&format_duration(late)]),
+    }
+}
+
+/// Formats a duration in milliseconds as a short human-readable string.
+fn format_duration(millis: i64) -> String {
+    let minutes = millis.abs() / 60_000;
+    let (days, hours, minutes) = (minutes / 1440, (minutes % 1440) / 60, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Describes one SLA target: when it is due and whether it was met or breached.
+///
+/// `done` is when the target was satisfied (first response or completion), if it has been.
+fn format_sla_target(
+    label: &str,
+    due: &SdpTimestamp,
+    done: Option<&SdpTimestamp>,
+    overdue_flag: bool,
+    now: i64,
+) -> String {
+    let mut line = format!("{}: {}", label, due.display().unwrap_or(tr("Unknown")));
+    let due_millis = due.epoch_millis();
+    match (due_millis, done.and_then(|d| d.epoch_millis())) {
+        (Some(due), Some(done)) if done > due => line.push_str(&format!(
+            " - met late by {} [VIOLATED]",
+            format_duration(done - due)
+        )),
+        (_, Some(_)) if overdue_flag => line.push_str(" - [VIOLATED]"),
+        (_, Some(_)) => line.push_str(" - met"),
+        (Some(due), None) if now > due => line.push_str(&format!(
+            " - overdue by {} [VIOLATED]",
+            format_duration(now - due)
+        )),
+        (Some(due), None) => line.push_str(&format!(" - due in {}", format_duration(due - now))),
+        (None, None) if overdue_flag => line.push_str(" - [VIOLATED]"),
+        (None, None) => {}
+    }
+    line.push('\n');
+    line
+}
+
+/// Formats a ticket's SLA state relative to `now` (epoch milliseconds).
+fn format_sla_info(request: &Request, sla: Option<&Sla>, now: i64) -> String {
+    let mut output = format!(
+        "SLA for ticket #{}: {}\n\n",
+        request.id,
+        request.display_subject()
+    );
+
+    let sla_name = sla
+        .map(|s| s.display_name())
+        .or_else(|| request.sla.as_ref().and_then(|s| s.name.as_deref()));
+    match sla_name {
+        Some(name) => output.push_str(&format!("{}: {}\n", tr("SLA"), name)),
+        None => output.push_str("SLA: None applied\n"),
+    }
+    if let Some(description) = sla
+        .and_then(|s| s.description.as_deref())
+        .filter(|d| !d.is_empty())
+    {
+        output.push_str(&format!("{}: {}\n", tr("Targets"), description));
+    }
+    output.push_str(&format!("{}: {}\n", tr("Status"), request.display_status()));
+
+    if let Some(created) = &request.created_time {
+        output.push_str(&format!(
+            "{}: {}",
+            tr("Created"),
+            created.display().unwrap_or(tr("Unknown"))
+        ));
+        let end = request
+            .completed_time
+            .as_ref()
+            .and_then(|t| t.epoch_millis())
+            .unwrap_or(now);
+        if let Some(start) = created.epoch_millis() {
+            output.push_str(&format!(" (elapsed {})", format_duration(end - start)));
+        }
+        output.push('\n');
+    }
+
+    output.push('\n');
+    let mut has_target = false;
+    if let Some(due) = &request.first_response_due_by_time {
+        has_target = true;
+        output.push_str(&format_sla_target(
+            "First response due",
+            due,
+            request.responded_time.as_ref(),
+            request.is_first_response_overdue == Some(true),
+            now,
+        ));
+    }
+    if let Some(due) = request
+        .resolution_due_by_time
+        .as_ref()
+        .or(request.due_by_time.as_ref())
+    {
+        has_target = true;
+        output.push_str(&format_sla_target(
+            "Resolution due",
+            due,
+            request.completed_time.as_ref(),
+            request.is_overdue == Some(true),
+            now,
+        ));
+    }
+    if !has_target {
+        output.push_str("No SLA due times are set on this ticket.\n");
+    }
+
+    output
+}
+
+// ============================================================================
+// Write operation formatting helpers (M4)
+// ============================================================================
+
+/// Formats the result of a create request operation.
+fn format_create_result(request: &Request, web_url: &str) -> String {
+    let mut output = String::new();
+
+    output.push_str(&tr_fill(
+        "Successfully created ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    ));
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), request.display_status()));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Priority"),
+        request.display_priority()
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Assigned to"),
+        request.display_technician()
+    ));
+
+    if let Some(group) = request.display_group() {
+        output.push_str(&format!("{}: {}\n", tr("Group"), group));
+    }
+
+    output.push_str(&format!(
+        "\n{}: {}\n",
+        tr("Requester"),
+        request.display_requester()
+    ));
+
+    if let Some(created) = request.created_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}\n", tr("Created"), created));
+    }
+
+    output.push_str(&format!("{}: {}\n", tr("Link"), web_url));
+
+    output.push_str("\nNext steps:\n");
+    output.push_str(&format!(
+        "  - View details: use get_request with request_id=\"{}\"\n",
+        request.id
+    ));
+    output.push_str(&format!(
+        "  - Add notes: use add_note with request_id=\"{}\"\n",
+        request.id
+    ));
+
+    output
+}
+
+/// Formats the result of an update request operation.
+fn format_update_result(request: &Request) -> String {
+    let mut output = String::new();
+
+    output.push_str(&tr_fill(
+        "Successfully updated ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    ));
+    output.push_str("\n\n");
+
+    output.push_str("Current state:\n");
+    output.push_str(&format!(
+        "  {}: {}\n",
+        tr("Status"),
+        request.display_status()
+    ));
+    output.push_str(&format!(
+        "  {}: {}\n",
+        tr("Priority"),
+        request.display_priority()
+    ));
+    output.push_str(&format!(
+        "  {}: {}\n",
+        tr("Assigned to"),
+        request.display_technician()
+    ));
+
+    if let Some(group) = request.display_group() {
+        output.push_str(&format!("  {}: {}\n", tr("Group"), group));
+    }
+
+    let category_path = request.category_path();
+    if category_path != tr("Uncategorized") {
+        output.push_str(&format!("  {}: {}\n", tr("Category"), category_path));
+    }
+
+    if let Some(updated) = request.last_updated_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("\n{}: {}\n", tr("Last updated"), updated));
+    }
+
+    output
+}
+
+/// Formats the result of an escalate request operation.
+fn format_escalate_result(request: &Request, reason: &str, note_error: Option<&str>) -> String {
+    let mut output = format!(
+        "Escalated ticket #{}: {}\n\n",
+        request.id,
+        request.display_subject()
+    );
+
+    output.push_str(&format!(
+        "  {}: {}\n",
+        tr("Priority"),
+        request.display_priority()
+    ));
+    output.push_str(&format!(
+        "  {}: {}\n",
+        tr("Assigned to"),
+        request.display_technician()
+    ));
+    if let Some(group) = request.display_group() {
+        output.push_str(&format!("  {}: {}\n", tr("Group"), group));
+    }
+    output.push_str(&format!(
+        "  {}: {}\n",
+        tr("Reason"),
+        truncate_text(reason, 500)
+    ));
+
+    match note_error {
+        None => output.push_str("\nThe reason was recorded as an internal note.\n"),
+        Some(error) => output.push_str(&format!(
+            "\nWarning: the escalation note could not be added ({}). Add it with add_note.\n",
+            error
+        )),
+    }
+
+    output
+}
+
+/// Formats the result of a close request operation.
+fn format_close_result(request: &Request) -> String {
+    let mut output = String::new();
+
+    output.push_str(&tr_fill(
+        "Successfully closed ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    ));
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), request.display_status()));
+
+    if let Some(closure) = &request.closure_info {
+        if let Some(code) = closure
+            .closure_code
+            .as_ref()
+            .and_then(|c| c.name.as_deref())
+        {
+            output.push_str(&format!("{}: {}\n", tr("Closure Code"), code));
+        }
+        if let Some(comments) = &closure.closure_comments {
+            output.push_str(&format!("{}: {}\n", tr("Closure Comments"), comments));
+        }
+        if let Some(closed_time) = closure.closed_time.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!("{}: {}\n", tr("Closed at"), closed_time));
+        }
+    }
+
+    output
+}
+
+/// Formats the result of an add note operation.
+fn format_add_note_result(request_id: &str, note: &Note) -> String {
+    let mut output = String::new();
+
+    output.push_str(&tr_fill(
+        "Successfully added note #{} to ticket #{}.",
+        &[&note.id, request_id],
+    ));
+    output.push_str("\n\n");
+
+    let visibility = if note.show_to_requester == Some(true) {
+        "Visible to requester"
+    } else {
+        "Internal (technicians only)"
+    };
+    output.push_str(&format!("{}: {}\n", tr("Visibility"), visibility));
+
+    if let Some(created) = note.created_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}\n", tr("Created"), created));
+    }
+
+    if note.notify_technician == Some(true) {
+        output.push_str("Technician notification: Sent\n");
+    }
+
+    output
+}
+
+/// Formats the result of an assign request operation.
+fn format_assign_result(request: &Request, input: &AssignRequestInput) -> String {
+    let mut output = String::new();
+
+    output.push_str(&tr_fill(
+        "Successfully assigned ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    ));
+    output.push_str("\n\n");
+
+    if input.technician_id.is_some() {
+        output.push_str(&format!(
+            "{}: {}\n",
+            tr("Technician"),
+            request.display_technician()
+        ));
+    }
+
+    if input.group.is_some() {
+        if let Some(group) = request.display_group() {
+            output.push_str(&format!("{}: {}\n", tr("Group"), group));
+        }
+    }
+
+    if let Some(updated) = request.last_updated_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("\n{}: {}\n", tr("Updated"), updated));
+    }
+
+    output
+}
+
+/// Formats the result of a change requester operation.
+fn format_change_requester_result(request: &Request) -> String {
+    let mut output = tr_fill(
+        "Successfully changed the requester of ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!(
+        "{}: {}",
+        tr("Requester"),
+        request.display_requester()
+    ));
+    if let Some(email) = request
+        .requester
+        .as_ref()
+        .and_then(|r| r.email_id.as_deref())
+    {
+        output.push_str(&format!(" <{}>", email));
+    }
+    output.push('\n');
+
+    output
+}
+
+/// Formats a ticket's CC list after an add_cc or remove_cc operation.
+fn format_cc_result(request: &Request) -> String {
+    let cc = request.email_ids_to_notify.as_deref().unwrap_or_default();
+    let mut output = format!(
+        "CC recipients on ticket #{}: {}\n",
+        request.id,
+        request.display_subject()
+    );
+    if cc.is_empty() {
+        output.push_str("  (none)\n");
+    }
+    for email in cc {
+        output.push_str(&format!("  {}\n", email));
+    }
+    output
+}
+
+/// Formats one conversation of a ticket: sender, direction, time, and content.
+fn format_conversation_entry(conv: &Conversation) -> String {
+    // Conversation header with sender, direction and timestamp
+    let from = conv.display_from();
+    let timestamp = conv.display_time().unwrap_or(tr("Unknown time"));
+    let direction = conv.direction();
+    let mut output = format!("\n[{}] {} ({})\n", timestamp, from, direction);
+
+    // Conversation content (truncated if needed)
+    let content = html_to_text(&conv.display_content());
+    output.push_str(&truncate_text(&content, 1500));
+    output.push('\n');
+    output
+}
+
+/// Formats one note of a ticket: author, time, visibility, and content.
+fn format_note_entry(note: &Note) -> String {
+    // Note header with author and timestamp
+    let author = note.display_created_by();
+    let timestamp = note
+        .created_time
+        .as_ref()
+        .and_then(|t| t.display())
+        .unwrap_or(tr("Unknown time"));
+    let visibility = if note.show_to_requester == Some(true) {
+        String::new()
+    } else {
+        format!(" [{}]", tr("Internal"))
+    };
+    let mut output = format!("\n[{}] {}{}\n", timestamp, author, visibility);
+
+    // Note content (truncated if needed)
+    let content = html_to_text(&note.display_content());
+    output.push_str(&truncate_text(&content, 1000));
+    output.push('\n');
+    output
+}
+
+/// Which of a ticket's paged entries a listing shows.
+#[derive(Debug, Clone, Copy)]
+enum EntryKind {
+    Notes,
+    Conversations,
+}
+
+impl EntryKind {
+    /// Returns the plural noun and the tool that pages through them.
+    fn names(self) -> (&'static str, &'static str) {
+        match self {
+            EntryKind::Notes => ("notes", "list_notes"),
+            EntryKind::Conversations => ("conversations", "list_conversations"),
+        }
+    }
+}
+
+/// Formats one page of a ticket's notes or conversations, saying which
+/// offset to request for the next page.
+fn format_entry_page<T>(
+    request_id: &str,
+    kind: EntryKind,
+    offset: u32,
+    page: &WithContent<T>,
+    format_entry: fn(&T) -> String,
+) -> String {
+    let (noun, tool) = kind.names();
+    let noun = tr(noun);
+    if page.items.is_empty() {
+        return if offset == 0 {
+            tr_fill("No {} on ticket #{}.", &[noun, request_id])
+        } else {
+            tr_fill(
+                "No {} on ticket #{} after offset {}.",
+                &[noun, request_id, &offset.to_string()],
+            )
+        };
+    }
+
+    let end = offset + page.items.len() as u32;
+    let mut output = tr_fill(
+        "Showing {} {}-{} of ticket #{}:",
+        &[
+            noun,
+            &(offset + 1).to_string(),
+            &end.to_string(),
+            request_id,
+        ],
+    );
+    output.push('\n');
+    for item in &page.items {
+        output.push_str(&format_entry(item));
+    }
+    for failure in &page.failures {
+        output.push('\n');
+        output.push_str(&tr_fill(
+            "Warning: Failed to fetch the content of {}: {}",
+            &[&failure.id, &failure.error],
+        ));
+        output.push('\n');
+    }
+    if page.has_more {
+        output.push('\n');
+        output.push_str(&tr_fill(
+            "More {} follow: call {} with offset {}.",
+            &[noun, tool, &end.to_string()],
+        ));
+        output.push('\n');
+    }
+    output
+}
+
+/// Points to list_conversations and list_notes when get_request or the
+/// timeline could not show every entry.
+fn format_more_entries_hint(
+    request_id: &str,
+    more_conversations: bool,
+    more_notes: bool,
+) -> String {
+    let mut hint = String::new();
+    let page_size = DETAIL_PAGE_SIZE.to_string();
+    for (more, kind) in [
+        (more_conversations, EntryKind::Conversations),
+        (more_notes, EntryKind::Notes),
+    ] {
+        let (noun, tool) = kind.names();
+        if more {
+            hint.push_str("\n\n");
+            hint.push_str(&tr_fill(
+                "Ticket #{} has more than {} {}; call {} with offset {} for the rest.",
+                &[request_id, &page_size, tr(noun), tool, &page_size],
+            ));
+        }
+    }
+    hint
+}
+
+/// Notes how many of a ticket's inline images are attached.
+fn format_inline_images_note(attached: usize, found: usize) -> String {
+    if found == 0 {
+        return "\n\nNo inline images found.".to_string();
+    }
+    let mut note = format!("\n\nInline images: {} of {} attached", attached, found);
+    if attached < found {
+        note.push_str(&format!(
+            " (at most {} are attached, each up to {} KB)",
+            MAX_INLINE_IMAGES,
+            MAX_INLINE_IMAGE_BYTES / 1024
+        ));
+    }
+    note.push('.');
+    note
+}
+
+/// Formats the watchers of a ticket.
+fn format_watcher_list(request_id: &str, watchers: &[Watcher]) -> String {
+    let mut output = tr_fill("Watchers on ticket #{}:", &[request_id]);
+    output.push('\n');
+    if watchers.is_empty() {
+        output.push_str(&format!("  {}\n", tr("(none)")));
+    }
+    for watcher in watchers {
+        output.push_str(&format!(
+            "  {} (ID: {})",
+            watcher.display_name(),
+            watcher.id
+        ));
+        if let (Some(_), Some(email)) = (&watcher.name, &watcher.email_id) {
+            output.push_str(&format!(" <{}>", email));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Formats the result of a pickup operation.
+fn format_pickup_result(request: &Request) -> String {
+    let mut output = tr_fill(
+        "Successfully picked up ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Technician"),
+        request.display_technician()
+    ));
+    output.push_str(&format!("{}: {}\n", tr("Status"), request.display_status()));
+
+    output
+}
+
+/// Formats the result of a set resolution operation.
+fn format_resolution_result(request_id: &str, resolution: &Resolution) -> String {
+    let mut output = tr_fill("Successfully set resolution on ticket #{}.", &[request_id]);
+    output.push_str("\n\n");
+
+    if let Some(submitted_by) = resolution
+        .submitted_by
+        .as_ref()
+        .and_then(|s| s.name.as_deref())
+    {
+        output.push_str(&format!("{}: {}\n", tr("Submitted by"), submitted_by));
+    }
+
+    if let Some(submitted_on) = resolution.submitted_on.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}\n", tr("Submitted"), submitted_on));
+    }
+
+    output.push_str("\nThe ticket status was not changed; use close_request to close it.\n");
+
+    output
+}
+
+/// Formats the result of a resolve request operation.
+fn format_resolve_result(request: &Request, resolution: &Resolution) -> String {
+    let mut output = tr_fill(
+        "Successfully resolved ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), request.display_status()));
+
+    if let Some(closure) = &request.closure_info {
+        if let Some(code) = closure
+            .closure_code
+            .as_ref()
+            .and_then(|c| c.name.as_deref())
+        {
+            output.push_str(&format!("{}: {}\n", tr("Closure Code"), code));
+        }
+        if let Some(comments) = &closure.closure_comments {
+            output.push_str(&format!("{}: {}\n", tr("Closure Comments"), comments));
+        }
+    }
+
+    if let Some(submitted_by) = resolution
+        .submitted_by
+        .as_ref()
+        .and_then(|s| s.name.as_deref())
+    {
+        output.push_str(&format!("{}: {}\n", tr("Resolution by"), submitted_by));
+    }
+
+    output
+}
+
+/// Formats a resolve failure that happened after the resolution was saved.
+///
+/// `rollback` is `None` when there was no previous resolution to restore,
+/// otherwise the outcome of restoring it.
+fn format_resolve_failure(
+    request_id: &str,
+    status: &str,
+    error: &str,
+    rollback: Option<Result<(), String>>,
+) -> String {
+    let mut output = format!(
+        "Failed to resolve request {}: the resolution was saved, but setting status '{}' failed: \
+         {}\n",
+        request_id, status, error
+    );
+
+    match rollback {
+        Some(Ok(())) => {
+            output.push_str("The previous resolution was restored; the ticket is unchanged.")
+        }
+        Some(Err(rollback_error)) => output.push_str(&format!(
+            "Restoring the previous resolution also failed ({}). The ticket now has the new \
+             resolution and its status is unchanged.",
+            rollback_error
+        )),
+        None => output.push_str(
+            "The ticket had no previous resolution, so the new one was kept. Its status is \
+             unchanged; retry with update_request or close_request.",
+        ),
+    }
+
+    output
+}
+
+/// Formats the result of a hold request operation.
+fn format_hold_result(request: &Request, scheduler: Option<&OnHoldScheduler>) -> String {
+    let mut output = tr_fill(
+        "Successfully put ticket #{} on hold: {}",
+        &[&request.id, request.display_subject()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), request.display_status()));
+
+    if let Some(scheduler) = scheduler {
+        output.push_str(&format_onhold_schedule(scheduler));
+    }
+
+    output
+}
+
+/// Builds the priority the matrix gives for an impact and urgency, with the
+/// matrix cell used and the inputs as data.
+///
+/// Returns an error naming the matrix's values when impact or urgency is
+/// unknown or not in the matrix.
+fn priority_suggestion(
+    matrix: &PriorityMatrix,
+    request: Option<&Request>,
+    impact: Option<&str>,
+    urgency: Option<&str>,
+) -> Result<ToolOutput, String> {
+    let ticket = request
+        .map(|r| format!("Ticket #{} has no ", r.id))
+        .unwrap_or_else(|| "No ".to_string());
+    let impact = impact.ok_or_else(|| format!("{}impact set; provide impact.", ticket))?;
+    let urgency = urgency.ok_or_else(|| format!("{}urgency set; provide urgency.", ticket))?;
+
+    let (cell_impact, cell_urgency, priority) = matrix.cell(impact, urgency).ok_or_else(|| {
+        format!(
+            "The priority matrix has no entry for impact '{}' and urgency '{}'. Impacts: {}. \
+             Urgencies: {}.",
+            impact,
+            urgency,
+            matrix.impacts().join(", "),
+            matrix.urgencies().join(", ")
+        )
+    })?;
+
+    let mut output = match request {
+        Some(request) => tr_fill(
+            "Suggested priority for ticket #{}: {}",
+            &[&request.id, priority],
+        ),
+        None => tr_fill("Suggested priority: {}", &[priority]),
+    };
+    output.push_str("\n\n");
+    output.push_str(&format!("{}: {}\n", tr("Impact"), impact));
+    output.push_str(&format!("{}: {}\n", tr("Urgency"), urgency));
+
+    if let Some(request) = request {
+        let current = request.display_priority();
+        output.push_str(&format!("{}: {}\n", tr("Priority"), current));
+        output.push('\n');
+        if current.eq_ignore_ascii_case(priority) {
+            output.push_str(tr("The ticket already has this priority."));
+        } else {
+            output.push_str(&tr_fill(
+                "The ticket's priority differs; set it to {} with update_request if you agree.",
+                &[priority],
+            ));
+        }
+        output.push('\n');
+    }
+
+    Ok(ToolOutput::new(
+        output,
+        json!({
+            "suggested_priority": priority,
+            "cell": {"impact": cell_impact, "urgency": cell_urgency, "priority": priority},
+            "inputs": {
+                "request_id": request.map(|r| &r.id),
+                "impact": impact,
+                "urgency": urgency,
+                "current_priority": request.map(|r| r.display_priority()),
+            },
+        }),
+    ))
+}
+
+/// Formats the result of a set_due_date operation.
+///
+/// `requested` is shown when SDP's response does not echo the new due date.
+fn format_due_date_result(
+    request: &Request,
+    requested: &SdpTimestamp,
+    note_error: Option<&str>,
+) -> String {
+    let mut output = tr_fill(
+        "Successfully changed the due date of ticket #{}: {}",
+        &[&request.id, request.display_subject()],
+    );
+    output.push_str("\n\n");
+
+    let due = request.due_by_time.as_ref().unwrap_or(requested);
+    let when = due.display_value.clone().or_else(|| {
+        due.epoch_millis()
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+    });
+    output.push_str(&format!(
+        "{}: {}",
+        tr("Due By"),
+        when.as_deref().unwrap_or(tr("Unknown"))
+    ));
+    if let Some(countdown) =
+        SlaCountdown::new(Some(due), None, chrono::Utc::now().timestamp_millis())
+    {
+        output.push_str(&format!(" {}", format_countdown(countdown)));
+    }
+    output.push('\n');
+
+    if let Some(error) = note_error {
+        output.push_str(&format!(
+            "\nWarning: the reason could not be recorded as a note ({}). Add it with add_note.\n",
+            error
+        ));
+    }
+
+    output
+}
+
+/// Formats an on-hold schedule as "Resumes ..." lines.
+fn format_onhold_schedule(scheduler: &OnHoldScheduler) -> String {
+    let mut output = String::new();
+
+    let when = scheduler.scheduled_time.as_ref().and_then(|t| {
+        t.display_value.clone().or_else(|| {
+            t.epoch_millis()
+                .and_then(chrono::DateTime::from_timestamp_millis)
+                .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        })
+    });
+    if let Some(when) = when {
+        let to = scheduler
+            .change_to_status
+            .as_ref()
+            .and_then(|s| s.name.as_deref())
+            .unwrap_or(Status::Open.name());
+        output.push_str(&format!("{}: {} (to {})\n", tr("Resumes"), when, to));
+    }
+
+    if let Some(comments) = &scheduler.comments {
+        output.push_str(&format!("{}: {}\n", tr("Hold reason"), comments));
+    }
+
+    output
+}
+
+/// Formats the result of a delete request operation.
+fn format_delete_result(request_id: &str) -> String {
+    let mut output = tr_fill(
+        "Successfully moved ticket #{} to the trash. Use restore_request to undo.",
+        &[request_id],
+    );
+    output.push('\n');
+    output
+}
+
+/// Formats a list of trashed requests as human-readable text.
+fn format_trashed_request_list(requests: &[RequestSummary]) -> String {
+    if requests.is_empty() {
+        return "The trash is empty.".to_string();
+    }
+
+    format!(
+        "{}: {}",
+        tr("Trash"),
+        format_request_list(requests, |_| None)
+    )
+}
+
+/// Formats the result of attaching an asset to a request.
+fn format_attach_asset_result(request: &Request, asset_id: &str) -> String {
+    let mut output = tr_fill(
+        "Successfully attached asset {} to ticket #{}: {}",
+        &[asset_id, &request.id, request.display_subject()],
+    );
+    output.push('\n');
+
+    if let Some(assets) = request.display_assets() {
+        output.push_str(&format!("\n{}: {}\n", tr("Assets"), assets));
+    }
+
+    output
+}
+
+// ============================================================================
+// Request link formatting helpers
+// ============================================================================
+
+/// Formats the result of a link or unlink operation.
+fn format_link_result(action: &'static str, request_id: &str, linked_request_id: &str) -> String {
+    let mut output = tr_fill(
+        "Successfully {} ticket #{} and ticket #{}.",
+        &[tr(action), request_id, linked_request_id],
+    );
+    output.push('\n');
+    output
+}
+
+/// Formats the result of associating or dissociating a problem or change.
+fn format_association_result(
+    associated: bool,
+    request_id: &str,
+    kind: AssociationKind,
+    target_id: &str,
+    record: Option<&AssociatedRecord>,
+) -> String {
+    let target = record
+        .map(AssociatedRecord::display_summary)
+        .unwrap_or_else(|| format!("#{}", target_id));
+    let mut output = match (associated, kind) {
+        (true, AssociationKind::Problem) => tr_fill(
+            "Successfully associated ticket #{} with problem {}.",
+            &[request_id, &target],
+        ),
+        (true, AssociationKind::Change) => tr_fill(
+            "Successfully associated ticket #{} with change {}.",
+            &[request_id, &target],
+        ),
+        (false, AssociationKind::Problem) => tr_fill(
+            "Successfully removed problem {} from ticket #{}.",
+            &[&target, request_id],
+        ),
+        (false, AssociationKind::Change) => tr_fill(
+            "Successfully removed change {} from ticket #{}.",
+            &[&target, request_id],
+        ),
+    };
+    output.push('\n');
+    output
+}
+
+// ============================================================================
+// Worklog formatting helpers
+// ============================================================================
+
+/// Formats a list of worklogs as human-readable text.
+fn format_worklog_list(request_id: &str, worklogs: &[Worklog]) -> String {
+    if worklogs.is_empty() {
+        return tr_fill("No worklogs recorded on ticket #{}.", &[request_id]);
+    }
+
+    let total: u32 = worklogs
+        .iter()
+        .filter_map(|w| w.time_spent.as_ref())
+        .map(|t| t.total_minutes())
+        .sum();
+
+    let mut output = format!(
+        "Found {} worklog(s) on ticket #{} (total {}h {:02}m):\n\n",
+        worklogs.len(),
+        request_id,
+        total / 60,
+        total % 60
+    );
+
+    for worklog in worklogs {
+        output.push_str(&format!(
+            "{}: {} | {}: {} | {}: {}\n",
+            tr("ID"),
+            worklog.id,
+            tr("Technician"),
+            worklog.display_owner(),
+            tr("Time"),
+            worklog.display_time_spent()
+        ));
+
+        if let Some(start) = worklog.start_time.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!("   {}: {}\n", tr("Started"), start));
+        }
+        if let Some(description) = &worklog.description {
+            output.push_str(&format!("   {}\n", truncate_text(description, 500)));
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats the result of an add or update worklog operation.
+fn format_worklog_result(action: &'static str, request_id: &str, worklog: &Worklog) -> String {
+    let mut output = tr_fill(
+        "Successfully {} worklog #{} on ticket #{}.",
+        &[tr(action), &worklog.id, request_id],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Technician"),
+        worklog.display_owner()
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Time spent"),
+        worklog.display_time_spent()
+    ));
+
+    if let Some(description) = &worklog.description {
+        output.push_str(&format!(
+            "{}: {}\n",
+            tr("Description"),
+            truncate_text(description, 500)
+        ));
+    }
+
+    output
+}
+
+// ============================================================================
+// Task formatting helpers
+// ============================================================================
+
+/// Formats a list of tasks as human-readable text.
+fn format_task_list(request_id: &str, tasks: &[Task]) -> String {
+    if tasks.is_empty() {
+        return tr_fill("No tasks on ticket #{}.", &[request_id]);
+    }
+
+    let mut output = tr_fill(
+        "Found {} task(s) on ticket #{}:",
+        &[&tasks.len().to_string(), request_id],
+    );
+    output.push_str("\n\n");
+
+    for task in tasks {
+        output.push_str(&format!(
+            "{}: {} - {}\n",
+            tr("ID"),
+            task.id,
+            task.display_title()
+        ));
+        output.push_str(&format!(
+            "   {}: {} | {}: {}\n",
+            tr("Status"),
+            task.display_status(),
+            tr("Owner"),
+            task.display_owner()
+        ));
+        if let Some(due) = task.display_due() {
+            output.push_str(&format!("   {}: {}\n", tr("Due"), due));
+        }
+        if task.overdue == Some(true) {
+            output.push_str(&format!("   [{}]\n", tr("OVERDUE")));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats the result of a create, update, or complete task operation.
+fn format_task_result(action: &'static str, request_id: &str, task: &Task) -> String {
+    let mut output = tr_fill(
+        "Successfully {} task #{} on ticket #{}: {}",
+        &[tr(action), &task.id, request_id, task.display_title()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), task.display_status()));
+    output.push_str(&format!("{}: {}\n", tr("Owner"), task.display_owner()));
+    if let Some(group) = task.group.as_ref().and_then(|g| g.name.as_deref()) {
+        output.push_str(&format!("{}: {}\n", tr("Group"), group));
+    }
+    if let Some(due) = task.display_due() {
+        output.push_str(&format!("{}: {}\n", tr("Due"), due));
+    }
+
+    output
+}
+
+// ============================================================================
+// Project formatting helpers
+// ============================================================================
+
+/// Formats a completion percentage returned by SDP (number or string).
+fn format_percentage(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Number(n) => Some(format!("{}%", n)),
+        serde_json::Value::String(s) if !s.is_empty() => Some(format!("{}%", s)),
+        _ => None,
+    }
+}
+
+/// Formats a list of projects as human-readable text.
+fn format_project_list(projects: &[Project]) -> String {
+    if projects.is_empty() {
+        return "No projects found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} project(s):", &[&projects.len().to_string()]);
+    output.push_str("\n\n");
+
+    for project in projects {
+        output.push_str(&format!(
+            "{}: {} - {}\n",
+            tr("ID"),
+            project.id,
+            project.display_title()
+        ));
+        output.push_str(&format!(
+            "   {}: {} | {}: {}\n",
+            tr("Status"),
+            project.display_status(),
+            tr("Owner"),
+            project.display_owner()
+        ));
+        if let Some(due) = project.display_due() {
+            output.push_str(&format!("   {}: {}\n", tr("Ends"), due));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a project with its milestones as human-readable text.
+fn format_project_details(
+    project: &Project,
+    milestones: &[Milestone],
+    milestones_error: Option<&str>,
+    max_length: usize,
+) -> String {
+    let mut output = format!(
+        "{} #{}: {}\n",
+        tr("Project"),
+        project.id,
+        project.display_title()
+    );
+    output.push_str(&"=".repeat(60));
+    output.push('\n');
+
+    output.push_str(&format!(
+        "\n{}: {}\n",
+        tr("Status"),
+        project.display_status()
+    ));
+    output.push_str(&format!("{}: {}\n", tr("Owner"), project.display_owner()));
+    if let Some(priority) = project.priority.as_ref().and_then(|p| p.name.as_deref()) {
+        output.push_str(&format!("{}: {}\n", tr("Priority"), priority));
+    }
+    if let Some(project_type) = project
+        .project_type
+        .as_ref()
+        .and_then(|t| t.name.as_deref())
+    {
+        output.push_str(&format!("{}: {}\n", tr("Type"), project_type));
+    }
+    if let Some(start) = project
+        .scheduled_start_time
+        .as_ref()
+        .and_then(|t| t.display())
+    {
+        output.push_str(&format!("{}: {}\n", tr("Starts"), start));
+    }
+    if let Some(due) = project.display_due() {
+        output.push_str(&format!("{}: {}\n", tr("Ends"), due));
+    }
+    if let Some(completion) = project
+        .percentage_completion
+        .as_ref()
+        .and_then(format_percentage)
+    {
+        output.push_str(&format!("{}: {}\n", tr("Completion"), completion));
+    }
+
+    if let Some(description) = project.description.as_deref().filter(|d| !d.is_empty()) {
+        output.push_str(&format!("\n--- {} ---\n", tr("Description")));
+        output.push_str(&truncate_text(&html_to_text(description), max_length));
+        output.push('\n');
+    }
+
+    output.push_str(&format!("\n--- {} ---\n", tr("Milestones")));
+    if let Some(error) = milestones_error {
+        output.push_str(&tr_fill("(Could not fetch milestones: {})", &[error]));
+        output.push('\n');
+    } else if milestones.is_empty() {
+        output.push_str(tr("No milestones."));
+        output.push('\n');
+    }
+    for milestone in milestones {
+        output.push_str(&format!(
+            "{}: {} - {} [{}]",
+            tr("ID"),
+            milestone.id,
+            milestone.display_title(),
+            milestone.display_status()
+        ));
+        if let Some(due) = milestone
+            .scheduled_end_time
+            .as_ref()
+            .and_then(|t| t.display())
+        {
+            output.push_str(&format!(" | {}: {}", tr("Ends"), due));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats the result of a create or update project operation.
+fn format_project_result(action: &'static str, project: &Project) -> String {
+    let mut output = tr_fill(
+        "Successfully {} project #{}: {}",
+        &[tr(action), &project.id, project.display_title()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), project.display_status()));
+    output.push_str(&format!("{}: {}\n", tr("Owner"), project.display_owner()));
+    if let Some(due) = project.display_due() {
+        output.push_str(&format!("{}: {}\n", tr("Ends"), due));
+    }
+
+    output
+}
+
+/// Formats a list of project tasks as human-readable text.
+fn format_project_task_list(project_id: &str, tasks: &[Task]) -> String {
+    if tasks.is_empty() {
+        return tr_fill("No tasks on project #{}.", &[project_id]);
+    }
+
+    let mut output = tr_fill(
+        "Found {} task(s) on project #{}:",
+        &[&tasks.len().to_string(), project_id],
+    );
+    output.push_str("\n\n");
+
+    for task in tasks {
+        output.push_str(&format!(
+            "{}: {} - {}\n",
+            tr("ID"),
+            task.id,
+            task.display_title()
+        ));
+        output.push_str(&format!(
+            "   {}: {} | {}: {}\n",
+            tr("Status"),
+            task.display_status(),
+            tr("Owner"),
+            task.display_owner()
+        ));
+        if let Some(due) = task.display_due() {
+            output.push_str(&format!("   {}: {}\n", tr("Due"), due));
+        }
+        if task.overdue == Some(true) {
+            output.push_str(&format!("   [{}]\n", tr("OVERDUE")));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats the result of a create or update project task operation.
+fn format_project_task_result(action: &'static str, project_id: &str, task: &Task) -> String {
+    let mut output = tr_fill(
+        "Successfully {} task #{} on project #{}: {}",
+        &[tr(action), &task.id, project_id, task.display_title()],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!("{}: {}\n", tr("Status"), task.display_status()));
+    output.push_str(&format!("{}: {}\n", tr("Owner"), task.display_owner()));
+    if let Some(due) = task.display_due() {
+        output.push_str(&format!("{}: {}\n", tr("Due"), due));
+    }
+
+    output
+}
+
+// ============================================================================
+// Approval formatting helpers
+// ============================================================================
+
+/// Formats approval levels and their approvals as human-readable text.
+///
+/// `approvals_by_level` must be parallel to `levels`; an `Err` entry holds
+/// the sanitized error from fetching that level's approvals.
+fn format_approval_list(
+    request_id: &str,
+    levels: &[ApprovalLevel],
+    approvals_by_level: &[Result<Vec<Approval>, String>],
+) -> String {
+    if levels.is_empty() {
+        return tr_fill("No approvals on ticket #{}.", &[request_id]);
+    }
+
+    let mut output = tr_fill(
+        "Found {} approval level(s) on ticket #{}:",
+        &[&levels.len().to_string(), request_id],
+    );
+    output.push('\n');
+
+    for (level, approvals) in levels.iter().zip(approvals_by_level) {
+        output.push_str(&format!(
+            "\nLevel {} ({}: {}) - {}\n",
+            level.display_level(),
+            tr("ID"),
+            level.id,
+            level.display_status()
+        ));
+
+        match approvals {
+            Ok(approvals) if approvals.is_empty() => {
+                output.push_str("   No approvers on this level.\n");
+            }
+            Ok(approvals) => {
+                for approval in approvals {
+                    output.push_str(&format!(
+                        "   {}: {} | {}: {} | {}: {}\n",
+                        tr("Approval ID"),
+                        approval.id,
+                        tr("Approver"),
+                        approval.display_approver(),
+                        tr("Status"),
+                        approval.display_status()
+                    ));
+                    if let Some(acted) = approval.action_taken_on.as_ref().and_then(|t| t.display())
+                    {
+                        output.push_str(&format!("      {}: {}\n", tr("Decided"), acted));
+                    }
+                    if let Some(comments) = &approval.comments {
+                        output.push_str(&format!(
+                            "      {}: {}\n",
+                            tr("Comments"),
+                            truncate_text(comments, 500)
+                        ));
+                    }
+                }
+            }
+            Err(e) => {
+                output.push_str(&format!("   Warning: Failed to fetch approvals: {}\n", e));
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns approval levels with their approvals as JSON.
+///
+/// A level whose approvals failed to load carries the error instead.
+fn approval_levels_json(
+    levels: &[ApprovalLevel],
+    approvals_by_level: &[Result<Vec<Approval>, String>],
+) -> serde_json::Value {
+    levels
+        .iter()
+        .zip(approvals_by_level)
+        .map(|(level, approvals)| match approvals {
+            Ok(approvals) => json!({"level": level, "approvals": approvals}),
+            Err(error) => json!({"level": level, "error": error}),
+        })
+        .collect()
+}
+
+// ============================================================================
+// Reply formatting helpers
+// ============================================================================
+
+/// Builds the default reply subject for a ticket, tagged for SDP threading.
+fn default_reply_subject(request: &Request) -> String {
+    format!(
+        "Re: {} : {}",
+        REPLY_SUBJECT_TAG.replace("{id}", &request.id),
+        request.display_subject()
+    )
+}
+
+/// A survey response together with the ticket it belongs to.
+#[derive(Debug, Serialize)]
+struct SurveyResult {
+    request_id: String,
+    subject: Option<String>,
+    #[serde(flatten)]
+    response: SurveyResponse,
+}
+
+/// Formats survey responses, worst rated first as the caller filtered them.
+fn format_survey_results(
+    results: &[SurveyResult],
+    checked: usize,
+    max_rating: Option<u32>,
+    failed: &[String],
+) -> String {
+    let filter = max_rating
+        .map(|max| format!(" rated {} or lower", max))
+        .unwrap_or_default();
+    let mut output = if results.is_empty() {
+        format!(
+            "No survey responses{} among {} ticket(s) checked.\n",
+            filter, checked
+        )
+    } else {
+        format!(
+            "Found {} survey response(s){} among {} ticket(s) checked:\n\n",
+            results.len(),
+            filter,
+            checked
+        )
+    };
+
+    for result in results {
+        let subject = result
+            .subject
+            .as_deref()
+            .map(|s| format!(" - {}", s))
+            .unwrap_or_default();
+        output.push_str(&format!("#{}{}\n", result.request_id, subject));
+        let response = &result.response;
+        output.push_str(&format!(
+            "   Rating: {}\n",
+            response
+                .display_rating()
+                .as_deref()
+                .unwrap_or(tr("Unknown"))
+        ));
+        if let Some(requester) = response.display_requester() {
+            output.push_str(&format!("   {}: {}\n", tr("Requester"), requester));
+        }
+        if let Some(on) = response.responded_on.as_ref().and_then(|t| t.display()) {
+            output.push_str(&format!("   Answered: {}\n", on));
+        }
+        if let Some(comments) = response
+            .comments
+            .as_deref()
+            .filter(|c| !c.trim().is_empty())
+        {
+            output.push_str(&format!(
+                "   {}: {}\n",
+                tr("Comments"),
+                truncate_text(comments, 500)
+            ));
+        }
+        for answer in &response.answers {
+            if let (Some(question), Some(reply)) =
+                (answer.display_question(), answer.display_answer())
+            {
+                output.push_str(&format!("   - {}: {}\n", question, reply));
+            }
+        }
+        output.push('\n');
+    }
+
+    if !failed.is_empty() {
+        output.push_str(&format!("\nCould not read {} survey(s):\n", failed.len()));
+        for error in failed {
+            output.push_str(&format!("   {}\n", error));
+        }
+    }
+
+    output
+}
+
+/// Formats the emails SDP sent on a ticket, oldest first as SDP lists them.
+fn format_notification_list(request_id: &str, notifications: &[Notification]) -> String {
+    if notifications.is_empty() {
+        return tr_fill("No emails sent on ticket #{}.", &[request_id]);
+    }
+
+    let mut output = tr_fill(
+        "Found {} email(s) sent on ticket #{}:",
+        &[&notifications.len().to_string(), request_id],
+    );
+    output.push_str("\n\n");
+
+    for notification in notifications {
+        let sent = notification
+            .sent_time
+            .as_ref()
+            .and_then(|t| t.display())
+            .unwrap_or(tr("Unknown time"));
+        let kind = notification
+            .notification_type
+            .as_deref()
+            .unwrap_or(tr("email"));
+        output.push_str(&format!(
+            "[{}] {} ({})\n",
+            sent,
+            notification
+                .subject
+                .as_deref()
+                .unwrap_or(tr("(No subject)")),
+            kind
+        ));
+        output.push_str(&format!(
+            "   {}: {} | {}: {}\n",
+            tr("From"),
+            notification.display_sender(),
+            tr("To"),
+            notification.display_to()
+        ));
+        let cc = notification.display_cc();
+        if !cc.is_empty() {
+            output.push_str(&format!("   {}: {}\n", tr("Cc"), cc));
+        }
+        if let Some(body) = &notification.description {
+            output.push_str(&format!("   {}\n", truncate_text(&html_to_text(body), 300)));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Notification fields shown in the header block of
+/// [`format_notification_details`]; any others are listed after it.
+const NOTIFICATION_HEADER_FIELDS: [&str; 9] = [
+    "id",
+    "subject",
+    "description",
+    "to",
+    "cc",
+    "bcc",
+    "sender",
+    "sent_time",
+    "type",
+];
+
+/// Formats one sent email in full: headers, other recorded fields, and body.
+fn format_notification_details(
+    request_id: &str,
+    fetched: &RawNotification,
+    include_html: bool,
+) -> String {
+    let notification = &fetched.notification;
+    let mut output = tr_fill(
+        "Email #{} sent on ticket #{}",
+        &[&notification.id, request_id],
+    );
+    output.push_str("\n\n");
+
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("From"),
+        notification.display_sender()
+    ));
+    output.push_str(&format!("{}: {}\n", tr("To"), notification.display_to()));
+    for (label, recipients) in [
+        (tr("Cc"), notification.display_cc()),
+        (tr("Bcc"), notification.display_bcc()),
+    ] {
+        if !recipients.is_empty() {
+            output.push_str(&format!("{}: {}\n", label, recipients));
+        }
+    }
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Subject"),
+        notification
+            .subject
+            .as_deref()
+            .unwrap_or(tr("(No subject)"))
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Sent"),
+        notification
+            .sent_time
+            .as_ref()
+            .and_then(|t| t.display())
+            .unwrap_or(tr("Unknown time"))
+    ));
+    output.push_str(&format!(
+        "{}: {}\n",
+        tr("Type"),
+        notification
+            .notification_type
+            .as_deref()
+            .unwrap_or(tr("email"))
+    ));
+
+    if let Some(fields) = fetched.raw.as_object() {
+        for (key, value) in fields {
+            if NOTIFICATION_HEADER_FIELDS.contains(&key.as_str()) || value.is_null() {
+                continue;
+            }
+            let text = display_json_value(value).unwrap_or_else(|| value.to_string());
+            output.push_str(&format!("{}: {}\n", key, text));
+        }
+    }
+
+    output.push_str(&format!("\n--- {} ---\n", tr("Content")));
+    match notification.description.as_deref() {
+        Some(body) => {
+            output.push_str(html_to_text(body).trim_end());
+            output.push('\n');
+            if include_html {
+                output.push_str("\n--- HTML ---\n");
+                output.push_str(body.trim_end());
+                output.push('\n');
+            }
+        }
+        None => {
+            output.push_str(tr("(No content)"));
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Formats the result of a reply or notification operation.
+fn format_reply_result(
+    request_id: &str,
+    reply: &ReplyRequest,
+    notification: &Notification,
+) -> String {
+    let recipients = |list: &[crate::models::EmailRecipient]| {
+        list.iter()
+            .map(|r| r.email_id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let kind = if reply.notification_type == "reply" {
+        "reply"
+    } else {
+        "notification"
+    };
+    let mut output = tr_fill(
+        "Successfully sent {} #{} on ticket #{}.",
+        &[tr(kind), &notification.id, request_id],
+    );
+    output.push_str("\n\n");
+    output.push_str(&format!("{}: {}\n", tr("Subject"), reply.subject));
+    output.push_str(&format!("{}: {}\n", tr("To"), recipients(&reply.to)));
+    if !reply.cc.is_empty() {
+        output.push_str(&format!("{}: {}\n", tr("Cc"), recipients(&reply.cc)));
+    }
+    if let Some(sent) = notification.sent_time.as_ref().and_then(|t| t.display()) {
+        output.push_str(&format!("{}: {}\n", tr("Sent"), sent));
+    }
+
+    output
+}
+
+// ============================================================================
+// Knowledge base formatting helpers
+// ============================================================================
+
+/// Builds knowledge base article content from a ticket's description and resolution.
+///
+/// Returns `None` if the ticket has no resolution.
+fn build_solution_content(request: &Request) -> Option<String> {
+    let resolution = request
+        .resolution
+        .as_ref()
+        .and_then(|r| r.content.as_deref())
+        .filter(|c| !c.trim().is_empty())?;
+
+    let mut content = String::new();
+    if let Some(description) = request
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        content.push_str("<h3>Problem</h3>\n");
+        content.push_str(description);
+        content.push_str("\n<h3>Solution</h3>\n");
+    }
+    content.push_str(resolution);
+
+    Some(content)
+}
+
+/// Formats the result of a create solution operation.
+fn format_create_solution_result(solution: &Solution, request_id: &str) -> String {
+    let mut output = tr_fill(
+        "Successfully created solution #{} from ticket #{}: {}",
+        &[&solution.id, request_id, solution.display_title()],
+    );
+    output.push('\n');
+
+    if let Some(topic) = solution.display_topic() {
+        output.push_str(&format!("\n{}: {}\n", tr("Topic"), topic));
+    }
+    if let Some(status) = solution.display_approval_status() {
+        output.push_str(&format!("{}: {}\n", tr("Approval"), status));
+    }
+
+    output
+}
+
+/// Formats a list of solutions as human-readable text.
+fn format_solution_list(solutions: &[Solution]) -> String {
+    if solutions.is_empty() {
+        return "No solutions found.".to_string();
+    }
+
+    let mut output = tr_fill("Found {} solution(s):", &[&solutions.len().to_string()]);
+    output.push_str("\n\n");
+
+    for solution in solutions {
+        output.push_str(&format!(
+            "{}: {} | {}",
+            tr("ID"),
+            solution.id,
+            solution.display_title()
+        ));
+        if let Some(topic) = solution.display_topic() {
+            output.push_str(&format!(" | {}: {}", tr("Topic"), topic));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Formats a solution with its content as human-readable text.
+fn format_solution_details(solution: &Solution, max_length: usize) -> String {
+    let mut output = format!(
+        "{} #{}: {}\n",
+        tr("Solution"),
+        solution.id,
+        solution.display_title()
+    );
+
+    if let Some(topic) = solution.display_topic() {
+        output.push_str(&format!("{}: {}\n", tr("Topic"), topic));
+    }
+    if let Some(keywords) = solution.keywords.as_deref().filter(|k| !k.is_empty()) {
+        output.push_str(&format!("{}: {}\n", tr("Keywords"), keywords));
+    }
+    if let Some(status) = solution.display_approval_status() {
+        output.push_str(&format!("{}: {}\n", tr("Approval"), status));
+    }
+    if let Some(author) = solution.created_by.as_ref().and_then(|c| c.name.as_deref()) {
+        output.push_str(&format!("{}: {}\n", tr("Author"), author));
+    }
+    if let Some(updated) = solution
+        .last_updated_time
+        .as_ref()
+        .and_then(|t| t.display())
+    {
+        output.push_str(&format!("{}: {}\n", tr("Updated"), updated));
+    }
+
+    if let Some(description) = &solution.description {
+        output.push_str(&format!("\n--- {} ---\n", tr("Content")));
+        output.push_str(&truncate_text(description, max_length));
+        output.push('\n');
+    }
+
+    output
+}
+
+// ============================================================================
+// Markdown tables (format="markdown")
+// ============================================================================
+
+/// Escapes a value for a markdown table cell.
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Builds a markdown table from a header row and data rows.
+fn markdown_table(headers: &[&'static str], rows: Vec<Vec<String>>) -> String {
+    let headers: Vec<&str> = headers.iter().map(|h| tr(h)).collect();
+    let mut output = format!("| {} |\n", headers.join(" | "));
+    output.push_str(&format!("|{}\n", "---|".repeat(headers.len())));
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|c| markdown_cell(c)).collect();
+        output.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    output
+}
+
+/// Formats ticket summaries as a markdown table.
+fn markdown_request_table(requests: &[RequestSummary]) -> String {
+    markdown_table(
+        &[
+            "ID",
+            "Subject",
+            "Status",
+            "Priority",
+            "Technician",
+            "Requester",
+        ],
+        requests
+            .iter()
+            .map(|r| {
+                vec![
+                    r.id.clone(),
+                    r.display_subject().to_string(),
+                    r.display_status().to_string(),
+                    r.display_priority().to_string(),
+                    r.display_technician().to_string(),
+                    r.display_requester().to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// Quotes a CSV field when needed (RFC 4180).
+///
+/// Text that a spreadsheet would run as a formula (starting with `=`, `+`,
+/// `-`, or `@`) is prefixed with an apostrophe, since ticket subjects come
+/// from requesters.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Formats ticket summaries as CSV with a header row.
+fn requests_csv(requests: &[RequestSummary], web_url: impl Fn(&str) -> String) -> String {
+    let name = |entity: &Option<NamedEntity>| {
+        entity
+            .as_ref()
+            .and_then(|e| e.name.clone())
+            .unwrap_or_default()
+    };
+    let time = |timestamp: &Option<SdpTimestamp>| {
+        timestamp
+            .as_ref()
+            .and_then(|t| t.display())
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let headers = [
+        "ID",
+        "Subject",
+        "Status",
+        "Priority",
+        "Technician",
+        "Requester",
+        "Group",
+        "Site",
+        "Category",
+        "Type",
+        "Created",
+        "Due By",
+        "Last updated",
+        "Link",
+    ];
+    let mut output = headers.map(tr).join(",");
+    output.push_str("\r\n");
+    for request in requests {
+        let row = [
+            request.id.clone(),
+            request.subject.clone().unwrap_or_default(),
+            name(&request.status),
+            name(&request.priority),
+            name(&request.technician),
+            name(&request.requester),
+            name(&request.group),
+            name(&request.site),
+            name(&request.category),
+            name(&request.request_type),
+            time(&request.created_time),
+            time(&request.due_by_time),
+            time(&request.last_updated_time),
+            web_url(&request.id),
+        ];
+        let cells: Vec<String> = row.iter().map(|cell| csv_field(cell)).collect();
+        output.push_str(&cells.join(","));
+        output.push_str("\r\n");
+    }
+    output
+}
+
+/// Writes `contents` to a new file at `path`, failing if the file exists.
+async fn write_new_file(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .await?;
+    file.write_all(contents).await?;
+    file.flush().await
+}
+
+/// Picks the file name of a saved export, adding the `.csv` extension when
+/// missing and defaulting to a timestamped name.
+fn export_file_name<Tz: chrono::TimeZone>(
+    requested: Option<&str>,
+    now: &chrono::DateTime<Tz>,
+) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    match requested {
+        Some(name) if name.to_ascii_lowercase().ends_with(".csv") => name.to_string(),
+        Some(name) => format!("{}.csv", name),
+        None => format!("requests-{}.csv", now.format("%Y%m%d-%H%M%S")),
+    }
+}
+
+/// Formats technicians as a markdown table.
+fn markdown_technician_table(technicians: &[Technician]) -> String {
+    markdown_table(
+        &["ID", "Name", "Email", "Active"],
+        technicians
+            .iter()
+            .map(|t| {
+                vec![
+                    t.id.clone(),
+                    t.display_name().to_string(),
+                    t.email().unwrap_or_default().to_string(),
+                    if t.is_active == Some(false) {
+                        "no"
+                    } else {
+                        "yes"
+                    }
+                    .to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// Formats requesters as a markdown table.
+fn markdown_requester_table(requesters: &[Requester]) -> String {
+    markdown_table(
+        &["ID", "Name", "Email", "Department", "VIP"],
+        requesters
+            .iter()
+            .map(|r| {
+                vec![
+                    r.id.clone(),
+                    r.display_name().to_string(),
+                    r.email_id.clone().unwrap_or_default(),
+                    r.display_department().unwrap_or_default().to_string(),
+                    if r.is_vip() { "yes" } else { "no" }.to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// Formats assets as a markdown table.
+fn markdown_asset_table(assets: &[Asset]) -> String {
+    markdown_table(
+        &["ID", "Name", "Product", "Serial", "User"],
+        assets
+            .iter()
+            .map(|a| {
+                vec![
+                    a.id.clone(),
+                    a.display_name().to_string(),
+                    a.display_product().unwrap_or_default().to_string(),
+                    a.serial_number.clone().unwrap_or_default(),
+                    a.display_user().unwrap_or_default().to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// Formats projects as a markdown table.
+fn markdown_project_table(projects: &[Project]) -> String {
+    markdown_table(
+        &["ID", "Title", "Status", "Owner", "Ends"],
+        projects
+            .iter()
+            .map(|p| {
+                vec![
+                    p.id.clone(),
+                    p.display_title().to_string(),
+                    p.display_status().to_string(),
+                    p.display_owner().to_string(),
+                    p.display_due().unwrap_or_default().to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// Formats knowledge base solutions as a markdown table.
+fn markdown_solution_table(solutions: &[Solution]) -> String {
+    markdown_table(
+        &["ID", "Title", "Topic"],
+        solutions
+            .iter()
+            .map(|s| {
+                vec![
+                    s.id.clone(),
+                    s.display_title().to_string(),
+                    s.display_topic().unwrap_or_default().to_string(),
+                ]
+            })
+            .collect(),
+    )
+}
+
+// ============================================================================
+// Confirmation helpers
+// ============================================================================
+
+/// How long to wait for the user to answer a confirmation request.
+const CONFIRMATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Error returned when the user turns down a destructive action.
+const NOT_CONFIRMED_MESSAGE: &str =
+    "Cancelled: the user did not confirm this action. Nothing was changed.";
+
+/// The user's answer to a confirmation request.
+#[derive(Debug, Deserialize, JsonSchema)]
+struct Confirmation {
+    /// Set to true to go ahead.
+    confirm: bool,
+}
+
+rmcp::elicit_safe!(Confirmation);
+
+/// Asks the user to confirm a destructive action through MCP elicitation.
+///
+/// The question goes to the user through the client, not to the assistant.
+/// Clients without elicitation support skip it, leaving confirmation to the
+/// assistant as before.
+async fn confirm_action(
+    context: &RequestContext<RoleServer>,
+    message: String,
+) -> Result<(), String> {
+    let answer = tokio::select! {
+        answer = context
+            .peer
+            .elicit_with_timeout::<Confirmation>(message, Some(CONFIRMATION_TIMEOUT)) => answer,
+        () = context.ct.cancelled() => return Err(crate::error::GlassError::Cancelled.to_string()),
+    };
+    confirmation_outcome(answer)
+}
+
+/// Decides whether to go ahead from the user's answer.
+fn confirmation_outcome(
+    answer: Result<Option<Confirmation>, ElicitationError>,
+) -> Result<(), String> {
+    match answer {
+        Ok(Some(Confirmation { confirm: true })) => Ok(()),
+        Err(ElicitationError::CapabilityNotSupported) => Ok(()),
+        Ok(_) | Err(ElicitationError::UserDeclined | ElicitationError::UserCancelled) => {
+            tracing::info!("Destructive action not confirmed by the user");
+            Err(NOT_CONFIRMED_MESSAGE.to_string())
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to ask the user for confirmation");
+            Err(format!(
+                "Could not get the user's confirmation, so nothing was changed: {}",
+                e
+            ))
+        }
+    }
+}
+
+/// Describes a bulk operation's tickets for a confirmation question,
+/// e.g. "3 ticket(s) (#1, #2, #3)".
+fn ticket_list(ids: &[String]) -> String {
+    const SHOWN: usize = 10;
+    let mut shown: Vec<String> = ids
+        .iter()
+        .take(SHOWN)
+        .map(|id| format!("#{}", id))
+        .collect();
+    if ids.len() > SHOWN {
+        shown.push(tr_fill("and {} more", &[&(ids.len() - SHOWN).to_string()]));
+    }
+    tr_fill(
+        "{} ticket(s) ({})",
+        &[&ids.len().to_string(), &shown.join(", ")],
+    )
+}
+
+// ============================================================================
+// Bulk operation helpers
+// ============================================================================
+
+/// Outcome of a bulk operation for one ticket: the updated request or a sanitized error.
+type BulkOutcome = (String, Result<Request, String>);
+
+/// Sends MCP progress notifications for one tool call.
+///
+/// Does nothing unless the client asked for progress by sending a
+/// progress token with the call.
+struct Progress {
+    target: Option<(Peer<RoleServer>, ProgressToken)>,
+}
+
+impl Progress {
+    /// Reads the progress token from the tool call's request context.
+    fn new(context: &RequestContext<RoleServer>) -> Self {
+        Self {
+            target: context
+                .meta
+                .get_progress_token()
+                .map(|token| (context.peer.clone(), token)),
+        }
+    }
+
+    /// Returns a reporter that sends nothing.
+    #[cfg(test)]
+    fn none() -> Self {
+        Self { target: None }
+    }
+
+    /// Reports that `done` of `total` steps are complete.
+    ///
+    /// Delivery failures are logged and otherwise ignored: progress is
+    /// best-effort and must not fail the operation.
+    async fn report(&self, done: usize, total: usize, message: String) {
+        let Some((peer, token)) = &self.target else {
+            return;
+        };
+        let param = ProgressNotificationParam {
+            progress_token: token.clone(),
+            progress: done as f64,
+            total: Some(total as f64),
+            message: Some(message),
+        };
+        if let Err(e) = peer.notify_progress(param).await {
+            tracing::debug!(error = %e, "Failed to send progress notification");
+        }
+    }
+}
+
+/// Runs `op` for every ID with at most `BULK_CONCURRENCY` operations in flight.
+///
+/// Results are returned in the same order as `ids`. Progress is reported
+/// as each operation finishes, e.g. "closed 7/25 tickets".
+async fn run_bulk<T, F, Fut>(
+    ids: &[String],
+    progress: &Progress,
+    action: &str,
+    op: F,
+) -> Vec<(String, Result<T, crate::error::GlassError>)>
+where
+    T: Send + 'static,
+    F: Fn(String) -> Fut,
+    Fut: std::future::Future<Output = Result<T, crate::error::GlassError>> + Send + 'static,
+{
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut results: Vec<Option<Result<T, crate::error::GlassError>>> =
+        ids.iter().map(|_| None).collect();
+    let mut pending = ids.iter().cloned().enumerate();
+
+    loop {
+        while tasks.len() < BULK_CONCURRENCY {
+            let Some((index, id)) = pending.next() else {
+                break;
+            };
+            let fut = op(id);
+            tasks.spawn(async move { (index, fut.await) });
+        }
+        match tasks.join_next().await {
+            Some(Ok((index, result))) => results[index] = Some(result),
+            Some(Err(e)) => tracing::error!(error = %e, "Bulk operation task failed"),
+            None => break,
+        }
+        let done = ids.len() - pending.len() - tasks.len();
+        progress
+            .report(
+                done,
+                ids.len(),
+                format!("{} {}/{} tickets", action, done, ids.len()),
+            )
+            .await;
+    }
+
+    ids.iter()
+        .cloned()
+        .zip(results)
+        .map(|(id, result)| {
+            let result = result.unwrap_or_else(|| {
+                Err(crate::error::GlassError::validation(
+                    "operation did not complete",
+                ))
+            });
+            (id, result)
+        })
+        .collect()
+}
+
+/// Formats per-ticket results of a bulk operation.
+///
+/// `action` is the verb used in the summary, e.g. "update" or "close".
+fn format_bulk_results(action: &'static str, outcomes: &[BulkOutcome]) -> String {
+    let succeeded: Vec<&Request> = outcomes
+        .iter()
+        .filter_map(|(_, r)| r.as_ref().ok())
+        .collect();
+    let failed: Vec<(&String, &String)> = outcomes
+        .iter()
+        .filter_map(|(id, r)| r.as_ref().err().map(|e| (id, e)))
+        .collect();
+
+    let mut output = tr_fill(
+        "Bulk {}: {} of {} ticket(s) succeeded",
+        &[
+            tr(action),
+            &succeeded.len().to_string(),
+            &outcomes.len().to_string(),
+        ],
+    );
+    if failed.is_empty() {
+        output.push_str(".\n");
+    } else {
+        output.push_str(&tr_fill(", {} failed.", &[&failed.len().to_string()]));
+        output.push('\n');
+    }
+
+    if !succeeded.is_empty() {
+        output.push_str(&format!("\n{}\n", tr("Succeeded:")));
+        for request in succeeded {
+            output.push_str(&format!(
+                "  #{} - {} ({}: {} | {}: {} | {}: {})\n",
+                request.id,
+                request.display_subject(),
+                tr("Status"),
+                request.display_status(),
+                tr("Priority"),
+                request.display_priority(),
+                tr("Assignee"),
+                request.display_technician()
+            ));
+        }
+    }
+
+    if !failed.is_empty() {
+        output.push_str(&format!("\n{}\n", tr("Failed:")));
+        for (id, error) in failed {
+            output.push_str(&format!("  #{}: {}\n", id, error));
+        }
+    }
+
+    output
+}
+
+/// Returns per-ticket results of a bulk operation as `{id, ok, error}` objects.
+fn bulk_results_json(outcomes: &[BulkOutcome]) -> serde_json::Value {
+    outcomes
+        .iter()
+        .map(|(id, result)| json!({"id": id, "ok": result.is_ok(), "error": result.as_ref().err()}))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::models::{NamedEntity, SdpTimestamp};
+
+    // ========================================================================
+    // Truncation tests
+    // ========================================================================
+
+    #[test]
+    fn test_truncate_text_short_text() {
+        let text = "Short text";
+        let result = truncate_text(text, 100);
+        assert_eq!(result, text);
+    }
+
+    #[test]
+    fn test_truncate_text_exact_length() {
+        let text = "x".repeat(100);
+        let result = truncate_text(&text, 100);
+        assert_eq!(result, text);
+    }
+
+    #[test]
+    fn test_truncate_text_long_text() {
+        let text = "word ".repeat(500); // 2500 chars
+        let result = truncate_text(&text, 100);
+        assert!(result.len() <= 100);
+        assert!(result.ends_with("... [truncated]"));
+    }
+
+    #[test]
+    fn test_truncate_text_breaks_at_word() {
+        let text = "hello world this is a long sentence that needs truncation";
+        let result = truncate_text(text, 30);
+        assert!(result.ends_with("... [truncated]"));
+        // Should break at a word boundary, not in the middle of a word
+        assert!(!result.contains("sente... [truncated]"));
+    }
+
+    #[test]
+    fn test_truncate_text_multibyte_utf8() {
+        // Danish characters (multi-byte) should not cause a panic
+        let text = "Ædansen gik over æblegrøden og blåbærkagen var også meget lækker i dag";
+        let result = truncate_text(text, 30);
+        assert!(result.ends_with("... [truncated]"));
+        // Verify the result is valid UTF-8 (this would panic if not)
+        assert!(result.len() <= 50); // 30 + room for suffix
+    }
+
+    #[test]
+    fn test_truncate_text_counts_characters() {
+        // 100 characters but 200 bytes: fits
+        let text = "æ".repeat(100);
+        assert_eq!(truncate_text(&text, 100), text);
+
+        let text = "ø".repeat(200);
+        let result = truncate_text(&text, 100);
+        assert_eq!(result.chars().count(), 100);
+        assert!(result.ends_with("... [truncated]"));
+    }
+
+    #[test]
+    fn test_truncate_text_keeps_combining_marks() {
+        // "e" + combining acute accent, with the cut landing on the accent
+        let text = format!("{}e\u{301}{}", "x".repeat(84), "y".repeat(50));
+        let result = truncate_text(&text, 100);
+        assert_eq!(result, format!("{}... [truncated]", "x".repeat(84)));
+    }
+
+    fn test_config() -> Config {
+        // Set env vars for Config::from_env() since api_key is private
+        std::env::set_var("SDP_BASE_URL", "https://test.example.com");
+        std::env::set_var("SDP_API_KEY", "test_key_12345");
+        Config::from_env().expect("Failed to create test config")
+    }
+
+    fn test_client() -> SdpClient {
+        SdpClient::new(&test_config()).expect("Failed to create test client")
+    }
+
+    #[test]
+    fn test_server_creation() {
+        let client = test_client();
+        let server = GlassServer::new(client);
+        let info = server.get_info();
+        assert!(info.instructions.is_some());
+    }
+
+    #[test]
+    fn test_server_info_has_tools_capability() {
+        let client = test_client();
+        let server = GlassServer::new(client);
+        let info = server.get_info();
+        assert!(info.capabilities.tools.is_some());
+    }
+
+    #[test]
+    fn test_ping_tool_returns_pong() {
+        let client = test_client();
+        let server = GlassServer::new(client);
+        let result = server.ping();
+        assert_eq!(result, "pong");
+    }
+
+    #[tokio::test]
+    async fn test_write_tool_uses_portal() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let sdp = MockServer::start().await;
+        let mut config = test_config();
+        config.base_url = sdp.uri();
+        let server = GlassServer::new(SdpClient::new(&config).unwrap());
+        Mock::given(method("POST"))
+            .and(path("/api/v3/requests/7/notes"))
+            .and(header("PORTALID", "1002"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response_status": {"status_code": 2000, "status": "success"},
+                "note": {"id": "55", "description": "Checked the VPN logs"},
+            })))
+            .expect(1)
+            .mount(&sdp)
+            .await;
+
+        let result = server
+            .add_note(Parameters(AddNoteInput {
+                request_id: "7".to_string(),
+                content: "Checked the VPN logs".to_string(),
+                show_to_requester: None,
+                notify_technician: None,
+                mark_first_response: None,
+                portal: Some("1002".to_string()),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false), "{:?}", result.content);
+    }
+
+    #[tokio::test]
+    async fn test_hold_request_uses_instance_status_names() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let sdp = MockServer::start().await;
+        let mut config = test_config();
+        config.base_url = sdp.uri();
+        let server = GlassServer::new(SdpClient::new(&config).unwrap());
+        let ok = serde_json::json!({"status_code": 2000, "status": "success"});
+        Mock::given(method("GET"))
+            .and(path("/api/v3/statuses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response_status": ok,
+                "statuses": [
+                    {"id": "1", "name": "Åben"},
+                    {"id": "2", "name": "På hold"},
+                    {"id": "3", "name": "Lukket"}
+                ]
+            })))
+            .mount(&sdp)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/api/v3/requests/7"))
+            .and(body_string_contains(
+                urlencoding::encode(r#""status":{"name":"På hold"}"#).into_owned(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response_status": ok,
+                "request": {"id": "7", "status": {"name": "På hold"}},
+            })))
+            .expect(1)
+            .mount(&sdp)
+            .await;
+
+        let result = server
+            .hold_request(Parameters(HoldRequestInput {
+                request_id: "7".to_string(),
+                status: None,
+                reason: None,
+                resume_at: None,
+                resume_status: None,
+                portal: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false), "{:?}", result.content);
+    }
+
+    #[tokio::test]
+    async fn test_reply_reports_unrecorded_first_response() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let sdp = MockServer::start().await;
+        let mut config = test_config();
+        config.base_url = sdp.uri();
+        let server = GlassServer::new(SdpClient::new(&config).unwrap());
+        Mock::given(method("POST"))
+            .and(path("/api/v3/requests/7/notifications"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "response_status": {"status_code": 2000, "status": "success"},
+                "notification": {"id": "88"},
+            })))
+            .expect(1)
+            .mount(&sdp)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v3/requests/7/notes"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "response_status": {"status_code": 4000, "status": "failed"},
+            })))
+            .mount(&sdp)
+            .await;
+
+        let result = server
+            .reply_to_requester(Parameters(ReplyToRequesterInput {
+                request_id: "7".to_string(),
+                content: "We are on it.".to_string(),
+                to: vec!["anna@example.com".to_string()],
+                cc: vec![],
+                subject: Some("Re: VPN down".to_string()),
+                html: None,
+                mark_first_response: Some(true),
+                portal: None,
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false), "{:?}", result.content);
+        let data = result.structured_content.unwrap();
+        assert_eq!(data["first_response"], false);
+        assert_eq!(data["notification"]["id"], "88");
+    }
+
+    #[test]
+    fn test_format_request_list_empty() {
+        let result = format_request_list(&[], |_| None);
+        assert_eq!(result, "No tickets found matching the criteria.");
+    }
+
+    #[test]
+    fn test_format_request_list_with_items() {
+        let requests = vec![RequestSummary {
+            id: "123".to_string(),
+            subject: Some("Test ticket".to_string()),
+            status: Some(NamedEntity {
+                id: Some("1".to_string()),
+                name: Some("Open".to_string()),
+                email_id: None,
+            }),
+            priority: Some(NamedEntity {
+                id: Some("2".to_string()),
+                name: Some("High".to_string()),
+                email_id: None,
+            }),
+            technician: None,
+            requester: Some(NamedEntity {
+                id: Some("3".to_string()),
+                name: Some("John Doe".to_string()),
+                email_id: None,
+            }),
+            created_time: Some(SdpTimestamp {
+                value: None,
+                display_value: Some("Feb 6, 2026".to_string()),
+            }),
+            last_updated_time: None,
+            due_by_time: None,
+            first_response_due_by_time: None,
+            responded_time: None,
+            completed_time: None,
+            request_type: None,
+            category: None,
+            subcategory: None,
+            site: None,
+            group: None,
+        }];
+
+        let result = format_request_list(&requests, |_| None);
+        assert!(result.contains("#123"));
+        assert!(result.contains("Test ticket"));
+        assert!(result.contains("Open"));
+        assert!(result.contains("High"));
+        assert!(result.contains("John Doe"));
+    }
+
+    #[test]
+    fn test_format_request_list_links() {
+        let request: RequestSummary = serde_json::from_value(json!({
+            "id": "123",
+            "subject": "Test ticket",
+        }))
+        .unwrap();
+        let requests = [request];
+
+        let without_links = format_request_list(&requests, |_| None);
+        assert!(!without_links.contains("Link:"));
+
+        let result = format_request_list(&requests, |id| Some(format!("https://sdp/{}", id)));
+        assert!(result.contains("   Link: https://sdp/123\n"));
+    }
+
+    #[test]
+    fn test_format_request_list_flags_sla_at_risk() {
+        let now = chrono::Utc::now().timestamp_millis();
+        let request: RequestSummary = serde_json::from_value(json!({
+            "id": "7",
+            "subject": "VPN down",
+            "status": {"name": "Open"},
+            "first_response_due_by_time": {"value": (now - 3_600_000).to_string()},
+            "due_by_time": {"value": (now + 90 * 60_000).to_string()},
+        }))
+        .unwrap();
+
+        let result = format_request_list(&[request], |_| None);
+        assert!(result.starts_with("Found 1 ticket(s):\n\n#7 - VPN down [BREACHES IN UNDER 2H]\n"));
+        assert!(result.contains("   First response due: "));
+        assert!(result.contains(" (1h 0m late)\n"));
+        assert!(result.contains(" (in 1h 29m)\n") || result.contains(" (in 1h 30m)\n"));
+    }
+
+    #[test]
+    fn test_format_technician_list_empty() {
+        let result = format_technician_list(&[]);
+        assert_eq!(result, "No technicians found.");
+    }
+
+    #[test]
+    fn test_format_technician_list_with_items() {
+        let technicians = vec![Technician {
+            id: "456".to_string(),
+            name: Some("Jane Smith".to_string()),
+            email_id: Some("jane@example.com".to_string()),
+            first_name: None,
+            last_name: None,
+            phone: None,
+            mobile: None,
+            job_title: None,
+            department: None,
+            is_active: Some(true),
+            site: None,
+        }];
+
+        let result = format_technician_list(&technicians);
+        assert!(result.contains("456"));
+        assert!(result.contains("Jane Smith"));
+        assert!(result.contains("jane@example.com"));
+    }
+
+    // ========================================================================
+    // Write operation formatting tests (M4)
+    // ========================================================================
+
+    fn create_test_request() -> Request {
+        Request {
+            id: "123".to_string(),
+            subject: Some("Test ticket".to_string()),
+            description: Some("Test description".to_string()),
+            status: Some(NamedEntity {
+                id: Some("1".to_string()),
+                name: Some("Open".to_string()),
+                email_id: None,
+            }),
+            priority: Some(NamedEntity {
+                id: Some("2".to_string()),
+                name: Some("High".to_string()),
+                email_id: None,
+            }),
+            urgency: None,
+            impact: None,
+            technician: Some(NamedEntity {
+                id: Some("456".to_string()),
+                name: Some("John Doe".to_string()),
+                email_id: None,
+            }),
+            requester: Some(NamedEntity {
+                id: Some("789".to_string()),
+                name: Some("Jane User".to_string()),
+                email_id: None,
+            }),
+            request_type: None,
+            category: None,
+            subcategory: None,
+            item: None,
+            site: None,
+            group: Some(NamedEntity {
+                id: Some("10".to_string()),
+                name: Some("IT Support".to_string()),
+                email_id: None,
+            }),
+            level: None,
+            mode: None,
+            service: None,
+            created_time: Some(SdpTimestamp {
+                value: None,
+                display_value: Some("Feb 6, 2026".to_string()),
+            }),
+            last_updated_time: Some(SdpTimestamp {
+                value: None,
+                display_value: Some("Feb 6, 2026 10:30 AM".to_string()),
+            }),
+            due_by_time: None,
+            first_response_due_by_time: None,
+            resolution_due_by_time: None,
+            completed_time: None,
+            resolution: None,
+            closure_info: None,
+            onhold_scheduler: None,
+            assets: None,
+            responded_time: None,
+            sla: None,
+            is_first_response_overdue: None,
+            is_overdue: None,
+            is_fcr: None,
+            has_attachments: None,
+            attachments: None,
+            has_notes: None,
+            email_ids_to_notify: None,
+            approval_status: None,
+            udf_fields: None,
+        }
+    }
+
+    #[test]
+    fn test_format_create_result() {
+        let request = create_test_request();
+        let result = format_create_result(&request, "https://sdp/123");
+
+        assert!(result.contains("Successfully created ticket #123"));
+        assert!(result.contains("Test ticket"));
+        assert!(result.contains("Status: Open"));
+        assert!(result.contains("Priority: High"));
+        assert!(result.contains("John Doe"));
+        assert!(result.contains("IT Support"));
+        assert!(result.contains("Next steps:"));
+    }
+
+    #[test]
+    fn test_format_create_result_includes_link() {
+        let request = create_test_request();
+        let result = format_create_result(&request, "https://sdp/123");
+        assert!(result.contains("Link: https://sdp/123\n"));
+    }
+
+    #[test]
+    fn test_format_update_result() {
+        let request = create_test_request();
+        let result = format_update_result(&request);
+
+        assert!(result.contains("Successfully updated ticket #123"));
+        assert!(result.contains("Current state:"));
+        assert!(result.contains("Status: Open"));
+        assert!(result.contains("Priority: High"));
+    }
+
+    #[test]
+    fn test_format_close_result() {
+        let mut request = create_test_request();
+        request.status = Some(NamedEntity {
+            id: Some("5".to_string()),
+            name: Some("Closed".to_string()),
+            email_id: None,
+        });
+        request.closure_info = Some(crate::models::ClosureInfo {
+            closure_code: Some(NamedEntity {
+                id: Some("1".to_string()),
+                name: Some("Success".to_string()),
+                email_id: None,
+            }),
+            closure_comments: Some("Issue resolved".to_string()),
+            closed_by: None,
+            closed_time: Some(SdpTimestamp {
+                value: None,
+                display_value: Some("Feb 6, 2026 11:00 AM".to_string()),
+            }),
+        });
+
+        let result = format_close_result(&request);
+
+        assert!(result.contains("Successfully closed ticket #123"));
+        assert!(result.contains("Status: Closed"));
+        assert!(result.contains("Closure Code: Success"));
+        assert!(result.contains("Issue resolved"));
+    }
+
+    #[test]
+    fn test_format_add_note_result() {
+        use crate::models::Note;
+
+        let note = Note {
+            id: "999".to_string(),
+            description: Some("Test note content".to_string()),
+            created_by: None,
+            created_time: Some(SdpTimestamp {
+                value: None,
+                display_value: Some("Feb 6, 2026 10:45 AM".to_string()),
+            }),
+            show_to_requester: Some(false),
+            notify_technician: Some(true),
+            content_url: None,
+        };
+
+        let result = format_add_note_result("123", &note);
+
+        assert!(result.contains("Successfully added note #999 to ticket #123"));
+        assert!(result.contains("Internal (technicians only)"));
+        assert!(result.contains("Technician notification: Sent"));
+    }
+
+    #[test]
+    fn test_format_assign_result() {
+        use crate::tools::AssignRequestInput;
+
+        let request = create_test_request();
+        let input = AssignRequestInput {
+            request_id: "123".to_string(),
+            technician_id: Some("456".to_string()),
+            group: Some("IT Support".to_string()),
+            portal: None,
+        };
+
+        let result = format_assign_result(&request, &input);
+
+        assert!(result.contains("Successfully assigned ticket #123"));
+        assert!(result.contains("Technician: John Doe"));
+        assert!(result.contains("Group: IT Support"));
+    }
+
+    // ========================================================================
+    // Worklog formatting tests
+    // ========================================================================
+
+    fn create_test_worklog(id: &str, minutes: u32) -> Worklog {
+        Worklog {
+            id: id.to_string(),
+            description: Some("Replaced toner".to_string()),
+            owner: Some(NamedEntity {
+                id: Some("456".to_string()),
+                name: Some("John Doe".to_string()),
+                email_id: None,
+            }),
+            start_time: None,
+            end_time: None,
+            time_spent: Some(crate::models::TimeSpent::from_minutes(minutes)),
+            worklog_type: None,
+        }
+    }
+
+    #[test]
+    fn test_format_worklog_list_empty() {
+        let result = format_worklog_list("123", &[]);
+        assert_eq!(result, "No worklogs recorded on ticket #123.");
+    }
+
+    #[test]
+    fn test_format_worklog_list_totals_time() {
+        let worklogs = vec![create_test_worklog("1", 45), create_test_worklog("2", 30)];
+        let result = format_worklog_list("123", &worklogs);
+        assert!(result.contains("Found 2 worklog(s) on ticket #123 (total 1h 15m)"));
+        assert!(result.contains("ID: 1 | Technician: John Doe | Time: 0h 45m"));
+        assert!(result.contains("Replaced toner"));
+    }
+
+    #[test]
+    fn test_format_worklog_result() {
+        let result = format_worklog_result("updated", "123", &create_test_worklog("9", 90));
+        assert!(result.contains("Successfully updated worklog #9 on ticket #123"));
+        assert!(result.contains("Time spent: 1h 30m"));
+    }
+
+    // ========================================================================
+    // Task formatting tests
+    // ========================================================================
+
+    fn create_test_task() -> Task {
+        Task {
+            id: "77".to_string(),
+            title: Some("Order replacement screen".to_string()),
+            description: None,
+            owner: Some(NamedEntity {
+                id: Some("456".to_string()),
+                name: Some("John Doe".to_string()),
+                email_id: None,
+            }),
+            group: None,
+            status: Some(NamedEntity {
+                id: Some("1".to_string()),
+                name: Some("Open".to_string()),
+                email_id: None,
+            }),
+            priority: None,
+            scheduled_start_time: None,
+            scheduled_end_time: Some(SdpTimestamp {
+                value: None,
+                display_value: Some("Feb 9, 2026 04:00 PM".to_string()),
+            }),
+            actual_end_time: None,
+            percentage_completion: None,
+            overdue: Some(true),
+            created_time: None,
+        }
+    }
+
+    #[test]
+    fn test_format_task_list_empty() {
+        assert_eq!(format_task_list("123", &[]), "No tasks on ticket #123.");
+    }
+
+    #[test]
+    fn test_format_task_list_with_items() {
+        let result = format_task_list("123", &[create_test_task()]);
+        assert!(result.contains("ID: 77 - Order replacement screen"));
+        assert!(result.contains("Status: Open | Owner: John Doe"));
+        assert!(result.contains("Due: Feb 9, 2026 04:00 PM"));
+        assert!(result.contains("[OVERDUE]"));
+    }
+
+    #[test]
+    fn test_format_task_result() {
+        let result = format_task_result("completed", "123", &create_test_task());
+        assert!(result.contains("Successfully completed task #77 on ticket #123"));
+    }
+
+    // ========================================================================
+    // Approval formatting tests
+    // ========================================================================
+
+    #[test]
+    fn test_format_approval_list_empty() {
+        assert_eq!(
+            format_approval_list("123", &[], &[]),
+            "No approvals on ticket #123."
+        );
+    }
+
+    #[test]
+    fn test_format_approval_list_with_levels() {
+        let levels: Vec<ApprovalLevel> = serde_json::from_str(
+            r#"[{"id": "301", "level": 1, "status": {"name": "Pending Approval"}},
+                {"id": "302", "level": 2, "status": {"name": "Pending Approval"}}]"#,
+        )
+        .unwrap();
+        let approvals: Vec<Approval> = serde_json::from_str(
+            r#"[{"id": "55", "approver": {"name": "Line Manager"},
+                 "status": {"name": "Approved"}, "comments": "Fine by me"}]"#,
+        )
+        .unwrap();
+
+        let result = format_approval_list(
+            "123",
+            &levels,
+            &[Ok(approvals), Err("HTTP 500".to_string())],
+        );
+        assert!(result.contains("Level 1 (ID: 301) - Pending Approval"));
+        assert!(result.contains("Approval ID: 55 | Approver: Line Manager | Status: Approved"));
+        assert!(result.contains("Comments: Fine by me"));
+        assert!(result.contains("Warning: Failed to fetch approvals: HTTP 500"));
+    }
+
+    // ========================================================================
+    // Reply formatting tests
+    // ========================================================================
+
+    #[test]
+    fn test_default_reply_subject_is_tagged() {
+        let request = create_test_request();
+        assert_eq!(
+            default_reply_subject(&request),
+            "Re: [Request ID :##123##] : Test ticket"
+        );
+    }
+
+    #[test]
+    fn test_format_survey_results() {
+        let response: SurveyResponse = serde_json::from_str(
+            r#"{"rating": {"name": "2 - Poor"}, "comments": "Took a week", "requester": {"name": "Anna Jensen"},
+                "answers": [{"question": "Was the issue solved?", "answer": "Partly"}]}"#,
+        )
+        .unwrap();
+        let results = vec![SurveyResult {
+            request_id: "42".to_string(),
+            subject: Some("VPN down".to_string()),
+            response,
+        }];
+        let output = format_survey_results(&results, 12, Some(2), &["#43: timeout".to_string()]);
+        assert!(output.starts_with(
+            "Found 1 survey response(s) rated 2 or lower among 12 ticket(s) checked:"
+        ));
+        assert!(output.contains("#42 - VPN down\n   Rating: 2 - Poor\n   Requester: Anna Jensen\n"));
+        assert!(output.contains("   Comments: Took a week\n   - Was the issue solved?: Partly\n"));
+        assert!(output.contains("Could not read 1 survey(s):\n   #43: timeout\n"));
+
+        assert_eq!(
+            format_survey_results(&[], 5, None, &[]),
+            "No survey responses among 5 ticket(s) checked.\n"
+        );
+    }
+
+    #[test]
+    fn test_format_notification_list() {
+        let notifications: Vec<Notification> = serde_json::from_str(
+            r#"[{"id": 88985, "subject": "Re: VPN down", "description": "<p>We are on it.</p>",
+                 "to": [{"email_id": "anna@example.com"}], "cc": [{"email_id": "boss@example.com"}],
+                 "sender": {"name": "Mikkel Holm"}, "type": "reply",
+                 "sent_time": {"display_value": "Mar 3, 2025 10:15 AM", "value": "1741000000000"}},
+                {"id": 88990, "subject": "Request closed", "to": [{"email_id": "anna@example.com"}]}]"#,
+        )
+        .unwrap();
+        let result = format_notification_list("42", &notifications);
+        assert!(result.starts_with("Found 2 email(s) sent on ticket #42:"));
+        assert!(result.contains("[Mar 3, 2025 10:15 AM] Re: VPN down (reply)\n"));
+        assert!(result.contains("   From: Mikkel Holm | To: anna@example.com\n   Cc: boss@example.com\n   We are on it.\n"));
+        assert!(result.contains(
+            "[Unknown time] Request closed (email)\n   From: System | To: anna@example.com\n"
+        ));
+
+        assert_eq!(
+            format_notification_list("42", &[]),
+            "No emails sent on ticket #42."
+        );
+
+        let danish = crate::i18n::with_language(crate::i18n::Lang::Da, || {
+            format_notification_list("42", &notifications)
+        });
+        assert!(danish.starts_with("Fandt 2 e-mail(s) sendt på sag #42:"));
+        assert!(
+            danish.contains("[Ukendt tidspunkt] Request closed (e-mail)\n   Fra: System | Til:")
+        );
+    }
+
+    #[test]
+    fn test_format_notification_details() {
+        let raw = json!({
+            "id": 88985, "subject": "Re: VPN down", "description": "<p>We are on it.</p>",
+            "to": [{"email_id": "anna@example.com"}], "bcc": [{"email_id": "audit@example.com"}],
+            "sender": {"name": "Mikkel Holm"}, "type": "reply", "reply_to": "desk@example.com",
+            "attachments": null,
+        });
+        let fetched = RawNotification {
+            notification: serde_json::from_value(raw.clone()).unwrap(),
+            raw,
+        };
+
+        let result = format_notification_details("42", &fetched, false);
+        assert!(result.starts_with("Email #88985 sent on ticket #42\n\n"));
+        assert!(result.contains(
+            "From: Mikkel Holm\nTo: anna@example.com\nBcc: audit@example.com\nSubject: Re: VPN down\n\
+             Sent: Unknown time\nType: reply\nreply_to: desk@example.com\n"
+        ));
+        assert!(result.ends_with("--- Content ---\nWe are on it.\n"));
+        assert!(!result.contains("attachments"));
+
+        let with_html = format_notification_details("42", &fetched, true);
+        assert!(with_html.ends_with("--- HTML ---\n<p>We are on it.</p>\n"));
+
+        let danish = crate::i18n::with_language(crate::i18n::Lang::Da, || {
+            format_notification_details("42", &fetched, false)
+        });
+        assert!(danish.starts_with("E-mail #88985 sendt på sag #42\n\n"));
+        assert!(
+            danish.contains("Fra: Mikkel Holm\nTil: anna@example.com\nBcc: audit@example.com\n")
+        );
+    }
+
+    #[test]
+    fn test_format_reply_result() {
+        let reply = ReplyRequest::new(
+            "Re: Test ticket",
+            "Hi",
+            vec!["jane@example.com".to_string()],
+        )
+        .with_cc(vec!["boss@example.com".to_string()]);
+        let notification: Notification = serde_json::from_str(r#"{"id": "42"}"#).unwrap();
+
+        let result = format_reply_result("123", &reply, &notification);
+        assert!(result.contains("Successfully sent reply #42 on ticket #123"));
+        assert!(result.contains("To: jane@example.com"));
+        assert!(result.contains("Cc: boss@example.com"));
+    }
+
+    #[test]
+    fn test_format_resolution_result() {
+        let resolution: Resolution = serde_json::from_str(
+            r#"{"content": "Replaced toner", "submitted_by": {"name": "Jane Smith"}}"#,
+        )
+        .unwrap();
+        let result = format_resolution_result("123", &resolution);
+        assert!(result.contains("Successfully set resolution on ticket #123"));
+        assert!(result.contains("Submitted by: Jane Smith"));
+        assert!(result.contains("close_request"));
+    }
+
+    #[test]
+    fn test_format_resolve_result() {
+        let mut request = create_test_request();
+        request.status = Some(NamedEntity {
+            id: None,
+            name: Some("Resolved".to_string()),
+            email_id: None,
+        });
+        let resolution: Resolution = serde_json::from_str(r#"{"content": "Fixed"}"#).unwrap();
+        let result = format_resolve_result(&request, &resolution);
+        assert!(result.contains("Successfully resolved ticket #123"));
+        assert!(result.contains("Status: Resolved"));
+    }
+
+    #[test]
+    fn test_format_resolve_failure_reports_rollback() {
+        let restored = format_resolve_failure("123", "Closed", "boom", Some(Ok(())));
+        assert!(restored.contains("setting status 'Closed' failed: boom"));
+        assert!(restored.contains("previous resolution was restored"));
+
+        let failed = format_resolve_failure("123", "Closed", "boom", Some(Err("nope".into())));
+        assert!(failed.contains("also failed (nope)"));
+
+        let kept = format_resolve_failure("123", "Closed", "boom", None);
+        assert!(kept.contains("new one was kept"));
+    }
+
+    #[test]
+    fn test_format_pickup_result() {
+        let request = create_test_request();
+        let result = format_pickup_result(&request);
+        assert!(result.contains("Successfully picked up ticket #123: Test ticket"));
+        assert!(result.contains("Technician: "));
+    }
+
+    // ========================================================================
+    // Request link formatting tests
+    // ========================================================================
+
+    #[test]
+    fn test_format_request_details_shows_links() {
+        let request = create_test_request();
+        let links: Vec<RequestLink> = serde_json::from_str(
+            r#"[{"linked_request": {"id": "456", "subject": "VPN down"}, "comments": "Same outage"}]"#,
+        )
+        .unwrap();
+        let result = format_request_details(
+            &request,
+            &[],
+            &[],
+            &links,
+            &RequestAssociations::default(),
+            "https://x",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(result.contains("--- Linked Requests ---"));
+        assert!(result.contains("#456: VPN down [Unknown] - Same outage"));
+    }
+
+    #[test]
+    fn test_format_request_details_shows_associations() {
+        let request = create_test_request();
+        let associations: RequestAssociations = serde_json::from_str(
+            r#"{"problem": {"id": "31", "title": "VPN gateway drops sessions", "status": {"name": "Open"}}}"#,
+        )
+        .unwrap();
+        let result = format_request_details(
+            &request,
+            &[],
+            &[],
+            &[],
+            &associations,
+            "https://x",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(result
+            .contains("--- Associations ---\nProblem: #31: VPN gateway drops sessions [Open]\n"));
+        assert!(!result.contains("Change:"));
+    }
+
+    #[test]
+    fn test_format_request_details_lists_attachments() {
+        let mut request = create_test_request();
+        request.has_attachments = Some(true);
+        let without_list = format_request_details(
+            &request,
+            &[],
+            &[],
+            &[],
+            &RequestAssociations::default(),
+            "https://x",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(without_list.contains("The ticket has attachments, but SDP did not list them."));
+
+        request.attachments = Some(
+            serde_json::from_str(
+                r#"[{"id": 1, "name": "error.png", "size": {"display_value": "18.09 KB"}, "attached_by": {"name": "Anna Jensen"}}]"#,
+            )
+            .unwrap(),
+        );
+        let result = format_request_details(
+            &request,
+            &[],
+            &[],
+            &[],
+            &RequestAssociations::default(),
+            "https://x",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(result.contains("--- Attachments ---\n- error.png (18.09 KB) - Anna Jensen\n"));
+    }
+
+    #[test]
+    fn test_format_request_details_renders_html_as_text() {
+        let mut request = create_test_request();
+        request.description = Some("<p>VPN drops.</p><ul><li>Since Monday</li></ul>".to_string());
+        let notes: Vec<Note> = serde_json::from_str(
+            r#"[{"id": "1", "description": "<div>See <a href=\"https://kb.example.com/7\">KB 7</a></div>"}]"#,
+        )
+        .unwrap();
+        let conversations: Vec<Conversation> =
+            serde_json::from_str(r#"[{"id": "2", "description": "Thanks<br>Anna &amp; Ole"}]"#)
+                .unwrap();
+
+        let result = format_request_details(
+            &request,
+            &notes,
+            &conversations,
+            &[],
+            &RequestAssociations::default(),
+            "https://x",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(result.contains("--- Description ---\nVPN drops.\n\n- Since Monday\n"));
+        assert!(result.contains("See KB 7 (https://kb.example.com/7)\n"));
+        assert!(result.contains("Thanks\nAnna & Ole\n"));
+        assert!(!result.contains("<p>"));
+    }
+
+    #[test]
+    fn test_format_request_markdown() {
+        let mut request = create_test_request();
+        request.resolution = Some(
+            serde_json::from_str(
+                r#"{"content": "<p>Replaced the dock.</p>", "submitted_by": {"name": "John Doe"},
+                    "submitted_on": {"display_value": "Feb 7, 2026"}}"#,
+            )
+            .unwrap(),
+        );
+        let timeline = Timeline {
+            events: vec![TimelineEvent {
+                millis: Some(1000),
+                time: "T1".to_string(),
+                kind: "Note".to_string(),
+                actor: "John Doe".to_string(),
+                body: "# not a heading\n\nOrdered a dock".to_string(),
+            }],
+            fetch_errors: vec!["Worklogs: timeout".to_string()],
+            more_hint: String::new(),
+        };
+
+        let result = format_request_markdown(&request, &timeline, "https://sdp/123");
+        assert!(result.starts_with(
+            "# Ticket #123: Test ticket\n\n- **Link:** https://sdp/123\n- **Status:** Open\n"
+        ));
+        assert!(result.contains("- **Group:** IT Support\n- **Created:** Feb 6, 2026\n"));
+        assert!(result.contains("\n## Description\n\nTest description\n"));
+        assert!(result
+            .contains("\n### Note - John Doe (T1)\n\n> # not a heading\n>\n> Ordered a dock\n"));
+        assert!(result.contains(
+            "\n## Resolution\n\nReplaced the dock.\n\n_Submitted by: John Doe, Feb 7, 2026_\n"
+        ));
+        assert!(result.ends_with("- Warning: Failed to fetch Worklogs: timeout\n"));
+        assert!(!result.contains("Closure Info"));
     }
 
-    output
-}
+    #[test]
+    fn test_format_link_result() {
+        let result = format_link_result("linked", "123", "456");
+        assert_eq!(result, "Successfully linked ticket #123 and ticket #456.\n");
+    }
 
-/// Formats a list of technicians as human-readable text.
-fn format_technician_list(technicians: &[Technician]) -> String {
-    if technicians.is_empty() {
-        return "No technicians found.".to_string();
+    #[test]
+    fn test_format_association_result() {
+        let record: AssociatedRecord =
+            serde_json::from_str(r#"{"id": 12, "title": "Replace VPN gateway"}"#).unwrap();
+        assert_eq!(
+            format_association_result(true, "123", AssociationKind::Change, "12", Some(&record)),
+            "Successfully associated ticket #123 with change #12: Replace VPN gateway [Unknown].\n"
+        );
+        assert_eq!(
+            format_association_result(false, "123", AssociationKind::Problem, "31", None),
+            "Successfully removed problem #31 from ticket #123.\n"
+        );
     }
 
-    let mut output = format!("Found {} technician(s):\n\n", technicians.len());
+    #[test]
+    fn test_format_delete_result() {
+        let result = format_delete_result("123");
+        assert!(result.contains("moved ticket #123 to the trash"));
+    }
 
-    for tech in technicians {
-        output.push_str(&format!("ID: {} | Name: {}", tech.id, tech.display_name()));
+    #[test]
+    fn test_format_trashed_request_list_empty() {
+        assert_eq!(format_trashed_request_list(&[]), "The trash is empty.");
+    }
 
-        if let Some(email) = tech.email() {
-            output.push_str(&format!(" | Email: {}", email));
-        }
+    // ========================================================================
+    // Hold formatting tests
+    // ========================================================================
 
-        if let Some(active) = tech.is_active {
-            if !active {
-                output.push_str(" [INACTIVE]");
-            }
-        }
+    #[test]
+    fn test_format_request_details_shows_hold_schedule() {
+        let mut request = create_test_request();
+        request.status = Some(NamedEntity {
+            id: None,
+            name: Some("On Hold".to_string()),
+            email_id: None,
+        });
+        request.onhold_scheduler = Some(
+            OnHoldScheduler::resume_at(SdpTimestamp::from_epoch_millis(0), "Open")
+                .with_comments("Waiting for vendor"),
+        );
+        let result = format_request_details(
+            &request,
+            &[],
+            &[],
+            &[],
+            &RequestAssociations::default(),
+            "https://x",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(result.contains("Status: On Hold"));
+        assert!(result.contains("Resumes: 1970-01-01 00:00 UTC (to Open)"));
+        assert!(result.contains("Hold reason: Waiting for vendor"));
+    }
 
-        output.push('\n');
+    #[test]
+    fn test_format_hold_result_without_schedule() {
+        let request = create_test_request();
+        let result = format_hold_result(&request, None);
+        assert!(result.contains("Successfully put ticket #123 on hold"));
+        assert!(!result.contains("Resumes"));
     }
 
-    output
-}
+    #[test]
+    fn test_priority_suggestion() {
+        let matrix = PriorityMatrix::default();
+        let result = priority_suggestion(&matrix, None, Some("High"), Some("Medium"));
+        assert_eq!(
+            result.unwrap().text,
+            "Suggested priority: High\n\nImpact: High\nUrgency: Medium\n"
+        );
 
-// ============================================================================
-// Write operation formatting helpers (M4)
-// ============================================================================
+        let mut request = create_test_request();
+        request.priority = Some(NamedEntity {
+            id: None,
+            name: Some("Low".to_string()),
+            email_id: None,
+        });
+        let result = priority_suggestion(&matrix, Some(&request), Some("low"), Some("high"))
+            .unwrap()
+            .text;
+        assert!(result.starts_with("Suggested priority for ticket #123: Medium\n"));
+        assert!(result.contains("Priority: Low\n"));
+        assert!(result.contains("set it to Medium with update_request"));
+
+        let danish = crate::i18n::with_language(crate::i18n::Lang::Da, || {
+            priority_suggestion(&matrix, Some(&request), Some("low"), Some("high"))
+        })
+        .unwrap()
+        .text;
+        assert!(danish.starts_with("Foreslået prioritet for sag #123: Medium\n"));
+        assert!(danish.contains("sæt den til Medium med update_request"));
+
+        let err = priority_suggestion(&matrix, Some(&request), None, Some("High")).unwrap_err();
+        assert_eq!(err, "Ticket #123 has no impact set; provide impact.");
+
+        let err = priority_suggestion(&matrix, None, Some("Critical"), Some("High")).unwrap_err();
+        assert!(err.contains("Impacts: High, Medium, Low."));
+    }
 
-/// Formats the result of a create request operation.
-fn format_create_result(request: &Request) -> String {
-    let mut output = String::new();
+    #[test]
+    fn test_priority_suggestion_data() {
+        let matrix = PriorityMatrix::default();
+        let request = create_test_request();
+        let output =
+            priority_suggestion(&matrix, Some(&request), Some("low"), Some("HIGH")).unwrap();
+        assert_eq!(output.data["suggested_priority"], "Medium");
+        assert_eq!(
+            output.data["cell"],
+            json!({"impact": "Low", "urgency": "High", "priority": "Medium"})
+        );
+        assert_eq!(output.data["inputs"]["request_id"], "123");
+        assert_eq!(output.data["inputs"]["impact"], "low");
+        assert_eq!(output.data["inputs"]["urgency"], "HIGH");
+    }
 
-    output.push_str(&format!(
-        "Successfully created ticket #{}: {}\n\n",
-        request.id,
-        request.display_subject()
-    ));
+    #[test]
+    fn test_format_due_date_result() {
+        let request = create_test_request();
+        let requested = SdpTimestamp::from_epoch_millis(1_772_380_800_000);
+        let result = format_due_date_result(&request, &requested, None);
+        assert!(result.contains("Successfully changed the due date of ticket #123"));
+        assert!(result.contains("Due By: 2026-03-01 16:00 UTC"));
+        assert!(!result.contains("Warning"));
+
+        let result = format_due_date_result(&request, &requested, Some("timeout"));
+        assert!(result.contains("Warning: the reason could not be recorded as a note (timeout)"));
+    }
 
-    output.push_str(&format!("Status: {}\n", request.display_status()));
-    output.push_str(&format!("Priority: {}\n", request.display_priority()));
-    output.push_str(&format!("Assigned to: {}\n", request.display_technician()));
+    // ========================================================================
+    // Metadata formatting tests
+    // ========================================================================
 
-    if let Some(group) = request.display_group() {
-        output.push_str(&format!("Group: {}\n", group));
+    #[test]
+    fn test_format_category_tree() {
+        let entry = |id: &str, name: &str| CategoryEntry {
+            id: id.to_string(),
+            name: Some(name.to_string()),
+            description: None,
+            deleted: None,
+        };
+        let tree = vec![(
+            entry("1", "Hardware"),
+            vec![(entry("2", "Laptop"), vec![entry("3", "Screen")])],
+        )];
+        let result = format_category_tree(&tree);
+        assert!(result.contains("Hardware (ID: 1)\n  - Laptop (ID: 2)\n      - Screen (ID: 3)"));
+        assert_eq!(format_category_tree(&[]), "No categories found.");
     }
 
-    output.push_str(&format!("\nRequester: {}\n", request.display_requester()));
-
-    if let Some(created) = request.created_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("Created: {}\n", created));
+    #[test]
+    fn test_format_site_list_skips_deleted() {
+        let sites: Vec<Site> = serde_json::from_str(
+            r#"[{"id": "1", "name": "Odense", "region": {"name": "Funen"}}, {"id": "2", "name": "Old", "deleted": true}]"#,
+        )
+        .unwrap();
+        let result = format_site_list(&sites);
+        assert!(result.contains("Found 1 site(s)"));
+        assert!(result.contains("ID: 1 | Name: Odense | Region: Funen"));
+        assert!(!result.contains("Old"));
     }
 
-    output.push_str("\nNext steps:\n");
-    output.push_str(&format!(
-        "  - View details: use get_request with request_id=\"{}\"\n",
-        request.id
-    ));
-    output.push_str(&format!(
-        "  - Add notes: use add_note with request_id=\"{}\"\n",
-        request.id
-    ));
+    #[test]
+    fn test_format_closure_code_list() {
+        let codes: Vec<ClosureCode> = serde_json::from_str(
+            r#"[{"id": "1", "name": "Success", "description": "Fixed"}, {"id": "2", "name": "Gone", "deleted": true}]"#,
+        )
+        .unwrap();
+        let result = format_closure_code_list(&codes);
+        assert!(result.contains("Found 1 closure code(s)"));
+        assert!(result.contains("- Success: Fixed"));
+        assert!(!result.contains("Gone"));
+    }
 
-    output
-}
+    #[test]
+    fn test_format_requester_list() {
+        let requesters: Vec<Requester> = serde_json::from_str(
+            r#"[{"id": "9", "name": "Anna Jensen", "email_id": "anna@example.com", "is_vipuser": true}]"#,
+        )
+        .unwrap();
+        let result = format_requester_list(&requesters);
+        assert!(result.contains("ID: 9 | Name: Anna Jensen | Email: anna@example.com [VIP]"));
+        assert_eq!(format_requester_list(&[]), "No requesters found.");
+    }
 
-/// Formats the result of an update request operation.
-fn format_update_result(request: &Request) -> String {
-    let mut output = String::new();
+    #[test]
+    fn test_format_requester_details() {
+        let requester: Requester = serde_json::from_str(
+            r#"{"id": "9", "name": "Anna Jensen", "phone": "", "mobile": "+45 1234", "site": {"name": "Odense"}, "is_vipuser": true}"#,
+        )
+        .unwrap();
+        let result = format_requester_details(&requester);
+        assert!(result.starts_with("Requester #9: Anna Jensen [VIP]\n"));
+        assert!(result.contains("Mobile: +45 1234"));
+        assert!(result.contains("Site: Odense"));
+        assert!(!result.contains("Phone"));
+    }
 
-    output.push_str(&format!(
-        "Successfully updated ticket #{}: {}\n\n",
-        request.id,
-        request.display_subject()
-    ));
+    #[test]
+    fn test_format_department_list() {
+        let departments: Vec<Department> = serde_json::from_str(
+            r#"[{"id": 3, "name": "Operations", "site": {"name": "Odense"}}, {"id": 4, "name": "Finance"}]"#,
+        )
+        .unwrap();
+        let result = format_department_list(&departments);
+        assert!(result.contains("Found 2 department(s)"));
+        assert!(result.contains("ID: 3 | Name: Operations | Site: Odense"));
+        assert!(result.contains("ID: 4 | Name: Finance\n"));
+    }
 
-    output.push_str("Current state:\n");
-    output.push_str(&format!("  Status: {}\n", request.display_status()));
-    output.push_str(&format!("  Priority: {}\n", request.display_priority()));
-    output.push_str(&format!(
-        "  Assigned to: {}\n",
-        request.display_technician()
-    ));
+    #[test]
+    fn test_format_asset_list_and_details() {
+        let asset: Asset = serde_json::from_str(
+            r#"{"id": 3001, "name": "DK-0421", "serial_number": "5CG1234XYZ", "product": {"name": "Latitude 7440"}, "user": {"name": "Anna Jensen"}, "location": ""}"#,
+        )
+        .unwrap();
+
+        let list = format_asset_list(std::slice::from_ref(&asset));
+        assert!(list.contains("Found 1 asset(s)"));
+        assert!(list.contains(
+            "ID: 3001 | Name: DK-0421 | Product: Latitude 7440 | Serial: 5CG1234XYZ | User: Anna Jensen"
+        ));
+        assert_eq!(format_asset_list(&[]), "No assets found.");
 
-    if let Some(group) = request.display_group() {
-        output.push_str(&format!("  Group: {}\n", group));
+        let details = format_asset_details(&asset);
+        assert!(details.starts_with("Asset #3001: DK-0421\n"));
+        assert!(details.contains("Serial Number: 5CG1234XYZ\n"));
+        assert!(!details.contains("Location"));
     }
 
-    let category_path = request.category_path();
-    if category_path != "Uncategorized" {
-        output.push_str(&format!("  Category: {}\n", category_path));
+    #[test]
+    fn test_format_attach_asset_result() {
+        let mut request = create_test_request();
+        request.assets = Some(vec![
+            NamedEntity {
+                id: Some("3000".to_string()),
+                name: Some("DK-0420".to_string()),
+                email_id: None,
+            },
+            NamedEntity {
+                id: Some("3001".to_string()),
+                name: Some("DK-0421".to_string()),
+                email_id: None,
+            },
+        ]);
+
+        let result = format_attach_asset_result(&request, "3001");
+        assert!(result.contains("Successfully attached asset 3001 to ticket #"));
+        assert!(result.contains("Assets: DK-0420, DK-0421"));
     }
 
-    if let Some(updated) = request.last_updated_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("\nLast updated: {}\n", updated));
+    // ========================================================================
+    // Knowledge base formatting tests
+    // ========================================================================
+
+    #[test]
+    fn test_format_solution_list() {
+        let solutions: Vec<Solution> = serde_json::from_str(
+            r#"[{"id": 4501, "title": "VPN drops after sleep", "topic": {"name": "Network"}}, {"id": 4502}]"#,
+        )
+        .unwrap();
+        let result = format_solution_list(&solutions);
+        assert!(result.contains("Found 2 solution(s)"));
+        assert!(result.contains("ID: 4501 | VPN drops after sleep | Topic: Network"));
+        assert!(result.contains("ID: 4502 | (No title)\n"));
+        assert_eq!(format_solution_list(&[]), "No solutions found.");
     }
 
-    output
-}
+    #[test]
+    fn test_format_solution_details() {
+        let solution: Solution = serde_json::from_str(
+            r#"{"id": 4501, "title": "VPN drops after sleep", "keywords": "vpn", "description": "<p>Disable power saving.</p>"}"#,
+        )
+        .unwrap();
+        let result = format_solution_details(&solution, DEFAULT_DESCRIPTION_LENGTH);
+        assert!(result.starts_with("Solution #4501: VPN drops after sleep\n"));
+        assert!(result.contains("Keywords: vpn\n"));
+        assert!(result.contains("--- Content ---\n<p>Disable power saving.</p>"));
+    }
 
-/// Formats the result of a close request operation.
-fn format_close_result(request: &Request) -> String {
-    let mut output = String::new();
+    #[test]
+    fn test_build_solution_content() {
+        let mut request = create_test_request();
+        request.description = Some("VPN drops after sleep".to_string());
+        assert!(build_solution_content(&request).is_none());
 
-    output.push_str(&format!(
-        "Successfully closed ticket #{}: {}\n\n",
-        request.id,
-        request.display_subject()
-    ));
+        request.resolution = Some(Resolution {
+            content: Some("Disable power saving on the adapter.".to_string()),
+            submitted_by: None,
+            submitted_on: None,
+        });
+        let content = build_solution_content(&request).unwrap();
+        assert!(content.starts_with("<h3>Problem</h3>\nVPN drops after sleep"));
+        assert!(content.ends_with("<h3>Solution</h3>\nDisable power saving on the adapter."));
+
+        request.description = None;
+        assert_eq!(
+            build_solution_content(&request).as_deref(),
+            Some("Disable power saving on the adapter.")
+        );
+    }
 
-    output.push_str(&format!("Status: {}\n", request.display_status()));
+    // ========================================================================
+    // Project formatting tests
+    // ========================================================================
 
-    if let Some(closure) = &request.closure_info {
-        if let Some(code) = closure
-            .closure_code
-            .as_ref()
-            .and_then(|c| c.name.as_deref())
-        {
-            output.push_str(&format!("Closure Code: {}\n", code));
-        }
-        if let Some(comments) = &closure.closure_comments {
-            output.push_str(&format!("Closure Comments: {}\n", comments));
-        }
-        if let Some(closed_time) = closure.closed_time.as_ref().and_then(|t| t.display()) {
-            output.push_str(&format!("Closed at: {}\n", closed_time));
-        }
+    #[test]
+    fn test_format_project_list() {
+        let projects: Vec<Project> = serde_json::from_str(
+            r#"[{"id": 61, "title": "Laptop refresh", "status": {"name": "Open"}, "owner": {"name": "Mikkel"}}]"#,
+        )
+        .unwrap();
+        let result = format_project_list(&projects);
+        assert!(result.contains("Found 1 project(s)"));
+        assert!(result.contains("ID: 61 - Laptop refresh"));
+        assert!(result.contains("Status: Open | Owner: Mikkel"));
+        assert_eq!(format_project_list(&[]), "No projects found.");
     }
 
-    output
-}
+    #[test]
+    fn test_format_project_details_with_milestones() {
+        let project: Project = serde_json::from_str(
+            r#"{"id": 61, "title": "Laptop refresh", "percentage_completion": "40"}"#,
+        )
+        .unwrap();
+        let milestones: Vec<Milestone> = serde_json::from_str(
+            r#"[{"id": 7, "title": "Pilot group", "status": {"name": "Closed"}}]"#,
+        )
+        .unwrap();
+
+        let result =
+            format_project_details(&project, &milestones, None, DEFAULT_DESCRIPTION_LENGTH);
+        assert!(result.starts_with("Project #61: Laptop refresh\n"));
+        assert!(result.contains("Completion: 40%"));
+        assert!(result.contains("ID: 7 - Pilot group [Closed]"));
+
+        let result =
+            format_project_details(&project, &[], Some("timeout"), DEFAULT_DESCRIPTION_LENGTH);
+        assert!(result.contains("(Could not fetch milestones: timeout)"));
+        assert!(!result.contains("No milestones."));
+    }
 
-/// Formats the result of an add note operation.
-fn format_add_note_result(request_id: &str, note: &Note) -> String {
-    let mut output = String::new();
+    #[test]
+    fn test_format_project_task_result() {
+        let task: Task = serde_json::from_str(
+            r#"{"id": 9, "title": "Order laptops", "status": {"name": "Open"}}"#,
+        )
+        .unwrap();
+        let result = format_project_task_result("created", "61", &task);
+        assert!(result.contains("Successfully created task #9 on project #61: Order laptops"));
+        assert!(result.contains("Owner: Unassigned"));
+    }
 
-    output.push_str(&format!(
-        "Successfully added note #{} to ticket #{}.\n\n",
-        note.id, request_id
-    ));
+    // ========================================================================
+    // SLA formatting tests
+    // ========================================================================
 
-    let visibility = if note.show_to_requester == Some(true) {
-        "Visible to requester"
-    } else {
-        "Internal (technicians only)"
-    };
-    output.push_str(&format!("Visibility: {}\n", visibility));
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(45 * 60_000), "45m");
+        assert_eq!(format_duration(125 * 60_000), "2h 5m");
+        assert_eq!(format_duration(-(26 * 3_600_000)), "1d 2h");
+    }
 
-    if let Some(created) = note.created_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("Created: {}\n", created));
+    #[test]
+    fn test_format_sla_info_open_ticket() {
+        let hour = 3_600_000;
+        let now = 1_800_000_000_000;
+        let mut request = create_test_request();
+        request.sla = Some(NamedEntity {
+            id: Some("301".to_string()),
+            name: Some("High SLA".to_string()),
+            email_id: None,
+        });
+        request.created_time = Some(SdpTimestamp::from_epoch_millis(now - 3 * hour));
+        request.first_response_due_by_time = Some(SdpTimestamp::from_epoch_millis(now - hour));
+        request.responded_time = Some(SdpTimestamp::from_epoch_millis(now - 2 * hour));
+        request.resolution_due_by_time = Some(SdpTimestamp::from_epoch_millis(now + 2 * hour));
+        request.completed_time = None;
+
+        let result = format_sla_info(&request, None, now);
+        assert!(result.contains("SLA: High SLA"));
+        assert!(result.contains("(elapsed 3h 0m)"));
+        assert!(result.contains(" - met\n"));
+        assert!(result.contains("Resolution due: "));
+        assert!(result.contains(" - due in 2h 0m"));
     }
 
-    if note.notify_technician == Some(true) {
-        output.push_str("Technician notification: Sent\n");
+    #[test]
+    fn test_format_sla_info_breached() {
+        let now = 1_800_000_000_000;
+        let mut request = create_test_request();
+        request.sla = None;
+        request.first_response_due_by_time = None;
+        request.due_by_time = None;
+        request.completed_time = None;
+        request.resolution_due_by_time = Some(SdpTimestamp::from_epoch_millis(now - 90 * 60_000));
+        request.is_overdue = Some(true);
+
+        let sla = Sla {
+            id: "301".to_string(),
+            name: Some("Low SLA".to_string()),
+            description: None,
+        };
+        let result = format_sla_info(&request, Some(&sla), now);
+        assert!(result.contains("SLA: Low SLA"));
+        assert!(result.contains("overdue by 1h 30m [VIOLATED]"));
     }
 
-    output
-}
+    #[test]
+    fn test_format_ticket_statistics() {
+        let input = TicketStatisticsInput {
+            group_by: "technician".to_string(),
+            created_after: Some("2026-01-01".to_string()),
+            created_before: None,
+            open_only: Some(true),
+            portal: None,
+        };
+        let counts = vec![("Anna".to_string(), 3), ("Mikkel".to_string(), 7)];
+        let result = format_ticket_statistics(&input, &counts, 12);
+        assert!(result.starts_with(
+            "Ticket counts by technician (open tickets only), created after 2026-01-01:"
+        ));
+        assert!(result.contains("Total: 12"));
+        assert!(result.find("Mikkel: 7").unwrap() < result.find("Anna: 3").unwrap());
+        assert!(result.contains("Unassigned / other: 2"));
 
-/// Formats the result of an assign request operation.
-fn format_assign_result(request: &Request, input: &AssignRequestInput) -> String {
-    let mut output = String::new();
+        let result = format_ticket_statistics(&input, &[], 0);
+        assert!(result.contains("No matching tickets."));
+    }
 
-    output.push_str(&format!(
-        "Successfully assigned ticket #{}: {}\n\n",
-        request.id,
-        request.display_subject()
-    ));
+    #[test]
+    fn test_format_my_queue() {
+        assert_eq!(
+            format_my_queue(None, &[]),
+            "No open tickets assigned to you."
+        );
+
+        let technician: Technician =
+            serde_json::from_str(r#"{"id": "5", "name": "Mikkel"}"#).unwrap();
+        let requests: Vec<RequestSummary> =
+            serde_json::from_str(r#"[{"id": "123", "subject": "Printer jam"}]"#).unwrap();
+        let result = format_my_queue(Some(&technician), &requests);
+        assert!(result.starts_with("Open tickets assigned to Mikkel:\n\nFound 1 ticket(s)"));
+        assert!(result.contains("#123 - Printer jam"));
+    }
 
-    if input.technician_id.is_some() {
-        output.push_str(&format!("Technician: {}\n", request.display_technician()));
+    #[tokio::test]
+    async fn test_structured_output_keeps_text_and_data() {
+        let site: Site = serde_json::from_str(r#"{"id": 3, "name": "Aarhus"}"#).unwrap();
+        let result = structured(async {
+            Ok(ToolOutput::new(
+                "Sites: Aarhus".to_string(),
+                json!({"sites": [site]}),
+            ))
+        })
+        .await
+        .unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let data = result.structured_content.clone().unwrap();
+        assert_eq!(data["sites"][0]["id"], "3");
+        assert_eq!(data["sites"][0]["name"], "Aarhus");
+        assert_eq!(result_text(Ok(result)).unwrap(), "Sites: Aarhus");
+
+        let failed = structured(async { Err("Failed to list sites".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(failed.is_error, Some(true));
+        assert!(failed.structured_content.is_none());
+        assert_eq!(result_text(Ok(failed)).unwrap_err(), "Failed to list sites");
     }
 
-    if input.group.is_some() {
-        if let Some(group) = request.display_group() {
-            output.push_str(&format!("Group: {}\n", group));
-        }
+    #[test]
+    fn test_list_defaults_apply_unless_overridden() {
+        let defaults = ListDefaults {
+            limit: 50,
+            group: Some("Service Desk".to_string()),
+            site: None,
+        };
+        assert_eq!(defaults.limit(None), 50);
+        assert_eq!(defaults.limit(Some(5)), 5);
+        assert_eq!(defaults.group(None).as_deref(), Some("Service Desk"));
+        assert_eq!(defaults.group(Some("Network")).as_deref(), Some("Network"));
+        assert_eq!(defaults.group(Some("ANY")), None);
+        assert_eq!(defaults.site(None), None);
     }
 
-    if let Some(updated) = request.last_updated_time.as_ref().and_then(|t| t.display()) {
-        output.push_str(&format!("\nUpdated: {}\n", updated));
+    #[test]
+    fn test_list_defaults_scope_params() {
+        let defaults = ListDefaults {
+            limit: 50,
+            group: Some("Service Desk".to_string()),
+            site: Some("Aarhus".to_string()),
+        };
+        let data = defaults
+            .scope(ListParams::new(), None, Some("any"))
+            .to_input_data();
+        let criteria = &data["list_info"]["search_criteria"];
+        assert_eq!(criteria[0]["field"], "group.name");
+        assert_eq!(criteria[0]["value"], "Service Desk");
+        assert!(criteria.get(1).is_none());
     }
 
-    output
-}
+    #[test]
+    fn test_format_server_info() {
+        let settings = ClientSettings {
+            base_url: "https://sdp.example.com/api/v3".to_string(),
+            cloud: false,
+            portal: None,
+            technician_id: Some("5".to_string()),
+            read_only: true,
+            cache_ttl_secs: Some(30),
+        };
+        let tools = vec![
+            ("list_requests".to_string(), true),
+            ("close_request".to_string(), false),
+        ];
+        let result = format_server_info(
+            &settings,
+            &["projects".to_string()],
+            &tools,
+            2000,
+            &ListDefaults::default(),
+        );
+        assert!(result.starts_with(&format!("Glass {} ", env!("CARGO_PKG_VERSION"))));
+        assert!(result.contains("SDP API: https://sdp.example.com/api/v3 (on-premises, API key)\n"));
+        assert!(result.contains("Read-only mode: on\n"));
+        assert!(result.contains("Cache: on (30 s)\n"));
+        assert!(result.contains("List defaults: 20 results, group any, site any\n"));
+        assert!(result.contains("Unsupported endpoints: projects"));
+        assert!(result.contains("Enabled tools (1): list_requests\n"));
+        assert!(result.contains("Disabled by read-only mode (1): close_request\n"));
+
+        let writable = ClientSettings {
+            read_only: false,
+            ..settings
+        };
+        let result = format_server_info(&writable, &[], &tools, 2000, &ListDefaults::default());
+        assert!(result.contains("Enabled tools (2): list_requests, close_request\n"));
+        assert!(!result.contains("Disabled by read-only mode"));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::Config;
-    use crate::models::{NamedEntity, SdpTimestamp};
+    #[test]
+    fn test_format_health() {
+        let healthy = HealthStatus {
+            circuit_open_since: None,
+            consecutive_failures: 0,
+            retries: 2,
+            last_error_at: None,
+            last_error: None,
+            unsupported_endpoints: vec![],
+        };
+        let result = format_health(
+            &Ok(()),
+            std::time::Duration::from_millis(182),
+            &healthy,
+            false,
+        );
+        assert!(result.contains("Connection: OK (round trip 182 ms)\n"));
+        assert!(result.contains("Circuit breaker: closed\n"));
+        assert!(result.contains("Retries since startup: 2\n"));
+        assert!(result.contains("Last error: none\n"));
+        assert!(result.contains("Read-only mode: off\n"));
+
+        let down = HealthStatus {
+            circuit_open_since: Some("14:02:11".to_string()),
+            consecutive_failures: 3,
+            last_error_at: Some("2026-10-18 14:02:11".to_string()),
+            last_error: Some("Connection refused".to_string()),
+            unsupported_endpoints: vec!["projects".to_string()],
+            ..healthy
+        };
+        let result = format_health(
+            &Err("SDP unreachable".to_string()),
+            std::time::Duration::from_millis(3),
+            &down,
+            true,
+        );
+        assert!(result.contains("Connection: FAILED after 3 ms - SDP unreachable\n"));
+        assert!(result.contains("Circuit breaker: open since 14:02:11 (3 consecutive failures"));
+        assert!(result.contains("Last error: 2026-10-18 14:02:11 - Connection refused\n"));
+        assert!(result.contains("Unsupported endpoints: projects\n"));
+        assert!(result.contains("Read-only mode: on\n"));
+    }
 
-    // ========================================================================
-    // Truncation tests
-    // ========================================================================
+    #[test]
+    fn test_markdown_request_table() {
+        let requests: Vec<RequestSummary> = serde_json::from_str(
+            r#"[{"id": "123", "subject": "VPN | Wi-Fi down", "status": {"name": "Open"}}]"#,
+        )
+        .unwrap();
+        let table = markdown_request_table(&requests);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| ID | Subject | Status | Priority | Technician | Requester |"
+        );
+        assert_eq!(lines[1], "|---|---|---|---|---|---|");
+        assert!(lines[2].starts_with("| 123 | VPN \\| Wi-Fi down | Open |"));
+        assert_eq!(lines.len(), 3);
+    }
 
     #[test]
-    fn test_truncate_text_short_text() {
-        let text = "Short text";
-        let result = truncate_text(text, 100);
-        assert_eq!(result, text);
+    fn test_requests_csv_quotes_and_neutralizes_formulas() {
+        let requests: Vec<RequestSummary> = serde_json::from_str(
+            r#"[{"id": "123", "subject": "VPN, \"again\"", "status": {"name": "Open"},
+                 "created_time": {"display_value": "Mar 3, 2025 10:15 AM", "value": "1741000000000"}},
+                {"id": "124", "subject": "=HYPERLINK(\"http://evil\")"}]"#,
+        )
+        .unwrap();
+        let csv = requests_csv(&requests, |id| format!("https://sdp/{}", id));
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(
+            lines[0],
+            "ID,Subject,Status,Priority,Technician,Requester,Group,Site,Category,Type,Created,Due By,Last updated,Link"
+        );
+        assert_eq!(
+            lines[1],
+            "123,\"VPN, \"\"again\"\"\",Open,,,,,,,,\"Mar 3, 2025 10:15 AM\",,,https://sdp/123"
+        );
+        assert!(lines[2].starts_with("124,\"'=HYPERLINK(\"\"http://evil\"\")\",,"));
+        assert_eq!(lines[3], "");
     }
 
     #[test]
-    fn test_truncate_text_exact_length() {
-        let text = "x".repeat(100);
-        let result = truncate_text(&text, 100);
-        assert_eq!(result, text);
+    fn test_export_file_name() {
+        use chrono::TimeZone;
+
+        let now = chrono::Utc.with_ymd_and_hms(2026, 3, 10, 8, 30, 5).unwrap();
+        assert_eq!(export_file_name(None, &now), "requests-20260310-083005.csv");
+        assert_eq!(export_file_name(Some("march"), &now), "march.csv");
+        assert_eq!(export_file_name(Some("march.CSV"), &now), "march.CSV");
+    }
+
+    #[tokio::test]
+    async fn test_write_new_file_never_overwrites() {
+        let path = std::env::temp_dir().join(format!("glass-export-{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        write_new_file(&path, b"id\r\n1\r\n").await.unwrap();
+        let err = write_new_file(&path, b"id\r\n").await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read(&path).unwrap(), b"id\r\n1\r\n");
+
+        std::fs::remove_file(&path).unwrap();
     }
 
     #[test]
-    fn test_truncate_text_long_text() {
-        let text = "word ".repeat(500); // 2500 chars
-        let result = truncate_text(&text, 100);
-        assert!(result.len() <= 100);
-        assert!(result.ends_with("... [truncated]"));
+    fn test_tool_output_formatted() {
+        let output = || ToolOutput::new("Found 1 site".to_string(), json!({"sites": ["Aarhus"]}));
+
+        assert_eq!(
+            output().formatted(None, || unreachable!()).text,
+            "Found 1 site"
+        );
+        assert_eq!(
+            output()
+                .formatted(Some(OutputFormat::Markdown), || "| Name |".to_string())
+                .text,
+            "| Name |"
+        );
+        let json = output().formatted(Some(OutputFormat::Json), || unreachable!());
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json.text).unwrap(),
+            json!({"sites": ["Aarhus"]})
+        );
+        assert_eq!(json.data, json!({"sites": ["Aarhus"]}));
     }
 
     #[test]
-    fn test_truncate_text_breaks_at_word() {
-        let text = "hello world this is a long sentence that needs truncation";
-        let result = truncate_text(text, 30);
-        assert!(result.ends_with("... [truncated]"));
-        // Should break at a word boundary, not in the middle of a word
-        assert!(!result.contains("sente... [truncated]"));
+    fn test_confirmation_outcome() {
+        assert!(confirmation_outcome(Ok(Some(Confirmation { confirm: true }))).is_ok());
+        // Clients without elicitation leave confirmation to the assistant
+        assert!(confirmation_outcome(Err(ElicitationError::CapabilityNotSupported)).is_ok());
+
+        for answer in [
+            Ok(Some(Confirmation { confirm: false })),
+            Ok(None),
+            Err(ElicitationError::UserDeclined),
+            Err(ElicitationError::UserCancelled),
+        ] {
+            assert_eq!(
+                confirmation_outcome(answer).unwrap_err(),
+                NOT_CONFIRMED_MESSAGE
+            );
+        }
+        let failed = confirmation_outcome(Err(ElicitationError::NoContent)).unwrap_err();
+        assert!(failed.starts_with("Could not get the user's confirmation"));
     }
 
     #[test]
-    fn test_truncate_text_multibyte_utf8() {
-        // Danish characters (multi-byte) should not cause a panic
-        let text = "Ædansen gik over æblegrøden og blåbærkagen var også meget lækker i dag";
-        let result = truncate_text(text, 30);
-        assert!(result.ends_with("... [truncated]"));
-        // Verify the result is valid UTF-8 (this would panic if not)
-        assert!(result.len() <= 50); // 30 + room for suffix
+    fn test_ticket_list() {
+        let ids: Vec<String> = (1..=12).map(|i| i.to_string()).collect();
+        assert_eq!(ticket_list(&ids[..2]), "2 ticket(s) (#1, #2)");
+        assert_eq!(
+            ticket_list(&ids),
+            "12 ticket(s) (#1, #2, #3, #4, #5, #6, #7, #8, #9, #10, and 2 more)"
+        );
     }
 
-    fn test_config() -> Config {
-        // Set env vars for Config::from_env() since api_key is private
-        std::env::set_var("SDP_BASE_URL", "https://test.example.com");
-        std::env::set_var("SDP_API_KEY", "test_key_12345");
-        Config::from_env().expect("Failed to create test config")
+    #[test]
+    fn test_every_tool_is_annotated() {
+        let router = GlassServer::tool_router();
+        for tool in router.list_all() {
+            let annotations = tool.annotations.as_ref().unwrap();
+            let read_only = annotations.read_only_hint == Some(true);
+            assert!(
+                read_only || annotations.destructive_hint.is_some(),
+                "{} needs read_only_hint or destructive_hint",
+                tool.name
+            );
+        }
+
+        let hints = |name: &str| router.get(name).unwrap().annotations.clone().unwrap();
+        assert_eq!(hints("get_request").read_only_hint, Some(true));
+        assert_eq!(hints("delete_request").destructive_hint, Some(true));
+        assert_eq!(hints("add_note").destructive_hint, Some(false));
+        assert_eq!(hints("add_note").idempotent_hint, Some(false));
+    }
+
+    #[test]
+    fn test_prompts_are_listed_with_arguments() {
+        let prompts = GlassServer::prompt_router().list_all();
+        let mut names: Vec<_> = prompts.iter().map(|p| p.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["daily_standup", "draft_reply", "triage_ticket"]);
+
+        let triage = prompts.iter().find(|p| p.name == "triage_ticket").unwrap();
+        let arguments = triage.arguments.as_ref().unwrap();
+        assert_eq!(arguments[0].name, "request_id");
+        assert_eq!(arguments[0].required, Some(true));
     }
 
-    fn test_client() -> SdpClient {
-        SdpClient::new(&test_config()).expect("Failed to create test client")
+    #[test]
+    fn test_format_whoami() {
+        assert!(format_whoami(None, false).contains("GLASS_TECHNICIAN_ID"));
+
+        let technician: Technician = serde_json::from_str(
+            r#"{"id": "5", "name": "Mikkel", "email_id": "mikkel@example.com"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            format_whoami(Some(&technician), true),
+            "You are Mikkel (technician ID: 5)\nEmail: mikkel@example.com\nSource: GLASS_TECHNICIAN_ID"
+        );
     }
 
     #[test]
-    fn test_server_creation() {
-        let client = test_client();
-        let server = GlassServer::new(client);
-        let info = server.get_info();
-        assert!(info.instructions.is_some());
+    fn test_format_unassigned_tickets() {
+        assert_eq!(
+            format_unassigned_tickets(Some("Network"), &[]),
+            "No unassigned open tickets in Network."
+        );
+
+        let requests: Vec<RequestSummary> =
+            serde_json::from_str(r#"[{"id": "123", "subject": "Printer jam"}]"#).unwrap();
+        let result = format_unassigned_tickets(None, &requests);
+        assert!(result.starts_with("Unassigned open tickets (oldest first):"));
+        assert!(result.contains("#123 - Printer jam"));
     }
 
     #[test]
-    fn test_server_info_has_tools_capability() {
-        let client = test_client();
-        let server = GlassServer::new(client);
-        let info = server.get_info();
-        assert!(info.capabilities.tools.is_some());
+    fn test_format_overdue_tickets() {
+        let now = 1_800_000_000_000;
+        let mut requests: Vec<RequestSummary> =
+            serde_json::from_str(r#"[{"id": "123", "subject": "Printer jam"}]"#).unwrap();
+        requests[0].due_by_time = Some(SdpTimestamp::from_epoch_millis(now - 26 * 3_600_000));
+
+        let result = format_overdue_tickets(&requests, now);
+        assert!(result.contains("Found 1 overdue ticket(s)"));
+        assert!(result.contains("#123 - Printer jam"));
+        assert!(result.contains("(1d 2h late)"));
+        assert_eq!(format_overdue_tickets(&[], now), "No overdue open tickets.");
     }
 
     #[test]
-    fn test_ping_tool_returns_pong() {
-        let client = test_client();
-        let server = GlassServer::new(client);
-        let result = server.ping();
-        assert_eq!(result, "pong");
+    fn test_due_window_end() {
+        use chrono::TimeZone;
+
+        let now = chrono::Utc.with_ymd_and_hms(2026, 3, 10, 8, 30, 0).unwrap();
+        let midnight = chrono::Utc.with_ymd_and_hms(2026, 3, 11, 0, 0, 0).unwrap();
+        assert_eq!(due_window_end(&now, None), midnight.timestamp_millis());
+        assert_eq!(
+            due_window_end(&now, Some(4)),
+            now.timestamp_millis() + 4 * 3_600_000
+        );
     }
 
     #[test]
-    fn test_format_request_list_empty() {
-        let result = format_request_list(&[]);
-        assert_eq!(result, "No tickets found matching the criteria.");
+    fn test_format_due_soon() {
+        let now = 1_800_000_000_000;
+        let mut requests: Vec<RequestSummary> =
+            serde_json::from_str(r#"[{"id": "77", "subject": "Payroll export"}]"#).unwrap();
+        requests[0].due_by_time = Some(SdpTimestamp::from_epoch_millis(now + 90 * 60_000));
+
+        let result = format_due_soon(&requests, now, Some(4));
+        assert!(result.contains("Found 1 ticket(s) due in the next 4 hour(s)"));
+        assert!(result.contains("#77 - Payroll export"));
+        assert!(result.contains("(in 1h 30m)"));
+        assert_eq!(
+            format_due_soon(&[], now, None),
+            "No open tickets due today."
+        );
     }
 
     #[test]
-    fn test_format_request_list_with_items() {
-        let requests = vec![RequestSummary {
-            id: "123".to_string(),
-            subject: Some("Test ticket".to_string()),
-            status: Some(NamedEntity {
-                id: Some("1".to_string()),
-                name: Some("Open".to_string()),
-            }),
-            priority: Some(NamedEntity {
-                id: Some("2".to_string()),
-                name: Some("High".to_string()),
-            }),
-            technician: None,
-            requester: Some(NamedEntity {
-                id: Some("3".to_string()),
-                name: Some("John Doe".to_string()),
-            }),
-            created_time: Some(SdpTimestamp {
-                value: None,
-                display_value: Some("Feb 6, 2026".to_string()),
-            }),
-            last_updated_time: None,
-            due_by_time: None,
-            request_type: None,
-            category: None,
-            subcategory: None,
-            site: None,
-            group: None,
-        }];
+    fn test_format_request_history() {
+        let history: Vec<HistoryEntry> = serde_json::from_str(
+            r#"[{
+                "operation": "UPDATE",
+                "by": {"name": "Mikkel Holm"},
+                "time": {"value": "1700000000000", "display_value": "Nov 14, 2023 11:13 PM"},
+                "diff": [{"field": "status", "prev_value": {"name": "Open"}, "current_value": {"name": "On Hold"}}]
+            }]"#,
+        )
+        .unwrap();
+
+        let result = format_request_history("123", &history);
+        assert!(result.contains("History for request #123 (1 entries)"));
+        assert!(result.contains("[Nov 14, 2023 11:13 PM] Mikkel Holm - UPDATE"));
+        assert!(result.contains("status: Open -> On Hold"));
+        assert_eq!(
+            format_request_history("123", &[]),
+            "No history recorded for request #123."
+        );
+    }
 
-        let result = format_request_list(&requests);
-        assert!(result.contains("#123"));
-        assert!(result.contains("Test ticket"));
-        assert!(result.contains("Open"));
-        assert!(result.contains("High"));
-        assert!(result.contains("John Doe"));
+    #[test]
+    fn test_build_timeline_sorts_sources_chronologically() {
+        let notes: Vec<Note> = serde_json::from_str(
+            r#"[{"id": "1", "description": "Called user back", "created_by": {"name": "Mikkel Holm"},
+                 "created_time": {"value": "3000", "display_value": "T3"}, "show_to_requester": false}]"#,
+        )
+        .unwrap();
+        let worklogs: Vec<Worklog> = serde_json::from_str(
+            r#"[{"id": "2", "description": "Reimaged laptop", "owner": {"name": "Mikkel Holm"},
+                 "start_time": {"value": "4000", "display_value": "T4"},
+                 "time_spent": {"hours": "1", "minutes": "30"}}]"#,
+        )
+        .unwrap();
+        let history: Vec<HistoryEntry> = serde_json::from_str(
+            r#"[{"operation": "UPDATE", "by": {"name": "Sofie Berg"},
+                 "time": {"value": "1000", "display_value": "T1"},
+                 "diff": [{"field": "status", "prev_value": "Open", "current_value": "In Progress"}]},
+                {"operation": "NOTE", "description": "No time on this entry"}]"#,
+        )
+        .unwrap();
+
+        let events = build_timeline(&notes, &[], &worklogs, &history);
+        let kinds: Vec<&str> = events.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                "Change (UPDATE)",
+                "Note [Internal]",
+                "Worklog",
+                "Change (NOTE)"
+            ]
+        );
+        assert_eq!(events[0].body, "status: Open -> In Progress");
+        assert!(events[2].body.starts_with("Logged 1h 30m: Reimaged laptop"));
+        assert_eq!(events[3].time, "Unknown time");
+
+        let output = format_timeline("42", &events, &["Conversations: timeout".to_string()]);
+        assert!(output.starts_with("Timeline for request #42 (4 events):"));
+        assert!(output.contains("[T3] Note [Internal] - Mikkel Holm\n   Called user back"));
+        assert!(output.contains("Warning: Failed to fetch Conversations: timeout"));
     }
 
     #[test]
-    fn test_format_technician_list_empty() {
-        let result = format_technician_list(&[]);
-        assert_eq!(result, "No technicians found.");
+    fn test_build_timeline_groups_email_threads() {
+        let conversations: Vec<Conversation> = serde_json::from_str(
+            r#"[{"id": "1", "subject": "VPN drops", "from": {"name": "Jane Smith"}, "is_incoming": true,
+                 "description": "It keeps dropping", "sent_time": {"value": "1000", "display_value": "T1"}},
+                {"id": "2", "subject": "RE: VPN drops [Request ID :##42##]", "from": {"name": "Mikkel Holm"},
+                 "is_incoming": false, "description": "<p>Which network?</p>",
+                 "sent_time": {"value": "3000", "display_value": "T3"}},
+                {"id": "3", "subject": "Laptop order", "from": {"name": "Jane Smith"}, "is_incoming": true,
+                 "description": "Also, my laptop", "sent_time": {"value": "2000", "display_value": "T2"}}]"#,
+        )
+        .unwrap();
+
+        let events = build_timeline(&[], &conversations, &[], &[]);
+        let kinds: Vec<&str> = events.iter().map(|e| e.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["Email thread (2 messages)", "Email (Incoming)"]);
+        assert_eq!(events[0].actor, "Jane Smith, Mikkel Holm");
+        assert_eq!(
+            events[0].body,
+            "Subject: VPN drops\n[T1] Jane Smith (Incoming):\n  It keeps dropping\n\
+             [T3] Mikkel Holm (Outgoing):\n  Which network?"
+        );
+
+        let danish = crate::i18n::with_language(crate::i18n::Lang::Da, || {
+            build_timeline(&[], &conversations, &[], &[])
+        });
+        assert_eq!(danish[0].kind, "E-mail-tråd (2 beskeder)");
+        assert_eq!(danish[1].kind, "E-mail (Indgående)");
+        assert!(danish[0]
+            .body
+            .starts_with("Emne: VPN drops\n[T1] Jane Smith (Indgående):\n"));
     }
 
     #[test]
-    fn test_format_technician_list_with_items() {
-        let technicians = vec![Technician {
-            id: "456".to_string(),
-            name: Some("Jane Smith".to_string()),
-            email_id: Some("jane@example.com".to_string()),
-            first_name: None,
-            last_name: None,
-            phone: None,
-            mobile: None,
-            job_title: None,
-            department: None,
-            is_active: Some(true),
-            site: None,
-        }];
+    fn test_detail_formatters_render_in_danish() {
+        use crate::i18n::{with_language, Lang};
+
+        let requester: Requester = serde_json::from_str(
+            r#"{"id": "9", "name": "Anna Jensen", "phone": "+45 8765", "mobile": "+45 1234",
+                "job_title": "Controller", "employee_id": "E-77", "site": {"name": "Odense"}}"#,
+        )
+        .unwrap();
+        let asset: Asset = serde_json::from_str(
+            r#"{"id": 3001, "name": "DK-0421", "serial_number": "5CG1234XYZ", "asset_tag": "T-9",
+                "barcode": "B-9", "location": "Room 4", "ip_address": "10.0.0.9"}"#,
+        )
+        .unwrap();
+        let project: Project =
+            serde_json::from_str(r#"{"id": 61, "title": "Laptop refresh"}"#).unwrap();
+        let solution: Solution =
+            serde_json::from_str(r#"{"id": 4501, "title": "VPN drops after sleep"}"#).unwrap();
+        let notes: Vec<Note> = serde_json::from_str(
+            r#"[{"id": "1", "description": "Called user back", "show_to_requester": false}]"#,
+        )
+        .unwrap();
+        let conversations: Vec<Conversation> = serde_json::from_str(
+            r#"[{"id": "2", "from": {"name": "Jane Smith"}, "is_incoming": true, "description": "Hi"}]"#,
+        )
+        .unwrap();
+        let worklogs: Vec<Worklog> =
+            serde_json::from_str(r#"[{"id": "3", "time_spent": {"hours": "1", "minutes": "30"}}]"#)
+                .unwrap();
+        let history: Vec<HistoryEntry> = serde_json::from_str(
+            r#"[{"operation": "UPDATE", "diff": [{"field": "status", "current_value": "Lukket"}]}]"#,
+        )
+        .unwrap();
+
+        let outputs = with_language(Lang::Da, || {
+            let events = build_timeline(&notes, &conversations, &worklogs, &history);
+            vec![
+                format_requester_details(&requester),
+                format_asset_details(&asset),
+                format_project_details(&project, &[], None, DEFAULT_DESCRIPTION_LENGTH),
+                format_project_details(&project, &[], Some("timeout"), DEFAULT_DESCRIPTION_LENGTH),
+                format_solution_details(&solution, DEFAULT_DESCRIPTION_LENGTH),
+                format_timeline("42", &events, &[]),
+                format_timeline("42", &[], &[]),
+                format_asset_list(&[]),
+                format_category_tree(&[]),
+            ]
+        });
 
-        let result = format_technician_list(&technicians);
-        assert!(result.contains("456"));
-        assert!(result.contains("Jane Smith"));
-        assert!(result.contains("jane@example.com"));
+        let english = [
+            "Requester",
+            "Phone",
+            "Mobile",
+            "Job Title",
+            "Employee ID",
+            "Site",
+            "Asset",
+            "Serial",
+            "Barcode",
+            "Location",
+            "IP Address",
+            "Project",
+            "Owner",
+            "Milestones",
+            "No milestones",
+            "Could not fetch",
+            "Solution",
+            "Timeline",
+            "events",
+            "no activity",
+            "Internal",
+            "Email",
+            "Incoming",
+            "Logged",
+            "Worklog",
+            "Change",
+            "(none)",
+            "No assets",
+            "No categories",
+        ];
+        for output in &outputs {
+            for word in english {
+                assert!(
+                    !output.contains(word),
+                    "{:?} in Danish output:\n{}",
+                    word,
+                    output
+                );
+            }
+        }
+        assert!(outputs[0].starts_with("Anmoder #9: Anna Jensen\n"));
+        assert!(outputs[5].starts_with("Tidslinje for sag #42 (4 hændelser):\n"));
     }
 
     // ========================================================================
-    // Write operation formatting tests (M4)
+    // Bulk operation tests
     // ========================================================================
 
-    fn create_test_request() -> Request {
-        Request {
-            id: "123".to_string(),
-            subject: Some("Test ticket".to_string()),
-            description: Some("Test description".to_string()),
-            status: Some(NamedEntity {
-                id: Some("1".to_string()),
-                name: Some("Open".to_string()),
-            }),
-            priority: Some(NamedEntity {
-                id: Some("2".to_string()),
-                name: Some("High".to_string()),
-            }),
-            urgency: None,
-            impact: None,
-            technician: Some(NamedEntity {
-                id: Some("456".to_string()),
-                name: Some("John Doe".to_string()),
-            }),
-            requester: Some(NamedEntity {
-                id: Some("789".to_string()),
-                name: Some("Jane User".to_string()),
-            }),
-            request_type: None,
-            category: None,
-            subcategory: None,
-            item: None,
-            site: None,
-            group: Some(NamedEntity {
-                id: Some("10".to_string()),
-                name: Some("IT Support".to_string()),
-            }),
-            level: None,
-            mode: None,
-            service: None,
-            created_time: Some(SdpTimestamp {
-                value: None,
-                display_value: Some("Feb 6, 2026".to_string()),
-            }),
-            last_updated_time: Some(SdpTimestamp {
-                value: None,
-                display_value: Some("Feb 6, 2026 10:30 AM".to_string()),
-            }),
-            due_by_time: None,
-            first_response_due_by_time: None,
-            resolution_due_by_time: None,
-            completed_time: None,
-            resolution: None,
-            closure_info: None,
-            is_overdue: None,
-            is_fcr: None,
-            has_attachments: None,
-            has_notes: None,
-            email_ids_to_notify: None,
-            approval_status: None,
-        }
+    #[tokio::test]
+    async fn test_run_bulk_keeps_order_and_isolates_failures() {
+        let ids: Vec<String> = (1..=10).map(|i| i.to_string()).collect();
+        let results = run_bulk(&ids, &Progress::none(), "updated", |id| async move {
+            if id == "3" {
+                Err(crate::error::GlassError::validation("boom"))
+            } else {
+                Ok(id.parse::<u32>().unwrap())
+            }
+        })
+        .await;
+
+        let returned: Vec<&str> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(
+            returned,
+            vec!["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"]
+        );
+        assert!(results[2].1.is_err());
+        assert_eq!(results[9].1.as_ref().unwrap(), &10);
     }
 
     #[test]
-    fn test_format_create_result() {
-        let request = create_test_request();
-        let result = format_create_result(&request);
+    fn test_format_bulk_results() {
+        let outcomes: Vec<BulkOutcome> = vec![
+            ("123".to_string(), Ok(create_test_request())),
+            (
+                "999".to_string(),
+                Err("Resource not found: 999".to_string()),
+            ),
+        ];
+        let result = format_bulk_results("update", &outcomes);
+        assert!(result.starts_with("Bulk update: 1 of 2 ticket(s) succeeded, 1 failed."));
+        assert!(result.contains("Succeeded:\n  #"));
+        assert!(result.contains("Failed:\n  #999: Resource not found: 999"));
+    }
 
-        assert!(result.contains("Successfully created ticket #123"));
-        assert!(result.contains("Test ticket"));
-        assert!(result.contains("Status: Open"));
-        assert!(result.contains("Priority: High"));
-        assert!(result.contains("John Doe"));
-        assert!(result.contains("IT Support"));
-        assert!(result.contains("Next steps:"));
+    #[test]
+    fn test_bulk_results_json() {
+        let outcomes: Vec<BulkOutcome> = vec![
+            ("123".to_string(), Ok(create_test_request())),
+            (
+                "999".to_string(),
+                Err("Resource not found: 999".to_string()),
+            ),
+        ];
+        assert_eq!(
+            bulk_results_json(&outcomes),
+            json!([
+                {"id": "123", "ok": true, "error": null},
+                {"id": "999", "ok": false, "error": "Resource not found: 999"}
+            ])
+        );
     }
 
     #[test]
-    fn test_format_update_result() {
+    fn test_format_request_details_custom_fields() {
+        let mut request = create_test_request();
+        request.udf_fields = serde_json::from_str(
+            r#"{"udf_sline_301": "PO-7781", "udf_date_303": {"display_value": "Mar 3, 2026", "value": "1772496000000"}, "udf_long_304": null}"#,
+        )
+        .unwrap();
+
+        let result = format_request_details(
+            &request,
+            &[],
+            &[],
+            &[],
+            &RequestAssociations::default(),
+            "https://example.com",
+            &[],
+            DEFAULT_DESCRIPTION_LENGTH,
+        );
+        assert!(result.contains(
+            "--- Custom Fields ---\nudf_date_303: Mar 3, 2026\nudf_sline_301: PO-7781\n"
+        ));
+        assert!(!result.contains("udf_long_304"));
+    }
+
+    #[test]
+    fn test_format_custom_field_list() {
+        let fields = vec![CustomFieldDefinition {
+            name: "udf_pick_302".to_string(),
+            label: Some("Support Tier".to_string()),
+            field_type: Some("Pick List".to_string()),
+            allowed_values: vec!["Bronze".to_string(), "Gold".to_string()],
+            mandatory: true,
+        }];
+        let result = format_custom_field_list(&fields);
+        assert!(result.contains("- udf_pick_302 (Support Tier) | Type: Pick List | Mandatory\n"));
+        assert!(result.contains("Allowed values: Bronze, Gold"));
+        assert_eq!(
+            format_custom_field_list(&[]),
+            "No custom fields are defined for tickets."
+        );
+    }
+
+    #[test]
+    fn test_format_reply_result_for_notification() {
+        let notification: Notification = serde_json::from_str(r#"{"id": "88"}"#).unwrap();
+        let forward = ReplyRequest::forward(
+            "Escalation",
+            "<p>Hi</p>",
+            vec!["boss@example.com".to_string()],
+        );
+        assert_eq!(forward.notification_type, "forward");
+
+        let result = format_reply_result("123", &forward, &notification);
+        assert!(result.starts_with("Successfully sent notification #88 on ticket #123."));
+        assert!(result.contains("To: boss@example.com"));
+    }
+
+    #[test]
+    fn test_format_escalate_result() {
         let request = create_test_request();
-        let result = format_update_result(&request);
+        let result = format_escalate_result(&request, "VIP user blocked", None);
+        assert!(result.starts_with(&format!("Escalated ticket #{}", request.id)));
+        assert!(result.contains("Reason: VIP user blocked"));
+        assert!(result.contains("recorded as an internal note"));
 
-        assert!(result.contains("Successfully updated ticket #123"));
-        assert!(result.contains("Current state:"));
-        assert!(result.contains("Status: Open"));
-        assert!(result.contains("Priority: High"));
+        let result = format_escalate_result(&request, "VIP user blocked", Some("timeout"));
+        assert!(result.contains("Warning: the escalation note could not be added (timeout)"));
     }
 
     #[test]
-    fn test_format_close_result() {
+    fn test_format_change_requester_result() {
         let mut request = create_test_request();
-        request.status = Some(NamedEntity {
-            id: Some("5".to_string()),
-            name: Some("Closed".to_string()),
-        });
-        request.closure_info = Some(crate::models::ClosureInfo {
-            closure_code: Some(NamedEntity {
-                id: Some("1".to_string()),
-                name: Some("Success".to_string()),
-            }),
-            closure_comments: Some("Issue resolved".to_string()),
-            closed_by: None,
-            closed_time: Some(SdpTimestamp {
-                value: None,
-                display_value: Some("Feb 6, 2026 11:00 AM".to_string()),
-            }),
+        request.requester = Some(NamedEntity {
+            id: Some("901".to_string()),
+            name: Some("Anna Jensen".to_string()),
+            email_id: Some("anna@example.com".to_string()),
         });
+        let result = format_change_requester_result(&request);
+        assert!(result.contains("Requester: Anna Jensen <anna@example.com>"));
+    }
 
-        let result = format_close_result(&request);
+    #[test]
+    fn test_format_cc_result() {
+        let mut request = create_test_request();
+        assert!(format_cc_result(&request).contains("  (none)"));
 
-        assert!(result.contains("Successfully closed ticket #123"));
-        assert!(result.contains("Status: Closed"));
-        assert!(result.contains("Closure Code: Success"));
-        assert!(result.contains("Issue resolved"));
+        request.email_ids_to_notify = Some(vec!["lead@example.com".to_string()]);
+        let result = format_cc_result(&request);
+        assert!(result.starts_with(&format!("CC recipients on ticket #{}", request.id)));
+        assert!(result.contains("  lead@example.com\n"));
     }
 
     #[test]
-    fn test_format_add_note_result() {
-        use crate::models::Note;
+    fn test_reply_signature_render() {
+        let technician: Technician = serde_json::from_str(
+            r#"{"id": "5", "name": "Jane Smith", "email_id": "jane@example.com"}"#,
+        )
+        .unwrap();
+        let signature =
+            ReplySignature::new("Best regards,\\n{technician}\\n{job_title}\\nIT Service Desk");
+        assert!(signature.needs_technician());
+        assert_eq!(
+            signature.render(Some(&technician)),
+            "Best regards,\nJane Smith\nIT Service Desk"
+        );
+        assert_eq!(
+            signature.append_to("<p>Fixed</p>", Some(&technician)),
+            "<p>Fixed</p><br><br>Best regards,<br>Jane Smith<br>IT Service Desk"
+        );
+
+        let fixed = ReplySignature::new("-- \nIT & Facilities");
+        assert!(!fixed.needs_technician());
+        assert_eq!(fixed.render(None), "--\nIT & Facilities");
+    }
 
-        let note = Note {
-            id: "999".to_string(),
-            description: Some("Test note content".to_string()),
-            created_by: None,
-            created_time: Some(SdpTimestamp {
-                value: None,
-                display_value: Some("Feb 6, 2026 10:45 AM".to_string()),
-            }),
-            show_to_requester: Some(false),
-            notify_technician: Some(true),
-            content_url: None,
+    #[test]
+    fn test_format_entry_page() {
+        let note: Note =
+            serde_json::from_str(r#"{"id": "9", "description": "<p>Rebooted the switch</p>"}"#)
+                .unwrap();
+        let page = WithContent {
+            items: vec![note],
+            failures: vec![],
+            has_more: true,
         };
+        let output = format_entry_page("42", EntryKind::Notes, 20, &page, format_note_entry);
+        assert!(output.starts_with("Showing notes 21-21 of ticket #42:\n"));
+        assert!(output.contains("Rebooted the switch"));
+        assert!(output.ends_with("More notes follow: call list_notes with offset 21.\n"));
+
+        let empty: WithContent<Conversation> = WithContent {
+            items: vec![],
+            failures: vec![],
+            has_more: false,
+        };
+        assert_eq!(
+            format_entry_page(
+                "42",
+                EntryKind::Conversations,
+                0,
+                &empty,
+                format_conversation_entry
+            ),
+            "No conversations on ticket #42."
+        );
+        assert_eq!(format_more_entries_hint("42", false, false), "");
+        assert!(format_more_entries_hint("42", true, false)
+            .contains("call list_conversations with offset 100"));
+
+        let danish = crate::i18n::with_language(crate::i18n::Lang::Da, || {
+            format_entry_page("42", EntryKind::Notes, 20, &page, format_note_entry)
+        });
+        assert!(danish.starts_with("Viser noter 21-21 på sag #42:\n"));
+        assert!(danish.ends_with("Flere noter følger: kald list_notes med offset 21.\n"));
+    }
 
-        let result = format_add_note_result("123", &note);
+    #[test]
+    fn test_format_inline_images_note() {
+        assert_eq!(
+            format_inline_images_note(0, 0),
+            "\n\nNo inline images found."
+        );
+        assert_eq!(
+            format_inline_images_note(2, 2),
+            "\n\nInline images: 2 of 2 attached."
+        );
+        assert!(
+            format_inline_images_note(5, 7).contains("(at most 5 are attached, each up to 512 KB)")
+        );
+    }
 
-        assert!(result.contains("Successfully added note #999 to ticket #123"));
-        assert!(result.contains("Internal (technicians only)"));
-        assert!(result.contains("Technician notification: Sent"));
+    #[test]
+    fn test_format_watcher_list() {
+        assert!(format_watcher_list("42", &[]).contains("  (none)"));
+
+        let watchers: Vec<Watcher> = serde_json::from_str(
+            r#"[{"id": 5, "name": "Jane Smith", "email_id": "jane@example.com"},
+                {"id": "6", "email_id": "ops@example.com"}]"#,
+        )
+        .unwrap();
+        let result = format_watcher_list("42", &watchers);
+        assert!(result.starts_with("Watchers on ticket #42:\n"));
+        assert!(result.contains("  Jane Smith (ID: 5) <jane@example.com>\n"));
+        assert!(result.contains("  ops@example.com (ID: 6)\n"));
+
+        let danish =
+            crate::i18n::with_language(crate::i18n::Lang::Da, || format_watcher_list("42", &[]));
+        assert_eq!(danish, "Følgere af sag #42:\n  (ingen)\n");
     }
 
+    // ========================================================================
+    // Duplicate detection tests
+    // ========================================================================
+
     #[test]
-    fn test_format_assign_result() {
-        use crate::tools::AssignRequestInput;
+    fn test_subject_tokens() {
+        let tokens = subject_tokens("RE: VPN not connecting after Windows update 23H2 - 2024");
+        let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+        assert_eq!(
+            tokens,
+            vec!["23h2", "connecting", "update", "vpn", "windows"]
+        );
+    }
 
-        let request = create_test_request();
-        let input = AssignRequestInput {
-            request_id: "123".to_string(),
-            technician_id: Some("456".to_string()),
-            group: Some("IT Support".to_string()),
-        };
+    #[test]
+    fn test_rank_duplicates() {
+        let mut source = create_test_request();
+        source.subject = Some("VPN not connecting from home".to_string());
+        source.requester = Some(NamedEntity {
+            id: Some("901".to_string()),
+            name: Some("Anna Jensen".to_string()),
+            email_id: None,
+        });
+        let candidates: Vec<RequestSummary> = serde_json::from_str(&format!(
+            r#"[
+                {{"id": "{}", "subject": "VPN not connecting from home"}},
+                {{"id": "2", "subject": "Printer out of toner"}},
+                {{"id": "3", "subject": "VPN connecting slowly", "requester": {{"id": "7"}}}},
+                {{"id": "4", "subject": "Home VPN not connecting", "requester": {{"id": "901"}}}}
+            ]"#,
+            source.id
+        ))
+        .unwrap();
 
-        let result = format_assign_result(&request, &input);
+        let tokens = subject_tokens(source.display_subject());
+        let matches = rank_duplicates(Some(&source), &tokens, &candidates, 10);
+        let ids: Vec<&str> = matches.iter().map(|(r, _)| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["4", "3"]);
+        assert_eq!(matches[0].1, 1.0);
 
-        assert!(result.contains("Successfully assigned ticket #123"));
-        assert!(result.contains("Technician: John Doe"));
-        assert!(result.contains("Group: IT Support"));
+        let output = format_duplicate_matches(Some("1"), "VPN not connecting", 30, &matches);
+        assert!(output.contains("Found 2 likely duplicate(s) of #1 \"VPN not connecting\""));
+        assert!(output.contains("#4 - Home VPN not connecting (100% match)"));
+    }
+
+    #[test]
+    fn test_rank_and_format_similar_requests() {
+        let candidates: Vec<RequestSummary> = serde_json::from_str(
+            r#"[
+                {"id": "1", "subject": "VPN drops after login"},
+                {"id": "2", "subject": "VPN drops every hour", "category": {"name": "Network"}},
+                {"id": "3", "subject": "VPN drops after login", "status": {"name": "Closed"}},
+                {"id": "4", "subject": "Printer out of toner", "category": {"name": "Network"}}
+            ]"#,
+        )
+        .unwrap();
+
+        let tokens = subject_tokens("VPN drops after login");
+        let matches = rank_similar(Some("1"), &tokens, Some("network"), &candidates, 5);
+        let ids: Vec<&str> = matches.iter().map(|(r, _)| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["3", "2"]);
+
+        let resolved: Vec<SimilarMatch<'_>> = vec![
+            (
+                matches[0].0,
+                matches[0].1,
+                Ok(Some("Reinstalled the client.\nRebooted.".to_string())),
+            ),
+            (matches[1].0, matches[1].1, Err("timeout".to_string())),
+        ];
+        let output = format_similar_requests(Some("1"), "VPN drops after login", 365, &resolved);
+        assert!(output.starts_with(
+            "Found 2 resolved or closed ticket(s) similar to #1 \"VPN drops after login\" in the last 365 day(s):\n\n#3 - VPN drops after login (100% match)\n   Status: Closed\n"
+        ));
+        assert!(output.contains("   Resolution: Reinstalled the client.\n   Rebooted.\n"));
+        assert!(output.contains("   Status: Unknown | Category: Network\n"));
+        assert!(output.contains("(Resolution could not be loaded: timeout)"));
+
+        assert_eq!(
+            format_similar_requests(None, "VPN", 30, &[]),
+            "No resolved or closed tickets similar to \"VPN\" in the last 30 day(s)."
+        );
+    }
+
+    #[test]
+    fn test_similar_matches_json() {
+        let candidates: Vec<RequestSummary> = serde_json::from_str(
+            r#"[{"id": "3", "subject": "VPN drops after login"}, {"id": "5", "subject": "VPN drops"}]"#,
+        )
+        .unwrap();
+        let resolved: Vec<SimilarMatch<'_>> = vec![
+            (
+                &candidates[0],
+                1.0,
+                Ok(Some("Reinstalled the client.".to_string())),
+            ),
+            (&candidates[1], 0.5, Err("timeout".to_string())),
+        ];
+        let data = similar_matches_json(&resolved);
+        assert_eq!(data[0]["request"]["id"], "3");
+        assert_eq!(data[0]["resolution"], "Reinstalled the client.");
+        assert_eq!(data[1]["score"], 0.5);
+        assert_eq!(data[1]["resolution_error"], "timeout");
+    }
+
+    #[test]
+    fn test_split_alternatives() {
+        assert_eq!(split_alternatives("High | Urgent"), vec!["High", "Urgent"]);
+        assert_eq!(
+            split_alternatives("Udført, afventer godkendelse"),
+            vec!["Udført, afventer godkendelse"]
+        );
+        assert!(split_alternatives(" | ").is_empty());
     }
 }
@@ -0,0 +1,167 @@
+//! OpenTelemetry export for the Glass MCP server.
+//!
+//! With the `otel` feature enabled and `OTEL_EXPORTER_OTLP_ENDPOINT` set,
+//! tracing spans (tool calls and SDP calls) and tool call metrics are sent
+//! to an OTLP collector over HTTP. The standard `OTEL_*` variables (headers,
+//! service name, ...) are honored by the exporter. Without the feature this
+//! module compiles to no-ops.
+
+use std::time::Duration;
+
+use tracing::Subscriber;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::error::GlassError;
+
+/// Environment variable holding the OTLP collector endpoint.
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Service name reported to the collector unless `OTEL_SERVICE_NAME` is set.
+#[cfg(feature = "otel")]
+const SERVICE_NAME: &str = "glass";
+
+/// Running OTLP export pipeline.
+///
+/// Call [`Telemetry::shutdown`] before exiting so buffered spans and
+/// metrics are flushed.
+pub struct Telemetry {
+    #[cfg(feature = "otel")]
+    tracer_provider: opentelemetry_sdk::trace::SdkTracerProvider,
+    #[cfg(feature = "otel")]
+    meter_provider: opentelemetry_sdk::metrics::SdkMeterProvider,
+}
+
+impl Telemetry {
+    /// Starts the OTLP exporters if an endpoint is configured.
+    ///
+    /// Returns `None` when `OTEL_EXPORTER_OTLP_ENDPOINT` is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GlassError::Config` if an exporter cannot be created.
+    #[cfg(feature = "otel")]
+    pub fn init() -> Result<Option<Self>, GlassError> {
+        use opentelemetry_otlp::{MetricExporter, SpanExporter};
+        use opentelemetry_sdk::metrics::SdkMeterProvider;
+        use opentelemetry_sdk::trace::SdkTracerProvider;
+        use opentelemetry_sdk::Resource;
+
+        if !Self::endpoint_configured() {
+            return Ok(None);
+        }
+
+        let exporter_error =
+            |e: opentelemetry_otlp::ExporterBuildError| GlassError::invalid_config(e.to_string());
+        let span_exporter = SpanExporter::builder()
+            .with_http()
+            .build()
+            .map_err(exporter_error)?;
+        let metric_exporter = MetricExporter::builder()
+            .with_http()
+            .build()
+            .map_err(exporter_error)?;
+
+        let resource = if std::env::var_os("OTEL_SERVICE_NAME").is_some() {
+            Resource::builder().build()
+        } else {
+            Resource::builder().with_service_name(SERVICE_NAME).build()
+        };
+        let tracer_provider = SdkTracerProvider::builder()
+            .with_batch_exporter(span_exporter)
+            .with_resource(resource.clone())
+            .build();
+        let meter_provider = SdkMeterProvider::builder()
+            .with_periodic_exporter(metric_exporter)
+            .with_resource(resource)
+            .build();
+        opentelemetry::global::set_meter_provider(meter_provider.clone());
+
+        Ok(Some(Self {
+            tracer_provider,
+            meter_provider,
+        }))
+    }
+
+    /// Starts the OTLP exporters if an endpoint is configured.
+    ///
+    /// Always returns `None`: this build has no OTLP support.
+    #[cfg(not(feature = "otel"))]
+    pub fn init() -> Result<Option<Self>, GlassError> {
+        Ok(None)
+    }
+
+    /// Returns whether `OTEL_EXPORTER_OTLP_ENDPOINT` is set.
+    pub fn endpoint_configured() -> bool {
+        std::env::var(OTLP_ENDPOINT_ENV).is_ok_and(|value| !value.trim().is_empty())
+    }
+
+    /// Returns the tracing layer that forwards spans to the collector.
+    #[cfg(feature = "otel")]
+    pub fn layer<S>(&self) -> impl Layer<S>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        use opentelemetry::trace::TracerProvider as _;
+
+        tracing_opentelemetry::layer().with_tracer(self.tracer_provider.tracer(SERVICE_NAME))
+    }
+
+    /// Returns the tracing layer that forwards spans to the collector.
+    #[cfg(not(feature = "otel"))]
+    pub fn layer<S>(&self) -> impl Layer<S>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        tracing_subscriber::layer::Identity::new()
+    }
+
+    /// Flushes and stops the exporters.
+    pub fn shutdown(self) {
+        #[cfg(feature = "otel")]
+        {
+            if let Err(e) = self.tracer_provider.shutdown() {
+                tracing::warn!(error = %e, "Failed to flush OTLP traces");
+            }
+            if let Err(e) = self.meter_provider.shutdown() {
+                tracing::warn!(error = %e, "Failed to flush OTLP metrics");
+            }
+        }
+    }
+}
+
+/// Records one tool call in the `glass.tool.calls` counter and the
+/// `glass.tool.duration` histogram (seconds).
+#[cfg(feature = "otel")]
+pub fn record_tool_call(tool: &str, elapsed: Duration, failed: bool) {
+    use opentelemetry::metrics::{Counter, Histogram};
+    use opentelemetry::KeyValue;
+    use std::sync::OnceLock;
+
+    static INSTRUMENTS: OnceLock<(Counter<u64>, Histogram<f64>)> = OnceLock::new();
+    let (calls, duration) = INSTRUMENTS.get_or_init(|| {
+        let meter = opentelemetry::global::meter(SERVICE_NAME);
+        (
+            meter
+                .u64_counter("glass.tool.calls")
+                .with_description("Number of MCP tool calls")
+                .build(),
+            meter
+                .f64_histogram("glass.tool.duration")
+                .with_description("Duration of MCP tool calls")
+                .with_unit("s")
+                .build(),
+        )
+    });
+
+    let attributes = [
+        KeyValue::new("tool", tool.to_string()),
+        KeyValue::new("outcome", if failed { "error" } else { "ok" }),
+    ];
+    calls.add(1, &attributes);
+    duration.record(elapsed.as_secs_f64(), &attributes);
+}
+
+/// Records one tool call; a no-op without the `otel` feature.
+#[cfg(not(feature = "otel"))]
+pub fn record_tool_call(_tool: &str, _elapsed: Duration, _failed: bool) {}
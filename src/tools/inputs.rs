@@ -15,6 +15,7 @@
 //! `sanitize()` and before processing.
 
 use crate::error::GlassError;
+use crate::models::{AssociationKind, SdpTimestamp};
 use rmcp::schemars::{self, JsonSchema};
 use serde::Deserialize;
 
@@ -32,6 +33,16 @@ const MAX_CLOSURE_COMMENTS_LEN: usize = 8_192;
 const MAX_SUBJECT_LEN: usize = 250;
 /// Maximum length for short string fields (category, group, etc.).
 const MAX_SHORT_FIELD_LEN: usize = 500;
+/// Maximum number of entries in list fields (approvers, recipients, etc.).
+const MAX_LIST_ITEMS: usize = 50;
+/// Maximum serialized length of a single custom field value.
+const MAX_CUSTOM_FIELD_VALUE_LEN: usize = 8_192;
+/// Maximum look-ahead window for the due_soon tool (one week).
+const MAX_DUE_SOON_HOURS: u32 = 168;
+/// Maximum number of tickets a single CSV export may contain.
+pub const MAX_EXPORT_ROWS: u32 = 5_000;
+/// Accepted range for `max_length` truncation parameters, in characters.
+const TRUNCATION_LENGTH_RANGE: std::ops::RangeInclusive<usize> = 100..=100_000;
 
 /// Checks that a required string field does not exceed `max_len` characters.
 /// Returns a `GlassError::Validation` if the limit is exceeded.
@@ -47,6 +58,21 @@ fn check_len(field_name: &str, value: &str, max_len: usize) -> Result<(), GlassE
     Ok(())
 }
 
+/// Checks that an optional `max_length` truncation parameter is within range.
+fn check_max_length(value: Option<usize>) -> Result<(), GlassError> {
+    match value {
+        Some(length) if !TRUNCATION_LENGTH_RANGE.contains(&length) => {
+            Err(GlassError::validation(format!(
+                "max_length must be between {} and {} (got {})",
+                TRUNCATION_LENGTH_RANGE.start(),
+                TRUNCATION_LENGTH_RANGE.end(),
+                length
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Checks that an optional string field does not exceed `max_len` characters.
 fn check_option_len(
     field_name: &str,
@@ -59,6 +85,161 @@ fn check_option_len(
     Ok(())
 }
 
+/// Checks that a list field has at most `MAX_LIST_ITEMS` entries of at most `max_len` characters.
+fn check_list_len(field_name: &str, values: &[String], max_len: usize) -> Result<(), GlassError> {
+    if values.len() > MAX_LIST_ITEMS {
+        return Err(GlassError::validation(format!(
+            "{} exceeds maximum of {} entries (got {})",
+            field_name,
+            MAX_LIST_ITEMS,
+            values.len()
+        )));
+    }
+    for value in values {
+        check_len(field_name, value, max_len)?;
+    }
+    Ok(())
+}
+
+/// Checks that every entry in a list field looks like an email address.
+fn check_emails(field_name: &str, values: &[String]) -> Result<(), GlassError> {
+    for value in values {
+        let valid = value
+            .split_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'))
+            && !value.chars().any(char::is_whitespace);
+        if !valid {
+            return Err(GlassError::validation(format!(
+                "{} contains an invalid email address: {:?}",
+                field_name,
+                value.chars().take(50).collect::<String>()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Trims each entry in a list and drops entries that end up empty.
+fn trim_list(values: &[String]) -> Vec<String> {
+    values
+        .iter()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// Trims a list of IDs, dropping empty entries and duplicates while keeping order.
+fn trim_id_list(values: &[String]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    trim_list(values)
+        .into_iter()
+        .filter(|v| seen.insert(v.clone()))
+        .collect()
+}
+
+/// Checks that a bulk operation received between one and `MAX_LIST_ITEMS` IDs.
+fn check_id_list(field_name: &str, values: &[String]) -> Result<(), GlassError> {
+    if values.is_empty() {
+        return Err(GlassError::validation(format!(
+            "{} must contain at least one ID",
+            field_name
+        )));
+    }
+    check_list_len(field_name, values, MAX_SHORT_FIELD_LEN)
+}
+
+/// Trims custom field names and string values, dropping entries with an empty name.
+fn trim_custom_fields(fields: &Option<CustomFields>) -> Option<CustomFields> {
+    let fields: CustomFields = fields
+        .iter()
+        .flatten()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => serde_json::Value::String(s.trim().to_string()),
+                other => other.clone(),
+            };
+            (name.trim().to_string(), value)
+        })
+        .filter(|(name, _)| !name.is_empty())
+        .collect();
+    if fields.is_empty() {
+        None
+    } else {
+        Some(fields)
+    }
+}
+
+/// Checks custom field names look like SDP `udf_` fields and values are within limits.
+fn check_custom_fields(fields: &Option<CustomFields>) -> Result<(), GlassError> {
+    let Some(fields) = fields else {
+        return Ok(());
+    };
+    if fields.len() > MAX_LIST_ITEMS {
+        return Err(GlassError::validation(format!(
+            "custom_fields exceeds maximum of {} entries (got {})",
+            MAX_LIST_ITEMS,
+            fields.len()
+        )));
+    }
+    for (name, value) in fields {
+        let valid_name = name.starts_with("udf_")
+            && name.len() <= 100
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            return Err(GlassError::validation(format!(
+                "custom_fields key {:?} is not a valid field name (expected e.g. \"udf_sline_301\")",
+                name.chars().take(50).collect::<String>()
+            )));
+        }
+        check_len(
+            &format!("custom_fields.{}", name),
+            &value.to_string(),
+            MAX_CUSTOM_FIELD_VALUE_LEN,
+        )?;
+    }
+    Ok(())
+}
+
+/// Parses an optional date field into epoch milliseconds.
+///
+/// Returns a validation error naming the field if the date does not parse.
+fn parse_date_millis(field: &str, value: &Option<String>) -> Result<Option<i64>, GlassError> {
+    value
+        .as_deref()
+        .map(|v| {
+            SdpTimestamp::parse_input(v)
+                .ok()
+                .and_then(|t| t.epoch_millis())
+                .ok_or_else(|| {
+                    GlassError::validation(format!(
+                        "{} must be a date like YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339",
+                        field
+                    ))
+                })
+        })
+        .transpose()
+}
+
+/// Parses an optional created_after/created_before pair into epoch milliseconds.
+///
+/// Returns a validation error naming the field if a date does not parse, or
+/// if the range is empty.
+fn parse_date_range(
+    after: &Option<String>,
+    before: &Option<String>,
+) -> Result<(Option<i64>, Option<i64>), GlassError> {
+    let after_millis = parse_date_millis("created_after", after)?;
+    let before_millis = parse_date_millis("created_before", before)?;
+    if let (Some(a), Some(b)) = (after_millis, before_millis) {
+        if a >= b {
+            return Err(GlassError::validation(
+                "created_after must be earlier than created_before",
+            ));
+        }
+    }
+    Ok((after_millis, before_millis))
+}
+
 /// Helper function to trim an optional string.
 fn trim_option(s: &Option<String>) -> Option<String> {
     s.as_ref()
@@ -66,20 +247,76 @@ fn trim_option(s: &Option<String>) -> Option<String> {
         .filter(|s| !s.is_empty())
 }
 
+/// Custom (UDF) field values keyed by SDP field name, e.g. "udf_sline_301".
+pub type CustomFields = std::collections::BTreeMap<String, serde_json::Value>;
+
+/// Fields the list_requests tool can sort by.
+pub const LIST_SORT_FIELDS: [&str; 7] = [
+    "created_time",
+    "last_updated_time",
+    "due_by_time",
+    "priority",
+    "status",
+    "subject",
+    "id",
+];
+
+/// Accepted sort directions.
+pub const SORT_ORDERS: [&str; 2] = ["asc", "desc"];
+
+/// How a list or search tool renders its text result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Human-readable lines (the default).
+    #[default]
+    Text,
+    /// The records as pretty-printed JSON, for scripting.
+    Json,
+    /// A markdown table, for pasting into reports or chat.
+    Markdown,
+}
+
+/// Order in which a ticket's notes and conversations are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryOrder {
+    /// Oldest entry first (the default).
+    #[default]
+    Oldest,
+    /// Newest entry first.
+    Newest,
+}
+
+impl EntryOrder {
+    /// Returns true for newest-first order.
+    pub fn newest_first(order: Option<Self>) -> bool {
+        order.unwrap_or_default() == EntryOrder::Newest
+    }
+}
+
 /// Input parameters for the list_requests tool.
 ///
 /// All fields are optional - use them to filter the results.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ListRequestsInput {
+    /// Keyword(s) to look for in the subject or description (partial match),
+    /// e.g. "VPN certificate".
+    #[serde(default)]
+    pub query: Option<String>,
+
     /// Filter by ticket status (e.g., "Åben", "Tildelt", "I gang", "Lukket").
+    /// Separate several with `|` to match any of them (e.g., "Åben|Tildelt").
     #[serde(default)]
     pub status: Option<String>,
 
     /// Filter by priority level (e.g., "Low", "Medium", "High", "Urgent").
+    /// Separate several with `|` to match any of them (e.g., "High|Urgent").
     #[serde(default)]
     pub priority: Option<String>,
 
-    /// Filter by assigned technician name (e.g., "Gorm Reventlow").
+    /// Filter by assigned technician name (e.g., "Gorm Reventlow"), or "me"
+    /// for your own tickets.
     #[serde(default)]
     pub technician: Option<String>,
 
@@ -87,18 +324,53 @@ pub struct ListRequestsInput {
     #[serde(default)]
     pub requester: Option<String>,
 
-    /// If true, only return open tickets (excludes Lukket, Annulleret, Udført statuses).
+    /// Filter by support group name (e.g., "IT Support"), or "any" to
+    /// ignore the server's default group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Filter by request type (e.g., "Incident", "Service Request").
+    #[serde(default)]
+    pub request_type: Option<String>,
+
+    /// Filter by category name (e.g., "Network"; use list_categories to see valid names).
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// Filter by subcategory name (e.g., "VPN").
+    #[serde(default)]
+    pub subcategory: Option<String>,
+
+    /// Filter by item name, the level below subcategory.
+    #[serde(default)]
+    pub item: Option<String>,
+
+    /// If true, only return open tickets (excludes resolved, closed, and cancelled statuses).
     #[serde(default)]
     pub open_only: Option<bool>,
 
-    /// Filter tickets created after this date (ISO 8601 format: YYYY-MM-DD).
+    /// If true, only return tickets past their due date.
+    #[serde(default)]
+    pub overdue_only: Option<bool>,
+
+    /// If true, only return tickets with attachments (logs, screenshots);
+    /// if false, only tickets without.
+    #[serde(default)]
+    pub has_attachments: Option<bool>,
+
+    /// Filter tickets created after this date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
     #[serde(default)]
     pub created_after: Option<String>,
 
-    /// Filter tickets created before this date (ISO 8601 format: YYYY-MM-DD).
+    /// Filter tickets created before this date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
     #[serde(default)]
     pub created_before: Option<String>,
 
+    /// Only return tickets changed after this time (YYYY-MM-DD, YYYY-MM-DD HH:MM,
+    /// or RFC 3339), e.g. to see what changed in the last hour.
+    #[serde(default)]
+    pub updated_after: Option<String>,
+
     /// Maximum number of tickets to return (default: 20, max: 100).
     #[serde(default)]
     pub limit: Option<u32>,
@@ -106,6 +378,29 @@ pub struct ListRequestsInput {
     /// Number of tickets to skip for pagination (default: 0).
     #[serde(default)]
     pub offset: Option<u32>,
+
+    /// Filter by site name (use list_sites to see valid names), or "any"
+    /// to ignore the server's default site.
+    #[serde(default)]
+    pub site: Option<String>,
+
+    /// Field to sort by: 'created_time', 'last_updated_time', 'due_by_time',
+    /// 'priority', 'status', 'subject', or 'id' (default: SDP's own order).
+    #[serde(default)]
+    pub sort_by: Option<String>,
+
+    /// Sort direction: 'asc' (oldest/lowest first) or 'desc' (default: 'desc').
+    #[serde(default)]
+    pub sort_order: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
 }
 
 impl ListRequestsInput {
@@ -113,378 +408,4133 @@ impl ListRequestsInput {
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
+            query: trim_option(&self.query),
             status: trim_option(&self.status),
             priority: trim_option(&self.priority),
             technician: trim_option(&self.technician),
             requester: trim_option(&self.requester),
+            group: trim_option(&self.group),
+            request_type: trim_option(&self.request_type),
+            category: trim_option(&self.category),
+            subcategory: trim_option(&self.subcategory),
+            item: trim_option(&self.item),
             open_only: self.open_only,
+            overdue_only: self.overdue_only,
+            has_attachments: self.has_attachments,
             created_after: trim_option(&self.created_after),
             created_before: trim_option(&self.created_before),
+            updated_after: trim_option(&self.updated_after),
             limit: self.limit,
             offset: self.offset,
+            site: trim_option(&self.site),
+            sort_by: trim_option(&self.sort_by).map(|f| f.to_lowercase()),
+            sort_order: trim_option(&self.sort_order).map(|o| o.to_lowercase()),
+            portal: trim_option(&self.portal),
+            format: self.format,
         }
     }
 
-    /// Validates field lengths. Call after `sanitize()`.
+    /// Validates sort options and field lengths. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        if let Some(field) = &self.sort_by {
+            if !LIST_SORT_FIELDS.contains(&field.as_str()) {
+                return Err(GlassError::validation(format!(
+                    "sort_by must be one of: {}",
+                    LIST_SORT_FIELDS.join(", ")
+                )));
+            }
+        }
+        if let Some(order) = &self.sort_order {
+            if !SORT_ORDERS.contains(&order.as_str()) {
+                return Err(GlassError::validation(format!(
+                    "sort_order must be one of: {}",
+                    SORT_ORDERS.join(", ")
+                )));
+            }
+        }
+        check_option_len("query", &self.query, MAX_SHORT_FIELD_LEN)?;
         check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
         check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
         check_option_len("technician", &self.technician, MAX_SHORT_FIELD_LEN)?;
         check_option_len("requester", &self.requester, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("request_type", &self.request_type, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("subcategory", &self.subcategory, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("item", &self.item, MAX_SHORT_FIELD_LEN)?;
         check_option_len("created_after", &self.created_after, MAX_SHORT_FIELD_LEN)?;
         check_option_len("created_before", &self.created_before, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("site", &self.site, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("updated_after", &self.updated_after, MAX_SHORT_FIELD_LEN)?;
+        self.created_range()?;
+        self.updated_after_millis()?;
         Ok(())
     }
+
+    /// Returns `created_after` and `created_before` as epoch milliseconds.
+    pub fn created_range(&self) -> Result<(Option<i64>, Option<i64>), GlassError> {
+        parse_date_range(&self.created_after, &self.created_before)
+    }
+
+    /// Returns `updated_after` as epoch milliseconds.
+    pub fn updated_after_millis(&self) -> Result<Option<i64>, GlassError> {
+        parse_date_millis("updated_after", &self.updated_after)
+    }
 }
 
-/// Input parameters for the get_request tool.
+/// Input parameters for the export_requests_csv tool.
+///
+/// Takes the same filters as list_requests, but exports every matching
+/// ticket instead of one page.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct GetRequestInput {
-    /// The unique ID of the ticket to retrieve.
-    pub request_id: String,
+pub struct ExportRequestsCsvInput {
+    /// Keyword(s) to look for in the subject or description (partial match).
+    #[serde(default)]
+    pub query: Option<String>,
+
+    /// Filter by ticket status. Separate several with `|` to match any of them.
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Filter by priority level. Separate several with `|` to match any of them.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// Filter by assigned technician name, or "me" for your own tickets.
+    #[serde(default)]
+    pub technician: Option<String>,
+
+    /// Filter by requester name.
+    #[serde(default)]
+    pub requester: Option<String>,
+
+    /// Filter by support group name, or "any" to ignore the server's default group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Filter by request type (e.g., "Incident", "Service Request").
+    #[serde(default)]
+    pub request_type: Option<String>,
+
+    /// Filter by category name.
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// Filter by subcategory name.
+    #[serde(default)]
+    pub subcategory: Option<String>,
+
+    /// Filter by item name.
+    #[serde(default)]
+    pub item: Option<String>,
+
+    /// If true, only export open tickets.
+    #[serde(default)]
+    pub open_only: Option<bool>,
+
+    /// If true, only export tickets past their due date.
+    #[serde(default)]
+    pub overdue_only: Option<bool>,
+
+    /// If true, only export tickets with attachments; if false, only tickets without.
+    #[serde(default)]
+    pub has_attachments: Option<bool>,
+
+    /// Filter tickets created after this date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
+    #[serde(default)]
+    pub created_after: Option<String>,
+
+    /// Filter tickets created before this date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
+    #[serde(default)]
+    pub created_before: Option<String>,
+
+    /// Only export tickets changed after this time (YYYY-MM-DD, YYYY-MM-DD HH:MM,
+    /// or RFC 3339).
+    #[serde(default)]
+    pub updated_after: Option<String>,
+
+    /// Filter by site name, or "any" to ignore the server's default site.
+    #[serde(default)]
+    pub site: Option<String>,
+
+    /// Field to sort by: 'created_time', 'last_updated_time', 'due_by_time',
+    /// 'priority', 'status', 'subject', or 'id' (default: SDP's own order).
+    #[serde(default)]
+    pub sort_by: Option<String>,
+
+    /// Sort direction: 'asc' or 'desc' (default: 'desc').
+    #[serde(default)]
+    pub sort_order: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to export from
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+
+    /// Maximum number of tickets to export (default: 1000, max: 5000).
+    #[serde(default)]
+    pub max_rows: Option<u32>,
+
+    /// Save the CSV as a file in the server's export directory
+    /// (GLASS_EXPORT_DIR) instead of returning it (default: false).
+    #[serde(default)]
+    pub save: Option<bool>,
+
+    /// File name for a saved export (default: requests-<date>-<time>.csv).
+    /// Letters, digits, '-', '_' and '.' only.
+    #[serde(default)]
+    pub file_name: Option<String>,
+}
+
+impl ExportRequestsCsvInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            query: trim_option(&self.query),
+            status: trim_option(&self.status),
+            priority: trim_option(&self.priority),
+            technician: trim_option(&self.technician),
+            requester: trim_option(&self.requester),
+            group: trim_option(&self.group),
+            request_type: trim_option(&self.request_type),
+            category: trim_option(&self.category),
+            subcategory: trim_option(&self.subcategory),
+            item: trim_option(&self.item),
+            open_only: self.open_only,
+            overdue_only: self.overdue_only,
+            has_attachments: self.has_attachments,
+            created_after: trim_option(&self.created_after),
+            created_before: trim_option(&self.created_before),
+            updated_after: trim_option(&self.updated_after),
+            site: trim_option(&self.site),
+            sort_by: trim_option(&self.sort_by).map(|f| f.to_lowercase()),
+            sort_order: trim_option(&self.sort_order).map(|o| o.to_lowercase()),
+            portal: trim_option(&self.portal),
+            max_rows: self.max_rows,
+            save: self.save,
+            file_name: trim_option(&self.file_name),
+        }
+    }
+
+    /// Validates the filters, row limit, and file name. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        self.filters().validate()?;
+        if let Some(max_rows) = self.max_rows {
+            if max_rows == 0 || max_rows > MAX_EXPORT_ROWS {
+                return Err(GlassError::validation(format!(
+                    "max_rows must be between 1 and {}",
+                    MAX_EXPORT_ROWS
+                )));
+            }
+        }
+        if let Some(name) = &self.file_name {
+            check_len("file_name", name, MAX_SHORT_FIELD_LEN)?;
+            let allowed = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.');
+            if name.starts_with('.') || !name.chars().all(allowed) {
+                return Err(GlassError::validation(
+                    "file_name may only contain letters, digits, '-', '_' and '.', \
+                     and must not start with '.'",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the filters as list_requests input, without paging or format.
+    pub fn filters(&self) -> ListRequestsInput {
+        ListRequestsInput {
+            query: self.query.clone(),
+            status: self.status.clone(),
+            priority: self.priority.clone(),
+            technician: self.technician.clone(),
+            requester: self.requester.clone(),
+            group: self.group.clone(),
+            request_type: self.request_type.clone(),
+            category: self.category.clone(),
+            subcategory: self.subcategory.clone(),
+            item: self.item.clone(),
+            open_only: self.open_only,
+            overdue_only: self.overdue_only,
+            has_attachments: self.has_attachments,
+            created_after: self.created_after.clone(),
+            created_before: self.created_before.clone(),
+            updated_after: self.updated_after.clone(),
+            limit: None,
+            offset: None,
+            site: self.site.clone(),
+            sort_by: self.sort_by.clone(),
+            sort_order: self.sort_order.clone(),
+            portal: self.portal.clone(),
+            format: None,
+        }
+    }
+}
+
+/// Input parameters for the search_requests tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SearchRequestsInput {
+    /// Text to search for in ticket subjects and descriptions (partial match).
+    pub query: String,
+
+    /// Also search note contents (default: false).
+    #[serde(default)]
+    pub include_notes: Option<bool>,
+
+    /// Only return open tickets (excludes closed, cancelled, resolved).
+    #[serde(default)]
+    pub open_only: Option<bool>,
+
+    /// Maximum number of tickets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+}
+
+impl SearchRequestsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            query: self.query.trim().to_string(),
+            include_notes: self.include_notes,
+            open_only: self.open_only,
+            limit: self.limit,
+            portal: trim_option(&self.portal),
+            format: self.format,
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        if self.query.is_empty() {
+            return Err(GlassError::validation("query must not be empty"));
+        }
+        check_len("query", &self.query, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the find_duplicate_requests tool.
+///
+/// Exactly one of `request_id` or `subject` is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FindDuplicateRequestsInput {
+    /// Find duplicates of this ticket (compares subject and requester).
+    #[serde(default)]
+    pub request_id: Option<String>,
+
+    /// Find tickets with a subject similar to this text.
+    #[serde(default)]
+    pub subject: Option<String>,
+
+    /// How many days back to search (default: 30, max: 365).
+    #[serde(default)]
+    pub days: Option<u32>,
+
+    /// Maximum number of duplicates to return (default: 10, max: 50).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl FindDuplicateRequestsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: trim_option(&self.request_id),
+            subject: trim_option(&self.subject),
+            days: self.days,
+            limit: self.limit,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates that exactly one source is given and field lengths.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        if self.request_id.is_some() == self.subject.is_some() {
+            return Err(GlassError::validation(
+                "provide exactly one of request_id or subject",
+            ));
+        }
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the find_similar_requests tool.
+///
+/// Exactly one of `request_id` or `text` is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FindSimilarRequestsInput {
+    /// Find finished tickets similar to this ticket (compares subject and category).
+    #[serde(default)]
+    pub request_id: Option<String>,
+
+    /// Find finished tickets with a subject similar to this text.
+    #[serde(default)]
+    pub text: Option<String>,
+
+    /// Category to favor; defaults to the ticket's category when request_id is given.
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// How many days back to search by completion date (default: 365, max: 1825).
+    #[serde(default)]
+    pub days: Option<u32>,
+
+    /// Maximum number of tickets to return (default: 5, max: 20).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl FindSimilarRequestsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: trim_option(&self.request_id),
+            text: trim_option(&self.text),
+            category: trim_option(&self.category),
+            days: self.days,
+            limit: self.limit,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates that exactly one source is given and field lengths.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        if self.request_id.is_some() == self.text.is_some() {
+            return Err(GlassError::validation(
+                "provide exactly one of request_id or text",
+            ));
+        }
+        check_option_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("text", &self.text, MAX_SUBJECT_LEN)?;
+        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_request tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetRequestInput {
+    /// The unique ID of the ticket to retrieve.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+
+    /// Show up to this many characters of the description and resolution
+    /// before truncating (default: 2000, or GLASS_MAX_DESCRIPTION_LENGTH).
+    #[serde(default)]
+    pub max_length: Option<usize>,
+
+    /// Attach the inline images of the description, notes, and
+    /// conversations (up to 5, 512 KB each) as image content (default: false).
+    #[serde(default)]
+    pub include_images: Option<bool>,
+
+    /// Show notes and conversations "oldest" (default) or "newest" first.
+    #[serde(default)]
+    pub order: Option<EntryOrder>,
+}
+
+impl GetRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+            max_length: self.max_length,
+            include_images: self.include_images,
+            order: self.order,
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_max_length(self.max_length)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_request_url tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetRequestUrlInput {
+    /// The unique ID of the ticket to link to.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetRequestUrlInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_request_history tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetRequestHistoryInput {
+    /// The unique ID of the ticket whose history to retrieve.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetRequestHistoryInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_request_timeline tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetRequestTimelineInput {
+    /// The unique ID of the ticket whose timeline to build.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetRequestTimelineInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the export_request_markdown tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ExportRequestMarkdownInput {
+    /// The unique ID of the ticket to export.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ExportRequestMarkdownInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_technicians tool.
+///
+/// All fields are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListTechniciansInput {
+    /// Filter technicians by support group name.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Maximum number of technicians to return (default: 50).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+}
+
+impl ListTechniciansInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            group: trim_option(&self.group),
+            limit: self.limit,
+            portal: trim_option(&self.portal),
+            format: self.format,
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the search_requesters tool.
+///
+/// Query is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SearchRequestersInput {
+    /// Name or email address to search for (partial match).
+    pub query: String,
+
+    /// Maximum number of requesters to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl SearchRequestersInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            query: self.query.trim().to_string(),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("query", &self.query, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_requester tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetRequesterInput {
+    /// The unique ID of the requester (use search_requesters to find IDs).
+    pub requester_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetRequesterInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            requester_id: self.requester_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("requester_id", &self.requester_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Dimensions the ticket_statistics tool can group counts by.
+pub const STATISTICS_GROUP_BY: [&str; 4] = ["status", "priority", "technician", "group"];
+
+/// Input parameters for the ticket_statistics tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct TicketStatisticsInput {
+    /// What to count tickets by: 'status', 'priority', 'technician', or 'group'.
+    pub group_by: String,
+
+    /// Only count tickets created after this date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
+    #[serde(default)]
+    pub created_after: Option<String>,
+
+    /// Only count tickets created before this date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
+    #[serde(default)]
+    pub created_before: Option<String>,
+
+    /// If true, only count open tickets.
+    #[serde(default)]
+    pub open_only: Option<bool>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl TicketStatisticsInput {
+    /// Sanitizes input by trimming whitespace and lowercasing `group_by`.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            group_by: self.group_by.trim().to_lowercase(),
+            created_after: trim_option(&self.created_after),
+            created_before: trim_option(&self.created_before),
+            open_only: self.open_only,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates `group_by` and field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        if !STATISTICS_GROUP_BY.contains(&self.group_by.as_str()) {
+            return Err(GlassError::validation(format!(
+                "group_by must be one of: {}",
+                STATISTICS_GROUP_BY.join(", ")
+            )));
+        }
+        check_option_len("created_after", &self.created_after, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("created_before", &self.created_before, MAX_SHORT_FIELD_LEN)?;
+        self.created_range()?;
+        Ok(())
+    }
+
+    /// Returns `created_after` and `created_before` as epoch milliseconds.
+    pub fn created_range(&self) -> Result<(Option<i64>, Option<i64>), GlassError> {
+        parse_date_range(&self.created_after, &self.created_before)
+    }
+}
+
+/// Input parameters for the my_queue tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct MyQueueInput {
+    /// Maximum number of tickets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl MyQueueInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the unassigned_tickets tool.
+///
+/// All fields are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UnassignedTicketsInput {
+    /// Only show tickets in this support group, or "any" to ignore the
+    /// server's default group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Maximum number of tickets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UnassignedTicketsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            group: trim_option(&self.group),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the overdue_tickets tool.
+///
+/// All fields are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct OverdueTicketsInput {
+    /// Only show tickets in this support group, or "any" to ignore the
+    /// server's default group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Only show tickets assigned to this technician name.
+    #[serde(default)]
+    pub technician: Option<String>,
+
+    /// Maximum number of tickets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl OverdueTicketsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            group: trim_option(&self.group),
+            technician: trim_option(&self.technician),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("technician", &self.technician, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the due_soon tool.
+///
+/// All fields are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DueSoonInput {
+    /// Look this many hours ahead (max: 168). Defaults to the end of today.
+    #[serde(default)]
+    pub hours: Option<u32>,
+
+    /// Only show tickets in this support group, or "any" to ignore the
+    /// server's default group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Only show tickets assigned to this technician name.
+    #[serde(default)]
+    pub technician: Option<String>,
+
+    /// Maximum number of tickets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl DueSoonInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            hours: self.hours,
+            group: trim_option(&self.group),
+            technician: trim_option(&self.technician),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths and the look-ahead window. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        if let Some(hours) = self.hours {
+            if hours == 0 || hours > MAX_DUE_SOON_HOURS {
+                return Err(GlassError::validation(format!(
+                    "hours must be between 1 and {}",
+                    MAX_DUE_SOON_HOURS
+                )));
+            }
+        }
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("technician", &self.technician, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_sla_info tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetSlaInfoInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetSlaInfoInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the suggest_priority tool.
+///
+/// Give impact and urgency, a request ID to read them from the ticket, or both
+/// to override the ticket's values.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SuggestPriorityInput {
+    /// Ticket whose impact, urgency, and current priority to use.
+    #[serde(default)]
+    pub request_id: Option<String>,
+
+    /// Impact name (e.g., 'High'); overrides the ticket's impact.
+    #[serde(default)]
+    pub impact: Option<String>,
+
+    /// Urgency name (e.g., 'Medium'); overrides the ticket's urgency.
+    #[serde(default)]
+    pub urgency: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl SuggestPriorityInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: trim_option(&self.request_id),
+            impact: trim_option(&self.impact),
+            urgency: trim_option(&self.urgency),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths and that impact and urgency can be known.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("impact", &self.impact, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("urgency", &self.urgency, MAX_SHORT_FIELD_LEN)?;
+        if self.request_id.is_none() && (self.impact.is_none() || self.urgency.is_none()) {
+            return Err(GlassError::validation(
+                "provide impact and urgency, or a request_id to read them from",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Input parameters for the search_assets tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SearchAssetsInput {
+    /// Asset name, asset tag, serial number, or barcode to search for (partial match).
+    pub query: String,
+
+    /// Maximum number of assets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl SearchAssetsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            query: self.query.trim().to_string(),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("query", &self.query, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_asset tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetAssetInput {
+    /// The unique ID of the asset (use search_assets to find IDs).
+    pub asset_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetAssetInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            asset_id: self.asset_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("asset_id", &self.asset_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_user_assets tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListUserAssetsInput {
+    /// The user whose assets to list: email address, requester ID, or exact name.
+    pub requester: String,
+
+    /// Maximum number of assets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListUserAssetsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            requester: self.requester.trim().to_string(),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("requester", &self.requester, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the search_solutions tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SearchSolutionsInput {
+    /// Text to search for in solution titles, keywords, and content (partial match).
+    pub query: String,
+
+    /// Maximum number of solutions to return (default: 10, max: 50).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl SearchSolutionsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            query: self.query.trim().to_string(),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("query", &self.query, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_solution tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetSolutionInput {
+    /// The unique ID of the solution (use search_solutions to find IDs).
+    pub solution_id: String,
+
+    /// Show up to this many characters of the article content before
+    /// truncating (default: 2000, or GLASS_MAX_DESCRIPTION_LENGTH).
+    #[serde(default)]
+    pub max_length: Option<usize>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetSolutionInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            solution_id: self.solution_id.trim().to_string(),
+            max_length: self.max_length,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("solution_id", &self.solution_id, MAX_SHORT_FIELD_LEN)?;
+        check_max_length(self.max_length)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the create_solution tool.
+///
+/// Request ID and topic are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CreateSolutionInput {
+    /// The unique ID of the resolved ticket to turn into an article.
+    pub request_id: String,
+
+    /// Knowledge base topic to file the article under (e.g., "Network").
+    pub topic: String,
+
+    /// Article title. Defaults to the ticket subject.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Search keywords (comma-separated).
+    #[serde(default)]
+    pub keywords: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CreateSolutionInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            topic: self.topic.trim().to_string(),
+            title: trim_option(&self.title),
+            keywords: trim_option(&self.keywords),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("topic", &self.topic, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("keywords", &self.keywords, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for tools that take nothing but the portal to work in,
+/// such as whoami, list_sites, and list_custom_fields.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct PortalInput {
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl PortalInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_categories tool.
+///
+/// All fields are optional. Without filters, the category/subcategory tree is returned.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListCategoriesInput {
+    /// Only show this category (name or ID) with its subcategories and items.
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// Only show this subcategory (name or ID) of `category` with its items.
+    #[serde(default)]
+    pub subcategory: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListCategoriesInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            category: trim_option(&self.category),
+            subcategory: trim_option(&self.subcategory),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths and that subcategory has a parent. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("subcategory", &self.subcategory, MAX_SHORT_FIELD_LEN)?;
+        if self.subcategory.is_some() && self.category.is_none() {
+            return Err(GlassError::validation(
+                "subcategory filter requires category to be set",
+            ));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Write operation input structs (M4)
+// ============================================================================
+
+/// Input parameters for the create_request tool.
+///
+/// Subject is required. All other fields are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CreateRequestInput {
+    /// Ticket subject/title (required, max 250 characters).
+    pub subject: String,
+
+    /// Detailed description of the issue or request (supports HTML).
+    ///
+    /// SECURITY: HTML content is passed through to SDP without sanitization.
+    /// SDP is responsible for sanitizing HTML on render.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Email address of the person reporting the issue.
+    #[serde(default)]
+    pub requester_email: Option<String>,
+
+    /// Priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// Category name for the ticket (e.g., 'Hardware', 'Software', 'Network').
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// Subcategory name (must be valid for the chosen category).
+    #[serde(default)]
+    pub subcategory: Option<String>,
+
+    /// Item name (must be valid for the chosen subcategory).
+    #[serde(default)]
+    pub item: Option<String>,
+
+    /// Support group to assign the ticket to.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// ID of technician to assign (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Site name for multi-site deployments (use list_sites to see valid names).
+    #[serde(default)]
+    pub site: Option<String>,
+
+    /// Custom field values keyed by SDP field name (e.g. {"udf_sline_301": "PO-7781"}).
+    #[serde(default)]
+    pub custom_fields: Option<CustomFields>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CreateRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            subject: self.subject.trim().to_string(),
+            description: trim_option(&self.description),
+            requester_email: trim_option(&self.requester_email),
+            priority: trim_option(&self.priority),
+            category: trim_option(&self.category),
+            subcategory: trim_option(&self.subcategory),
+            item: trim_option(&self.item),
+            group: trim_option(&self.group),
+            technician_id: trim_option(&self.technician_id),
+            site: trim_option(&self.site),
+            custom_fields: trim_custom_fields(&self.custom_fields),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len(
+            "requester_email",
+            &self.requester_email,
+            MAX_SHORT_FIELD_LEN,
+        )?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("subcategory", &self.subcategory, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("item", &self.item, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("site", &self.site, MAX_SHORT_FIELD_LEN)?;
+        check_custom_fields(&self.custom_fields)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the update_request tool.
+///
+/// Request ID is required. At least one other field must be provided.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UpdateRequestInput {
+    /// The unique ID of the ticket to update.
+    pub request_id: String,
+
+    /// New subject/title for the ticket (max 250 characters).
+    #[serde(default)]
+    pub subject: Option<String>,
+
+    /// Updated description (supports HTML).
+    ///
+    /// SECURITY: HTML content is passed through to SDP without sanitization.
+    /// SDP is responsible for sanitizing HTML on render.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// New priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// New status (e.g., 'Open', 'In Progress', 'On Hold', 'Resolved').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// New category name.
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// New subcategory name.
+    #[serde(default)]
+    pub subcategory: Option<String>,
+
+    /// New support group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// ID of technician to reassign to.
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Custom field values to set, keyed by SDP field name (e.g. {"udf_sline_301": "PO-7781"}).
+    #[serde(default)]
+    pub custom_fields: Option<CustomFields>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UpdateRequestInput {
+    /// Returns true if at least one field besides request_id is set.
+    pub fn has_updates(&self) -> bool {
+        self.subject.is_some()
+            || self.description.is_some()
+            || self.priority.is_some()
+            || self.status.is_some()
+            || self.category.is_some()
+            || self.subcategory.is_some()
+            || self.group.is_some()
+            || self.technician_id.is_some()
+            || self.custom_fields.is_some()
+    }
+
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            subject: trim_option(&self.subject),
+            description: trim_option(&self.description),
+            priority: trim_option(&self.priority),
+            status: trim_option(&self.status),
+            category: trim_option(&self.category),
+            subcategory: trim_option(&self.subcategory),
+            group: trim_option(&self.group),
+            technician_id: trim_option(&self.technician_id),
+            custom_fields: trim_custom_fields(&self.custom_fields),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        self.validate_fields()
+    }
+
+    /// Validates the lengths of the updatable fields.
+    fn validate_fields(&self) -> Result<(), GlassError> {
+        check_option_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("subcategory", &self.subcategory, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_custom_fields(&self.custom_fields)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the bulk_update_requests tool.
+///
+/// The same field values are applied to every listed ticket.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BulkUpdateRequestsInput {
+    /// IDs of the tickets to update (max 50).
+    pub request_ids: Vec<String>,
+
+    /// New priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// New status (e.g., 'Open', 'In Progress', 'On Hold', 'Resolved').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// New category name.
+    #[serde(default)]
+    pub category: Option<String>,
+
+    /// New subcategory name.
+    #[serde(default)]
+    pub subcategory: Option<String>,
+
+    /// New support group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// ID of technician to reassign to.
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl BulkUpdateRequestsInput {
+    /// Builds the per-ticket update for one request ID.
+    pub fn to_update(&self, request_id: &str) -> UpdateRequestInput {
+        UpdateRequestInput {
+            request_id: request_id.to_string(),
+            subject: None,
+            description: None,
+            priority: self.priority.clone(),
+            status: self.status.clone(),
+            category: self.category.clone(),
+            subcategory: self.subcategory.clone(),
+            group: self.group.clone(),
+            technician_id: self.technician_id.clone(),
+            custom_fields: None,
+            portal: None,
+        }
+    }
+
+    /// Sanitizes input by trimming whitespace and dropping duplicate IDs.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_ids: trim_id_list(&self.request_ids),
+            priority: trim_option(&self.priority),
+            status: trim_option(&self.status),
+            category: trim_option(&self.category),
+            subcategory: trim_option(&self.subcategory),
+            group: trim_option(&self.group),
+            technician_id: trim_option(&self.technician_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates the ID list, that something is updated, and field lengths.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_id_list("request_ids", &self.request_ids)?;
+        if !self.to_update("").has_updates() {
+            return Err(GlassError::validation(
+                "At least one field must be provided for update (priority, status, category, subcategory, group, or technician_id)",
+            ));
+        }
+        self.to_update("").validate_fields()
+    }
+}
+
+/// Input parameters for the bulk_close tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BulkCloseInput {
+    /// IDs of the tickets to close (max 50).
+    pub request_ids: Vec<String>,
+
+    /// Closure reason code applied to every ticket (e.g., 'Success', 'Cancelled').
+    #[serde(default)]
+    pub closure_code: Option<String>,
+
+    /// Closure comment applied to every ticket.
+    #[serde(default)]
+    pub closure_comments: Option<String>,
+
+    /// Must be true to confirm closing. Only set this after the user has reviewed the list of tickets.
+    pub confirm: bool,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl BulkCloseInput {
+    /// Sanitizes input by trimming whitespace and dropping duplicate IDs.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_ids: trim_id_list(&self.request_ids),
+            closure_code: trim_option(&self.closure_code),
+            closure_comments: trim_option(&self.closure_comments),
+            confirm: self.confirm,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates the ID list and field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_id_list("request_ids", &self.request_ids)?;
+        check_option_len("closure_code", &self.closure_code, MAX_SHORT_FIELD_LEN)?;
+        check_option_len(
+            "closure_comments",
+            &self.closure_comments,
+            MAX_CLOSURE_COMMENTS_LEN,
+        )?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the close_request tool.
+///
+/// Request ID is required. Closure code and comments are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CloseRequestInput {
+    /// The unique ID of the ticket to close.
+    pub request_id: String,
+
+    /// Closure reason code (e.g., 'Success', 'Cancelled', 'Unable to Reproduce').
+    #[serde(default)]
+    pub closure_code: Option<String>,
+
+    /// Explanation of how the issue was resolved or why it's being closed.
+    #[serde(default)]
+    pub closure_comments: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CloseRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            closure_code: trim_option(&self.closure_code),
+            closure_comments: trim_option(&self.closure_comments),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("closure_code", &self.closure_code, MAX_SHORT_FIELD_LEN)?;
+        check_option_len(
+            "closure_comments",
+            &self.closure_comments,
+            MAX_CLOSURE_COMMENTS_LEN,
+        )?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the add_note tool.
+///
+/// Request ID and content are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddNoteInput {
+    /// The unique ID of the ticket to add a note to.
+    pub request_id: String,
+
+    /// The note content (supports HTML formatting).
+    ///
+    /// SECURITY: HTML content is passed through to SDP without sanitization.
+    /// SDP is responsible for sanitizing HTML on render.
+    pub content: String,
+
+    /// If true, the note will be visible to the requester. Default: false (internal note).
+    #[serde(default)]
+    pub show_to_requester: Option<bool>,
+
+    /// If true, send notification to assigned technician. Default: false.
+    #[serde(default)]
+    pub notify_technician: Option<bool>,
+
+    /// If true, SDP records this note as the ticket's first response,
+    /// stopping the first-response SLA clock. Default: false.
+    #[serde(default)]
+    pub mark_first_response: Option<bool>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl AddNoteInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            content: self.content.trim().to_string(),
+            show_to_requester: self.show_to_requester,
+            notify_technician: self.notify_technician,
+            mark_first_response: self.mark_first_response,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("content", &self.content, MAX_CONTENT_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the assign_request tool.
+///
+/// Request ID is required. At least one of technician_id or group must be provided.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AssignRequestInput {
+    /// The unique ID of the ticket to assign.
+    pub request_id: String,
+
+    /// ID of the technician to assign (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Name of the support group to assign to.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl AssignRequestInput {
+    /// Returns true if at least one of technician_id or group is set.
+    pub fn has_assignment(&self) -> bool {
+        self.technician_id.is_some() || self.group.is_some()
+    }
+
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            technician_id: trim_option(&self.technician_id),
+            group: trim_option(&self.group),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the bulk_assign tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BulkAssignInput {
+    /// IDs of the tickets to assign (max 50).
+    pub request_ids: Vec<String>,
+
+    /// ID of the technician to assign every ticket to (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Name of the support group to assign every ticket to.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl BulkAssignInput {
+    /// Sanitizes input by trimming whitespace and dropping duplicate IDs.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_ids: trim_id_list(&self.request_ids),
+            technician_id: trim_option(&self.technician_id),
+            group: trim_option(&self.group),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates the ID list, that a target is given, and field lengths.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_id_list("request_ids", &self.request_ids)?;
+        if self.technician_id.is_none() && self.group.is_none() {
+            return Err(GlassError::validation(
+                "At least one of technician_id or group must be provided for assignment",
+            ));
+        }
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the escalate_request tool.
+///
+/// Request ID and reason are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EscalateRequestInput {
+    /// The unique ID of the ticket to escalate.
+    pub request_id: String,
+
+    /// Why the ticket is being escalated; recorded as an internal note.
+    pub reason: String,
+
+    /// New priority level (default: 'High').
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// Escalation support group to assign to.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// ID of the technician to escalate to (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl EscalateRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            reason: self.reason.trim().to_string(),
+            priority: trim_option(&self.priority),
+            group: trim_option(&self.group),
+            technician_id: trim_option(&self.technician_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates required fields and lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        if self.reason.is_empty() {
+            return Err(GlassError::validation("reason must not be empty"));
+        }
+        check_len("reason", &self.reason, MAX_CONTENT_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the add_resolution tool.
+///
+/// Request ID and content are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddResolutionInput {
+    /// The unique ID of the ticket to resolve.
+    pub request_id: String,
+
+    /// The resolution content (supports HTML formatting). Replaces any existing resolution.
+    ///
+    /// SECURITY: HTML content is passed through to SDP without sanitization.
+    /// SDP is responsible for sanitizing HTML on render.
+    pub content: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl AddResolutionInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            content: self.content.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("content", &self.content, MAX_CONTENT_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the resolve_request tool.
+///
+/// Request ID and resolution are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ResolveRequestInput {
+    /// The unique ID of the ticket to resolve.
+    pub request_id: String,
+
+    /// The resolution content (supports HTML formatting).
+    ///
+    /// SECURITY: HTML content is passed through to SDP without sanitization.
+    /// SDP is responsible for sanitizing HTML on render.
+    pub resolution: String,
+
+    /// Status to move the ticket to (e.g., 'Resolved', 'Closed'). Default: the
+    /// instance's resolved status.
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Closure reason code (e.g., 'Success', 'Cancelled', 'Unable to Reproduce').
+    #[serde(default)]
+    pub closure_code: Option<String>,
+
+    /// Closure comments.
+    #[serde(default)]
+    pub closure_comments: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ResolveRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            resolution: self.resolution.trim().to_string(),
+            status: trim_option(&self.status),
+            closure_code: trim_option(&self.closure_code),
+            closure_comments: trim_option(&self.closure_comments),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("resolution", &self.resolution, MAX_CONTENT_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("closure_code", &self.closure_code, MAX_SHORT_FIELD_LEN)?;
+        check_option_len(
+            "closure_comments",
+            &self.closure_comments,
+            MAX_CLOSURE_COMMENTS_LEN,
+        )?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the pickup_request tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PickupRequestInput {
+    /// The unique ID of the ticket to pick up.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl PickupRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the change_requester tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ChangeRequesterInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// The new requester's email address or requester ID (use search_requesters to find one).
+    pub requester: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ChangeRequesterInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            requester: self.requester.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths and that the requester is an email or ID.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("requester", &self.requester, MAX_SHORT_FIELD_LEN)?;
+        if self.requester.contains('@') {
+            check_emails("requester", std::slice::from_ref(&self.requester))?;
+        } else if self.requester.is_empty() || !self.requester.chars().all(|c| c.is_ascii_digit()) {
+            return Err(GlassError::validation(
+                "requester must be an email address or a numeric requester ID; use search_requesters to look one up by name",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Input parameters for the add_cc and remove_cc tools.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CcRecipientsInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// Email addresses to add to or remove from the ticket's CC list.
+    pub emails: Vec<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CcRecipientsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            emails: trim_list(&self.emails),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths and email format. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        if self.emails.is_empty() {
+            return Err(GlassError::validation(
+                "emails must contain at least one address",
+            ));
+        }
+        check_list_len("emails", &self.emails, MAX_SHORT_FIELD_LEN)?;
+        check_emails("emails", &self.emails)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_notes tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListNotesInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// Maximum number of entries to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Number of entries to skip for pagination (default: 0).
+    #[serde(default)]
+    pub offset: Option<u32>,
+
+    /// Show entries "oldest" (default) or "newest" first within the page.
+    #[serde(default)]
+    pub order: Option<EntryOrder>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListNotesInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            limit: self.limit,
+            offset: self.offset,
+            order: self.order,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_conversations tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListConversationsInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// Maximum number of entries to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Number of entries to skip for pagination (default: 0).
+    #[serde(default)]
+    pub offset: Option<u32>,
+
+    /// Show entries "oldest" (default) or "newest" first within the page.
+    #[serde(default)]
+    pub order: Option<EntryOrder>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListConversationsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            limit: self.limit,
+            offset: self.offset,
+            order: self.order,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_watchers tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListWatchersInput {
+    /// The unique ID of the ticket whose watchers to list.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListWatchersInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the add_watchers and remove_watchers tools.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WatchersInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// User IDs of the technicians or requesters to add or remove
+    /// (use list_technicians or search_requesters to find them).
+    pub user_ids: Vec<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl WatchersInput {
+    /// Sanitizes input by trimming whitespace and dropping duplicate IDs.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            user_ids: trim_id_list(&self.user_ids),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_id_list("user_ids", &self.user_ids)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the attach_asset_to_request tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AttachAssetInput {
+    /// The unique ID of the ticket.
+    pub request_id: String,
+
+    /// The unique ID of the asset to attach (use search_assets or list_user_assets).
+    pub asset_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl AttachAssetInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            asset_id: self.asset_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("asset_id", &self.asset_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the hold_request tool.
+///
+/// Request ID is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct HoldRequestInput {
+    /// The unique ID of the ticket to put on hold.
+    pub request_id: String,
+
+    /// On-hold status to set. Default: the instance's on-hold status
+    /// (e.g., 'On Hold' or 'På hold').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Reason for the hold (e.g., 'Waiting for vendor').
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// When to take the ticket off hold automatically (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339).
+    #[serde(default)]
+    pub resume_at: Option<String>,
+
+    /// Status to resume to when resume_at is reached. Default: the instance's
+    /// open status (e.g., 'Open' or 'Åben').
+    #[serde(default)]
+    pub resume_status: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl HoldRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            status: trim_option(&self.status),
+            reason: trim_option(&self.reason),
+            resume_at: trim_option(&self.resume_at),
+            resume_status: trim_option(&self.resume_status),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("reason", &self.reason, MAX_CLOSURE_COMMENTS_LEN)?;
+        check_option_len("resume_at", &self.resume_at, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("resume_status", &self.resume_status, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the set_due_date tool.
+///
+/// Request ID and due_by are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SetDueDateInput {
+    /// The unique ID of the ticket whose deadline changes.
+    pub request_id: String,
+
+    /// The new due date (YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC 3339 such as
+    /// 2026-03-01T16:00:00+01:00). Values without an offset use the server's time zone.
+    pub due_by: String,
+
+    /// Why the deadline moved (e.g., 'Requester agreed to Friday'), recorded as an internal note.
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl SetDueDateInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            due_by: self.due_by.trim().to_string(),
+            reason: trim_option(&self.reason),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths and the date. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("due_by", &self.due_by, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("reason", &self.reason, MAX_CLOSURE_COMMENTS_LEN)?;
+        if self.due_by.is_empty() {
+            return Err(GlassError::validation("due_by is required"));
+        }
+        parse_date_millis("due_by", &Some(self.due_by.clone()))?;
+        Ok(())
+    }
+
+    /// Returns the new due date as an SDP timestamp. Call after `validate()`.
+    pub fn due_timestamp(&self) -> Result<SdpTimestamp, GlassError> {
+        SdpTimestamp::parse_input(&self.due_by)
+    }
+}
+
+/// Input parameters for the resume_request tool.
+///
+/// Request ID is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ResumeRequestInput {
+    /// The unique ID of the on-hold ticket to resume.
+    pub request_id: String,
+
+    /// Status to resume to. Default: the instance's open status (e.g., 'Open'
+    /// or 'Åben').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Comment on the status change.
+    #[serde(default)]
+    pub comments: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ResumeRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            status: trim_option(&self.status),
+            comments: trim_option(&self.comments),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("comments", &self.comments, MAX_CLOSURE_COMMENTS_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the delete_request tool.
+///
+/// Request ID is required, and `confirm` must be true.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DeleteRequestInput {
+    /// The unique ID of the ticket to move to the trash.
+    pub request_id: String,
+
+    /// Must be true to confirm the deletion. Only set this after the user has explicitly asked to delete the ticket.
+    pub confirm: bool,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl DeleteRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            confirm: self.confirm,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_trashed_requests tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListTrashedRequestsInput {
+    /// Maximum number of tickets to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Number of tickets to skip for pagination (default: 0).
+    #[serde(default)]
+    pub offset: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListTrashedRequestsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            limit: self.limit,
+            offset: self.offset,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the restore_request tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RestoreRequestInput {
+    /// The unique ID of the trashed ticket to restore.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl RestoreRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Request link input structs
+// ============================================================================
+
+/// Input parameters for the link_requests tool.
+///
+/// Both request IDs are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct LinkRequestsInput {
+    /// The unique ID of the ticket to link from.
+    pub request_id: String,
+
+    /// The unique ID of the ticket to link to.
+    pub linked_request_id: String,
+
+    /// Comment explaining why the tickets are related.
+    #[serde(default)]
+    pub comments: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl LinkRequestsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            linked_request_id: self.linked_request_id.trim().to_string(),
+            comments: trim_option(&self.comments),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len(
+            "linked_request_id",
+            &self.linked_request_id,
+            MAX_SHORT_FIELD_LEN,
+        )?;
+        check_option_len("comments", &self.comments, MAX_CLOSURE_COMMENTS_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the unlink_requests tool.
+///
+/// Both request IDs are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UnlinkRequestsInput {
+    /// The unique ID of the ticket to unlink from.
+    pub request_id: String,
+
+    /// The unique ID of the linked ticket to remove.
+    pub linked_request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UnlinkRequestsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            linked_request_id: self.linked_request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len(
+            "linked_request_id",
+            &self.linked_request_id,
+            MAX_SHORT_FIELD_LEN,
+        )?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Problem and change association input structs
+// ============================================================================
+
+/// Input parameters for the associate_request tool.
+///
+/// Exactly one of `problem_id` or `change_id` is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AssociateRequestInput {
+    /// The unique ID of the ticket to associate.
+    pub request_id: String,
+
+    /// The problem to associate the ticket with.
+    #[serde(default)]
+    pub problem_id: Option<String>,
+
+    /// The change to associate the ticket with.
+    #[serde(default)]
+    pub change_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl AssociateRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            problem_id: trim_option(&self.problem_id),
+            change_id: trim_option(&self.change_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates that exactly one target is given and field lengths.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        association_target(&self.problem_id, &self.change_id)?;
+        Ok(())
+    }
+
+    /// Returns whether a problem or change is given, and its ID.
+    pub fn target(&self) -> Result<(AssociationKind, &str), GlassError> {
+        association_target(&self.problem_id, &self.change_id)
+    }
+}
+
+/// Input parameters for the dissociate_request tool.
+///
+/// Exactly one of `problem_id` or `change_id` is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DissociateRequestInput {
+    /// The unique ID of the ticket to dissociate.
+    pub request_id: String,
+
+    /// The associated problem to remove.
+    #[serde(default)]
+    pub problem_id: Option<String>,
+
+    /// The associated change to remove.
+    #[serde(default)]
+    pub change_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl DissociateRequestInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            problem_id: trim_option(&self.problem_id),
+            change_id: trim_option(&self.change_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates that exactly one target is given and field lengths.
+    /// Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        association_target(&self.problem_id, &self.change_id)?;
+        Ok(())
+    }
+
+    /// Returns whether a problem or change is given, and its ID.
+    pub fn target(&self) -> Result<(AssociationKind, &str), GlassError> {
+        association_target(&self.problem_id, &self.change_id)
+    }
+}
+
+/// Picks the problem or change ID when exactly one is given.
+fn association_target<'a>(
+    problem_id: &'a Option<String>,
+    change_id: &'a Option<String>,
+) -> Result<(AssociationKind, &'a str), GlassError> {
+    let target = match (problem_id, change_id) {
+        (Some(id), None) => (AssociationKind::Problem, id.as_str()),
+        (None, Some(id)) => (AssociationKind::Change, id.as_str()),
+        _ => {
+            return Err(GlassError::validation(
+                "provide exactly one of problem_id or change_id",
+            ))
+        }
+    };
+    check_len(
+        &format!("{}_id", target.0.key()),
+        target.1,
+        MAX_SHORT_FIELD_LEN,
+    )?;
+    Ok(target)
+}
+
+// ============================================================================
+// Worklog input structs
+// ============================================================================
+
+/// Input parameters for the list_worklogs tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListWorklogsInput {
+    /// The unique ID of the ticket whose worklogs to list.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListWorklogsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the add_worklog tool.
+///
+/// Request ID and time spent are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddWorklogInput {
+    /// The unique ID of the ticket to log time on.
+    pub request_id: String,
+
+    /// Time spent in minutes (e.g., 90 for 1h 30m).
+    pub time_spent_minutes: u32,
+
+    /// Description of the work performed.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician who did the work (defaults to the API key's technician).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl AddWorklogInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            time_spent_minutes: self.time_spent_minutes,
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("description", &self.description, MAX_CONTENT_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the update_worklog tool.
+///
+/// Request ID and worklog ID are required. At least one other field must be provided.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UpdateWorklogInput {
+    /// The unique ID of the ticket the worklog belongs to.
+    pub request_id: String,
+
+    /// The unique ID of the worklog to update (use list_worklogs to find IDs).
+    pub worklog_id: String,
+
+    /// Corrected time spent in minutes.
+    #[serde(default)]
+    pub time_spent_minutes: Option<u32>,
+
+    /// Corrected description of the work performed.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician the worklog should belong to.
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UpdateWorklogInput {
+    /// Returns true if at least one field besides the IDs is set.
+    pub fn has_updates(&self) -> bool {
+        self.time_spent_minutes.is_some()
+            || self.description.is_some()
+            || self.technician_id.is_some()
+    }
+
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            worklog_id: self.worklog_id.trim().to_string(),
+            time_spent_minutes: self.time_spent_minutes,
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("worklog_id", &self.worklog_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("description", &self.description, MAX_CONTENT_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the delete_worklog tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DeleteWorklogInput {
+    /// The unique ID of the ticket the worklog belongs to.
+    pub request_id: String,
+
+    /// The unique ID of the worklog to delete (use list_worklogs to find IDs).
+    pub worklog_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl DeleteWorklogInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            worklog_id: self.worklog_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("worklog_id", &self.worklog_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Task input structs
+// ============================================================================
+
+/// Input parameters for the list_tasks tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListTasksInput {
+    /// The unique ID of the ticket whose tasks to list.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListTasksInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the create_task tool.
+///
+/// Request ID and title are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CreateTaskInput {
+    /// The unique ID of the ticket to add the task to.
+    pub request_id: String,
+
+    /// Task title (max 250 characters).
+    pub title: String,
+
+    /// Detailed description of the task.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician who owns the task (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Support group to assign the task to.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// Priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// Due date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub due_date: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CreateTaskInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            title: self.title.trim().to_string(),
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            group: trim_option(&self.group),
+            priority: trim_option(&self.priority),
+            due_date: trim_option(&self.due_date),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("due_date", &self.due_date, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the update_task tool.
+///
+/// Request ID and task ID are required. At least one other field must be provided.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UpdateTaskInput {
+    /// The unique ID of the ticket the task belongs to.
+    pub request_id: String,
+
+    /// The unique ID of the task to update (use list_tasks to find IDs).
+    pub task_id: String,
+
+    /// New task title (max 250 characters).
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// New description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician to reassign the task to.
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// New support group.
+    #[serde(default)]
+    pub group: Option<String>,
+
+    /// New task status (e.g., 'Open', 'In Progress', 'Closed').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// New priority level.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// New due date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub due_date: Option<String>,
+
+    /// Completion percentage (0-100).
+    #[serde(default)]
+    pub percentage_completion: Option<u8>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UpdateTaskInput {
+    /// Returns true if at least one field besides the IDs is set.
+    pub fn has_updates(&self) -> bool {
+        self.title.is_some()
+            || self.description.is_some()
+            || self.technician_id.is_some()
+            || self.group.is_some()
+            || self.status.is_some()
+            || self.priority.is_some()
+            || self.due_date.is_some()
+            || self.percentage_completion.is_some()
+    }
+
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            task_id: self.task_id.trim().to_string(),
+            title: trim_option(&self.title),
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            group: trim_option(&self.group),
+            status: trim_option(&self.status),
+            priority: trim_option(&self.priority),
+            due_date: trim_option(&self.due_date),
+            percentage_completion: self.percentage_completion,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("task_id", &self.task_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("due_date", &self.due_date, MAX_SHORT_FIELD_LEN)?;
+        if self.percentage_completion.is_some_and(|p| p > 100) {
+            return Err(GlassError::validation(
+                "percentage_completion must be between 0 and 100",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Input parameters for the complete_task tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CompleteTaskInput {
+    /// The unique ID of the ticket the task belongs to.
+    pub request_id: String,
+
+    /// The unique ID of the task to complete (use list_tasks to find IDs).
+    pub task_id: String,
+
+    /// Status name that marks the task as done on this instance (default: the
+    /// instance's closed status, e.g. 'Closed' or 'Lukket').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CompleteTaskInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: self.request_id.trim().to_string(),
+            task_id: self.task_id.trim().to_string(),
+            status: trim_option(&self.status),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("task_id", &self.task_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Approval input structs
+// ============================================================================
+
+/// Input parameters for the list_projects tool.
+///
+/// All fields are optional.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListProjectsInput {
+    /// Only show projects with this status (e.g., 'Open', 'In Progress', 'Closed').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Maximum number of projects to return (default: 20, max: 100).
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Output format: "text" (default), "json", or "markdown" (tables).
+    #[serde(default)]
+    pub format: Option<OutputFormat>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListProjectsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            status: trim_option(&self.status),
+            limit: self.limit,
+            format: self.format,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the get_project tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetProjectInput {
+    /// The unique ID of the project (use list_projects to find IDs).
+    pub project_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl GetProjectInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            project_id: self.project_id.trim().to_string(),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("project_id", &self.project_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_project_tasks tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListProjectTasksInput {
+    /// The unique ID of the project.
+    pub project_id: String,
+
+    /// Only show tasks under this milestone (use get_project to find milestone IDs).
+    #[serde(default)]
+    pub milestone_id: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListProjectTasksInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            project_id: self.project_id.trim().to_string(),
+            milestone_id: trim_option(&self.milestone_id),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("project_id", &self.project_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("milestone_id", &self.milestone_id, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the create_project tool.
+///
+/// Title is required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CreateProjectInput {
+    /// Project title (max 250 characters).
+    pub title: String,
+
+    /// Detailed description of the project.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician who owns the project (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// Scheduled start date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub start_date: Option<String>,
+
+    /// Scheduled end date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub end_date: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CreateProjectInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            title: self.title.trim().to_string(),
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            priority: trim_option(&self.priority),
+            start_date: trim_option(&self.start_date),
+            end_date: trim_option(&self.end_date),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("start_date", &self.start_date, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("end_date", &self.end_date, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the update_project tool.
+///
+/// Project ID is required. At least one other field must be provided.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UpdateProjectInput {
+    /// The unique ID of the project to update.
+    pub project_id: String,
+
+    /// New project title (max 250 characters).
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// New description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician to make owner of the project.
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// New project status (e.g., 'Open', 'In Progress', 'Closed').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// New priority level.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// New scheduled start date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub start_date: Option<String>,
+
+    /// New scheduled end date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub end_date: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UpdateProjectInput {
+    /// Returns true if at least one field besides the ID is set.
+    pub fn has_updates(&self) -> bool {
+        self.title.is_some()
+            || self.description.is_some()
+            || self.technician_id.is_some()
+            || self.status.is_some()
+            || self.priority.is_some()
+            || self.start_date.is_some()
+            || self.end_date.is_some()
+    }
+
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            project_id: self.project_id.trim().to_string(),
+            title: trim_option(&self.title),
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            status: trim_option(&self.status),
+            priority: trim_option(&self.priority),
+            start_date: trim_option(&self.start_date),
+            end_date: trim_option(&self.end_date),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("project_id", &self.project_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("start_date", &self.start_date, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("end_date", &self.end_date, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the create_project_task tool.
+///
+/// Project ID and title are required.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct CreateProjectTaskInput {
+    /// The unique ID of the project to add the task to.
+    pub project_id: String,
+
+    /// Milestone to file the task under (use get_project to find milestone IDs).
+    #[serde(default)]
+    pub milestone_id: Option<String>,
+
+    /// Task title (max 250 characters).
+    pub title: String,
+
+    /// Detailed description of the task.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician who owns the task (use list_technicians to find IDs).
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// Priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// Due date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub due_date: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl CreateProjectTaskInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            project_id: self.project_id.trim().to_string(),
+            milestone_id: trim_option(&self.milestone_id),
+            title: self.title.trim().to_string(),
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            priority: trim_option(&self.priority),
+            due_date: trim_option(&self.due_date),
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("project_id", &self.project_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("milestone_id", &self.milestone_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("due_date", &self.due_date, MAX_SHORT_FIELD_LEN)?;
+        Ok(())
+    }
+}
+
+/// Input parameters for the update_project_task tool.
+///
+/// Project ID and task ID are required. At least one other field must be provided.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct UpdateProjectTaskInput {
+    /// The unique ID of the project the task belongs to.
+    pub project_id: String,
+
+    /// The unique ID of the task to update (use list_project_tasks to find IDs).
+    pub task_id: String,
+
+    /// New task title (max 250 characters).
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// New description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// ID of the technician to reassign the task to.
+    #[serde(default)]
+    pub technician_id: Option<String>,
+
+    /// New task status (e.g., 'Open', 'In Progress', 'Closed').
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// New priority level.
+    #[serde(default)]
+    pub priority: Option<String>,
+
+    /// New due date (YYYY-MM-DD or YYYY-MM-DD HH:MM).
+    #[serde(default)]
+    pub due_date: Option<String>,
+
+    /// Completion percentage (0-100).
+    #[serde(default)]
+    pub percentage_completion: Option<u8>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl UpdateProjectTaskInput {
+    /// Returns true if at least one field besides the IDs is set.
+    pub fn has_updates(&self) -> bool {
+        self.title.is_some()
+            || self.description.is_some()
+            || self.technician_id.is_some()
+            || self.status.is_some()
+            || self.priority.is_some()
+            || self.due_date.is_some()
+            || self.percentage_completion.is_some()
+    }
+
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            project_id: self.project_id.trim().to_string(),
+            task_id: self.task_id.trim().to_string(),
+            title: trim_option(&self.title),
+            description: trim_option(&self.description),
+            technician_id: trim_option(&self.technician_id),
+            status: trim_option(&self.status),
+            priority: trim_option(&self.priority),
+            due_date: trim_option(&self.due_date),
+            percentage_completion: self.percentage_completion,
+            portal: trim_option(&self.portal),
+        }
+    }
+
+    /// Validates field lengths. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("project_id", &self.project_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("task_id", &self.task_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("title", &self.title, MAX_SUBJECT_LEN)?;
+        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
+        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("due_date", &self.due_date, MAX_SHORT_FIELD_LEN)?;
+        if self.percentage_completion.is_some_and(|p| p > 100) {
+            return Err(GlassError::validation(
+                "percentage_completion must be between 0 and 100",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Input parameters for the list_approvals tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListApprovalsInput {
+    /// The unique ID of the ticket whose approvals to list.
+    pub request_id: String,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
 }
 
-impl GetRequestInput {
+impl ListApprovalsInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
             request_id: self.request_id.trim().to_string(),
+            portal: trim_option(&self.portal),
         }
     }
 
     /// Validates field lengths. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
         check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
         Ok(())
     }
 }
 
-/// Input parameters for the list_technicians tool.
+/// Input parameters for the submit_for_approval tool.
 ///
-/// All fields are optional.
+/// Request ID and at least one approver email are required.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct ListTechniciansInput {
-    /// Filter technicians by support group name.
+pub struct SubmitForApprovalInput {
+    /// The unique ID of the ticket to submit for approval.
+    pub request_id: String,
+
+    /// Email addresses of the people who should approve.
+    pub approver_emails: Vec<String>,
+
+    /// Existing approval level to add the approvers to. If omitted, a new level is created.
     #[serde(default)]
-    pub group: Option<String>,
+    pub approval_level_id: Option<String>,
 
-    /// Maximum number of technicians to return (default: 50).
+    /// Message to include in the approval request.
     #[serde(default)]
-    pub limit: Option<u32>,
+    pub comments: Option<String>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
 }
 
-impl ListTechniciansInput {
+impl SubmitForApprovalInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
-            group: trim_option(&self.group),
-            limit: self.limit,
+            request_id: self.request_id.trim().to_string(),
+            approver_emails: trim_list(&self.approver_emails),
+            approval_level_id: trim_option(&self.approval_level_id),
+            comments: trim_option(&self.comments),
+            portal: trim_option(&self.portal),
         }
     }
 
-    /// Validates field lengths. Call after `sanitize()`.
+    /// Validates field lengths and email format. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
-        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_list_len(
+            "approver_emails",
+            &self.approver_emails,
+            MAX_SHORT_FIELD_LEN,
+        )?;
+        check_emails("approver_emails", &self.approver_emails)?;
+        check_option_len(
+            "approval_level_id",
+            &self.approval_level_id,
+            MAX_SHORT_FIELD_LEN,
+        )?;
+        check_option_len("comments", &self.comments, MAX_CONTENT_LEN)?;
         Ok(())
     }
 }
 
-// ============================================================================
-// Write operation input structs (M4)
-// ============================================================================
-
-/// Input parameters for the create_request tool.
-///
-/// Subject is required. All other fields are optional.
+/// Input parameters for the approve_request and reject_request tools.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct CreateRequestInput {
-    /// Ticket subject/title (required, max 250 characters).
-    pub subject: String,
-
-    /// Detailed description of the issue or request (supports HTML).
-    ///
-    /// SECURITY: HTML content is passed through to SDP without sanitization.
-    /// SDP is responsible for sanitizing HTML on render.
-    #[serde(default)]
-    pub description: Option<String>,
-
-    /// Email address of the person reporting the issue.
-    #[serde(default)]
-    pub requester_email: Option<String>,
-
-    /// Priority level: 'Low', 'Medium', 'High', or 'Urgent'.
-    #[serde(default)]
-    pub priority: Option<String>,
-
-    /// Category name for the ticket (e.g., 'Hardware', 'Software', 'Network').
-    #[serde(default)]
-    pub category: Option<String>,
+pub struct ApprovalDecisionInput {
+    /// The unique ID of the ticket the approval belongs to.
+    pub request_id: String,
 
-    /// Subcategory name (must be valid for the chosen category).
-    #[serde(default)]
-    pub subcategory: Option<String>,
+    /// The approval level ID (use list_approvals to find IDs).
+    pub approval_level_id: String,
 
-    /// Item name (must be valid for the chosen subcategory).
-    #[serde(default)]
-    pub item: Option<String>,
+    /// The approval ID (use list_approvals to find IDs).
+    pub approval_id: String,
 
-    /// Support group to assign the ticket to.
+    /// Comments explaining the decision.
     #[serde(default)]
-    pub group: Option<String>,
+    pub comments: Option<String>,
 
-    /// ID of technician to assign (use list_technicians to find IDs).
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
     #[serde(default)]
-    pub technician_id: Option<String>,
+    pub portal: Option<String>,
 }
 
-impl CreateRequestInput {
+impl ApprovalDecisionInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
-            subject: self.subject.trim().to_string(),
-            description: trim_option(&self.description),
-            requester_email: trim_option(&self.requester_email),
-            priority: trim_option(&self.priority),
-            category: trim_option(&self.category),
-            subcategory: trim_option(&self.subcategory),
-            item: trim_option(&self.item),
-            group: trim_option(&self.group),
-            technician_id: trim_option(&self.technician_id),
+            request_id: self.request_id.trim().to_string(),
+            approval_level_id: self.approval_level_id.trim().to_string(),
+            approval_id: self.approval_id.trim().to_string(),
+            comments: trim_option(&self.comments),
+            portal: trim_option(&self.portal),
         }
     }
 
     /// Validates field lengths. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
-        check_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
-        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
-        check_option_len(
-            "requester_email",
-            &self.requester_email,
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_len(
+            "approval_level_id",
+            &self.approval_level_id,
             MAX_SHORT_FIELD_LEN,
         )?;
-        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("subcategory", &self.subcategory, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("item", &self.item, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
+        check_len("approval_id", &self.approval_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("comments", &self.comments, MAX_CONTENT_LEN)?;
         Ok(())
     }
 }
 
-/// Input parameters for the update_request tool.
+// ============================================================================
+// Reply input structs
+// ============================================================================
+
+/// Input parameters for the survey_results tool.
 ///
-/// Request ID is required. At least one other field must be provided.
+/// Give a request ID for one ticket, or a closed date range to review
+/// recently closed tickets.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct UpdateRequestInput {
-    /// The unique ID of the ticket to update.
-    pub request_id: String,
-
-    /// New subject/title for the ticket (max 250 characters).
+pub struct SurveyResultsInput {
+    /// Show the survey response of this ticket only.
     #[serde(default)]
-    pub subject: Option<String>,
+    pub request_id: Option<String>,
 
-    /// Updated description (supports HTML).
-    ///
-    /// SECURITY: HTML content is passed through to SDP without sanitization.
-    /// SDP is responsible for sanitizing HTML on render.
+    /// Only check tickets closed after this date (YYYY-MM-DD, YYYY-MM-DD HH:MM,
+    /// or RFC 3339). Defaults to 7 days ago.
     #[serde(default)]
-    pub description: Option<String>,
+    pub closed_after: Option<String>,
 
-    /// New priority level: 'Low', 'Medium', 'High', or 'Urgent'.
+    /// Only check tickets closed before this date (YYYY-MM-DD, YYYY-MM-DD HH:MM,
+    /// or RFC 3339).
     #[serde(default)]
-    pub priority: Option<String>,
+    pub closed_before: Option<String>,
 
-    /// New status (e.g., 'Open', 'In Progress', 'On Hold', 'Resolved').
+    /// Only show responses rated at or below this value (e.g., 2 for poor ratings).
     #[serde(default)]
-    pub status: Option<String>,
+    pub max_rating: Option<u32>,
 
-    /// New category name.
+    /// Maximum number of closed tickets to check (default: 20, max: 50).
     #[serde(default)]
-    pub category: Option<String>,
+    pub limit: Option<u32>,
 
-    /// New subcategory name.
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
     #[serde(default)]
-    pub subcategory: Option<String>,
+    pub portal: Option<String>,
+}
 
-    /// New support group.
-    #[serde(default)]
-    pub group: Option<String>,
+impl SurveyResultsInput {
+    /// Sanitizes input by trimming whitespace from all string fields.
+    #[must_use]
+    pub fn sanitize(self) -> Self {
+        Self {
+            request_id: trim_option(&self.request_id),
+            closed_after: trim_option(&self.closed_after),
+            closed_before: trim_option(&self.closed_before),
+            max_rating: self.max_rating,
+            limit: self.limit,
+            portal: trim_option(&self.portal),
+        }
+    }
 
-    /// ID of technician to reassign to.
-    #[serde(default)]
-    pub technician_id: Option<String>,
-}
+    /// Validates field lengths and dates. Call after `sanitize()`.
+    pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("closed_after", &self.closed_after, MAX_SHORT_FIELD_LEN)?;
+        check_option_len("closed_before", &self.closed_before, MAX_SHORT_FIELD_LEN)?;
+        if self.request_id.is_some()
+            && (self.closed_after.is_some() || self.closed_before.is_some())
+        {
+            return Err(GlassError::validation(
+                "give either request_id or a closed_after/closed_before range, not both",
+            ));
+        }
+        self.closed_range()?;
+        Ok(())
+    }
 
-impl UpdateRequestInput {
-    /// Returns true if at least one field besides request_id is set.
-    pub fn has_updates(&self) -> bool {
-        self.subject.is_some()
-            || self.description.is_some()
-            || self.priority.is_some()
-            || self.status.is_some()
-            || self.category.is_some()
-            || self.subcategory.is_some()
-            || self.group.is_some()
-            || self.technician_id.is_some()
+    /// Returns `closed_after` and `closed_before` as epoch milliseconds.
+    pub fn closed_range(&self) -> Result<(Option<i64>, Option<i64>), GlassError> {
+        let after = parse_date_millis("closed_after", &self.closed_after)?;
+        let before = parse_date_millis("closed_before", &self.closed_before)?;
+        if let (Some(a), Some(b)) = (after, before) {
+            if a >= b {
+                return Err(GlassError::validation(
+                    "closed_after must be earlier than closed_before",
+                ));
+            }
+        }
+        Ok((after, before))
     }
+}
+
+/// Input parameters for the list_notifications tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListNotificationsInput {
+    /// The unique ID of the ticket whose sent emails to list.
+    pub request_id: String,
 
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
+
+impl ListNotificationsInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
             request_id: self.request_id.trim().to_string(),
-            subject: trim_option(&self.subject),
-            description: trim_option(&self.description),
-            priority: trim_option(&self.priority),
-            status: trim_option(&self.status),
-            category: trim_option(&self.category),
-            subcategory: trim_option(&self.subcategory),
-            group: trim_option(&self.group),
-            technician_id: trim_option(&self.technician_id),
+            portal: trim_option(&self.portal),
         }
     }
 
     /// Validates field lengths. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
         check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
-        check_option_len("description", &self.description, MAX_DESCRIPTION_LEN)?;
-        check_option_len("priority", &self.priority, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("status", &self.status, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("category", &self.category, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("subcategory", &self.subcategory, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
         Ok(())
     }
 }
 
-/// Input parameters for the close_request tool.
-///
-/// Request ID is required. Closure code and comments are optional.
+/// Input parameters for the get_notification tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct CloseRequestInput {
-    /// The unique ID of the ticket to close.
+pub struct GetNotificationInput {
+    /// The unique ID of the ticket the email was sent on.
     pub request_id: String,
 
-    /// Closure reason code (e.g., 'Success', 'Cancelled', 'Unable to Reproduce').
+    /// The unique ID of the notification (use list_notifications to find IDs).
+    pub notification_id: String,
+
+    /// Also return the body as the original HTML (default: false).
     #[serde(default)]
-    pub closure_code: Option<String>,
+    pub include_html: Option<bool>,
 
-    /// Explanation of how the issue was resolved or why it's being closed.
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
     #[serde(default)]
-    pub closure_comments: Option<String>,
+    pub portal: Option<String>,
 }
 
-impl CloseRequestInput {
+impl GetNotificationInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
             request_id: self.request_id.trim().to_string(),
-            closure_code: trim_option(&self.closure_code),
-            closure_comments: trim_option(&self.closure_comments),
+            notification_id: self.notification_id.trim().to_string(),
+            include_html: self.include_html,
+            portal: trim_option(&self.portal),
         }
     }
 
     /// Validates field lengths. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
         check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("closure_code", &self.closure_code, MAX_SHORT_FIELD_LEN)?;
-        check_option_len(
-            "closure_comments",
-            &self.closure_comments,
-            MAX_CLOSURE_COMMENTS_LEN,
+        check_len(
+            "notification_id",
+            &self.notification_id,
+            MAX_SHORT_FIELD_LEN,
         )?;
         Ok(())
     }
 }
 
-/// Input parameters for the add_note tool.
+/// Input parameters for the reply_to_requester tool.
 ///
 /// Request ID and content are required.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct AddNoteInput {
-    /// The unique ID of the ticket to add a note to.
+pub struct ReplyToRequesterInput {
+    /// The unique ID of the ticket to reply on.
     pub request_id: String,
 
-    /// The note content (supports HTML formatting).
+    /// The reply body. Plain text unless `html` is true.
     ///
-    /// SECURITY: HTML content is passed through to SDP without sanitization.
-    /// SDP is responsible for sanitizing HTML on render.
+    /// SECURITY: When `html` is true, content is passed through to SDP
+    /// without sanitization. SDP is responsible for sanitizing HTML on render.
     pub content: String,
 
-    /// If true, the note will be visible to the requester. Default: false (internal note).
+    /// Recipient email addresses. Defaults to the ticket's requester.
     #[serde(default)]
-    pub show_to_requester: Option<bool>,
+    pub to: Vec<String>,
 
-    /// If true, send notification to assigned technician. Default: false.
+    /// CC email addresses.
     #[serde(default)]
-    pub notify_technician: Option<bool>,
+    pub cc: Vec<String>,
+
+    /// Email subject. Defaults to the ticket subject, tagged so replies thread back to the ticket.
+    #[serde(default)]
+    pub subject: Option<String>,
+
+    /// If true, content is sent as HTML as-is. Default: false (plain text).
+    #[serde(default)]
+    pub html: Option<bool>,
+
+    /// If true, also record the reply as the ticket's first response so the
+    /// first-response SLA clock stops. Default: false.
+    #[serde(default)]
+    pub mark_first_response: Option<bool>,
+
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
 }
 
-impl AddNoteInput {
+impl ReplyToRequesterInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
             request_id: self.request_id.trim().to_string(),
             content: self.content.trim().to_string(),
-            show_to_requester: self.show_to_requester,
-            notify_technician: self.notify_technician,
+            to: trim_list(&self.to),
+            cc: trim_list(&self.cc),
+            subject: trim_option(&self.subject),
+            html: self.html,
+            mark_first_response: self.mark_first_response,
+            portal: trim_option(&self.portal),
         }
     }
 
-    /// Validates field lengths. Call after `sanitize()`.
+    /// Validates field lengths and email format. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
         check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
-        check_len("content", &self.content, MAX_CONTENT_LEN)?;
+        check_len("content", &self.content, MAX_DESCRIPTION_LEN)?;
+        check_list_len("to", &self.to, MAX_SHORT_FIELD_LEN)?;
+        check_emails("to", &self.to)?;
+        check_list_len("cc", &self.cc, MAX_SHORT_FIELD_LEN)?;
+        check_emails("cc", &self.cc)?;
+        check_option_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
         Ok(())
     }
 }
 
-/// Input parameters for the assign_request tool.
+/// Input parameters for the notify tool.
 ///
-/// Request ID is required. At least one of technician_id or group must be provided.
+/// Request ID, recipients, subject, and content are required.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
-pub struct AssignRequestInput {
-    /// The unique ID of the ticket to assign.
+pub struct NotifyInput {
+    /// The unique ID of the ticket the email is about.
     pub request_id: String,
 
-    /// ID of the technician to assign (use list_technicians to find IDs).
+    /// Recipient email addresses (e.g. a manager or vendor contact).
+    pub to: Vec<String>,
+
+    /// CC email addresses.
     #[serde(default)]
-    pub technician_id: Option<String>,
+    pub cc: Vec<String>,
 
-    /// Name of the support group to assign to.
+    /// Email subject.
+    pub subject: String,
+
+    /// The email body. Plain text unless `html` is true.
+    ///
+    /// SECURITY: When `html` is true, content is passed through to SDP
+    /// without sanitization. SDP is responsible for sanitizing HTML on render.
+    pub content: String,
+
+    /// If true, content is sent as HTML as-is. Default: false (plain text).
     #[serde(default)]
-    pub group: Option<String>,
-}
+    pub html: Option<bool>,
 
-impl AssignRequestInput {
-    /// Returns true if at least one of technician_id or group is set.
-    pub fn has_assignment(&self) -> bool {
-        self.technician_id.is_some() || self.group.is_some()
-    }
+    /// ServiceDesk Plus MSP account (portal ID) to work in
+    /// (default: the configured SDP_PORTAL_ID).
+    #[serde(default)]
+    pub portal: Option<String>,
+}
 
+impl NotifyInput {
     /// Sanitizes input by trimming whitespace from all string fields.
     #[must_use]
     pub fn sanitize(self) -> Self {
         Self {
             request_id: self.request_id.trim().to_string(),
-            technician_id: trim_option(&self.technician_id),
-            group: trim_option(&self.group),
+            to: trim_list(&self.to),
+            cc: trim_list(&self.cc),
+            subject: self.subject.trim().to_string(),
+            content: self.content.trim().to_string(),
+            html: self.html,
+            portal: trim_option(&self.portal),
         }
     }
 
-    /// Validates field lengths. Call after `sanitize()`.
+    /// Validates required fields, lengths, and email format. Call after `sanitize()`.
     pub fn validate(&self) -> Result<(), GlassError> {
+        check_option_len("portal", &self.portal, MAX_SHORT_FIELD_LEN)?;
         check_len("request_id", &self.request_id, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("technician_id", &self.technician_id, MAX_SHORT_FIELD_LEN)?;
-        check_option_len("group", &self.group, MAX_SHORT_FIELD_LEN)?;
+        if self.to.is_empty() {
+            return Err(GlassError::validation(
+                "to must contain at least one recipient",
+            ));
+        }
+        if self.subject.is_empty() {
+            return Err(GlassError::validation("subject must not be empty"));
+        }
+        if self.content.is_empty() {
+            return Err(GlassError::validation("content must not be empty"));
+        }
+        check_list_len("to", &self.to, MAX_SHORT_FIELD_LEN)?;
+        check_emails("to", &self.to)?;
+        check_list_len("cc", &self.cc, MAX_SHORT_FIELD_LEN)?;
+        check_emails("cc", &self.cc)?;
+        check_len("subject", &self.subject, MAX_SUBJECT_LEN)?;
+        check_len("content", &self.content, MAX_DESCRIPTION_LEN)?;
         Ok(())
     }
 }
@@ -518,15 +4568,29 @@ mod tests {
     #[test]
     fn test_list_requests_input_sanitize() {
         let input = ListRequestsInput {
+            query: None,
             status: Some("  Åben  ".to_string()),
             priority: Some("".to_string()),
             technician: Some("  Gorm Reventlow  ".to_string()),
             requester: None,
+            group: None,
+            request_type: None,
+            category: None,
+            subcategory: None,
+            item: None,
             open_only: Some(true),
+            overdue_only: None,
+            has_attachments: None,
             created_after: None,
             created_before: None,
+            updated_after: None,
             limit: Some(10),
             offset: None,
+            site: None,
+            sort_by: Some(" Created_Time ".to_string()),
+            sort_order: Some("ASC".to_string()),
+            portal: None,
+            format: None,
         };
         let sanitized = input.sanitize();
         assert_eq!(sanitized.status, Some("Åben".to_string()));
@@ -534,17 +4598,38 @@ mod tests {
         assert_eq!(sanitized.technician, Some("Gorm Reventlow".to_string()));
         assert_eq!(sanitized.open_only, Some(true));
         assert_eq!(sanitized.limit, Some(10));
+        assert_eq!(sanitized.sort_by, Some("created_time".to_string()));
+        assert_eq!(sanitized.sort_order, Some("asc".to_string()));
+        assert!(sanitized.validate().is_ok());
     }
 
     #[test]
     fn test_get_request_input_sanitize() {
         let input = GetRequestInput {
             request_id: "  12345  ".to_string(),
+            portal: None,
+            max_length: None,
+            include_images: None,
+            order: None,
         };
         let sanitized = input.sanitize();
         assert_eq!(sanitized.request_id, "12345");
     }
 
+    #[test]
+    fn test_get_request_input_max_length_range() {
+        let input = |max_length| GetRequestInput {
+            request_id: "12345".to_string(),
+            portal: None,
+            max_length,
+            include_images: None,
+            order: None,
+        };
+        assert!(input(Some(10_000)).validate().is_ok());
+        assert!(input(Some(99)).validate().is_err());
+        assert!(input(Some(100_001)).validate().is_err());
+    }
+
     #[test]
     fn test_create_request_input_sanitize() {
         let input = CreateRequestInput {
@@ -557,6 +4642,9 @@ mod tests {
             item: None,
             group: None,
             technician_id: None,
+            site: None,
+            custom_fields: None,
+            portal: None,
         };
         let sanitized = input.sanitize();
         assert_eq!(sanitized.subject, "Test subject");
@@ -575,6 +4663,8 @@ mod tests {
             content: "  Note content  ".to_string(),
             show_to_requester: Some(true),
             notify_technician: None,
+            mark_first_response: None,
+            portal: None,
         };
         let sanitized = input.sanitize();
         assert_eq!(sanitized.request_id, "123");
@@ -603,6 +4693,31 @@ mod tests {
         assert_eq!(input.limit, Some(10));
     }
 
+    #[test]
+    fn test_export_requests_csv_input_validate() {
+        let input: ExportRequestsCsvInput = serde_json::from_str(
+            r#"{"status": " Open ", "sort_by": "Created_Time", "file_name": "march.csv"}"#,
+        )
+        .unwrap();
+        let input = input.sanitize();
+        assert!(input.validate().is_ok());
+        let filters = input.filters();
+        assert_eq!(filters.status.as_deref(), Some("Open"));
+        assert_eq!(filters.sort_by.as_deref(), Some("created_time"));
+        assert_eq!(filters.limit, None);
+
+        for json in [
+            r#"{"max_rows": 0}"#,
+            r#"{"max_rows": 5001}"#,
+            r#"{"file_name": "../etc/passwd"}"#,
+            r#"{"file_name": ".hidden"}"#,
+            r#"{"sort_by": "requester"}"#,
+        ] {
+            let input: ExportRequestsCsvInput = serde_json::from_str(json).unwrap();
+            assert!(input.sanitize().validate().is_err(), "{} should fail", json);
+        }
+    }
+
     #[test]
     fn test_get_request_input_deserialize() {
         let json = r#"{"request_id": "12345"}"#;
@@ -720,6 +4835,9 @@ mod tests {
             item: None,
             group: None,
             technician_id: None,
+            site: None,
+            custom_fields: None,
+            portal: None,
         };
         assert!(input.validate().is_ok());
     }
@@ -736,6 +4854,9 @@ mod tests {
             item: None,
             group: None,
             technician_id: None,
+            site: None,
+            custom_fields: None,
+            portal: None,
         };
         let err = input.validate().unwrap_err();
         assert!(err.to_string().contains("subject"));
@@ -754,6 +4875,9 @@ mod tests {
             item: None,
             group: None,
             technician_id: None,
+            site: None,
+            custom_fields: None,
+            portal: None,
         };
         let err = input.validate().unwrap_err();
         assert!(err.to_string().contains("description"));
@@ -767,6 +4891,8 @@ mod tests {
             content: "x".repeat(32_769),
             show_to_requester: None,
             notify_technician: None,
+            mark_first_response: None,
+            portal: None,
         };
         let err = input.validate().unwrap_err();
         assert!(err.to_string().contains("content"));
@@ -779,6 +4905,7 @@ mod tests {
             request_id: "123".to_string(),
             closure_code: None,
             closure_comments: Some("x".repeat(8_193)),
+            portal: None,
         };
         let err = input.validate().unwrap_err();
         assert!(err.to_string().contains("closure_comments"));
@@ -788,15 +4915,29 @@ mod tests {
     #[test]
     fn test_list_requests_validate_short_field_too_long() {
         let input = ListRequestsInput {
+            query: None,
             status: Some("x".repeat(501)),
             priority: None,
             technician: None,
             requester: None,
+            group: None,
+            request_type: None,
+            category: None,
+            subcategory: None,
+            item: None,
             open_only: None,
+            overdue_only: None,
+            has_attachments: None,
             created_after: None,
             created_before: None,
+            updated_after: None,
             limit: None,
             offset: None,
+            site: None,
+            sort_by: None,
+            sort_order: None,
+            portal: None,
+            format: None,
         };
         let err = input.validate().unwrap_err();
         assert!(err.to_string().contains("status"));
@@ -815,6 +4956,8 @@ mod tests {
             subcategory: None,
             group: None,
             technician_id: None,
+            custom_fields: None,
+            portal: None,
         };
         assert!(input.validate().is_ok());
     }
@@ -825,7 +4968,630 @@ mod tests {
             request_id: "123".to_string(),
             technician_id: Some("456".to_string()),
             group: None,
+            portal: None,
+        };
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_worklog_input_has_updates() {
+        let json = r#"{"request_id": "123", "worklog_id": "456"}"#;
+        let input: UpdateWorklogInput = serde_json::from_str(json).unwrap();
+        assert!(!input.has_updates());
+
+        let json = r#"{"request_id": "123", "worklog_id": "456", "time_spent_minutes": 45}"#;
+        let input: UpdateWorklogInput = serde_json::from_str(json).unwrap();
+        assert!(input.has_updates());
+        assert_eq!(input.time_spent_minutes, Some(45));
+    }
+
+    #[test]
+    fn test_delete_worklog_input_sanitize() {
+        let input = DeleteWorklogInput {
+            request_id: " 123 ".to_string(),
+            worklog_id: " 456 ".to_string(),
+            portal: None,
+        };
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.request_id, "123");
+        assert_eq!(sanitized.worklog_id, "456");
+    }
+
+    #[test]
+    fn test_create_task_input_sanitize() {
+        let json = r#"{"request_id": " 123 ", "title": "  Order screen  ", "due_date": " "}"#;
+        let input: CreateTaskInput = serde_json::from_str(json).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.request_id, "123");
+        assert_eq!(sanitized.title, "Order screen");
+        assert_eq!(sanitized.due_date, None);
+    }
+
+    #[test]
+    fn test_update_task_validate_percentage() {
+        let json = r#"{"request_id": "123", "task_id": "456", "percentage_completion": 101}"#;
+        let input: UpdateTaskInput = serde_json::from_str(json).unwrap();
+        assert!(input.has_updates());
+        let err = input.validate().unwrap_err();
+        assert!(err.to_string().contains("percentage_completion"));
+    }
+
+    #[test]
+    fn test_submit_for_approval_input_sanitize_drops_blank_emails() {
+        let json = r#"{"request_id": "123", "approver_emails": [" lm@example.com ", "  "]}"#;
+        let input: SubmitForApprovalInput = serde_json::from_str(json).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(
+            sanitized.approver_emails,
+            vec!["lm@example.com".to_string()]
+        );
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_check_emails_rejects_invalid() {
+        let err = check_emails("approver_emails", &["not-an-email".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("invalid email"));
+        assert!(check_emails("to", &["a@b".to_string()]).is_err());
+        assert!(check_emails("to", &["a b@example.com".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_check_list_len_rejects_too_many() {
+        let values = vec!["a@example.com".to_string(); 51];
+        let err = check_list_len("approver_emails", &values, 500).unwrap_err();
+        assert!(err.to_string().contains("50 entries"));
+    }
+
+    #[test]
+    fn test_reply_to_requester_input_defaults() {
+        let json = r#"{"request_id": "123", "content": "Hello"}"#;
+        let input: ReplyToRequesterInput = serde_json::from_str(json).unwrap();
+        assert!(input.to.is_empty());
+        assert!(input.cc.is_empty());
+        assert!(input.subject.is_none());
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_reply_to_requester_validate_bad_cc() {
+        let json = r#"{"request_id": "123", "content": "Hello", "cc": ["nope"]}"#;
+        let input: ReplyToRequesterInput = serde_json::from_str(json).unwrap();
+        let err = input.sanitize().validate().unwrap_err();
+        assert!(err.to_string().contains("cc"));
+    }
+
+    #[test]
+    fn test_add_resolution_input_validate_too_long() {
+        let input = AddResolutionInput {
+            request_id: "123".to_string(),
+            content: "x".repeat(MAX_CONTENT_LEN + 1),
+            portal: None,
+        };
+        let err = input.sanitize().validate().unwrap_err();
+        assert!(err.to_string().contains("content"));
+    }
+
+    #[test]
+    fn test_resolve_request_input_sanitize() {
+        let json = r#"{"request_id": " 123 ", "resolution": " Fixed ", "status": "  "}"#;
+        let input: ResolveRequestInput = serde_json::from_str(json).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.request_id, "123");
+        assert_eq!(sanitized.resolution, "Fixed");
+        assert_eq!(sanitized.status, None);
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_link_requests_input_sanitize() {
+        let json = r#"{"request_id": " 1 ", "linked_request_id": " 2 ", "comments": " "}"#;
+        let input: LinkRequestsInput = serde_json::from_str(json).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.request_id, "1");
+        assert_eq!(sanitized.linked_request_id, "2");
+        assert_eq!(sanitized.comments, None);
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_delete_request_input_requires_confirm() {
+        let missing: Result<DeleteRequestInput, _> =
+            serde_json::from_str(r#"{"request_id": "123"}"#);
+        assert!(missing.is_err());
+
+        let input: DeleteRequestInput =
+            serde_json::from_str(r#"{"request_id": "123", "confirm": true}"#).unwrap();
+        assert!(input.confirm);
+    }
+
+    #[test]
+    fn test_hold_request_input_sanitize() {
+        let json = r#"{"request_id": "42", "reason": " Vendor ", "resume_at": " 2026-03-01 "}"#;
+        let input: HoldRequestInput = serde_json::from_str(json).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.reason.as_deref(), Some("Vendor"));
+        assert_eq!(sanitized.resume_at.as_deref(), Some("2026-03-01"));
+        assert!(sanitized.resume_status.is_none());
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_find_similar_requests_input_validate() {
+        let input: FindSimilarRequestsInput =
+            serde_json::from_str(r#"{"text": " VPN drops after login "}"#).unwrap();
+        let input = input.sanitize();
+        assert_eq!(input.text.as_deref(), Some("VPN drops after login"));
+        assert!(input.validate().is_ok());
+
+        let both: FindSimilarRequestsInput =
+            serde_json::from_str(r#"{"request_id": "42", "text": "VPN"}"#).unwrap();
+        assert!(both.sanitize().validate().is_err());
+
+        let neither: FindSimilarRequestsInput =
+            serde_json::from_str(r#"{"category": "Network"}"#).unwrap();
+        assert!(neither.sanitize().validate().is_err());
+    }
+
+    #[test]
+    fn test_suggest_priority_input_validate() {
+        let input: SuggestPriorityInput =
+            serde_json::from_str(r#"{"impact": " High ", "urgency": "Low"}"#).unwrap();
+        let input = input.sanitize();
+        assert_eq!(input.impact.as_deref(), Some("High"));
+        assert!(input.validate().is_ok());
+
+        let input: SuggestPriorityInput = serde_json::from_str(r#"{"impact": "High"}"#).unwrap();
+        assert!(input.sanitize().validate().is_err());
+
+        let input: SuggestPriorityInput = serde_json::from_str(r#"{"request_id": "42"}"#).unwrap();
+        assert!(input.sanitize().validate().is_ok());
+    }
+
+    #[test]
+    fn test_set_due_date_input_validate() {
+        let input: SetDueDateInput = serde_json::from_str(
+            r#"{"request_id": " 42 ", "due_by": " 2026-03-01T16:00:00+00:00 "}"#,
+        )
+        .unwrap();
+        let input = input.sanitize();
+        assert!(input.validate().is_ok());
+        assert_eq!(
+            input.due_timestamp().unwrap().epoch_millis(),
+            Some(1_772_380_800_000)
+        );
+
+        let input: SetDueDateInput =
+            serde_json::from_str(r#"{"request_id": "42", "due_by": "next friday"}"#).unwrap();
+        let err = input.sanitize().validate().unwrap_err().to_string();
+        assert!(err.contains("due_by"));
+    }
+
+    #[test]
+    fn test_list_categories_input_subcategory_requires_category() {
+        let input: ListCategoriesInput =
+            serde_json::from_str(r#"{"subcategory": "Laptop"}"#).unwrap();
+        assert!(input.sanitize().validate().is_err());
+
+        let input: ListCategoriesInput =
+            serde_json::from_str(r#"{"category": "Hardware", "subcategory": "Laptop"}"#).unwrap();
+        assert!(input.sanitize().validate().is_ok());
+    }
+
+    #[test]
+    fn test_search_requesters_input_sanitize() {
+        let input: SearchRequestersInput =
+            serde_json::from_str(r#"{"query": "  anna@example.com "}"#).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.query, "anna@example.com");
+        assert!(sanitized.limit.is_none());
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_search_assets_input_sanitize() {
+        let input: SearchAssetsInput =
+            serde_json::from_str(r#"{"query": " DK-0421  ", "limit": 5}"#).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.query, "DK-0421");
+        assert_eq!(sanitized.limit, Some(5));
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_list_user_assets_input_sanitize() {
+        let input: ListUserAssetsInput =
+            serde_json::from_str(r#"{"requester": "  anna@example.com "}"#).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.requester, "anna@example.com");
+        assert!(sanitized.limit.is_none());
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_search_solutions_input_sanitize() {
+        let input: SearchSolutionsInput =
+            serde_json::from_str(r#"{"query": "  vpn sleep  "}"#).unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.query, "vpn sleep");
+        assert!(sanitized.validate().is_ok());
+
+        let long = SearchSolutionsInput {
+            query: "x".repeat(MAX_SHORT_FIELD_LEN + 1),
+            limit: None,
+            format: None,
+            portal: None,
+        };
+        assert!(long.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_solution_input_sanitize() {
+        let input: CreateSolutionInput = serde_json::from_str(
+            r#"{"request_id": " 123 ", "topic": " Network ", "title": "   ", "keywords": " vpn "}"#,
+        )
+        .unwrap();
+        let sanitized = input.sanitize();
+        assert_eq!(sanitized.request_id, "123");
+        assert_eq!(sanitized.topic, "Network");
+        assert!(sanitized.title.is_none());
+        assert_eq!(sanitized.keywords.as_deref(), Some("vpn"));
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn test_update_project_input_has_updates() {
+        let input: UpdateProjectInput = serde_json::from_str(r#"{"project_id": " 61 "}"#).unwrap();
+        let input = input.sanitize();
+        assert_eq!(input.project_id, "61");
+        assert!(!input.has_updates());
+
+        let input: UpdateProjectInput =
+            serde_json::from_str(r#"{"project_id": "61", "status": " Closed "}"#).unwrap();
+        let input = input.sanitize();
+        assert!(input.has_updates());
+        assert_eq!(input.status.as_deref(), Some("Closed"));
+    }
+
+    #[test]
+    fn test_update_project_task_input_rejects_bad_percentage() {
+        let input: UpdateProjectTaskInput = serde_json::from_str(
+            r#"{"project_id": "61", "task_id": "9", "percentage_completion": 120}"#,
+        )
+        .unwrap();
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_ticket_statistics_input_group_by() {
+        let input: TicketStatisticsInput =
+            serde_json::from_str(r#"{"group_by": " Technician "}"#).unwrap();
+        let input = input.sanitize();
+        assert_eq!(input.group_by, "technician");
+        assert!(input.validate().is_ok());
+
+        let input: TicketStatisticsInput =
+            serde_json::from_str(r#"{"group_by": "category"}"#).unwrap();
+        let err = input.sanitize().validate().unwrap_err();
+        assert!(err.to_string().contains("group_by must be one of"));
+    }
+
+    #[test]
+    fn test_due_soon_validate_hours() {
+        let input = |hours| DueSoonInput {
+            hours,
+            group: None,
+            technician: None,
+            limit: None,
+            format: None,
+            portal: None,
+        };
+        assert!(input(None).validate().is_ok());
+        assert!(input(Some(4)).validate().is_ok());
+        assert!(input(Some(0)).validate().is_err());
+        assert!(input(Some(MAX_DUE_SOON_HOURS + 1)).validate().is_err());
+    }
+
+    #[test]
+    fn test_search_requests_rejects_blank_query() {
+        let input = SearchRequestsInput {
+            query: "   ".to_string(),
+            include_notes: None,
+            open_only: None,
+            limit: None,
+            portal: None,
+            format: None,
+        }
+        .sanitize();
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_bulk_update_sanitize_dedupes_ids() {
+        let input = BulkUpdateRequestsInput {
+            request_ids: vec![" 101 ".into(), "102".into(), "101".into(), " ".into()],
+            priority: None,
+            status: Some(" On Hold ".into()),
+            category: None,
+            subcategory: None,
+            group: None,
+            technician_id: None,
+            portal: None,
+        }
+        .sanitize();
+        assert_eq!(input.request_ids, vec!["101", "102"]);
+        assert!(input.validate().is_ok());
+        assert_eq!(input.to_update("102").status.as_deref(), Some("On Hold"));
+    }
+
+    #[test]
+    fn test_bulk_update_validate_requires_ids_and_fields() {
+        let mut input = BulkUpdateRequestsInput {
+            request_ids: vec![],
+            priority: Some("High".into()),
+            status: None,
+            category: None,
+            subcategory: None,
+            group: None,
+            technician_id: None,
+            portal: None,
+        };
+        assert!(input.validate().is_err());
+        input.request_ids = vec!["101".into()];
+        assert!(input.validate().is_ok());
+        input.priority = None;
+        assert!(input.validate().is_err());
+        input.request_ids = (0..=MAX_LIST_ITEMS).map(|i| i.to_string()).collect();
+        input.priority = Some("High".into());
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_bulk_close_validate() {
+        let input = BulkCloseInput {
+            request_ids: vec!["101".into(), " 101".into()],
+            closure_code: Some(" Cancelled ".into()),
+            closure_comments: Some("x".repeat(MAX_CLOSURE_COMMENTS_LEN + 1)),
+            confirm: true,
+            portal: None,
+        }
+        .sanitize();
+        assert_eq!(input.request_ids, vec!["101"]);
+        assert_eq!(input.closure_code.as_deref(), Some("Cancelled"));
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_bulk_assign_requires_target() {
+        let mut input = BulkAssignInput {
+            request_ids: vec!["101".into(), "102".into()],
+            technician_id: Some("  ".into()),
+            group: None,
+            portal: None,
+        }
+        .sanitize();
+        assert!(input.validate().is_err());
+        input.group = Some("Network".into());
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_custom_fields_sanitize_and_validate() {
+        let json = r#"{
+            "request_id": "123",
+            "custom_fields": {" udf_sline_301 ": "  PO-7781 ", "udf_pick_302": {"name": "Gold"}}
+        }"#;
+        let input: UpdateRequestInput = serde_json::from_str(json).unwrap();
+        let input = input.sanitize();
+        assert!(input.has_updates());
+        let fields = input.custom_fields.as_ref().unwrap();
+        assert_eq!(fields["udf_sline_301"], "PO-7781");
+        assert!(input.validate().is_ok());
+
+        let json = r#"{"subject": "x", "custom_fields": {"cost center": "42"}}"#;
+        let input: CreateRequestInput = serde_json::from_str(json).unwrap();
+        let err = input.sanitize().validate().unwrap_err();
+        assert!(err.to_string().contains("custom_fields"));
+    }
+
+    #[test]
+    fn test_notify_input_validate() {
+        let input = NotifyInput {
+            request_id: "123".into(),
+            to: vec![" vendor@example.com ".into()],
+            cc: vec![],
+            subject: " Escalation ".into(),
+            content: "Please call us".into(),
+            html: None,
+            portal: None,
+        }
+        .sanitize();
+        assert_eq!(input.to, vec!["vendor@example.com"]);
+        assert!(input.validate().is_ok());
+
+        let mut missing_to = input.clone();
+        missing_to.to.clear();
+        assert!(missing_to.validate().is_err());
+
+        let mut bad_cc = input;
+        bad_cc.cc = vec!["not-an-email".into()];
+        assert!(bad_cc.validate().is_err());
+    }
+
+    #[test]
+    fn test_escalate_request_requires_reason() {
+        let input = EscalateRequestInput {
+            request_id: "123".into(),
+            reason: "   ".into(),
+            priority: None,
+            group: Some(" Tier 2 ".into()),
+            technician_id: None,
+            portal: None,
+        }
+        .sanitize();
+        assert_eq!(input.group.as_deref(), Some("Tier 2"));
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_change_requester_validate() {
+        let input = |requester: &str| ChangeRequesterInput {
+            request_id: "123".into(),
+            requester: requester.into(),
+            portal: None,
         };
+        assert!(input(" anna@example.com ").sanitize().validate().is_ok());
+        assert!(input("901").validate().is_ok());
+        assert!(input("Anna Jensen").validate().is_err());
+        assert!(input("anna@").validate().is_err());
+    }
+
+    #[test]
+    fn test_cc_recipients_validate() {
+        let input = CcRecipientsInput {
+            request_id: "123".into(),
+            emails: vec![" lead@example.com ".into(), "".into()],
+            portal: None,
+        }
+        .sanitize();
+        assert_eq!(input.emails, vec!["lead@example.com"]);
         assert!(input.validate().is_ok());
+
+        let empty = CcRecipientsInput {
+            request_id: "123".into(),
+            emails: vec!["  ".into()],
+            portal: None,
+        }
+        .sanitize();
+        assert!(empty.validate().is_err());
+    }
+
+    #[test]
+    fn test_associate_request_requires_one_target() {
+        let input = |problem_id: Option<&str>, change_id: Option<&str>| {
+            AssociateRequestInput {
+                request_id: " 7 ".to_string(),
+                problem_id: problem_id.map(String::from),
+                change_id: change_id.map(String::from),
+                portal: None,
+            }
+            .sanitize()
+        };
+        let problem = input(Some(" 31 "), None);
+        assert!(problem.validate().is_ok());
+        assert_eq!(problem.target().unwrap(), (AssociationKind::Problem, "31"));
+        assert_eq!(
+            input(None, Some("12")).target().unwrap(),
+            (AssociationKind::Change, "12")
+        );
+        assert!(input(Some("31"), Some("12")).validate().is_err());
+        assert!(input(None, None).validate().is_err());
+    }
+
+    #[test]
+    fn test_find_duplicates_requires_one_source() {
+        let input = |request_id: Option<&str>, subject: Option<&str>| {
+            FindDuplicateRequestsInput {
+                request_id: request_id.map(String::from),
+                subject: subject.map(String::from),
+                days: None,
+                limit: None,
+                portal: None,
+            }
+            .sanitize()
+        };
+        assert!(input(Some("123"), None).validate().is_ok());
+        assert!(input(None, Some("VPN down")).validate().is_ok());
+        assert!(input(None, None).validate().is_err());
+        assert!(input(Some("123"), Some("VPN down")).validate().is_err());
+        assert!(input(Some(" "), None).validate().is_err());
+    }
+
+    #[test]
+    fn test_list_requests_validate_sort() {
+        let input = |sort_by: &str, sort_order: &str| ListRequestsInput {
+            query: None,
+            status: None,
+            priority: None,
+            technician: None,
+            requester: None,
+            group: None,
+            request_type: None,
+            category: None,
+            subcategory: None,
+            item: None,
+            open_only: None,
+            overdue_only: None,
+            has_attachments: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            limit: None,
+            offset: None,
+            site: None,
+            sort_by: Some(sort_by.to_string()),
+            sort_order: Some(sort_order.to_string()),
+            portal: None,
+            format: None,
+        };
+        assert!(input("due_by_time", "desc").validate().is_ok());
+        let err = input("requester", "asc").validate().unwrap_err();
+        assert!(err.to_string().contains("sort_by must be one of"));
+        let err = input("created_time", "oldest").validate().unwrap_err();
+        assert!(err.to_string().contains("sort_order must be one of"));
+    }
+
+    #[test]
+    fn test_list_requests_created_range() {
+        let input = |after: Option<&str>, before: Option<&str>| ListRequestsInput {
+            query: None,
+            status: None,
+            priority: None,
+            technician: None,
+            requester: None,
+            group: None,
+            request_type: None,
+            category: None,
+            subcategory: None,
+            item: None,
+            open_only: None,
+            overdue_only: None,
+            has_attachments: None,
+            created_after: after.map(String::from),
+            created_before: before.map(String::from),
+            updated_after: None,
+            limit: None,
+            offset: None,
+            site: None,
+            sort_by: None,
+            sort_order: None,
+            portal: None,
+            format: None,
+        };
+        let (after, before) = input(Some("2026-02-01T00:00:00Z"), None)
+            .created_range()
+            .unwrap();
+        assert_eq!(after, Some(1_769_904_000_000));
+        assert_eq!(before, None);
+
+        assert!(input(Some("2026-01-01"), Some("2026-02-01"))
+            .validate()
+            .is_ok());
+        let err = input(Some("last week"), None).validate().unwrap_err();
+        assert!(err.to_string().contains("created_after must be a date"));
+        let err = input(Some("2026-02-01"), Some("2026-01-01"))
+            .validate()
+            .unwrap_err();
+        assert!(err.to_string().contains("earlier than created_before"));
+
+        let mut updated = input(None, None);
+        updated.updated_after = Some("2026-02-01T09:00:00Z".to_string());
+        assert_eq!(
+            updated.updated_after_millis().unwrap(),
+            Some(1_769_936_400_000)
+        );
+        updated.updated_after = Some("an hour ago".to_string());
+        let err = updated.validate().unwrap_err();
+        assert!(err.to_string().contains("updated_after must be a date"));
     }
 }